@@ -0,0 +1,251 @@
+use std::{ collections::BTreeMap, fs, path::{ Path, PathBuf } };
+
+use casper_types::{ account::AccountHash, ContractHash, Key, U512 };
+use common_lib::enums::{ registration_mode::RegistrationMode, roles::Role };
+use serde::Deserialize;
+
+use crate::error::{ AResult, AdminError };
+
+const DEFAULT_PAYMENT_AMOUNT: &str = "5000000000";
+const DEFAULT_INSTALL_PAYMENT: &str = "300000000000";
+const DEFAULT_TTL: u64 = 30 * 60 * 1000;
+const DEFAULT_GAS_PRICE: u64 = 1;
+const DEFAULT_DEPLOY_TIMEOUT: u64 = 300;
+
+/**
+ * Whole system in one file, `.json` files are read as JSON, the others as TOML.
+ * Contracts and their hashes are referenced by name with `@`: `@administration`,
+ * `@registry`, `@price_oracle`, `@main`, `@nft`, `@treasury`, `@database:<ext>` and `@nft_core:<ext>`.
+ */
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+	pub network: NetworkConfig,
+	pub contracts: ContractsConfig,
+	#[serde(default)]
+	pub extensions: Vec<ExtensionConfig>,
+	/// Role name (`PRICE_SETTER`, ...) to the accounts or contracts granted with it
+	#[serde(default)]
+	pub roles: BTreeMap<String, Vec<String>>,
+}
+
+/// ttl - milliseconds, deploy_timeout - seconds the execution of a sent deploy is awaited
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkConfig {
+	pub node_address: String,
+	pub chain_name: String,
+	pub secret_key: PathBuf,
+	pub payment_amount: Option<String>,
+	pub ttl: Option<u64>,
+	pub gas_price: Option<u64>,
+	pub deploy_timeout: Option<u64>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ContractsConfig {
+	pub administration: ContractConfig,
+	pub registry: ContractConfig,
+	pub price_oracle: ContractConfig,
+	pub main: ContractConfig,
+	pub nft: ContractConfig,
+	pub transfer_filter: ContractConfig,
+	pub treasury: ContractConfig,
+}
+
+/**
+ * wasm - installed when `hash` is not set
+ * args - additional install arguments
+ * whitelist - contracts whitelisted by the registry, the registry only
+ */
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ContractConfig {
+	pub wasm: Option<PathBuf>,
+	pub install_payment: Option<String>,
+	pub hash: Option<String>,
+	#[serde(default)]
+	pub args: BTreeMap<String, ArgValue>,
+	#[serde(default)]
+	pub whitelist: Vec<String>,
+}
+
+/// Unset limits are left as they are on chain
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExtensionConfig {
+	pub name: String,
+	pub chars_min_count: Option<u8>,
+	pub max_chars_count: Option<u8>,
+	pub max_subscription_years: Option<u8>,
+	pub grace_period: Option<u64>,
+	pub subdomain_limit: Option<u8>,
+	pub registration_mode: Option<String>,
+	pub fee_recipient: Option<String>,
+	pub price: Option<PriceConfig>,
+	#[serde(default)]
+	pub database: ContractConfig,
+	#[serde(default)]
+	pub nft_core: ContractConfig,
+}
+
+/// Fixed price unless `mid` or `more` is set, `more` is the price above the last `mid` step
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PriceConfig {
+	pub price: String,
+	#[serde(default)]
+	pub mid: Vec<PriceStep>,
+	pub more: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PriceStep {
+	pub chars_count: u64,
+	pub price: String,
+}
+
+/**
+ * Install argument: strings, integers (`u64`) and booleans are taken as they are,
+ * other types are written as `{ type = "u8", value = "1" }`, see `plan::to_cl_value`
+ */
+#[derive(Deserialize, Clone)]
+#[serde(untagged)]
+pub enum ArgValue {
+	Bool(bool),
+	Integer(u64),
+	Text(String),
+	Typed {
+		#[serde(rename = "type")]
+		cl_type: String,
+		value: String,
+	},
+}
+
+impl Config {
+	pub fn load(path: &Path) -> AResult<Self> {
+		let content = fs::read_to_string(path)?;
+		let config: Config = if path.extension().is_some_and(|ext| ext == "json") {
+			serde_json
+				::from_str(&content)
+				.map_err(|error| AdminError::Config(error.to_string()))?
+		} else {
+			toml::from_str(&content).map_err(|error| AdminError::Config(error.to_string()))?
+		};
+
+		// Relative paths are relative to the config file
+		let base = path.parent().map(Path::to_path_buf).unwrap_or_default();
+		Ok(config.with_base(&base))
+	}
+
+	fn with_base(mut self, base: &Path) -> Self {
+		self.network.secret_key = base.join(&self.network.secret_key);
+		let contracts = [
+			&mut self.contracts.administration,
+			&mut self.contracts.registry,
+			&mut self.contracts.price_oracle,
+			&mut self.contracts.main,
+			&mut self.contracts.nft,
+			&mut self.contracts.transfer_filter,
+			&mut self.contracts.treasury
+		];
+		for contract in contracts {
+			contract.wasm = contract.wasm.as_ref().map(|wasm| base.join(wasm));
+		}
+		for extension in self.extensions.iter_mut() {
+			for contract in [&mut extension.database, &mut extension.nft_core] {
+				contract.wasm = contract.wasm.as_ref().map(|wasm| base.join(wasm));
+			}
+		}
+		self
+	}
+}
+
+impl NetworkConfig {
+	pub fn payment_amount(&self) -> AResult<U512> {
+		parse_u512(self.payment_amount.as_deref().unwrap_or(DEFAULT_PAYMENT_AMOUNT))
+	}
+
+	pub fn ttl(&self) -> u64 {
+		self.ttl.unwrap_or(DEFAULT_TTL)
+	}
+
+	pub fn gas_price(&self) -> u64 {
+		self.gas_price.unwrap_or(DEFAULT_GAS_PRICE)
+	}
+
+	pub fn deploy_timeout(&self) -> u64 {
+		self.deploy_timeout.unwrap_or(DEFAULT_DEPLOY_TIMEOUT)
+	}
+}
+
+impl ContractConfig {
+	pub fn install_payment(&self) -> AResult<U512> {
+		parse_u512(self.install_payment.as_deref().unwrap_or(DEFAULT_INSTALL_PAYMENT))
+	}
+}
+
+impl PriceConfig {
+	pub fn is_dynamic(&self) -> bool {
+		!self.mid.is_empty() || self.more.is_some()
+	}
+}
+
+// Parsers of the values written in the config
+
+pub fn parse_u512(value: &str) -> AResult<U512> {
+	U512::from_dec_str(value).map_err(|_| {
+		AdminError::Config(format!("`{}` is not a decimal U512", value))
+	})
+}
+
+/// `account-hash-<hex>`, `hash-<hex>` or `uref-<hex>-<rights>`
+pub fn parse_key(value: &str) -> AResult<Key> {
+	Key::from_formatted_str(value).map_err(|error| {
+		AdminError::Config(format!("`{}` is not a key: {}", value, error))
+	})
+}
+
+pub fn parse_account_hash(value: &str) -> AResult<AccountHash> {
+	AccountHash::from_formatted_str(value).map_err(|error| {
+		AdminError::Config(format!("`{}` is not an account hash: {}", value, error))
+	})
+}
+
+/// `contract-<hex>` or `hash-<hex>`
+pub fn parse_contract_hash(value: &str) -> AResult<ContractHash> {
+	let formatted = match value.strip_prefix("hash-") {
+		Some(hex) => format!("contract-{}", hex),
+		None => value.to_string(),
+	};
+	ContractHash::from_formatted_str(&formatted).map_err(|error| {
+		AdminError::Config(format!("`{}` is not a contract hash: {:?}", value, error))
+	})
+}
+
+pub fn parse_role(value: &str) -> AResult<Role> {
+	[
+		Role::Admin,
+		Role::PriceSetter,
+		Role::ExtensionManager,
+		Role::ShardManager,
+		Role::MarketplaceOperator,
+		Role::Guardian,
+		Role::TreasuryManager,
+		Role::SystemContract,
+	]
+		.iter()
+		.find(|role| role.to_string() == value)
+		.copied()
+		.ok_or_else(|| AdminError::Config(format!("unknown role `{}`", value)))
+}
+
+pub fn parse_registration_mode(value: &str) -> AResult<RegistrationMode> {
+	match value {
+		"open" => Ok(RegistrationMode::Open),
+		"allowlisted" => Ok(RegistrationMode::Allowlisted),
+		_ => Err(AdminError::Config(format!("unknown registration mode `{}`", value))),
+	}
+}
@@ -0,0 +1,224 @@
+use std::{ path::Path, time::{ SystemTime, UNIX_EPOCH } };
+
+use casper_execution_engine::core::engine_state::executable_deploy_item::ExecutableDeployItem;
+use casper_hashing::Digest;
+use casper_types::{
+	account::AccountHash,
+	bytesrepr::ToBytes,
+	crypto,
+	runtime_args,
+	PublicKey,
+	RuntimeArgs,
+	SecretKey,
+	Signature,
+	U512,
+};
+use cspr_names_client::{ CallTarget, ContractCall, SessionCall };
+use serde_json::{ json, Value };
+
+use crate::{ config::NetworkConfig, error::{ AResult, AdminError } };
+
+const ARG_AMOUNT: &str = "amount";
+
+pub struct Signer {
+	secret_key: SecretKey,
+	pub public_key: PublicKey,
+}
+
+impl Signer {
+	/// PEM file written by `casper-client keygen`
+	pub fn from_file(path: &Path) -> AResult<Self> {
+		let secret_key = SecretKey::from_file(path).map_err(|error| {
+			AdminError::Config(format!("secret key {}: {}", path.display(), error))
+		})?;
+		Ok(Self::new(secret_key))
+	}
+
+	pub fn new(secret_key: SecretKey) -> Self {
+		let public_key = PublicKey::from(&secret_key);
+		Self { secret_key, public_key }
+	}
+
+	pub fn account_hash(&self) -> AccountHash {
+		self.public_key.to_account_hash()
+	}
+}
+
+/**
+ * Signed deploy in the form accepted by `account_put_deploy`.
+ * The body hash covers the serialized payment and session, the deploy hash covers the
+ * serialized header and the approval signs the deploy hash.
+ */
+pub struct Deploy {
+	pub hash: Digest,
+	account: PublicKey,
+	timestamp: u64,
+	ttl: u64,
+	gas_price: u64,
+	body_hash: Digest,
+	chain_name: String,
+	payment: ExecutableDeployItem,
+	session: ExecutableDeployItem,
+	signature: Signature,
+}
+
+impl Deploy {
+	pub fn new(
+		session: ExecutableDeployItem,
+		payment_amount: U512,
+		network: &NetworkConfig,
+		signer: &Signer
+	) -> AResult<Self> {
+		let timestamp = SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.expect("Error while reading the system time")
+			.as_millis() as u64;
+		Self::with_timestamp(session, payment_amount, network, signer, timestamp)
+	}
+
+	/// timestamp - milliseconds since the Unix epoch
+	pub fn with_timestamp(
+		session: ExecutableDeployItem,
+		payment_amount: U512,
+		network: &NetworkConfig,
+		signer: &Signer,
+		timestamp: u64
+	) -> AResult<Self> {
+		let payment = ExecutableDeployItem::ModuleBytes {
+			module_bytes: Vec::new().into(),
+			args: runtime_args! { ARG_AMOUNT => payment_amount },
+		};
+
+		let mut body = payment.to_bytes().map_err(to_bytes_error)?;
+		body.append(&mut session.to_bytes().map_err(to_bytes_error)?);
+		let body_hash = Digest::hash(body);
+
+		let mut header = signer.public_key.to_bytes().map_err(to_bytes_error)?;
+		header.append(&mut timestamp.to_bytes().map_err(to_bytes_error)?);
+		header.append(&mut network.ttl().to_bytes().map_err(to_bytes_error)?);
+		header.append(&mut network.gas_price().to_bytes().map_err(to_bytes_error)?);
+		header.append(&mut body_hash.to_bytes().map_err(to_bytes_error)?);
+		// No dependencies
+		header.append(&mut Vec::<Digest>::new().to_bytes().map_err(to_bytes_error)?);
+		header.append(&mut network.chain_name.to_bytes().map_err(to_bytes_error)?);
+		let hash = Digest::hash(header);
+
+		let signature = crypto::sign(hash, &signer.secret_key, &signer.public_key);
+
+		Ok(Self {
+			hash,
+			account: signer.public_key.clone(),
+			timestamp,
+			ttl: network.ttl(),
+			gas_price: network.gas_price(),
+			body_hash,
+			chain_name: network.chain_name.clone(),
+			payment,
+			session,
+			signature,
+		})
+	}
+
+	pub fn hash_hex(&self) -> String {
+		to_json(&self.hash).as_str().unwrap_or_default().to_string()
+	}
+
+	pub fn to_json(&self) -> Value {
+		json!({
+			"hash": to_json(&self.hash),
+			"header": {
+				"account": to_json(&self.account),
+				"timestamp": format_timestamp(self.timestamp),
+				"ttl": format!("{}ms", self.ttl),
+				"gas_price": self.gas_price,
+				"body_hash": to_json(&self.body_hash),
+				"dependencies": [],
+				"chain_name": self.chain_name,
+			},
+			"payment": to_json(&self.payment),
+			"session": to_json(&self.session),
+			"approvals": [
+				{
+					"signer": to_json(&self.account),
+					"signature": to_json(&self.signature),
+				}
+			],
+		})
+	}
+}
+
+pub fn contract_session(call: &ContractCall) -> ExecutableDeployItem {
+	let entry_point = call.entry_point.clone();
+	let args = call.args.clone();
+	match &call.target {
+		CallTarget::ContractHash(hash) =>
+			ExecutableDeployItem::StoredContractByHash {
+				hash: *hash,
+				entry_point,
+				args,
+			},
+		CallTarget::ContractPackageHash { package_hash, version } =>
+			ExecutableDeployItem::StoredVersionedContractByHash {
+				hash: *package_hash,
+				version: *version,
+				entry_point,
+				args,
+			},
+		CallTarget::ContractName(name) =>
+			ExecutableDeployItem::StoredContractByName {
+				name: name.clone(),
+				entry_point,
+				args,
+			},
+		CallTarget::PackageName { name, version } =>
+			ExecutableDeployItem::StoredVersionedContractByName {
+				name: name.clone(),
+				version: *version,
+				entry_point,
+				args,
+			},
+	}
+}
+
+pub fn install_session(call: &SessionCall) -> ExecutableDeployItem {
+	ExecutableDeployItem::ModuleBytes {
+		module_bytes: call.module_bytes.clone().into(),
+		args: call.args.clone(),
+	}
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Value {
+	serde_json::to_value(value).expect("Error while serializing the deploy")
+}
+
+fn to_bytes_error(error: casper_types::bytesrepr::Error) -> AdminError {
+	AdminError::Config(format!("deploy serialization: {}", error))
+}
+
+/// RFC 3339 in UTC with milliseconds, the form of `Timestamp` of the node
+pub fn format_timestamp(millis: u64) -> String {
+	let seconds = millis / 1000;
+	let (hour, minute, second) = ((seconds / 3600) % 24, (seconds / 60) % 60, seconds % 60);
+
+	// Civil date from the days since 1970-01-01
+	let days = (seconds / 86400) as i64 + 719468;
+	let era = days / 146097;
+	let day_of_era = days - era * 146097;
+	let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+	let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	let month_index = (5 * day_of_year + 2) / 153;
+	let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+	let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+	let year = year_of_era + era * 400 + (if month <= 2 { 1 } else { 0 });
+
+	format!(
+		"{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+		year,
+		month,
+		day,
+		hour,
+		minute,
+		second,
+		millis % 1000
+	)
+}
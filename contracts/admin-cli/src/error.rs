@@ -0,0 +1,60 @@
+use std::fmt;
+
+use cspr_names_client::ClientError;
+
+#[derive(Debug)]
+pub enum AdminError {
+	Usage(String),
+	Config(String),
+	/// Contract is not installed and its hash is not set in the config
+	MissingHash(String),
+	Io(String),
+	Rpc(String),
+	DeployFailed {
+		deploy_hash: String,
+		message: String,
+	},
+	Client(ClientError),
+}
+
+impl fmt::Display for AdminError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Usage(message) => write!(f, "{}", message),
+			Self::Config(message) => write!(f, "config: {}", message),
+			Self::MissingHash(name) =>
+				write!(
+					f,
+					"hash of `{}` is unknown, install it or set its `hash` in the config",
+					name
+				),
+			Self::Io(message) => write!(f, "io: {}", message),
+			Self::Rpc(message) => write!(f, "rpc: {}", message),
+			Self::DeployFailed { deploy_hash, message } =>
+				write!(f, "deploy {} failed: {}", deploy_hash, message),
+			Self::Client(error) => write!(f, "{}", error),
+		}
+	}
+}
+
+impl std::error::Error for AdminError {}
+
+impl From<ClientError> for AdminError {
+	fn from(error: ClientError) -> Self {
+		Self::Client(error)
+	}
+}
+
+impl From<std::io::Error> for AdminError {
+	fn from(error: std::io::Error) -> Self {
+		Self::Io(error.to_string())
+	}
+}
+
+impl From<serde_json::Error> for AdminError {
+	fn from(error: serde_json::Error) -> Self {
+		Self::Rpc(error.to_string())
+	}
+}
+
+pub type AResult<T> = Result<T, AdminError>;
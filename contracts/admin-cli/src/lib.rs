@@ -0,0 +1,11 @@
+//! Installs and configures the whole system from one config file: contract directory,
+//! registry whitelist, roles, extensions and prices.
+//!
+//! The `cspr-names-admin` binary runs the commands, the modules are shared with the tests.
+
+pub mod config;
+pub mod deploy;
+pub mod error;
+pub mod plan;
+pub mod rpc;
+pub mod settings;
@@ -0,0 +1,242 @@
+//! Command line of `cspr_names_admin`.
+//!
+//! Usage: `cargo run -p cspr-names-admin -- <install|configure|deploy|diff> <config> [--offline <dir>]`
+
+use std::{ env, fs, path::{ Path, PathBuf }, process, time::Duration };
+
+use casper_execution_engine::core::engine_state::executable_deploy_item::ExecutableDeployItem;
+use casper_types::U512;
+
+use cspr_names_admin::{
+	config::Config,
+	deploy::{ contract_session, install_session, Deploy, Signer },
+	error::{ AResult, AdminError },
+	plan::{ installs, Deployment },
+	rpc::NodeClient,
+	settings::{ settings, Setting, StateQuery },
+};
+
+const USAGE: &str =
+	"Usage: cspr-names-admin <command> <config> [--offline <dir>]
+
+Commands:
+  install    installs the contracts which have no `hash` in the config
+  configure  sends the settings which differ from the chain
+  deploy     install and configure
+  diff       prints the settings which differ from the chain
+
+With --offline the signed deploys are written to <dir> instead of being sent,
+`configure` then writes every setting as nothing is compared.";
+
+enum Command {
+	Install,
+	Configure,
+	Deploy,
+	Diff,
+}
+
+struct Options {
+	command: Command,
+	config: PathBuf,
+	offline: Option<PathBuf>,
+}
+
+/// Sends the deploys to the node or writes them to the offline dir
+struct Sender<'a> {
+	config: &'a Config,
+	signer: Signer,
+	node: Option<NodeClient>,
+	offline: Option<PathBuf>,
+	written: usize,
+}
+
+fn main() {
+	if let Err(error) = run(env::args().skip(1).collect()) {
+		eprintln!("error: {}", error);
+		process::exit(1);
+	}
+}
+
+fn run(args: Vec<String>) -> AResult<()> {
+	let options = parse_args(args)?;
+	let config = Config::load(&options.config)?;
+	let mut deployment = Deployment::from_config(&config)?;
+
+	if options.offline.is_some() && matches!(options.command, Command::Deploy | Command::Diff) {
+		return Err(AdminError::Usage(format!("--offline only applies to install and configure\n\n{}", USAGE)));
+	}
+	if let Some(dir) = options.offline.as_ref() {
+		fs::create_dir_all(dir)?;
+	}
+
+	let mut sender = Sender {
+		config: &config,
+		signer: Signer::from_file(&config.network.secret_key)?,
+		node: match options.offline {
+			Some(_) => None,
+			None => Some(NodeClient::new(&config.network.node_address)?),
+		},
+		offline: options.offline,
+		written: 0,
+	};
+
+	match options.command {
+		Command::Install => install(&mut sender, &mut deployment),
+		Command::Configure => configure(&mut sender, &deployment),
+		Command::Deploy => {
+			install(&mut sender, &mut deployment)?;
+			configure(&mut sender, &deployment)
+		}
+		Command::Diff => {
+			diff(&sender, &deployment)?;
+			Ok(())
+		}
+	}
+}
+
+fn parse_args(args: Vec<String>) -> AResult<Options> {
+	let usage = || AdminError::Usage(USAGE.to_string());
+	let mut args = args.into_iter();
+
+	let command = match args.next().as_deref() {
+		Some("install") => Command::Install,
+		Some("configure") => Command::Configure,
+		Some("deploy") => Command::Deploy,
+		Some("diff") => Command::Diff,
+		_ => {
+			return Err(usage());
+		}
+	};
+	let config = args.next().map(PathBuf::from).ok_or_else(usage)?;
+	let offline = match args.next().as_deref() {
+		Some("--offline") => Some(args.next().map(PathBuf::from).ok_or_else(usage)?),
+		Some(_) => {
+			return Err(usage());
+		}
+		None => None,
+	};
+	if args.next().is_some() {
+		return Err(usage());
+	}
+
+	Ok(Options { command, config, offline })
+}
+
+/**
+ * Installs the contracts without a hash in the config and prints their hashes to be
+ * put in the config. Every install writes the hash to the named key of the account
+ * (see `Install::hash_key`), so it is read right after the install is executed.
+ * Offline installs of the contracts depending on a not installed one are skipped.
+ */
+fn install(sender: &mut Sender, deployment: &mut Deployment) -> AResult<()> {
+	for install in installs(sender.config) {
+		if deployment.contains(&install.name) {
+			continue;
+		}
+		let session = match install.session(deployment) {
+			Err(AdminError::MissingHash(dependency)) if sender.node.is_none() => {
+				println!("{}: skipped, needs the hash of `{}`", install.name, dependency);
+				continue;
+			}
+			session => session?,
+		};
+
+		sender.submit(
+			&format!("install {}", install.name),
+			install_session(&session),
+			install.contract.install_payment()?
+		)?;
+
+		if let Some(node) = sender.node.as_ref() {
+			let hash = node.get_account_contract_hash(
+				sender.signer.account_hash(),
+				&install.hash_key()?
+			)?;
+			println!("{}: hash = \"{}\"", install.name, hash.to_formatted_string());
+			deployment.insert(&install.name, hash);
+		}
+	}
+	Ok(())
+}
+
+/// Online only the settings which differ from the chain are sent
+fn configure(sender: &mut Sender, deployment: &Deployment) -> AResult<()> {
+	let settings = match sender.node.is_some() {
+		true => diff(sender, deployment)?,
+		false => settings(sender.config, deployment)?,
+	};
+	let payment_amount = sender.config.network.payment_amount()?;
+	for setting in settings.iter() {
+		sender.submit(&setting.label, contract_session(&setting.call), payment_amount)?;
+	}
+	Ok(())
+}
+
+/// Prints every setting and returns the ones which differ
+fn diff(sender: &Sender, deployment: &Deployment) -> AResult<Vec<Setting>> {
+	let node = sender.node.as_ref().expect("Error while reading the node client");
+	let state_root_hash = node.state_root_hash()?;
+
+	let mut differ = Vec::new();
+	for setting in settings(sender.config, deployment)? {
+		let actual = read_setting(node, &state_root_hash, &setting)?;
+		if actual == setting.expected {
+			println!("= {}: {}", setting.label, actual);
+		} else {
+			println!("~ {}: {} -> {}", setting.label, actual, setting.expected);
+			differ.push(setting);
+		}
+	}
+	println!("{} setting(s) differ", differ.len());
+	Ok(differ)
+}
+
+fn read_setting(node: &NodeClient, state_root_hash: &str, setting: &Setting) -> AResult<String> {
+	let value = match &setting.query {
+		StateQuery::NamedKey { contract, name } =>
+			node.get_named_key_value(state_root_hash, *contract, name)?,
+		StateQuery::Dictionary { contract, dictionary_name, item_key } =>
+			node.get_dictionary_item(state_root_hash, *contract, dictionary_name, item_key)?,
+	};
+	(setting.render)(value)
+}
+
+impl<'a> Sender<'a> {
+	/// Online the execution is awaited, a failed deploy stops the run
+	fn submit(&mut self, label: &str, session: ExecutableDeployItem, payment_amount: U512) -> AResult<()> {
+		let deploy = Deploy::new(session, payment_amount, &self.config.network, &self.signer)?;
+
+		if let Some(dir) = self.offline.as_ref() {
+			self.written += 1;
+			let path = dir.join(format!("{:02}-{}.json", self.written, file_name(label)));
+			write_deploy(&path, &deploy)?;
+			println!("{}: {}", label, path.display());
+			return Ok(());
+		}
+
+		let node = self.node.as_ref().expect("Error while reading the node client");
+		node.put_deploy(&deploy)?;
+		println!("{}: deploy {}", label, deploy.hash_hex());
+		node.wait_for_deploy(
+			&deploy.hash_hex(),
+			Duration::from_secs(self.config.network.deploy_timeout())
+		)
+	}
+}
+
+fn write_deploy(path: &Path, deploy: &Deploy) -> AResult<()> {
+	let content = serde_json::to_string_pretty(&deploy.to_json())?;
+	fs::write(path, content + "\n")?;
+	Ok(())
+}
+
+fn file_name(label: &str) -> String {
+	label
+		.chars()
+		.map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '-' })
+		.collect::<String>()
+		.split('-')
+		.filter(|part| !part.is_empty())
+		.collect::<Vec<&str>>()
+		.join("-")
+}
@@ -0,0 +1,295 @@
+use std::{ collections::BTreeMap, path::Path };
+
+use casper_types::{ bytesrepr::ToBytes, CLTyped, CLValue, ContractHash, Key, RuntimeArgs };
+use common_lib::{
+	constants::common_keys::{
+		AdministractionStoreKeys,
+		CommonArgs,
+		CommonKeys,
+		ARG_MAIN_PRICE_ORACLE_CONTRACT_HASH,
+		KEY_MAIN_CONTRACT_HASH,
+		KEY_MAIN_DICTIONARY_DOMAIN_METADATA,
+		KEY_REGISTRY_DOMAIN_CONTRACT_HASH,
+		NFTContractKeys,
+		PriceOracleKeys,
+		TreasuryKeys,
+	},
+	enums::contracts_enum::ContractKind,
+};
+use cspr_names_client::{
+	AdministrationClient,
+	CallTarget,
+	ContractCall,
+	MainClient,
+	NftClient,
+	PriceOracleClient,
+	RegistryClient,
+	SessionCall,
+	TreasuryClient,
+};
+
+use crate::{
+	config::{
+		parse_account_hash,
+		parse_contract_hash,
+		parse_key,
+		parse_u512,
+		ArgValue,
+		Config,
+		ContractConfig,
+	},
+	error::{ AResult, AdminError },
+};
+
+/// `collection_name` install argument of nft-core names its named keys
+const ARG_NFT_CORE_COLLECTION_NAME: &str = "collection_name";
+const PREFIX_NFT_CORE_CONTRACT_NAME: &str = "cep78_contract_hash";
+
+/// Hashes of the system contracts by reference name, see `Config`
+#[derive(Default)]
+pub struct Deployment {
+	hashes: BTreeMap<String, ContractHash>,
+}
+
+impl Deployment {
+	/// Hashes set in the config
+	pub fn from_config(config: &Config) -> AResult<Self> {
+		let mut deployment = Self::default();
+		for install in installs(config) {
+			if let Some(hash) = install.contract.hash.as_ref() {
+				deployment.insert(&install.name, parse_contract_hash(hash)?);
+			}
+		}
+		Ok(deployment)
+	}
+
+	pub fn insert(&mut self, name: &str, hash: ContractHash) {
+		self.hashes.insert(name.to_string(), hash);
+	}
+
+	pub fn contains(&self, name: &str) -> bool {
+		self.hashes.contains_key(name)
+	}
+
+	pub fn get(&self, name: &str) -> AResult<ContractHash> {
+		self.hashes
+			.get(name)
+			.copied()
+			.ok_or_else(|| AdminError::MissingHash(name.to_string()))
+	}
+
+	/// `@<name>` or `contract-<hex>` / `hash-<hex>`
+	pub fn resolve_hash(&self, value: &str) -> AResult<ContractHash> {
+		match value.strip_prefix('@') {
+			Some(name) => self.get(name),
+			None => parse_contract_hash(value),
+		}
+	}
+
+	/// `@<name>` is the hash key of the contract, otherwise a formatted key
+	pub fn resolve_key(&self, value: &str) -> AResult<Key> {
+		match value.strip_prefix('@') {
+			Some(name) => self.get(name).map(Key::from),
+			None => parse_key(value),
+		}
+	}
+}
+
+/// Contract of the system, installs run in the order of `installs`
+pub struct Install<'a> {
+	pub name: String,
+	pub kind: ContractKind,
+	pub extension: Option<String>,
+	pub contract: &'a ContractConfig,
+}
+
+pub fn installs(config: &Config) -> Vec<Install<'_>> {
+	let contracts = &config.contracts;
+	let mut installs = vec![
+		Install::simple(
+			"administration",
+			ContractKind::Administration,
+			&contracts.administration
+		),
+		Install::simple("registry", ContractKind::Registry, &contracts.registry),
+		Install::simple(
+			"price_oracle",
+			ContractKind::PriceOracle,
+			&contracts.price_oracle
+		),
+		Install::simple("main", ContractKind::Main, &contracts.main),
+		Install::simple("nft", ContractKind::NFT, &contracts.nft),
+		Install::simple(
+			"transfer_filter",
+			ContractKind::TransferFilter,
+			&contracts.transfer_filter
+		),
+		Install::simple("treasury", ContractKind::Treasury, &contracts.treasury)
+	];
+	for extension in config.extensions.iter() {
+		installs.push(Install {
+			name: format!("database:{}", extension.name),
+			kind: ContractKind::Database,
+			extension: Some(extension.name.clone()),
+			contract: &extension.database,
+		});
+		installs.push(Install {
+			name: format!("nft_core:{}", extension.name),
+			kind: ContractKind::NFTCore,
+			extension: Some(extension.name.clone()),
+			contract: &extension.nft_core,
+		});
+	}
+	installs
+}
+
+impl<'a> Install<'a> {
+	fn simple(name: &str, kind: ContractKind, contract: &'a ContractConfig) -> Self {
+		Self {
+			name: name.to_string(),
+			kind,
+			extension: None,
+			contract,
+		}
+	}
+
+	/// Install session, price oracle, nft, transfer filter, treasury, database shards and main get the hashes they depend on
+	pub fn session(&self, deployment: &Deployment) -> AResult<SessionCall> {
+		let wasm = self.contract.wasm
+			.as_ref()
+			.ok_or_else(|| AdminError::Config(format!("`{}` has no wasm", self.name)))?;
+
+		let mut args = RuntimeArgs::new();
+		match self.kind {
+			ContractKind::PriceOracle
+			| ContractKind::NFT
+			| ContractKind::TransferFilter
+			| ContractKind::Treasury
+			| ContractKind::Database =>
+				args.insert_cl_value(
+					CommonArgs::AdministrationContract.to_string(),
+					cl_value(deployment.get("administration")?)?
+				),
+			ContractKind::Main =>
+				args.insert_cl_value(
+					ARG_MAIN_PRICE_ORACLE_CONTRACT_HASH,
+					cl_value(deployment.get("price_oracle")?)?
+				),
+			_ => {}
+		}
+		for (name, value) in self.contract.args.iter() {
+			args.insert_cl_value(name.clone(), to_cl_value(value, deployment)?);
+		}
+
+		read_session(wasm, args)
+	}
+
+	/// Named key of the installing account which holds the contract hash
+	pub fn hash_key(&self) -> AResult<String> {
+		match self.kind {
+			ContractKind::Main => Ok(KEY_MAIN_CONTRACT_HASH.to_string()),
+			ContractKind::NFTCore =>
+				match self.contract.args.get(ARG_NFT_CORE_COLLECTION_NAME) {
+					Some(ArgValue::Text(collection_name)) =>
+						Ok(format!("{}_{}", PREFIX_NFT_CORE_CONTRACT_NAME, collection_name)),
+					_ =>
+						Err(
+							AdminError::Config(
+								format!(
+									"`{}` has no {} argument",
+									self.name,
+									ARG_NFT_CORE_COLLECTION_NAME
+								)
+							)
+						),
+				}
+			_ => Ok(CommonKeys::ContractHash.to_string()),
+		}
+	}
+
+	/// `init` of the contract and the named key it creates
+	pub fn init_call(&self, hash: ContractHash) -> Option<(String, ContractCall)> {
+		let target = CallTarget::ContractHash(hash);
+		match self.kind {
+			ContractKind::Administration =>
+				Some((
+					AdministractionStoreKeys::ContractAuthority.to_string(),
+					AdministrationClient::new(target).init(),
+				)),
+			ContractKind::Registry =>
+				Some((
+					KEY_REGISTRY_DOMAIN_CONTRACT_HASH.to_string(),
+					RegistryClient::new(target).init(),
+				)),
+			ContractKind::Main =>
+				Some((
+					KEY_MAIN_DICTIONARY_DOMAIN_METADATA.to_string(),
+					MainClient::new(target).init(),
+				)),
+			ContractKind::NFT =>
+				Some((
+					NFTContractKeys::TokenMetadata.to_string(),
+					NftClient::new(target).init(),
+				)),
+			// Price oracle has no `init`, the migration creates the payment tokens
+			ContractKind::PriceOracle =>
+				Some((
+					PriceOracleKeys::PaymentTokens.to_string(),
+					PriceOracleClient::new(target).migrate(),
+				)),
+			ContractKind::Treasury =>
+				Some((
+					TreasuryKeys::Purse.to_string(),
+					TreasuryClient::new(target).init(),
+				)),
+			_ => None,
+		}
+	}
+}
+
+fn read_session(wasm: &Path, args: RuntimeArgs) -> AResult<SessionCall> {
+	SessionCall::from_file(wasm, args).map_err(|error| {
+		AdminError::Io(format!("{}: {}", wasm.display(), error))
+	})
+}
+
+fn cl_value<T: CLTyped + ToBytes>(value: T) -> AResult<CLValue> {
+	CLValue::from_t(value).map_err(|error| AdminError::Config(format!("{:?}", error)))
+}
+
+/// Types of the typed form: bool, u8, u32, u64, u512, string, key, option_key, key_list,
+/// account_hash and contract_hash. Keys and contract hashes can reference the contracts
+/// with `@<name>`, a key_list separates its keys with commas
+pub fn to_cl_value(value: &ArgValue, deployment: &Deployment) -> AResult<CLValue> {
+	let parse_error = |cl_type: &str, value: &str| {
+		AdminError::Config(format!("`{}` is not a {}", value, cl_type))
+	};
+	match value {
+		ArgValue::Bool(value) => cl_value(*value),
+		ArgValue::Integer(value) => cl_value(*value),
+		ArgValue::Text(value) => cl_value(value.clone()),
+		ArgValue::Typed { cl_type, value } =>
+			match cl_type.as_str() {
+				"bool" => cl_value(value.parse::<bool>().map_err(|_| parse_error(cl_type, value))?),
+				"u8" => cl_value(value.parse::<u8>().map_err(|_| parse_error(cl_type, value))?),
+				"u32" => cl_value(value.parse::<u32>().map_err(|_| parse_error(cl_type, value))?),
+				"u64" => cl_value(value.parse::<u64>().map_err(|_| parse_error(cl_type, value))?),
+				"u512" => cl_value(parse_u512(value)?),
+				"string" => cl_value(value.clone()),
+				"key" => cl_value(deployment.resolve_key(value)?),
+				"option_key" => cl_value(Some(deployment.resolve_key(value)?)),
+				"key_list" =>
+					cl_value(
+						value
+							.split(',')
+							.map(str::trim)
+							.filter(|key| !key.is_empty())
+							.map(|key| deployment.resolve_key(key))
+							.collect::<AResult<Vec<Key>>>()?
+					),
+				"account_hash" => cl_value(parse_account_hash(value)?),
+				"contract_hash" => cl_value(deployment.resolve_hash(value)?),
+				_ => Err(AdminError::Config(format!("unknown argument type `{}`", cl_type))),
+			}
+	}
+}
@@ -0,0 +1,216 @@
+use std::{ io::{ Read, Write }, net::TcpStream, thread, time::{ Duration, Instant } };
+
+use casper_types::{ account::AccountHash, CLValue, ContractHash };
+use cspr_names_client::decode::decode_cl_value;
+use serde_json::{ json, Value };
+
+use crate::{
+	config::parse_contract_hash,
+	deploy::Deploy,
+	error::{ AResult, AdminError },
+};
+
+const DEFAULT_RPC_PATH: &str = "/rpc";
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// JSON-RPC client of a node over plain HTTP, e.g. `http://127.0.0.1:7777`
+pub struct NodeClient {
+	host: String,
+	path: String,
+}
+
+impl NodeClient {
+	pub fn new(node_address: &str) -> AResult<Self> {
+		let address = node_address
+			.strip_prefix("http://")
+			.ok_or_else(|| {
+				AdminError::Config(
+					format!("node address `{}` has to start with http://", node_address)
+				)
+			})?;
+		let (host, path) = match address.find('/') {
+			Some(index) if index + 1 < address.len() => (&address[..index], &address[index..]),
+			Some(index) => (&address[..index], DEFAULT_RPC_PATH),
+			None => (address, DEFAULT_RPC_PATH),
+		};
+		Ok(Self {
+			host: host.to_string(),
+			path: path.to_string(),
+		})
+	}
+
+	pub fn put_deploy(&self, deploy: &Deploy) -> AResult<()> {
+		self.request("account_put_deploy", json!({ "deploy": deploy.to_json() }))?;
+		Ok(())
+	}
+
+	/// Waits until the deploy is executed, a failed execution is an error
+	pub fn wait_for_deploy(&self, deploy_hash: &str, timeout: Duration) -> AResult<()> {
+		let started = Instant::now();
+		loop {
+			let result = self.request("info_get_deploy", json!({ "deploy_hash": deploy_hash }))?;
+			if let Some(execution) = result["execution_results"].get(0) {
+				if let Some(failure) = execution["result"].get("Failure") {
+					return Err(AdminError::DeployFailed {
+						deploy_hash: deploy_hash.to_string(),
+						message: failure["error_message"].as_str().unwrap_or_default().to_string(),
+					});
+				}
+				return Ok(());
+			}
+			if started.elapsed() > timeout {
+				return Err(AdminError::DeployFailed {
+					deploy_hash: deploy_hash.to_string(),
+					message: "execution is not awaited in time".to_string(),
+				});
+			}
+			thread::sleep(POLL_INTERVAL);
+		}
+	}
+
+	pub fn state_root_hash(&self) -> AResult<String> {
+		let result = self.request("chain_get_state_root_hash", json!([]))?;
+		result["state_root_hash"]
+			.as_str()
+			.map(str::to_string)
+			.ok_or_else(|| AdminError::Rpc("state root hash is not returned".to_string()))
+	}
+
+	/// Contract hash kept under the named key of the account. Our contracts keep it in a
+	/// URef (`CommonKeys::ContractHash`), nft-core puts the hash key itself.
+	pub fn get_account_contract_hash(
+		&self,
+		account: AccountHash,
+		name: &str
+	) -> AResult<ContractHash> {
+		let state_root_hash = self.state_root_hash()?;
+		let account = self.query(&state_root_hash, &account.to_formatted_string(), &[])?;
+		let key = account["Account"]["named_keys"]
+			.as_array()
+			.and_then(|named_keys| {
+				named_keys.iter().find(|named_key| named_key["name"] == name)
+			})
+			.and_then(|named_key| named_key["key"].as_str())
+			.ok_or_else(|| AdminError::Rpc(format!("account has no named key `{}`", name)))?
+			.to_string();
+
+		if key.starts_with("hash-") {
+			return parse_contract_hash(&key);
+		}
+		let stored = self.query(&state_root_hash, &key, &[])?;
+		Ok(decode_cl_value(to_cl_value(&stored)?)?)
+	}
+
+	/// `None` when the contract has no such named key
+	pub fn get_named_key_value(
+		&self,
+		state_root_hash: &str,
+		contract: ContractHash,
+		name: &str
+	) -> AResult<Option<CLValue>> {
+		match self.query(state_root_hash, &hash_key(contract), &[name.to_string()]) {
+			Ok(stored) => to_cl_value(&stored).map(Some),
+			Err(AdminError::Rpc(_)) => Ok(None),
+			Err(error) => Err(error),
+		}
+	}
+
+	/// `None` when the dictionary has no such item
+	pub fn get_dictionary_item(
+		&self,
+		state_root_hash: &str,
+		contract: ContractHash,
+		dictionary_name: &str,
+		item_key: &str
+	) -> AResult<Option<CLValue>> {
+		let params = json!({
+			"state_root_hash": state_root_hash,
+			"dictionary_identifier": {
+				"ContractNamedKey": {
+					"key": hash_key(contract),
+					"dictionary_name": dictionary_name,
+					"dictionary_item_key": item_key,
+				}
+			}
+		});
+		match self.request("state_get_dictionary_item", params) {
+			Ok(result) => to_cl_value(&result["stored_value"]).map(Some),
+			Err(AdminError::Rpc(_)) => Ok(None),
+			Err(error) => Err(error),
+		}
+	}
+
+	fn query(&self, state_root_hash: &str, key: &str, path: &[String]) -> AResult<Value> {
+		let params = json!({
+			"state_identifier": { "StateRootHash": state_root_hash },
+			"key": key,
+			"path": path,
+		});
+		let result = self.request("query_global_state", params)?;
+		Ok(result["stored_value"].clone())
+	}
+
+	fn request(&self, method: &str, params: Value) -> AResult<Value> {
+		let body = json!({
+			"jsonrpc": "2.0",
+			"id": 1,
+			"method": method,
+			"params": params,
+		}).to_string();
+
+		let mut stream = TcpStream::connect(&self.host)?;
+		write!(
+			stream,
+			"POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+			self.path,
+			self.host,
+			body.len(),
+			body
+		)?;
+		let mut response = Vec::new();
+		stream.read_to_end(&mut response)?;
+
+		let response = String::from_utf8_lossy(&response);
+		let (head, content) = response
+			.split_once("\r\n\r\n")
+			.ok_or_else(|| AdminError::Rpc("malformed http response".to_string()))?;
+		let content = if head.to_lowercase().contains("transfer-encoding: chunked") {
+			dechunk(content)
+		} else {
+			content.to_string()
+		};
+
+		let response: Value = serde_json::from_str(&content)?;
+		if let Some(error) = response.get("error") {
+			return Err(AdminError::Rpc(format!("{}: {}", method, error)));
+		}
+		Ok(response["result"].clone())
+	}
+}
+
+fn hash_key(contract: ContractHash) -> String {
+	format!("hash-{}", contract)
+}
+
+/// `CLValue` of the `stored_value` returned by the node
+pub fn to_cl_value(stored_value: &Value) -> AResult<CLValue> {
+	let cl_value = stored_value
+		.get("CLValue")
+		.ok_or_else(|| AdminError::Rpc("stored value is not a CLValue".to_string()))?;
+	Ok(serde_json::from_value(cl_value.clone())?)
+}
+
+/// Body of a response sent with `Transfer-Encoding: chunked`
+pub fn dechunk(content: &str) -> String {
+	let mut body = String::new();
+	let mut rest = content;
+	while let Some((size, tail)) = rest.split_once("\r\n") {
+		let size = usize::from_str_radix(size.trim(), 16).unwrap_or(0);
+		if size == 0 || tail.len() < size {
+			break;
+		}
+		body.push_str(&tail[..size]);
+		rest = tail[size..].trim_start_matches("\r\n");
+	}
+	body
+}
@@ -0,0 +1,430 @@
+use casper_types::{ account::AccountHash, CLValue, ContractHash, Key, U512 };
+use common_lib::{
+	constants::common_keys::{
+		AdministractionStoreKeys,
+		CommonKeys,
+		KEY_REGISTRY_WHITELIST_CONTRACT_HASH,
+	},
+	enums::{ price_oracle_contract::PriceType, registration_mode::RegistrationMode },
+	models::{
+		confiugration::ExtensionConfig as StoredExtensionConfig,
+		price::Price,
+		proposal::ProposalAction,
+		registry_pointer::CompoundContract,
+	},
+};
+use cspr_names_client::{
+	decode::decode_optional,
+	dictionary::{ self, DictionaryItem },
+	AdministrationClient,
+	CallTarget,
+	ContractCall,
+	RegistryClient,
+};
+
+use crate::{
+	config::{
+		parse_account_hash,
+		parse_registration_mode,
+		parse_role,
+		parse_u512,
+		Config,
+		ExtensionConfig,
+		PriceConfig,
+	},
+	error::AResult,
+	plan::{ installs, Deployment },
+};
+
+const NOT_SET: &str = "<not set>";
+
+/// Stored value the setting is compared with
+pub enum StateQuery {
+	NamedKey {
+		contract: ContractHash,
+		name: String,
+	},
+	Dictionary {
+		contract: ContractHash,
+		dictionary_name: String,
+		item_key: String,
+	},
+}
+
+/**
+ * One value of the config on chain. `render` renders the stored value (`None` - nothing
+ * is stored) the way `expected` is rendered, `call` writes the expected value.
+ */
+pub struct Setting {
+	pub label: String,
+	pub expected: String,
+	pub query: StateQuery,
+	pub render: Box<dyn Fn(Option<CLValue>) -> AResult<String>>,
+	pub call: ContractCall,
+}
+
+/**
+ * Settings in the order they are applied:
+ * 1. init of the installed contracts
+ * 2. contract directory of the administration contract
+ * 3. registry whitelist
+ * 4. roles, contracts calling each other hold the SYSTEM_CONTRACT role
+ * 5. allowed extensions and their limits
+ * 6. prices, queued as timelocked proposals
+ */
+pub fn settings(config: &Config, deployment: &Deployment) -> AResult<Vec<Setting>> {
+	let administration = deployment.get("administration")?;
+	let admin = AdministrationClient::new(CallTarget::ContractHash(administration));
+	let mut settings = Vec::new();
+
+	for install in installs(config) {
+		if let Some((name, call)) = install.init_call(deployment.get(&install.name)?) {
+			settings.push(Setting {
+				label: format!("{}: init", install.name),
+				expected: "initialized".to_string(),
+				query: StateQuery::NamedKey {
+					contract: call_contract(&call),
+					name,
+				},
+				render: Box::new(|value| {
+					Ok(
+						(if value.is_some() { "initialized" } else { "not initialized" }).to_string()
+					)
+				}),
+				call,
+			});
+		}
+	}
+
+	for install in installs(config) {
+		let key = Key::from(deployment.get(&install.name)?);
+		let expected = key.to_formatted_string();
+		let call = admin.add_contract(install.kind, key, install.extension.clone());
+		let setting = match &install.extension {
+			None =>
+				Setting {
+					label: format!("directory: {}", install.name),
+					expected,
+					query: named_key(administration, &install.kind.to_string()),
+					render: Box::new(|value| {
+						Ok(render_or_not_set(decode_stored::<Key>(value)?, render_key))
+					}),
+					call,
+				},
+			Some(extension) =>
+				Setting {
+					label: format!("directory: {}", install.name),
+					expected: expected.clone(),
+					query: named_key(administration, &format!("{}:{}", install.kind, extension)),
+					render: Box::new(move |value| {
+						let keys = decode_stored::<Vec<CompoundContract>>(value)?
+							.unwrap_or_default()
+							.iter()
+							.map(|contract| contract.key)
+							.collect::<Vec<Key>>();
+						Ok(render_contains(&keys, &key, &expected))
+					}),
+					call,
+				},
+		};
+		settings.push(setting);
+	}
+
+	let registry = deployment.get("registry")?;
+	for contract in config.contracts.registry.whitelist.iter() {
+		let contract_hash = deployment.resolve_hash(contract)?;
+		settings.push(Setting {
+			label: format!("registry whitelist: {}", contract),
+			expected: "whitelisted".to_string(),
+			query: named_key(registry, KEY_REGISTRY_WHITELIST_CONTRACT_HASH),
+			render: Box::new(move |value| {
+				let whitelist = decode_stored::<Vec<ContractHash>>(value)?.unwrap_or_default();
+				Ok(
+					(
+						if whitelist.contains(&contract_hash) {
+							"whitelisted"
+						} else {
+							"not whitelisted"
+						}
+					).to_string()
+				)
+			}),
+			call: RegistryClient::new(CallTarget::ContractHash(registry)).add_to_whitelist(
+				contract_hash
+			),
+		});
+	}
+
+	for (role_name, members) in config.roles.iter() {
+		let role = parse_role(role_name)?;
+		for member in members.iter() {
+			let account = deployment.resolve_key(member)?;
+			settings.push(Setting {
+				label: format!("role {}: {}", role, member),
+				expected: "granted".to_string(),
+				query: named_key(administration, &format!("{}:{}", CommonKeys::Roles, role)),
+				render: Box::new(move |value| {
+					let members = decode_stored::<Vec<Key>>(value)?.unwrap_or_default();
+					Ok(
+						(if members.contains(&account) { "granted" } else { "not granted" }).to_string()
+					)
+				}),
+				call: admin.grant_role(role, account),
+			});
+		}
+	}
+
+	if !config.extensions.is_empty() {
+		let extensions = config.extensions
+			.iter()
+			.map(|extension| extension.name.clone())
+			.collect::<Vec<String>>();
+		settings.push(Setting {
+			label: "allowed extensions".to_string(),
+			expected: extensions.join(", "),
+			query: named_key(administration, &AdministractionStoreKeys::AllowedExtensions.to_string()),
+			render: Box::new(|value| {
+				Ok(decode_stored::<Vec<String>>(value)?.unwrap_or_default().join(", "))
+			}),
+			call: admin.set_allowed_extensions(extensions),
+		});
+	}
+
+	for extension in config.extensions.iter() {
+		settings.append(&mut extension_settings(&admin, administration, extension)?);
+	}
+
+	let price_oracle = deployment.get("price_oracle")?;
+	for extension in config.extensions.iter() {
+		if let Some(price) = extension.price.as_ref() {
+			settings.push(Setting {
+				label: format!("{}: price", extension.name),
+				expected: render_price_config(price)?,
+				query: dictionary_item(price_oracle, &dictionary::price(&extension.name)),
+				render: Box::new(|value| {
+					Ok(render_or_not_set(decode_stored::<Price>(value)?, render_price))
+				}),
+				call: price_call(&admin, &extension.name, price)?,
+			});
+		}
+	}
+
+	Ok(settings)
+}
+
+/// Limits of the extension, unset ones are left out
+fn extension_settings(
+	admin: &AdministrationClient,
+	administration: ContractHash,
+	extension: &ExtensionConfig
+) -> AResult<Vec<Setting>> {
+	let name = Some(extension.name.clone());
+	let mut settings = Vec::new();
+	let mut push = |field: &str, expected: String, read: fn(&StoredExtensionConfig) -> String, call| {
+		settings.push(Setting {
+			label: format!("{}: {}", extension.name, field),
+			expected,
+			query: named_key(
+				administration,
+				&format!("{}:{}", AdministractionStoreKeys::ExtensionConfig, extension.name)
+			),
+			// Defaults are in effect until the extension is configured
+			render: Box::new(move |value| {
+				Ok(read(&decode_stored::<StoredExtensionConfig>(value)?.unwrap_or_default()))
+			}),
+			call,
+		});
+	};
+
+	if let Some(value) = extension.chars_min_count {
+		push(
+			"chars_min_count",
+			value.to_string(),
+			|config| config.min_chars_count.to_string(),
+			admin.set_chars_min_count(value, name.clone())
+		);
+	}
+	if let Some(value) = extension.max_chars_count {
+		push(
+			"max_chars_count",
+			value.to_string(),
+			|config| config.max_chars_count.to_string(),
+			admin.set_max_chars_count(name.clone(), value)
+		);
+	}
+	if let Some(value) = extension.max_subscription_years {
+		push(
+			"max_subscription_years",
+			value.to_string(),
+			|config| config.max_subscription_years.to_string(),
+			admin.set_max_subscription_years(name.clone(), value)
+		);
+	}
+	if let Some(value) = extension.grace_period {
+		push(
+			"grace_period",
+			value.to_string(),
+			|config| config.grace_period.to_string(),
+			admin.set_grace_period(name.clone(), value)
+		);
+	}
+	if let Some(value) = extension.subdomain_limit {
+		push(
+			"subdomain_limit",
+			value.to_string(),
+			|config| config.max_subdomain_count.to_string(),
+			admin.set_subdomain_limit(name.clone(), value)
+		);
+	}
+	if let Some(value) = extension.registration_mode.as_ref() {
+		let mode = parse_registration_mode(value)?;
+		push(
+			"registration_mode",
+			render_registration_mode(mode),
+			|config| render_registration_mode(config.registration_mode),
+			admin.set_registration_mode(name.clone(), mode)
+		);
+	}
+	if let Some(value) = extension.fee_recipient.as_ref() {
+		// `none` sends the fees to the maintainer purse
+		let fee_recipient = match value.as_str() {
+			"none" => None,
+			value => Some(parse_account_hash(value)?),
+		};
+		push(
+			"fee_recipient",
+			render_fee_recipient(fee_recipient),
+			|config| render_fee_recipient(config.fee_recipient),
+			admin.set_fee_recipient(name.clone(), fee_recipient)
+		);
+	}
+
+	Ok(settings)
+}
+
+/// Prices are changed only by a timelocked proposal, the queued proposal is executed once its eta is reached
+fn price_call(
+	admin: &AdministrationClient,
+	extension: &str,
+	price: &PriceConfig
+) -> AResult<ContractCall> {
+	let extension = extension.to_string();
+	let action = if !price.is_dynamic() {
+		ProposalAction::SetPrice {
+			extension,
+			price_type: PriceType::Fixed,
+			price: parse_u512(&price.price)?,
+			price_mid: Vec::new(),
+			chars_count_mid: Vec::new(),
+			price_more: U512::zero(),
+		}
+	} else {
+		let price_mid = price.mid
+			.iter()
+			.map(|step| parse_u512(&step.price))
+			.collect::<AResult<Vec<U512>>>()?;
+		let chars_count_mid = price.mid
+			.iter()
+			.map(|step| step.chars_count)
+			.collect::<Vec<u64>>();
+		ProposalAction::SetPrice {
+			extension,
+			price_type: PriceType::Dynamic,
+			price: parse_u512(&price.price)?,
+			price_mid,
+			chars_count_mid,
+			price_more: parse_u512(price.more.as_deref().unwrap_or("0"))?,
+		}
+	};
+	Ok(admin.queue_proposal(action))
+}
+
+fn call_contract(call: &ContractCall) -> ContractHash {
+	match call.target {
+		CallTarget::ContractHash(hash) => hash,
+		_ => unreachable!("Settings call the contracts by hash"),
+	}
+}
+
+fn named_key(contract: ContractHash, name: &str) -> StateQuery {
+	StateQuery::NamedKey {
+		contract,
+		name: name.to_string(),
+	}
+}
+
+fn dictionary_item<T>(contract: ContractHash, item: &DictionaryItem<T>) -> StateQuery {
+	StateQuery::Dictionary {
+		contract,
+		dictionary_name: item.dictionary_name.clone(),
+		item_key: item.item_key.clone(),
+	}
+}
+
+fn decode_stored<T: casper_types::CLTyped + casper_types::bytesrepr::FromBytes>(
+	value: Option<CLValue>
+) -> AResult<Option<T>> {
+	match value {
+		Some(cl_value) => Ok(decode_optional(cl_value)?),
+		None => Ok(None),
+	}
+}
+
+fn render_or_not_set<T>(value: Option<T>, render: fn(&T) -> String) -> String {
+	value.as_ref().map(render).unwrap_or_else(|| NOT_SET.to_string())
+}
+
+fn render_key(key: &Key) -> String {
+	key.to_formatted_string()
+}
+
+fn render_keys(keys: &[Key]) -> String {
+	format!("[{}]", keys.iter().map(render_key).collect::<Vec<String>>().join(", "))
+}
+
+/// `expected` when the list contains the key, otherwise the whole list
+fn render_contains(keys: &[Key], key: &Key, expected: &str) -> String {
+	if keys.contains(key) { expected.to_string() } else { render_keys(keys) }
+}
+
+fn render_registration_mode(mode: RegistrationMode) -> String {
+	(match mode {
+		RegistrationMode::Open => "open",
+		RegistrationMode::Allowlisted => "allowlisted",
+	}).to_string()
+}
+
+fn render_fee_recipient(fee_recipient: Option<AccountHash>) -> String {
+	fee_recipient.map_or_else(|| "none".to_string(), |account| account.to_formatted_string())
+}
+
+fn render_price(price: &Price) -> String {
+	match price.price_type {
+		PriceType::Fixed => format!("fixed {}", price.price),
+		PriceType::Dynamic => {
+			let steps = price.price_by_count
+				.iter()
+				.map(|item| format!("{}: {}", item.char_count, item.price))
+				.collect::<Vec<String>>();
+			format!("dynamic {}, [{}], more {}", price.price, steps.join(", "), price.price_more)
+		}
+	}
+}
+
+fn render_price_config(price: &PriceConfig) -> AResult<String> {
+	if !price.is_dynamic() {
+		return Ok(format!("fixed {}", parse_u512(&price.price)?));
+	}
+	let steps = price.mid
+		.iter()
+		.map(|step| Ok(format!("{}: {}", step.chars_count, parse_u512(&step.price)?)))
+		.collect::<AResult<Vec<String>>>()?;
+	Ok(
+		format!(
+			"dynamic {}, [{}], more {}",
+			parse_u512(&price.price)?,
+			steps.join(", "),
+			parse_u512(price.more.as_deref().unwrap_or("0"))?
+		)
+	)
+}
@@ -41,6 +41,20 @@ pub trait ContractAuthorities {
 		contract_hash: ContractHash,
 		key: Key
 	) -> ();
+
+	/// Authority list of the previous contract version is moved to the new one
+	fn move_contract_authority_list(
+		&self,
+		previous_contract_hash: ContractHash,
+		contract_hash: ContractHash
+	) -> ();
+
+	fn replace_contract_authority(
+		&self,
+		contract_hash: ContractHash,
+		previous_key: Key,
+		key: Key
+	) -> ();
 }
 
 impl ContractAuthorities for ContractAuthoritiesStore {
@@ -74,9 +88,39 @@ impl ContractAuthorities for ContractAuthoritiesStore {
 		let mut authorities = self.get_contract_authority(contract_hash.clone());
 		let position = authorities
 			.iter()
-			.position(|item| item == key)
+			.position(|item| *item == key)
 			.unwrap_or_revert_with(CommonError::ItemNotFound);
 		authorities.remove(position);
 		self.set_contract_authority_list(contract_hash, authorities)
 	}
+
+	fn move_contract_authority_list(
+		&self,
+		previous_contract_hash: ContractHash,
+		contract_hash: ContractHash
+	) -> () {
+		let authorities = self.get_contract_authority(previous_contract_hash);
+		if authorities.is_empty() {
+			return;
+		}
+		self.set_contract_authority_list(contract_hash, authorities);
+		self.set_contract_authority_list(previous_contract_hash, vec![])
+	}
+
+	fn replace_contract_authority(
+		&self,
+		contract_hash: ContractHash,
+		previous_key: Key,
+		key: Key
+	) -> () {
+		let authorities = self.get_contract_authority(contract_hash);
+		if !authorities.contains(&previous_key) {
+			return;
+		}
+		let authorities = authorities
+			.into_iter()
+			.map(|item| if item == previous_key { key } else { item })
+			.collect::<Vec<Key>>();
+		self.set_contract_authority_list(contract_hash, authorities)
+	}
 }
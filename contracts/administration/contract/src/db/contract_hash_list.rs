@@ -1,6 +1,7 @@
-use alloc::{ vec::Vec, vec, string::ToString, format };
+use alloc::{ vec::Vec, vec, string::{ String, ToString }, format };
 use casper_types::Key;
 use common_lib::{
+	constants::common_keys::AdministractionStoreKeys,
 	db::{ store::Store, traits::Storable },
 	enums::contracts_enum::ContractKind,
 	models::registry_pointer::CompoundContract,
@@ -37,6 +38,18 @@ pub(crate) trait ContractHashList {
 		contract_kind: ContractKind,
 		extension: &str
 	) -> Vec<CompoundContract>;
+
+	/// Extensions the compound contracts of the kind were added for
+	fn get_compound_extensions(&self, contract_kind: ContractKind) -> Vec<String>;
+
+	/// Keeps the count of stored items, returns false when the previous key is not in the list
+	fn replace_compound_contract(
+		&self,
+		contract_kind: ContractKind,
+		extension: &str,
+		previous_key: Key,
+		key: Key
+	) -> bool;
 }
 
 impl ContractHashList for Store {
@@ -54,6 +67,7 @@ impl ContractHashList for Store {
 		extension: &str,
 		keys: Vec<CompoundContract>
 	) -> () {
+		self.add_compound_extension(contract_kind, extension);
 		self.set(&format!("{}:{}", contract_kind, extension), keys);
 	}
 
@@ -68,6 +82,7 @@ impl ContractHashList for Store {
 			.get::<Vec<CompoundContract>>(store_key)
 			.unwrap_or(vec![]);
 		keys.push(key);
+		self.add_compound_extension(contract_kind, extension);
 		self.set(store_key, keys);
 	}
 
@@ -106,4 +121,43 @@ impl ContractHashList for Store {
 		let store_key = &format!("{}:{}", contract_kind, extension);
 		self.get(&store_key).unwrap_or(vec![])
 	}
+
+	fn get_compound_extensions(&self, contract_kind: ContractKind) -> Vec<String> {
+		let store_key = &format!("{}:{}", AdministractionStoreKeys::CompoundExtensions, contract_kind);
+		self.get(store_key).unwrap_or(vec![])
+	}
+
+	fn replace_compound_contract(
+		&self,
+		contract_kind: ContractKind,
+		extension: &str,
+		previous_key: Key,
+		key: Key
+	) -> bool {
+		let mut keys = self.get_compound_contracts(contract_kind, extension);
+		match keys.iter().position(|item| item.key == previous_key) {
+			Some(position) => {
+				keys[position].key = key;
+				self.init_compound_contracts(contract_kind, extension, keys);
+				true
+			}
+			None => false,
+		}
+	}
+}
+
+trait CompoundExtensions {
+	fn add_compound_extension(&self, contract_kind: ContractKind, extension: &str) -> ();
+}
+
+impl CompoundExtensions for Store {
+	fn add_compound_extension(&self, contract_kind: ContractKind, extension: &str) -> () {
+		let mut extensions = self.get_compound_extensions(contract_kind);
+		if extensions.iter().any(|item| item == extension) {
+			return;
+		}
+		extensions.push(extension.to_string());
+		let store_key = &format!("{}:{}", AdministractionStoreKeys::CompoundExtensions, contract_kind);
+		self.set(store_key, extensions);
+	}
 }
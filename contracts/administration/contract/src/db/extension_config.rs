@@ -0,0 +1,68 @@
+use alloc::{ format, string::{ String, ToString }, vec, vec::Vec };
+use casper_types::Key;
+use common_lib::{
+	constants::common_keys::AdministractionStoreKeys,
+	db::{ store::Store, traits::Storable },
+	models::confiugration::ExtensionConfig,
+};
+
+/**
+ * 1. Extension config - defaults are returned until the extension is configured
+ * 2. Registration allowlist - accounts which can register names while the extension is allowlisted
+ */
+pub(crate) trait ExtensionConfigs {
+	fn get_extension_config(&self, extension: &str) -> ExtensionConfig;
+	fn set_extension_config(&self, extension: &str, config: ExtensionConfig) -> ();
+	/// Stored config is written back as it is, defaults are not stored
+	fn rewrite_extension_config(&self, extension: &str) -> ();
+	fn get_registration_allowlist(&self, extension: &str) -> Vec<Key>;
+	fn set_registration_allowlist(&self, extension: &str, accounts: Vec<Key>) -> ();
+}
+
+impl ExtensionConfigs for Store {
+	fn get_extension_config(&self, extension: &str) -> ExtensionConfig {
+		self
+			.get::<ExtensionConfig>(
+				&to_extension_key(AdministractionStoreKeys::ExtensionConfig, extension)
+			)
+			.unwrap_or_default()
+	}
+
+	fn set_extension_config(&self, extension: &str, config: ExtensionConfig) -> () {
+		self.set(
+			&to_extension_key(AdministractionStoreKeys::ExtensionConfig, extension),
+			config
+		)
+	}
+
+	fn rewrite_extension_config(&self, extension: &str) -> () {
+		self.rewrite::<ExtensionConfig>(
+			&to_extension_key(AdministractionStoreKeys::ExtensionConfig, extension)
+		)
+	}
+
+	fn get_registration_allowlist(&self, extension: &str) -> Vec<Key> {
+		self
+			.get::<Vec<Key>>(
+				&to_extension_key(
+					AdministractionStoreKeys::RegistrationAllowlist,
+					extension
+				)
+			)
+			.unwrap_or(vec![])
+	}
+
+	fn set_registration_allowlist(&self, extension: &str, accounts: Vec<Key>) -> () {
+		self.set(
+			&to_extension_key(
+				AdministractionStoreKeys::RegistrationAllowlist,
+				extension
+			),
+			accounts
+		)
+	}
+}
+
+fn to_extension_key(key: AdministractionStoreKeys, extension: &str) -> String {
+	format!("{}:{}", key.to_string(), extension)
+}
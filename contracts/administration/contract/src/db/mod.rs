@@ -3,3 +3,9 @@ pub mod allowed_extensions;
 pub mod contract_authorities;
 pub mod contract_hash_list;
 pub mod domain_limit;
+pub mod extension_config;
+pub mod multisig;
+pub mod pause;
+pub mod proposals;
+pub mod standby;
+pub mod timelock;
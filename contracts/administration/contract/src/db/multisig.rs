@@ -0,0 +1,45 @@
+use alloc::{ vec::Vec, vec, string::ToString };
+use casper_types::Key;
+use common_lib::{
+	constants::common_keys::AdministractionStoreKeys,
+	db::{ store::Store, traits::Storable },
+};
+
+/**
+ * M-of-N signer set: proposals need `threshold` distinct approvals out of `signers`.
+ * Zero threshold means that multisig is not configured yet.
+ */
+pub(crate) trait Multisig {
+	fn get_signers(&self) -> Vec<Key>;
+	fn get_threshold(&self) -> u8;
+	fn set_signers(&self, signers: Vec<Key>, threshold: u8) -> ();
+	fn is_signer(&self, key: &Key) -> bool;
+	fn is_multisig_active(&self) -> bool;
+}
+
+impl Multisig for Store {
+	fn get_signers(&self) -> Vec<Key> {
+		self
+			.get(&AdministractionStoreKeys::Signers.to_string())
+			.unwrap_or(vec![])
+	}
+
+	fn get_threshold(&self) -> u8 {
+		self
+			.get::<u8>(&AdministractionStoreKeys::Threshold.to_string())
+			.unwrap_or(0)
+	}
+
+	fn set_signers(&self, signers: Vec<Key>, threshold: u8) -> () {
+		self.set(&AdministractionStoreKeys::Signers.to_string(), signers);
+		self.set(&AdministractionStoreKeys::Threshold.to_string(), threshold);
+	}
+
+	fn is_signer(&self, key: &Key) -> bool {
+		self.get_signers().contains(key)
+	}
+
+	fn is_multisig_active(&self) -> bool {
+		self.get_threshold() > 0
+	}
+}
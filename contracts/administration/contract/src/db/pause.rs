@@ -0,0 +1,58 @@
+use core::fmt::Display;
+
+use alloc::{ format, string::{ String, ToString } };
+use common_lib::{
+	constants::common_keys::CommonKeys,
+	db::{ store::Store, traits::Storable },
+	enums::contracts_enum::ContractKind,
+};
+
+/**
+ * Pause switches, every scope is kept under its own named key:
+ * 1. paused:global - whole system
+ * 2. paused:<ContractKind> - single contract kind
+ * 3. paused:ext:<extension> - single extension
+ */
+pub(crate) enum PauseScope {
+	Global,
+	Contract(ContractKind),
+	Extension(String),
+}
+
+impl Display for PauseScope {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			Self::Global => write!(f, "global"),
+			Self::Contract(kind) => write!(f, "{}", kind),
+			Self::Extension(extension) => write!(f, "ext:{}", extension),
+		}
+	}
+}
+
+pub(crate) trait Pause {
+	fn is_scope_paused(&self, scope: &PauseScope) -> bool;
+	fn set_scope_paused(&self, scope: &PauseScope, paused: bool) -> ();
+	fn is_paused(&self, kind: ContractKind, extension: Option<String>) -> bool;
+}
+
+impl Pause for Store {
+	fn is_scope_paused(&self, scope: &PauseScope) -> bool {
+		self.get::<bool>(&to_pause_key(scope)).unwrap_or(false)
+	}
+
+	fn set_scope_paused(&self, scope: &PauseScope, paused: bool) -> () {
+		self.set(&to_pause_key(scope), paused)
+	}
+
+	fn is_paused(&self, kind: ContractKind, extension: Option<String>) -> bool {
+		self.is_scope_paused(&PauseScope::Global) ||
+			self.is_scope_paused(&PauseScope::Contract(kind)) ||
+			extension.map_or(false, |extension|
+				self.is_scope_paused(&PauseScope::Extension(extension))
+			)
+	}
+}
+
+fn to_pause_key(scope: &PauseScope) -> String {
+	format!("{}:{}", CommonKeys::Paused.to_string(), scope)
+}
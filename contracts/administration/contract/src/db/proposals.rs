@@ -0,0 +1,93 @@
+use alloc::{ format, string::{ String, ToString }, vec::Vec };
+use casper_types::{ bytesrepr::{ Error, FromBytes }, CLType, CLTyped, Key };
+use common_lib::{
+	constants::common_keys::AdministractionStoreKeys,
+	db::{ dictionary::Dictionary, traits::Storable },
+	enums::proposal_status::ProposalStatus,
+	models::proposal::{ Proposal, ProposalAction },
+};
+
+pub struct ProposalStore {
+	pub dictionary: Dictionary,
+}
+
+impl ProposalStore {
+	pub fn instance() -> Self {
+		Self {
+			dictionary: Dictionary::instance(
+				&AdministractionStoreKeys::Proposals.to_string()
+			),
+		}
+	}
+
+	pub fn initialize() -> () {
+		Dictionary::init(&AdministractionStoreKeys::Proposals.to_string())
+	}
+
+	/// Proposal stored before `SCHEMA_VERSION` 4 is saved again in the current layout
+	pub fn rewrite_legacy_proposal(&self, id: u64) {
+		if let Some(LegacyProposal(proposal)) = self.dictionary.get(&id.to_string()) {
+			self.save_proposal(proposal);
+		}
+	}
+}
+
+pub trait Proposals {
+	fn get_proposal(&self, id: u64) -> Option<Proposal>;
+	fn save_proposal(&self, proposal: Proposal) -> ();
+	fn get_cancellations(&self, id: u64) -> Vec<Key>;
+	fn save_cancellations(&self, id: u64, signers: Vec<Key>) -> ();
+}
+
+impl Proposals for ProposalStore {
+	fn get_proposal(&self, id: u64) -> Option<Proposal> {
+		self.dictionary.get(&id.to_string())
+	}
+
+	fn save_proposal(&self, proposal: Proposal) -> () {
+		self.dictionary.set(&proposal.id.to_string(), proposal)
+	}
+
+	/// Signers who approved the cancellation of the proposal
+	fn get_cancellations(&self, id: u64) -> Vec<Key> {
+		self.dictionary.get(&to_cancellations_key(id)).unwrap_or_default()
+	}
+
+	fn save_cancellations(&self, id: u64, signers: Vec<Key>) -> () {
+		self.dictionary.set(&to_cancellations_key(id), signers)
+	}
+}
+
+/// Proposal with the action in the layout of `ProposalAction::from_legacy_bytes`
+struct LegacyProposal(Proposal);
+
+impl CLTyped for LegacyProposal {
+	fn cl_type() -> CLType {
+		CLType::Any
+	}
+}
+
+impl FromBytes for LegacyProposal {
+	fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+		let (id, remainder) = u64::from_bytes(bytes)?;
+		let (action, remainder) = ProposalAction::from_legacy_bytes(remainder)?;
+		let (proposer, remainder) = Key::from_bytes(remainder)?;
+		let (eta, remainder) = u64::from_bytes(remainder)?;
+		let (status, remainder) = ProposalStatus::from_bytes(remainder)?;
+		let (approvals, remainder) = Vec::<Key>::from_bytes(remainder)?;
+
+		let proposal = Proposal {
+			id,
+			action,
+			proposer,
+			eta,
+			status,
+			approvals,
+		};
+		Ok((LegacyProposal(proposal), remainder))
+	}
+}
+
+fn to_cancellations_key(id: u64) -> String {
+	format!("{}:cancellations", id)
+}
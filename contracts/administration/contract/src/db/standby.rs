@@ -0,0 +1,103 @@
+use alloc::{ format, string::{ String, ToString }, vec, vec::Vec };
+use casper_types::Key;
+use common_lib::{
+	constants::common_keys::AdministractionStoreKeys,
+	db::{ store::Store, traits::Storable },
+	enums::contracts_enum::ContractKind,
+};
+
+/**
+ * Pre-deployed compound contracts (Database, NFTCore) waiting to be promoted
+ * once every active contract of the extension is filled.
+ */
+pub(crate) trait StandbyContracts {
+	fn get_standby_contracts(
+		&self,
+		kind: ContractKind,
+		extension: &str
+	) -> Vec<Key>;
+	fn add_standby_contract(
+		&self,
+		kind: ContractKind,
+		extension: &str,
+		key: Key
+	) -> ();
+	fn take_standby_contract(
+		&self,
+		kind: ContractKind,
+		extension: &str
+	) -> Option<Key>;
+	fn replace_standby_contract(
+		&self,
+		kind: ContractKind,
+		extension: &str,
+		previous_key: Key,
+		key: Key
+	) -> bool;
+}
+
+impl StandbyContracts for Store {
+	fn get_standby_contracts(
+		&self,
+		kind: ContractKind,
+		extension: &str
+	) -> Vec<Key> {
+		self
+			.get::<Vec<Key>>(&to_standby_key(kind, extension))
+			.unwrap_or(vec![])
+	}
+
+	fn add_standby_contract(
+		&self,
+		kind: ContractKind,
+		extension: &str,
+		key: Key
+	) -> () {
+		let mut keys = self.get_standby_contracts(kind, extension);
+		if !keys.contains(&key) {
+			keys.push(key);
+			self.set(&to_standby_key(kind, extension), keys);
+		}
+	}
+
+	fn take_standby_contract(
+		&self,
+		kind: ContractKind,
+		extension: &str
+	) -> Option<Key> {
+		let mut keys = self.get_standby_contracts(kind, extension);
+		if keys.is_empty() {
+			return None;
+		}
+		let key = keys.remove(0);
+		self.set(&to_standby_key(kind, extension), keys);
+		Some(key)
+	}
+
+	fn replace_standby_contract(
+		&self,
+		kind: ContractKind,
+		extension: &str,
+		previous_key: Key,
+		key: Key
+	) -> bool {
+		let mut keys = self.get_standby_contracts(kind, extension);
+		match keys.iter().position(|item| item == &previous_key) {
+			Some(position) => {
+				keys[position] = key;
+				self.set(&to_standby_key(kind, extension), keys);
+				true
+			}
+			None => false,
+		}
+	}
+}
+
+fn to_standby_key(kind: ContractKind, extension: &str) -> String {
+	format!(
+		"{}:{}:{}",
+		AdministractionStoreKeys::Standby.to_string(),
+		kind,
+		extension
+	)
+}
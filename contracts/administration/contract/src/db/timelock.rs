@@ -0,0 +1,46 @@
+use alloc::string::ToString;
+use common_lib::{
+	constants::common_keys::AdministractionStoreKeys,
+	db::{ store::Store, traits::Storable },
+};
+
+/// Proposals which were not executed within this window after their eta can not be executed anymore (14 days)
+pub(crate) const PROPOSAL_EXECUTION_WINDOW: u64 = 14 * 24 * 60 * 60 * 1000;
+
+/**
+ * 1. Timelock min delay (in millis) - zero means that timelock is not active yet
+ * 2. Proposal counter - used as the id for the next queued proposal
+ */
+pub(crate) trait Timelock {
+	fn get_min_delay(&self) -> u64;
+	fn set_min_delay(&self, delay: u64) -> ();
+	fn is_timelock_active(&self) -> bool;
+	fn next_proposal_id(&self) -> u64;
+	fn get_proposal_count(&self) -> u64;
+}
+
+impl Timelock for Store {
+	fn get_min_delay(&self) -> u64 {
+		self
+			.get::<u64>(&AdministractionStoreKeys::MinDelay.to_string())
+			.unwrap_or(0)
+	}
+
+	fn set_min_delay(&self, delay: u64) -> () {
+		self.set(&AdministractionStoreKeys::MinDelay.to_string(), delay)
+	}
+
+	fn is_timelock_active(&self) -> bool {
+		self.get_min_delay() > 0
+	}
+
+	fn next_proposal_id(&self) -> u64 {
+		let id = self.get_proposal_count();
+		self.set(&AdministractionStoreKeys::ProposalCount.to_string(), id + 1);
+		id
+	}
+
+	fn get_proposal_count(&self) -> u64 {
+		self.get::<u64>(&AdministractionStoreKeys::ProposalCount.to_string()).unwrap_or(0)
+	}
+}
@@ -40,7 +40,11 @@ use common_lib::{
 		CommonArgs,
 		CommonEndpoints,
 	},
-	enums::{ contracts_enum::ContractKind, controller_roles::ControllerRoles },
+	enums::{
+		contracts_enum::ContractKind,
+		controller_roles::ControllerRoles,
+		roles::Role,
+	},
 	utils::{
 		contract::{ create_entrypoint, setup_contract_info },
 		response::controller,
@@ -55,7 +59,7 @@ use common_lib::controllers::authorities;
 pub extern "C" fn set_contract_authority_list() {
 	controller(
 		service::contract_authority::set_contract_autority_list,
-		vec![ControllerRoles::OnlyLocalOperators]
+		vec![ControllerRoles::OnlyRole(Role::ShardManager)]
 	)
 }
 
@@ -63,7 +67,7 @@ pub extern "C" fn set_contract_authority_list() {
 pub extern "C" fn add_contract_authority() {
 	controller(
 		service::contract_authority::add_contract_authority,
-		vec![ControllerRoles::OnlyLocalOperators]
+		vec![ControllerRoles::OnlyRole(Role::ShardManager)]
 	)
 }
 
@@ -82,7 +86,7 @@ pub extern "C" fn get_contract_authority_list() {
 pub extern "C" fn remove_contract_authority() {
 	controller(
 		service::contract_authority::remove_contract_authority,
-		vec![ControllerRoles::OnlyLocalOperators]
+		vec![ControllerRoles::OnlyRole(Role::ShardManager)]
 	)
 }
 
@@ -102,7 +106,7 @@ pub extern "C" fn get_contract() {
 pub extern "C" fn add_contract() {
 	controller(
 		service::contracts::add_contract,
-		vec![ControllerRoles::OnlyLocalOperators]
+		vec![ControllerRoles::OnlyRole(Role::ShardManager)]
 	)
 }
 
@@ -133,7 +137,7 @@ pub extern "C" fn decrement_contract() {
 pub extern "C" fn set_allowed_extensions() {
 	controller(
 		service::extensions::set_allowed_extensions,
-		vec![ControllerRoles::OnlyLocalOperators]
+		vec![ControllerRoles::OnlyRole(Role::ExtensionManager)]
 	)
 }
 
@@ -152,7 +156,7 @@ pub extern "C" fn get_allowed_extensions() {
 pub extern "C" fn add_extension() {
 	controller(
 		service::extensions::add_extension,
-		vec![ControllerRoles::OnlyLocalOperators]
+		vec![ControllerRoles::OnlyRole(Role::ExtensionManager)]
 	)
 }
 
@@ -160,7 +164,7 @@ pub extern "C" fn add_extension() {
 pub extern "C" fn remove_extension() {
 	controller(
 		service::extensions::remove_extension,
-		vec![ControllerRoles::OnlyLocalOperators]
+		vec![ControllerRoles::OnlyRole(Role::ExtensionManager)]
 	)
 }
 
@@ -180,7 +184,193 @@ pub extern "C" fn get_chars_min_count() {
 pub extern "C" fn set_chars_min_count() {
 	controller(
 		service::limits::set_chars_min_count,
-		vec![ControllerRoles::OnlyLocalOperators]
+		vec![ControllerRoles::OnlyRole(Role::ExtensionManager)]
+	)
+}
+
+/// Extension Config Endpoints (length limits, subscription years, grace period, registration mode ...)
+#[no_mangle]
+pub extern "C" fn get_extension_config() {
+	controller(
+		service::extension_config::get_extension_config,
+		vec![
+			ControllerRoles::OnlyLocalOperators,
+			ControllerRoles::OnlyAuthorizedContracts
+		]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn get_max_chars_count() {
+	controller(
+		service::extension_config::get_max_chars_count,
+		vec![
+			ControllerRoles::OnlyLocalOperators,
+			ControllerRoles::OnlyAuthorizedContracts
+		]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn set_max_chars_count() {
+	controller(
+		service::extension_config::set_max_chars_count,
+		vec![ControllerRoles::OnlyRole(Role::ExtensionManager)]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn get_max_subscription_years() {
+	controller(
+		service::extension_config::get_max_subscription_years,
+		vec![
+			ControllerRoles::OnlyLocalOperators,
+			ControllerRoles::OnlyAuthorizedContracts
+		]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn set_max_subscription_years() {
+	controller(
+		service::extension_config::set_max_subscription_years,
+		vec![ControllerRoles::OnlyRole(Role::ExtensionManager)]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn get_grace_period() {
+	controller(
+		service::extension_config::get_grace_period,
+		vec![
+			ControllerRoles::OnlyLocalOperators,
+			ControllerRoles::OnlyAuthorizedContracts
+		]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn set_grace_period() {
+	controller(
+		service::extension_config::set_grace_period,
+		vec![ControllerRoles::OnlyRole(Role::ExtensionManager)]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn get_subdomain_limit() {
+	controller(
+		service::extension_config::get_subdomain_limit,
+		vec![
+			ControllerRoles::OnlyLocalOperators,
+			ControllerRoles::OnlyAuthorizedContracts
+		]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn set_subdomain_limit() {
+	controller(
+		service::extension_config::set_subdomain_limit,
+		vec![ControllerRoles::OnlyRole(Role::ExtensionManager)]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn get_registration_mode() {
+	controller(
+		service::extension_config::get_registration_mode,
+		vec![
+			ControllerRoles::OnlyLocalOperators,
+			ControllerRoles::OnlyAuthorizedContracts
+		]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn set_registration_mode() {
+	controller(
+		service::extension_config::set_registration_mode,
+		vec![ControllerRoles::OnlyRole(Role::ExtensionManager)]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn get_fee_recipient() {
+	controller(
+		service::extension_config::get_fee_recipient,
+		vec![
+			ControllerRoles::OnlyLocalOperators,
+			ControllerRoles::OnlyAuthorizedContracts
+		]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn set_fee_recipient() {
+	controller(
+		service::extension_config::set_fee_recipient,
+		vec![ControllerRoles::OnlyRole(Role::ExtensionManager)]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn add_to_allowlist() {
+	controller(
+		service::extension_config::add_to_allowlist,
+		vec![ControllerRoles::OnlyRole(Role::ExtensionManager)]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn remove_from_allowlist() {
+	controller(
+		service::extension_config::remove_from_allowlist,
+		vec![ControllerRoles::OnlyRole(Role::ExtensionManager)]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn is_allowlisted() {
+	controller(
+		service::extension_config::is_allowlisted,
+		vec![
+			ControllerRoles::OnlyLocalOperators,
+			ControllerRoles::OnlyAuthorizedContracts
+		]
+	)
+}
+
+/// Provisioning Endpoints (standby shards are promoted when every shard of the extension is filled)
+#[no_mangle]
+pub extern "C" fn add_standby_contract() {
+	controller(
+		service::provisioning::add_standby_contract,
+		vec![ControllerRoles::OnlyRole(Role::ShardManager)]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn get_standby_contracts() {
+	controller(
+		service::provisioning::get_standby_contracts,
+		vec![
+			ControllerRoles::OnlyLocalOperators,
+			ControllerRoles::OnlyAuthorizedContracts
+		]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn provision_contract() {
+	controller(service::provisioning::provision_contract, vec![])
+}
+
+#[no_mangle]
+pub extern "C" fn migrate_domains() {
+	controller(
+		service::migration::migrate_domains,
+		vec![ControllerRoles::OnlyRole(Role::ShardManager)]
 	)
 }
 
@@ -200,7 +390,162 @@ pub extern "C" fn get_listing_limit() {
 pub extern "C" fn set_listing_limit() {
 	controller(
 		service::limits::set_listing_limit,
-		vec![ControllerRoles::OnlyLocalOperators]
+		vec![ControllerRoles::OnlyRole(Role::ShardManager)]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn init() {
+	controller(
+		service::init::initialize,
+		vec![ControllerRoles::OnlyMaintainer]
+	)
+}
+
+/// Timelock Endpoints (critical changes are queued and executed after min delay)
+/// Proposers are checked by the service: local operators or signers if multisig is configured
+#[no_mangle]
+pub extern "C" fn queue_proposal() {
+	controller(service::timelock::queue_proposal, vec![])
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_proposal() {
+	controller(service::timelock::cancel_proposal, vec![])
+}
+
+#[no_mangle]
+pub extern "C" fn execute_proposal() {
+	controller(service::timelock::execute_proposal, vec![])
+}
+
+#[no_mangle]
+pub extern "C" fn get_proposal() {
+	controller(service::timelock::get_proposal, vec![])
+}
+
+#[no_mangle]
+pub extern "C" fn get_min_delay() {
+	controller(service::timelock::get_min_delay, vec![])
+}
+
+#[no_mangle]
+pub extern "C" fn set_min_delay() {
+	controller(
+		service::timelock::set_min_delay,
+		vec![ControllerRoles::OnlyMaintainer]
+	)
+}
+
+/// Multisig Endpoints (M-of-N approvals for proposals)
+#[no_mangle]
+pub extern "C" fn approve_proposal() {
+	controller(service::multisig::approve_proposal, vec![])
+}
+
+#[no_mangle]
+pub extern "C" fn revoke_approval() {
+	controller(service::multisig::revoke_approval, vec![])
+}
+
+#[no_mangle]
+pub extern "C" fn get_signers() {
+	controller(service::multisig::get_signers, vec![])
+}
+
+#[no_mangle]
+pub extern "C" fn set_signers() {
+	controller(
+		service::multisig::set_signers,
+		vec![ControllerRoles::OnlyMaintainer]
+	)
+}
+
+/// Role Endpoints (PRICE_SETTER, EXTENSION_MANAGER, SHARD_MANAGER, MARKETPLACE_OPERATOR ...)
+#[no_mangle]
+pub extern "C" fn grant_role() {
+	controller(
+		service::roles::grant_role,
+		vec![ControllerRoles::OnlyRole(Role::Admin)]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn revoke_role() {
+	controller(
+		service::roles::revoke_role,
+		vec![ControllerRoles::OnlyRole(Role::Admin)]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn renounce_role() {
+	controller(service::roles::renounce_role, vec![])
+}
+
+#[no_mangle]
+pub extern "C" fn has_role() {
+	controller(service::roles::has_role, vec![])
+}
+
+#[no_mangle]
+pub extern "C" fn get_role_members() {
+	controller(service::roles::get_role_members, vec![])
+}
+
+/// Pause Endpoints (GUARDIAN stops the whole system, a contract kind or an extension during incidents)
+#[no_mangle]
+pub extern "C" fn pause() {
+	controller(
+		service::pause::pause,
+		vec![ControllerRoles::OnlyRole(Role::Guardian)]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn unpause() {
+	controller(
+		service::pause::unpause,
+		vec![ControllerRoles::OnlyRole(Role::Guardian)]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn is_paused() {
+	controller(service::pause::is_paused, vec![])
+}
+
+/// Upgrade Endpoints
+#[no_mangle]
+pub extern "C" fn migrate() {
+	controller(
+		service::upgrade::migrate,
+		vec![ControllerRoles::OnlyMaintainer]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn contract_upgraded() {
+	controller(
+		service::upgrade::contract_upgraded,
+		vec![ControllerRoles::OnlyRole(Role::ShardManager)]
+	)
+}
+
+/// Read-only report of directory and authority problems
+#[no_mangle]
+pub extern "C" fn verify_topology() {
+	controller(service::topology::verify_topology, vec![])
+}
+
+#[no_mangle]
+pub extern "C" fn get_compound_contracts() {
+	controller(
+		service::contracts::get_compound_contracts,
+		vec![
+			ControllerRoles::OnlyLocalOperators,
+			ControllerRoles::OnlyAuthorizedContracts
+		]
 	)
 }
 
@@ -220,6 +565,49 @@ pub extern "C" fn set_listing_limit() {
 /// 12. set_chars_min_count
 /// 13. get_listing_limit
 /// 14. set_listing_limit
+/// 15. init
+/// 16. queue_proposal
+/// 17. cancel_proposal
+/// 18. execute_proposal
+/// 19. get_proposal
+/// 20. get_min_delay
+/// 21. set_min_delay
+/// 22. approve_proposal
+/// 23. revoke_approval
+/// 24. get_signers
+/// 25. set_signers
+/// 26. grant_role
+/// 27. revoke_role
+/// 28. renounce_role
+/// 29. has_role
+/// 30. get_role_members
+/// 31. pause
+/// 32. unpause
+/// 33. is_paused
+/// 34. get_extension_config
+/// 35. get_max_chars_count
+/// 36. set_max_chars_count
+/// 37. get_max_subscription_years
+/// 38. set_max_subscription_years
+/// 39. get_grace_period
+/// 40. set_grace_period
+/// 41. get_subdomain_limit
+/// 42. set_subdomain_limit
+/// 43. get_registration_mode
+/// 44. set_registration_mode
+/// 45. get_fee_recipient
+/// 46. set_fee_recipient
+/// 47. add_to_allowlist
+/// 48. remove_from_allowlist
+/// 49. is_allowlisted
+/// 50. add_standby_contract
+/// 51. get_standby_contracts
+/// 52. provision_contract
+/// 53. migrate_domains
+/// 54. migrate
+/// 55. contract_upgraded
+/// 56. verify_topology
+/// 57. get_compound_contracts
 
 #[no_mangle]
 pub extern "C" fn call() {
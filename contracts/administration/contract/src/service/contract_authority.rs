@@ -1,51 +1,60 @@
 use alloc::{ vec::Vec, string::ToString };
-use casper_contract::{
-	contract_api::runtime,
-	unwrap_or_revert::UnwrapOrRevert,
-};
+use casper_contract::contract_api::runtime;
 use casper_types::{ Key, ContractHash };
-use common_lib::{
-	constants::common_keys::AdministrationArgs,
-	errors::CommonError,
-	utils::authority::ensure_caller_has_permission,
-};
+use common_lib::constants::common_keys::AdministrationArgs;
 
 use crate::{
 	types::TResult,
 	db::contract_authorities::{ ContractAuthoritiesStore, ContractAuthorities },
+	utils::ensure_direct_change_allowed,
 };
 
+/// Once the timelock or the multisig is configured the authority lists are changed only through a proposal
 pub fn set_contract_autority_list() -> TResult<()> {
+	ensure_direct_change_allowed()?;
 	let contract_hash: ContractHash = runtime::get_named_arg(
 		&AdministrationArgs::ContractHash.to_string()
 	);
 	let contract_authorities: Vec<Key> = runtime::get_named_arg(
 		&AdministrationArgs::ContractAuthorities.to_string()
 	);
+	apply_contract_authority_list(contract_hash, contract_authorities)
+}
+
+pub(crate) fn apply_contract_authority_list(
+	contract_hash: ContractHash,
+	contract_authorities: Vec<Key>
+) -> TResult<()> {
 	let store = ContractAuthoritiesStore::instance();
 	store.set_contract_authority_list(contract_hash, contract_authorities);
 	Ok(())
 }
 
 pub fn add_contract_authority() -> TResult<()> {
-	let contract_hash: ContractHash = runtime::get_named_arg(
-		&AdministrationArgs::ContractHash.to_string()
-	);
-	let contract_authority: Key = runtime::get_named_arg(
-		&AdministrationArgs::ContractAuthority.to_string()
-	);
+	ensure_direct_change_allowed()?;
+	let (contract_hash, contract_authority) = get_contract_authority_args();
+	apply_add_contract_authority(contract_hash, contract_authority)
+}
+
+pub(crate) fn apply_add_contract_authority(
+	contract_hash: ContractHash,
+	contract_authority: Key
+) -> TResult<()> {
 	let store = ContractAuthoritiesStore::instance();
 	store.add_contract_authority(contract_hash, contract_authority);
 	Ok(())
 }
 
 pub fn remove_contract_authority() -> TResult<()> {
-	let contract_hash: ContractHash = runtime::get_named_arg(
-		&AdministrationArgs::ContractHash.to_string()
-	);
-	let contract_authority: Key = runtime::get_named_arg(
-		&AdministrationArgs::ContractAuthority.to_string()
-	);
+	ensure_direct_change_allowed()?;
+	let (contract_hash, contract_authority) = get_contract_authority_args();
+	apply_remove_contract_authority(contract_hash, contract_authority)
+}
+
+pub(crate) fn apply_remove_contract_authority(
+	contract_hash: ContractHash,
+	contract_authority: Key
+) -> TResult<()> {
 	let store = ContractAuthoritiesStore::instance();
 	store.remove_contract_authority(contract_hash, contract_authority);
 	Ok(())
@@ -58,3 +67,14 @@ pub fn get_contract_authority_list() -> TResult<Vec<Key>> {
 	let store = ContractAuthoritiesStore::instance();
 	Ok(store.get_contract_authority(contract_hash))
 }
+
+/// Helpers
+fn get_contract_authority_args() -> (ContractHash, Key) {
+	let contract_hash: ContractHash = runtime::get_named_arg(
+		&AdministrationArgs::ContractHash.to_string()
+	);
+	let contract_authority: Key = runtime::get_named_arg(
+		&AdministrationArgs::ContractAuthority.to_string()
+	);
+	(contract_hash, contract_authority)
+}
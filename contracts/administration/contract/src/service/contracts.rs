@@ -1,4 +1,4 @@
-use alloc::{ string::ToString, vec::Vec };
+use alloc::{ string::{ String, ToString }, vec, vec::Vec };
 use casper_contract::{
 	contract_api::runtime,
 	unwrap_or_revert::UnwrapOrRevert,
@@ -14,10 +14,7 @@ use common_lib::{
 	},
 	errors::{ AdministrationErrors, CommonError },
 	models::registry_pointer::CompoundContract,
-	utils::{
-		authority::ensure_caller_has_permission,
-		registry::get_verified_caller,
-	},
+	utils::registry::get_verified_caller,
 };
 
 use crate::{
@@ -25,7 +22,10 @@ use crate::{
 	types::TResult,
 };
 
-use crate::utils::get_extension_arg;
+use crate::{
+	service::provisioning::promote_standby_contract,
+	utils::{ get_extension_arg, ensure_direct_change_allowed },
+};
 
 /// This service is closed, only authorized calls are passed (including contracts)
 /// Parameters:
@@ -51,7 +51,9 @@ pub fn get_contract() -> TResult<(Key, Option<u32>)> {
 				.map(|item| *item)
 				.collect::<Vec<CompoundContract>>();
 			if filtered.is_empty() {
-				return Err(AdministrationErrors::ContractIsFilled);
+				// Every shard is filled, the registration continues on a promoted standby shard
+				let promoted = promote_standby_contract(&store, kind, &extension)?;
+				return Ok((promoted.key, promoted.count));
 			}
 			let first = filtered.first().unwrap();
 			Ok((first.key, first.count))
@@ -67,6 +69,39 @@ pub fn get_contract() -> TResult<(Key, Option<u32>)> {
 	}
 }
 
+/// This service is closed, only authorized calls are passed (including contracts)
+/// Parameters:
+/// - contract_kind: required (Database or NFTCore)
+/// - extension - optional, without it the shards of every extension are returned
+/// Returns every active shard of the extension, standby shards are not included
+pub fn get_compound_contracts() -> TResult<Vec<Key>> {
+	let kind: ContractKind = runtime::get_named_arg(
+		&AdministrationArgs::ContractKind.to_string()
+	);
+	if !is_compound(kind) {
+		return Err(AdministrationErrors::ContractNotFound);
+	}
+	let extension: Option<String> = runtime::get_named_arg(
+		&AdministrationArgs::Extension.to_string()
+	);
+
+	let store = Store::instance();
+	let extensions = match extension {
+		Some(extension) => vec![extension],
+		None => store.get_compound_extensions(kind),
+	};
+	let mut keys: Vec<Key> = vec![];
+	for extension in extensions {
+		for item in store.get_compound_contracts(kind, &extension) {
+			// Shards might serve several extensions
+			if !keys.contains(&item.key) {
+				keys.push(item.key);
+			}
+		}
+	}
+	Ok(keys)
+}
+
 /// This service is closed, only authorized calls are passed
 /// Parameters:
 /// - contract_kind: required
@@ -74,14 +109,30 @@ pub fn get_contract() -> TResult<(Key, Option<u32>)> {
 /// - extension - optional for simple contracts, otherwise is required
 
 pub fn add_contract() -> TResult<()> {
+	ensure_direct_change_allowed()?;
 	let kind: ContractKind = runtime::get_named_arg(
 		&AdministrationArgs::ContractKind.to_string()
 	);
 	let key: Key = runtime::get_named_arg(&AdministrationArgs::Key.to_string());
+	let extension = if is_compound(kind) {
+		Some(get_extension_arg().unwrap_or_revert())
+	} else {
+		None
+	};
+	apply_add_contract(kind, key, extension)
+}
+
+pub(crate) fn apply_add_contract(
+	kind: ContractKind,
+	key: Key,
+	extension: Option<String>
+) -> TResult<()> {
 	let store = Store::instance();
 
 	if is_compound(kind) {
-		let extension = get_extension_arg().unwrap_or_revert();
+		let extension = extension.unwrap_or_revert_with(
+			AdministrationErrors::ProvideExtensionArgument
+		);
 		store.add_compound_contract(kind, &extension, CompoundContract {
 			key: key,
 			count: Some(0),
@@ -99,7 +150,6 @@ pub fn add_contract() -> TResult<()> {
 /// - extension - optional for simple contracts, otherwise is required
 
 pub fn increment_contract() -> TResult<()> {
-	ensure_caller_has_permission().unwrap();
 	let kind: ContractKind = runtime::get_named_arg(
 		&AdministrationArgs::ContractKind.to_string()
 	);
@@ -120,7 +170,6 @@ pub fn increment_contract() -> TResult<()> {
 /// - extension - optional for simple contracts, otherwise is required
 
 pub fn decrement_contract() -> TResult<()> {
-	ensure_caller_has_permission().unwrap();
 	let kind: ContractKind = runtime::get_named_arg(
 		&AdministrationArgs::ContractKind.to_string()
 	);
@@ -136,6 +185,18 @@ pub fn decrement_contract() -> TResult<()> {
 }
 
 /// Helpers
-fn is_compound(contract_kind: ContractKind) -> bool {
+pub(crate) const CONTRACT_KINDS: [ContractKind; 9] = [
+	ContractKind::Main,
+	ContractKind::Database,
+	ContractKind::NFT,
+	ContractKind::NFTCore,
+	ContractKind::PriceOracle,
+	ContractKind::Registry,
+	ContractKind::Administration,
+	ContractKind::TransferFilter,
+	ContractKind::Treasury,
+];
+
+pub(crate) fn is_compound(contract_kind: ContractKind) -> bool {
 	[ContractKind::Database, ContractKind::NFTCore].contains(&contract_kind)
 }
@@ -0,0 +1,217 @@
+use alloc::string::{ String, ToString };
+use casper_contract::{
+	contract_api::runtime,
+	unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{ account::AccountHash, Key };
+use common_lib::{
+	constants::common_keys::AdministrationArgs,
+	db::store::Store,
+	enums::registration_mode::RegistrationMode,
+	errors::AdministrationErrors,
+	models::confiugration::ExtensionConfig,
+};
+
+use crate::{
+	db::extension_config::ExtensionConfigs,
+	types::TResult,
+	utils::{ get_extension_arg, ensure_direct_change_allowed },
+};
+
+/// Parameters:
+/// - extension - required
+pub fn get_extension_config() -> TResult<ExtensionConfig> {
+	let extension: String = get_extension_arg().unwrap_or_revert();
+	let store = Store::instance();
+	Ok(store.get_extension_config(&extension))
+}
+
+/// Parameters:
+/// - extension - required
+pub fn get_max_chars_count() -> TResult<u8> {
+	Ok(get_config_by_arg().max_chars_count)
+}
+
+/// Parameters:
+/// - extension - required
+/// - max_chars_count - required
+pub fn set_max_chars_count() -> TResult<()> {
+	let value: u8 = runtime::get_named_arg(
+		&AdministrationArgs::MaxCharsCount.to_string()
+	);
+	update_config(|config| {
+		config.max_chars_count = value;
+	})
+}
+
+/// Parameters:
+/// - extension - required
+pub fn get_max_subscription_years() -> TResult<u8> {
+	Ok(get_config_by_arg().max_subscription_years)
+}
+
+/// Parameters:
+/// - extension - required
+/// - max_subscription_years - required
+pub fn set_max_subscription_years() -> TResult<()> {
+	let value: u8 = runtime::get_named_arg(
+		&AdministrationArgs::MaxSubscriptionYears.to_string()
+	);
+	update_config(|config| {
+		config.max_subscription_years = value;
+	})
+}
+
+/// Parameters:
+/// - extension - required
+pub fn get_grace_period() -> TResult<u64> {
+	Ok(get_config_by_arg().grace_period)
+}
+
+/// Parameters:
+/// - extension - required
+/// - grace_period - required (millis)
+pub fn set_grace_period() -> TResult<()> {
+	let value: u64 = runtime::get_named_arg(
+		&AdministrationArgs::GracePeriod.to_string()
+	);
+	update_config(|config| {
+		config.grace_period = value;
+	})
+}
+
+/// Parameters:
+/// - extension - required
+pub fn get_subdomain_limit() -> TResult<u8> {
+	Ok(get_config_by_arg().max_subdomain_count)
+}
+
+/// Parameters:
+/// - extension - required
+/// - subdomain_limit - required
+pub fn set_subdomain_limit() -> TResult<()> {
+	let value: u8 = runtime::get_named_arg(
+		&AdministrationArgs::SubdomainLimit.to_string()
+	);
+	update_config(|config| {
+		config.max_subdomain_count = value;
+	})
+}
+
+/// Parameters:
+/// - extension - required
+pub fn get_registration_mode() -> TResult<RegistrationMode> {
+	Ok(get_config_by_arg().registration_mode)
+}
+
+/// Parameters:
+/// - extension - required
+/// - registration_mode - required
+pub fn set_registration_mode() -> TResult<()> {
+	let value: RegistrationMode = runtime::get_named_arg(
+		&AdministrationArgs::RegistrationMode.to_string()
+	);
+	update_config(|config| {
+		config.registration_mode = value;
+	})
+}
+
+/// Parameters:
+/// - extension - required
+pub fn get_fee_recipient() -> TResult<Option<AccountHash>> {
+	Ok(get_config_by_arg().fee_recipient)
+}
+
+/// Parameters:
+/// - extension - required
+/// - fee_recipient - required, fees are deposited to the treasury whatever it is set to
+pub fn set_fee_recipient() -> TResult<()> {
+	let value: Option<AccountHash> = runtime::get_named_arg(
+		&AdministrationArgs::FeeRecipient.to_string()
+	);
+	update_config(|config| {
+		config.fee_recipient = value;
+	})
+}
+
+/// Parameters:
+/// - extension - required
+/// - account - required
+pub fn add_to_allowlist() -> TResult<()> {
+	let (extension, account) = get_allowlist_args();
+	let store = Store::instance();
+	let mut accounts = store.get_registration_allowlist(&extension);
+	if !accounts.contains(&account) {
+		accounts.push(account);
+		store.set_registration_allowlist(&extension, accounts);
+	}
+	Ok(())
+}
+
+/// Parameters:
+/// - extension - required
+/// - account - required
+pub fn remove_from_allowlist() -> TResult<()> {
+	let (extension, account) = get_allowlist_args();
+	let store = Store::instance();
+	let mut accounts = store.get_registration_allowlist(&extension);
+	if let Some(position) = accounts.iter().position(|item| item == &account) {
+		accounts.remove(position);
+		store.set_registration_allowlist(&extension, accounts);
+	}
+	Ok(())
+}
+
+/// Parameters:
+/// - extension - required
+/// - account - required
+pub fn is_allowlisted() -> TResult<bool> {
+	let (extension, account) = get_allowlist_args();
+	let store = Store::instance();
+	Ok(store.get_registration_allowlist(&extension).contains(&account))
+}
+
+pub(crate) fn apply_extension_config(
+	extension: &str,
+	config: ExtensionConfig
+) -> TResult<()> {
+	validate_extension_config(&config)?;
+	let store = Store::instance();
+	store.set_extension_config(extension, config);
+	Ok(())
+}
+
+pub(crate) fn validate_extension_config(config: &ExtensionConfig) -> TResult<()> {
+	if
+		config.min_chars_count == 0 ||
+		config.min_chars_count > config.max_chars_count ||
+		config.max_subscription_years == 0
+	{
+		return Err(AdministrationErrors::InvalidExtensionConfig);
+	}
+	Ok(())
+}
+
+/// Helpers
+fn get_config_by_arg() -> ExtensionConfig {
+	let extension: String = get_extension_arg().unwrap_or_revert();
+	let store = Store::instance();
+	store.get_extension_config(&extension)
+}
+
+fn update_config<F>(update: F) -> TResult<()> where F: Fn(&mut ExtensionConfig) {
+	ensure_direct_change_allowed()?;
+	let extension: String = get_extension_arg().unwrap_or_revert();
+	let store = Store::instance();
+	let mut config = store.get_extension_config(&extension);
+	update(&mut config);
+	apply_extension_config(&extension, config)
+}
+
+fn get_allowlist_args() -> (String, Key) {
+	let extension: String = get_extension_arg().unwrap_or_revert();
+	let account: Key = runtime::get_named_arg(
+		&AdministrationArgs::Account.to_string()
+	);
+	(extension, account)
+}
@@ -6,18 +6,24 @@ use casper_contract::{
 use common_lib::{
 	db::store::Store,
 	constants::common_keys::AdministrationArgs,
-	utils::authority::ensure_caller_has_permission,
 	errors::AdministrationErrors,
 };
 
-use crate::{ types::TResult, db::allowed_extensions::AllowedExtensions };
+use crate::{
+	types::TResult,
+	db::allowed_extensions::AllowedExtensions,
+	utils::ensure_direct_change_allowed,
+};
 
 pub fn set_allowed_extensions() -> TResult<()> {
-	ensure_caller_has_permission().unwrap();
+	ensure_direct_change_allowed()?;
 	let extensions: Vec<String> = runtime::get_named_arg(
 		&AdministrationArgs::AllowedExtensions.to_string()
 	);
+	apply_allowed_extensions(extensions)
+}
 
+pub(crate) fn apply_allowed_extensions(extensions: Vec<String>) -> TResult<()> {
 	let store = Store::instance();
 	store.set_allowed_extensions(extensions);
 	Ok(())
@@ -29,20 +35,28 @@ pub fn get_allowed_extensions() -> TResult<Vec<String>> {
 }
 
 pub fn add_extension() -> TResult<()> {
-	ensure_caller_has_permission().unwrap();
+	ensure_direct_change_allowed()?;
 	let extension: String = runtime::get_named_arg(
 		&AdministrationArgs::AllowedExtension.to_string()
 	);
+	apply_add_extension(extension)
+}
+
+pub(crate) fn apply_add_extension(extension: String) -> TResult<()> {
 	let store = Store::instance();
 	store.add_extension(extension);
 	Ok(())
 }
 
 pub fn remove_extension() -> TResult<()> {
-	ensure_caller_has_permission().unwrap();
+	ensure_direct_change_allowed()?;
 	let extension: String = runtime::get_named_arg(
 		&AdministrationArgs::AllowedExtension.to_string()
 	);
+	apply_remove_extension(extension)
+}
+
+pub(crate) fn apply_remove_extension(extension: String) -> TResult<()> {
 	let store = Store::instance();
 	store.remove_extension(extension);
 	Ok(())
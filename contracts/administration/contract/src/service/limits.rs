@@ -12,8 +12,9 @@ use common_lib::{
 
 use crate::{
 	types::TResult,
-	utils::get_extension_arg,
-	db::domain_limit::DomainLimit,
+	utils::{ get_extension_arg, ensure_direct_change_allowed },
+	db::{ domain_limit::DomainLimit, extension_config::ExtensionConfigs },
+	service::extension_config::apply_extension_config,
 };
 
 /// Parameters:
@@ -28,13 +29,20 @@ pub fn get_chars_min_count() -> TResult<u8> {
 /// - extension - required
 /// - count - required
 pub fn set_chars_min_count() -> TResult<()> {
+	ensure_direct_change_allowed()?;
 	let count: u8 = runtime::get_named_arg(
 		&AdministrationArgs::CharsCount.to_string()
 	);
 	let extension: String = get_extension_arg().unwrap_or_revert();
+	apply_chars_min_count(&extension, count)
+}
+
+/// Validated as a part of the extension config, 0 or more than the max chars count is rejected
+pub(crate) fn apply_chars_min_count(extension: &str, count: u8) -> TResult<()> {
 	let store = Store::instance();
-	store.set_chars_min_count(&extension, count);
-	Ok(())
+	let mut config = store.get_extension_config(extension);
+	config.min_chars_count = count;
+	apply_extension_config(extension, config)
 }
 
 /// Parameters:
@@ -52,12 +60,17 @@ pub fn get_listing_limit() -> TResult<u32> {
 /// - contract_kind - required
 /// - value - required
 pub fn set_listing_limit() -> TResult<()> {
+	ensure_direct_change_allowed()?;
 	let kind: ContractKind = runtime::get_named_arg(
 		&AdministrationArgs::ContractKind.to_string()
 	);
 	let value: u32 = runtime::get_named_arg(
 		&AdministrationArgs::CharsCount.to_string()
 	);
+	apply_listing_limit(kind, value)
+}
+
+pub(crate) fn apply_listing_limit(kind: ContractKind, value: u32) -> TResult<()> {
 	let store = Store::instance();
 	store.set_listing_limit(kind, value);
 	Ok(())
@@ -0,0 +1,152 @@
+use alloc::{ string::{ String, ToString }, vec::Vec };
+use casper_contract::{
+	contract_api::runtime,
+	unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{ runtime_args, ContractHash, Key, RuntimeArgs };
+use common_lib::{
+	constants::common_keys::{
+		AdministrationArgs,
+		DatabaseArgs,
+		DatabaseEndpoints,
+		RegistryArgs,
+		RegistryEndpoints,
+		MAX_MIGRATION_BATCH_SIZE,
+	},
+	db::store::Store,
+	enums::contracts_enum::ContractKind,
+	errors::AdministrationErrors,
+	events::administration::DomainsMigrated,
+	models::domain_migration::DomainMigrationEntry,
+	utils::domain_name::get_domain_name_extension,
+};
+
+use crate::{
+	db::contract_hash_list::ContractHashList,
+	types::TResult,
+	utils::get_extension_arg,
+};
+
+/// Moves a bounded batch of domain names between two database shards of the extension.
+/// Every step runs in the same deploy, so the batch is moved completely or not at all.
+/// Names which are not stored in the source shard are skipped, so a large shard is drained
+/// by sending batches one after another and a failed batch can be sent again.
+/// Administration contract should be an authority of both shards and whitelisted in the registry.
+/// This service is closed, only maintainer and SHARD_MANAGER role are passed
+/// Parameters:
+/// - from - required
+/// - to - required
+/// - extension - required
+/// - domain_names - required
+/// Names already stored in the target shard keep the stored entry there, the exported one is dropped.
+/// Returns count of domain names imported by the target shard
+pub fn migrate_domains() -> TResult<u32> {
+	let from: Key = runtime::get_named_arg(&AdministrationArgs::From.to_string());
+	let to: Key = runtime::get_named_arg(&AdministrationArgs::To.to_string());
+	let extension: String = get_extension_arg().unwrap_or_revert();
+	let domain_names: Vec<String> = runtime::get_named_arg(
+		&AdministrationArgs::DomainNames.to_string()
+	);
+
+	if domain_names.len() > MAX_MIGRATION_BATCH_SIZE {
+		return Err(AdministrationErrors::MigrationBatchTooLarge);
+	}
+	let store = Store::instance();
+	validate_migration(&store, from, to, &extension, &domain_names)?;
+
+	let from_hash = get_contract_hash(from)?;
+	let to_hash = get_contract_hash(to)?;
+
+	let entries: Vec<DomainMigrationEntry> = runtime::call_contract(
+		from_hash,
+		&DatabaseEndpoints::ExportDomains.to_string(),
+		runtime_args! {
+			DatabaseArgs::DomainNames.to_string() => domain_names
+		}
+	);
+	if entries.is_empty() {
+		return Ok(0);
+	}
+	let moved_names = entries
+		.iter()
+		.map(|entry| entry.domain.name.clone())
+		.collect::<Vec<String>>();
+
+	let imported_count: u32 = runtime::call_contract(
+		to_hash,
+		&DatabaseEndpoints::ImportDomains.to_string(),
+		runtime_args! {
+			DatabaseArgs::MigrationEntries.to_string() => entries
+		}
+	);
+
+	if let Some(registry) = store.get_simple_contract(ContractKind::Registry) {
+		runtime::call_contract::<()>(
+			get_contract_hash(registry)?,
+			&RegistryEndpoints::RemapDomainNames.to_string(),
+			runtime_args! {
+				RegistryArgs::DomainNames.to_string() => moved_names.clone(),
+				RegistryArgs::DatabaseContractHash.to_string() => to_hash
+			}
+		);
+	}
+
+	// Every exported name left the source shard, only the imported ones were added to the target
+	store.change_count_of_compound_contracts(
+		ContractKind::Database,
+		&extension,
+		from,
+		-(moved_names.len() as i32)
+	);
+	store.change_count_of_compound_contracts(
+		ContractKind::Database,
+		&extension,
+		to,
+		imported_count as i32
+	);
+
+	casper_event_standard::emit(DomainsMigrated {
+		extension,
+		from,
+		to,
+		domain_names: moved_names,
+	});
+	Ok(imported_count)
+}
+
+/// Helpers
+fn validate_migration(
+	store: &Store,
+	from: Key,
+	to: Key,
+	extension: &str,
+	domain_names: &Vec<String>
+) -> TResult<()> {
+	if from == to {
+		return Err(AdministrationErrors::InvalidMigrationTarget);
+	}
+	let contracts = store.get_compound_contracts(
+		ContractKind::Database,
+		extension
+	);
+	let is_shard = |key: Key| contracts.iter().any(|item| item.key == key);
+	if !is_shard(from) || !is_shard(to) {
+		return Err(AdministrationErrors::InvalidMigrationTarget);
+	}
+	let has_foreign_name = domain_names
+		.iter()
+		.any(|name| {
+			get_domain_name_extension(name).as_deref() != Some(extension)
+		});
+	if has_foreign_name {
+		return Err(AdministrationErrors::InvalidMigrationTarget);
+	}
+	Ok(())
+}
+
+fn get_contract_hash(key: Key) -> TResult<ContractHash> {
+	key
+		.into_hash()
+		.map(ContractHash::new)
+		.ok_or(AdministrationErrors::ContractNotFound)
+}
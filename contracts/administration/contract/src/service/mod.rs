@@ -1,5 +1,14 @@
 pub mod contract_authority;
 pub mod contracts;
+pub mod extension_config;
 pub mod extensions;
 pub mod init;
 pub mod limits;
+pub mod migration;
+pub mod multisig;
+pub mod pause;
+pub mod provisioning;
+pub mod roles;
+pub mod timelock;
+pub mod topology;
+pub mod upgrade;
@@ -0,0 +1,124 @@
+use alloc::{ string::ToString, vec::Vec };
+use casper_contract::contract_api::runtime;
+use casper_types::Key;
+use common_lib::{
+	constants::common_keys::AdministrationArgs,
+	db::store::Store,
+	enums::{
+		caller_verification_type::CallerVerificationType,
+		proposal_status::ProposalStatus,
+	},
+	errors::AdministrationErrors,
+	events::administration::{
+		ApprovalRevoked,
+		ProposalApproved,
+		SignersChanged,
+	},
+	models::proposal::Proposal,
+	utils::registry::get_verified_caller,
+};
+
+use crate::{
+	db::{ multisig::Multisig, proposals::{ ProposalStore, Proposals } },
+	service::timelock::{ queue_if_approved, validate_signers },
+	types::TResult,
+	utils::ensure_direct_change_allowed,
+};
+
+/// This service is closed, only signers are passed
+/// Parameters:
+/// - proposal_id - required
+pub fn approve_proposal() -> TResult<()> {
+	let store = Store::instance();
+	let signer = get_signer_caller(&store)?;
+	let proposal_store = ProposalStore::instance();
+	let mut proposal = get_pending_proposal(&proposal_store)?;
+
+	if proposal.approvals.contains(&signer) {
+		return Err(AdministrationErrors::ProposalAlreadyApproved);
+	}
+	proposal.approvals.push(signer);
+
+	casper_event_standard::emit(ProposalApproved {
+		proposal_id: proposal.id,
+		signer,
+	});
+	queue_if_approved(&store, &mut proposal);
+	proposal_store.save_proposal(proposal);
+	Ok(())
+}
+
+/// This service is closed, only signers are passed
+/// Parameters:
+/// - proposal_id - required
+pub fn revoke_approval() -> TResult<()> {
+	let store = Store::instance();
+	let signer = get_signer_caller(&store)?;
+	let proposal_store = ProposalStore::instance();
+	let mut proposal = get_pending_proposal(&proposal_store)?;
+
+	let position = proposal.approvals
+		.iter()
+		.position(|item| item == &signer)
+		.ok_or(AdministrationErrors::ApprovalNotFound)?;
+	proposal.approvals.remove(position);
+
+	casper_event_standard::emit(ApprovalRevoked {
+		proposal_id: proposal.id,
+		signer,
+	});
+	proposal_store.save_proposal(proposal);
+	Ok(())
+}
+
+pub fn get_signers() -> TResult<(Vec<Key>, u8)> {
+	let store = Store::instance();
+	Ok((store.get_signers(), store.get_threshold()))
+}
+
+/// Bootstraps the multisig, afterwards the signer set is rotated only by a proposal approved under the current threshold
+/// Parameters:
+/// - signers - required
+/// - threshold - required
+pub fn set_signers() -> TResult<()> {
+	ensure_direct_change_allowed()?;
+	let signers: Vec<Key> = runtime::get_named_arg(
+		&AdministrationArgs::Signers.to_string()
+	);
+	let threshold: u8 = runtime::get_named_arg(
+		&AdministrationArgs::Threshold.to_string()
+	);
+	validate_signers(&signers, threshold)?;
+	apply_signers(signers, threshold)
+}
+
+pub(crate) fn apply_signers(signers: Vec<Key>, threshold: u8) -> TResult<()> {
+	let store = Store::instance();
+	store.set_signers(signers.clone(), threshold);
+
+	casper_event_standard::emit(SignersChanged { signers, threshold });
+	Ok(())
+}
+
+/// Helpers
+fn get_signer_caller(store: &Store) -> TResult<Key> {
+	let caller = get_verified_caller(CallerVerificationType::All).unwrap();
+	if !store.is_signer(&caller) {
+		return Err(AdministrationErrors::NotASigner);
+	}
+	Ok(caller)
+}
+
+fn get_pending_proposal(proposal_store: &ProposalStore) -> TResult<Proposal> {
+	let id: u64 = runtime::get_named_arg(
+		&AdministrationArgs::ProposalId.to_string()
+	);
+	let proposal = proposal_store
+		.get_proposal(id)
+		.ok_or(AdministrationErrors::ProposalNotFound)?;
+
+	if proposal.status != ProposalStatus::Pending {
+		return Err(AdministrationErrors::ProposalIsNotQueued);
+	}
+	Ok(proposal)
+}
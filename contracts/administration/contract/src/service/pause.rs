@@ -0,0 +1,80 @@
+use alloc::string::{ String, ToString };
+use casper_contract::contract_api::runtime;
+use common_lib::{
+	constants::common_keys::AdministrationArgs,
+	db::store::Store,
+	enums::{
+		caller_verification_type::CallerVerificationType,
+		contracts_enum::ContractKind,
+	},
+	errors::AdministrationErrors,
+	events::administration::{ Paused, Unpaused },
+	utils::registry::get_verified_caller,
+};
+
+use crate::{ db::pause::{ Pause, PauseScope }, types::TResult };
+
+/// This service is closed, only maintainer and GUARDIAN role are passed
+/// Parameters:
+/// - contract_kind - optional, pauses the contract kind
+/// - extension - optional, pauses the extension
+/// Global pause if both are omitted
+pub fn pause() -> TResult<()> {
+	let scope = get_scope_args()?;
+	let store = Store::instance();
+	store.set_scope_paused(&scope, true);
+
+	casper_event_standard::emit(Paused {
+		scope: scope.to_string(),
+		sender: get_verified_caller(CallerVerificationType::All).unwrap(),
+	});
+	Ok(())
+}
+
+/// This service is closed, only maintainer and GUARDIAN role are passed
+/// Parameters:
+/// - contract_kind - optional, unpauses the contract kind
+/// - extension - optional, unpauses the extension
+/// Global unpause if both are omitted
+pub fn unpause() -> TResult<()> {
+	let scope = get_scope_args()?;
+	let store = Store::instance();
+	store.set_scope_paused(&scope, false);
+
+	casper_event_standard::emit(Unpaused {
+		scope: scope.to_string(),
+		sender: get_verified_caller(CallerVerificationType::All).unwrap(),
+	});
+	Ok(())
+}
+
+/// Parameters:
+/// - contract_kind - required
+/// - extension - optional
+/// Returns true if the system, the contract kind or the extension is paused
+pub fn is_paused() -> TResult<bool> {
+	let kind: ContractKind = runtime::get_named_arg(
+		&AdministrationArgs::ContractKind.to_string()
+	);
+	let extension: Option<String> = runtime::get_named_arg(
+		&AdministrationArgs::Extension.to_string()
+	);
+	let store = Store::instance();
+	Ok(store.is_paused(kind, extension))
+}
+
+/// Helpers
+fn get_scope_args() -> TResult<PauseScope> {
+	let kind: Option<ContractKind> = runtime::get_named_arg(
+		&AdministrationArgs::ContractKind.to_string()
+	);
+	let extension: Option<String> = runtime::get_named_arg(
+		&AdministrationArgs::Extension.to_string()
+	);
+	match (kind, extension) {
+		(None, None) => Ok(PauseScope::Global),
+		(Some(kind), None) => Ok(PauseScope::Contract(kind)),
+		(None, Some(extension)) => Ok(PauseScope::Extension(extension)),
+		(Some(_), Some(_)) => Err(AdministrationErrors::InvalidPauseScope),
+	}
+}
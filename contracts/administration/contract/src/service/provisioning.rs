@@ -0,0 +1,174 @@
+use alloc::{ string::{ String, ToString }, vec, vec::Vec };
+use casper_contract::{
+	contract_api::runtime,
+	unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{ runtime_args, ContractHash, Key, RuntimeArgs };
+use common_lib::{
+	constants::common_keys::{ AdministrationArgs, NFTCoreContractEndpoints },
+	db::store::Store,
+	enums::{ contracts_enum::ContractKind, roles::Role },
+	errors::AdministrationErrors,
+	events::administration::ContractProvisioned,
+	models::registry_pointer::CompoundContract,
+	utils::contract::get_called_contract_hash,
+};
+
+use crate::{
+	db::{
+		contract_authorities::{ ContractAuthorities, ContractAuthoritiesStore },
+		contract_hash_list::ContractHashList,
+		domain_limit::DomainLimit,
+		standby::StandbyContracts,
+	},
+	service::contracts::is_compound,
+	types::TResult,
+	utils::get_extension_arg,
+};
+
+/// Casper contracts can not install packages built from another wasm,
+/// so new shards are deployed ahead of time and kept in the standby pool until they are needed.
+/// This service is closed, only maintainer and SHARD_MANAGER role are passed
+/// Parameters:
+/// - contract_kind - required (Database or NFTCore)
+/// - extension - required
+/// - key - required
+pub fn add_standby_contract() -> TResult<()> {
+	let kind = get_compound_kind_arg()?;
+	let extension: String = get_extension_arg().unwrap_or_revert();
+	let key: Key = runtime::get_named_arg(&AdministrationArgs::Key.to_string());
+	let store = Store::instance();
+	store.add_standby_contract(kind, &extension, key);
+	Ok(())
+}
+
+/// Parameters:
+/// - contract_kind - required
+/// - extension - required
+pub fn get_standby_contracts() -> TResult<Vec<Key>> {
+	let kind = get_compound_kind_arg()?;
+	let extension: String = get_extension_arg().unwrap_or_revert();
+	let store = Store::instance();
+	Ok(store.get_standby_contracts(kind, &extension))
+}
+
+/// Keeper call, this service is open: it only promotes a standby shard once every active shard is filled
+/// Parameters:
+/// - contract_kind - required
+/// - extension - required
+/// Returns key of the promoted contract
+pub fn provision_contract() -> TResult<Key> {
+	let kind = get_compound_kind_arg()?;
+	let extension: String = get_extension_arg().unwrap_or_revert();
+	let store = Store::instance();
+
+	let limit = store.get_listing_limit(kind);
+	let has_free_contract = store
+		.get_compound_contracts(kind, &extension)
+		.iter()
+		.any(|item| item.count.unwrap_or(0) < limit);
+	if has_free_contract {
+		return Err(AdministrationErrors::ContractIsNotFilled);
+	}
+
+	promote_standby_contract(&store, kind, &extension).map(|item| item.key)
+}
+
+/// Moves the first standby contract into the compound contract list
+/// and gives it the same authorities and SYSTEM_CONTRACT role as its filled sibling
+pub(crate) fn promote_standby_contract(
+	store: &Store,
+	kind: ContractKind,
+	extension: &str
+) -> TResult<CompoundContract> {
+	let key = store
+		.take_standby_contract(kind, extension)
+		.ok_or(AdministrationErrors::ContractIsFilled)?;
+	let sibling = store
+		.get_compound_contracts(kind, extension)
+		.first()
+		.map(|item| item.key);
+
+	let contract = CompoundContract {
+		key,
+		count: Some(0),
+	};
+	store.add_compound_contract(kind, extension, contract);
+
+	if let Some(sibling) = sibling {
+		wire_authorities(sibling, key);
+		if kind == ContractKind::NFTCore {
+			whitelist_nft_contract(store, key);
+		}
+		if store.has_role(Role::SystemContract, &sibling) {
+			store.grant_role(Role::SystemContract, key);
+		}
+	}
+
+	casper_event_standard::emit(ContractProvisioned {
+		contract_kind: kind.to_string(),
+		extension: extension.to_string(),
+		key,
+	});
+	Ok(contract)
+}
+
+/// Helpers
+fn wire_authorities(sibling: Key, key: Key) {
+	let authorities_store = ContractAuthoritiesStore::instance();
+	let sibling_hash = ContractHash::new(
+		sibling
+			.into_hash()
+			.unwrap_or_revert_with(AdministrationErrors::ContractNotFound)
+	);
+	let contract_hash = ContractHash::new(
+		key
+			.into_hash()
+			.unwrap_or_revert_with(AdministrationErrors::ContractNotFound)
+	);
+
+	// Callers of the sibling are allowed to call the new contract
+	authorities_store.set_contract_authority_list(
+		contract_hash,
+		authorities_store.get_contract_authority(sibling_hash)
+	);
+
+	// The new contract calls the administration contract the same way the sibling does
+	let administration_hash = get_called_contract_hash().unwrap_or_revert_with(
+		AdministrationErrors::ContractNotFound
+	);
+	let administration_authorities = authorities_store.get_contract_authority(
+		administration_hash
+	);
+	if administration_authorities.contains(&sibling) {
+		authorities_store.add_contract_authority(administration_hash, key);
+	}
+}
+
+/// nft-core shards are installed with the administration contract as their ACL manager,
+/// only the nft contract mints names
+fn whitelist_nft_contract(store: &Store, key: Key) {
+	let nft_key = store
+		.get_simple_contract(ContractKind::NFT)
+		.unwrap_or_revert_with(AdministrationErrors::ContractNotFound);
+	let nft_core_hash = ContractHash::new(
+		key.into_hash().unwrap_or_revert_with(AdministrationErrors::ContractNotFound)
+	);
+	runtime::call_contract::<()>(
+		nft_core_hash,
+		&NFTCoreContractEndpoints::SetAclWhitelist.to_string(),
+		runtime_args! {
+			"acl_whitelist" => vec![nft_key],
+		}
+	);
+}
+
+fn get_compound_kind_arg() -> TResult<ContractKind> {
+	let kind: ContractKind = runtime::get_named_arg(
+		&AdministrationArgs::ContractKind.to_string()
+	);
+	if !is_compound(kind) {
+		return Err(AdministrationErrors::ContractNotFound);
+	}
+	Ok(kind)
+}
@@ -0,0 +1,108 @@
+use alloc::{ string::ToString, vec::Vec };
+use casper_contract::contract_api::runtime;
+use casper_types::Key;
+use common_lib::{
+	constants::common_keys::AdministrationArgs,
+	db::store::Store,
+	enums::{ caller_verification_type::CallerVerificationType, roles::Role },
+	events::administration::{ RoleGranted, RoleRevoked },
+	utils::registry::get_verified_caller,
+};
+
+use crate::{ types::TResult, utils::ensure_direct_change_allowed };
+
+/// This service is closed, only maintainer and ADMIN role are passed.
+/// Once the timelock or the multisig is configured roles are granted only through a proposal
+/// Parameters:
+/// - role - required
+/// - account - required
+pub fn grant_role() -> TResult<()> {
+	ensure_direct_change_allowed()?;
+	let (role, account) = get_role_args();
+	apply_grant_role(role, account)
+}
+
+pub(crate) fn apply_grant_role(role: Role, account: Key) -> TResult<()> {
+	let store = Store::instance();
+	store.grant_role(role, account);
+
+	casper_event_standard::emit(RoleGranted {
+		role: role.to_string(),
+		account,
+		sender: get_verified_caller(CallerVerificationType::All).unwrap(),
+	});
+	Ok(())
+}
+
+/// This service is closed, only maintainer and ADMIN role are passed.
+/// Once the timelock or the multisig is configured roles are revoked only through a proposal
+/// Parameters:
+/// - role - required
+/// - account - required
+pub fn revoke_role() -> TResult<()> {
+	ensure_direct_change_allowed()?;
+	let (role, account) = get_role_args();
+	apply_revoke_role(role, account)
+}
+
+pub(crate) fn apply_revoke_role(role: Role, account: Key) -> TResult<()> {
+	let store = Store::instance();
+	store.revoke_role(role, &account);
+
+	casper_event_standard::emit(RoleRevoked {
+		role: role.to_string(),
+		account,
+		sender: get_verified_caller(CallerVerificationType::All).unwrap(),
+	});
+	Ok(())
+}
+
+/// Caller gives up its own role
+/// Parameters:
+/// - role - required
+pub fn renounce_role() -> TResult<()> {
+	let role: Role = runtime::get_named_arg(
+		&AdministrationArgs::Role.to_string()
+	);
+	let caller = get_verified_caller(CallerVerificationType::All).unwrap();
+	let store = Store::instance();
+	store.revoke_role(role, &caller);
+
+	casper_event_standard::emit(RoleRevoked {
+		role: role.to_string(),
+		account: caller,
+		sender: caller,
+	});
+	Ok(())
+}
+
+/// Used by other contracts for the role checks
+/// Parameters:
+/// - role - required
+/// - account - required
+pub fn has_role() -> TResult<bool> {
+	let (role, account) = get_role_args();
+	let store = Store::instance();
+	Ok(store.has_role(role, &account))
+}
+
+/// Parameters:
+/// - role - required
+pub fn get_role_members() -> TResult<Vec<Key>> {
+	let role: Role = runtime::get_named_arg(
+		&AdministrationArgs::Role.to_string()
+	);
+	let store = Store::instance();
+	Ok(store.get_role_members(role))
+}
+
+/// Helpers
+fn get_role_args() -> (Role, Key) {
+	let role: Role = runtime::get_named_arg(
+		&AdministrationArgs::Role.to_string()
+	);
+	let account: Key = runtime::get_named_arg(
+		&AdministrationArgs::Account.to_string()
+	);
+	(role, account)
+}
@@ -0,0 +1,374 @@
+use alloc::{ string::ToString, vec, vec::Vec };
+use casper_contract::{
+	contract_api::runtime,
+	unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{ runtime_args, ContractHash, Key, RuntimeArgs };
+use common_lib::{
+	constants::common_keys::{
+		AdministrationArgs,
+		PriceOracleArgs,
+		ENDPOINT_PO_PRICE_SET_SIMPLE_OPERATIONS,
+		ENDPOINT_PO_SET_PRICE,
+	},
+	db::store::Store,
+	enums::{
+		caller_verification_type::CallerVerificationType,
+		contracts_enum::ContractKind,
+		proposal_status::ProposalStatus,
+	},
+	errors::AdministrationErrors,
+	events::administration::{
+		CancellationApproved,
+		ProposalCancelled,
+		ProposalApproved,
+		ProposalExecuted,
+		ProposalQueued,
+	},
+	models::proposal::{ Proposal, ProposalAction },
+	utils::{
+		authority::ensure_caller_has_permission,
+		registry::get_verified_caller,
+		roles::has_role,
+	},
+};
+
+use crate::{
+	db::{
+		contract_hash_list::ContractHashList,
+		multisig::Multisig,
+		proposals::{ ProposalStore, Proposals },
+		timelock::{ Timelock, PROPOSAL_EXECUTION_WINDOW },
+	},
+	service::{
+		contract_authority,
+		contracts,
+		extension_config,
+		extensions,
+		limits,
+		multisig,
+		roles,
+	},
+	types::TResult,
+	utils::ensure_direct_change_allowed,
+};
+
+/// Closed for local operators and holders of the action's role, or only for signers if multisig is configured
+/// Parameters:
+/// - proposal_action - required
+/// Returns id of the proposal, it is queued at once or after collecting multisig approvals
+pub fn queue_proposal() -> TResult<u64> {
+	let action: ProposalAction = runtime::get_named_arg(
+		&AdministrationArgs::ProposalAction.to_string()
+	);
+	validate_action(&action)?;
+	let proposer = ensure_caller_can_propose(&action)?;
+	let store = Store::instance();
+
+	let mut proposal = Proposal {
+		id: store.next_proposal_id(),
+		action,
+		proposer,
+		eta: 0,
+		status: ProposalStatus::Pending,
+		approvals: vec![proposer],
+	};
+
+	if store.is_multisig_active() {
+		casper_event_standard::emit(ProposalApproved {
+			proposal_id: proposal.id,
+			signer: proposer,
+		});
+	}
+	queue_if_approved(&store, &mut proposal);
+
+	let id = proposal.id;
+	ProposalStore::instance().save_proposal(proposal);
+	Ok(id)
+}
+
+/// Closed for local operators and holders of the action's role, or only for signers if multisig is configured.
+/// Under multisig the proposer cancels at once, other signers approve the cancellation until the threshold is reached
+/// Parameters:
+/// - proposal_id - required
+pub fn cancel_proposal() -> TResult<()> {
+	let store = Store::instance();
+	let proposal_store = ProposalStore::instance();
+	let id: u64 = runtime::get_named_arg(
+		&AdministrationArgs::ProposalId.to_string()
+	);
+	let mut proposal = proposal_store
+		.get_proposal(id)
+		.ok_or(AdministrationErrors::ProposalNotFound)?;
+	let canceller = ensure_caller_can_propose(&proposal.action)?;
+
+	if
+		proposal.status != ProposalStatus::Queued &&
+		proposal.status != ProposalStatus::Pending
+	{
+		return Err(AdministrationErrors::ProposalIsNotQueued);
+	}
+
+	if store.is_multisig_active() && canceller != proposal.proposer {
+		let mut cancellations = proposal_store.get_cancellations(id);
+		if cancellations.contains(&canceller) {
+			return Err(AdministrationErrors::ProposalAlreadyApproved);
+		}
+		cancellations.push(canceller);
+		casper_event_standard::emit(CancellationApproved {
+			proposal_id: id,
+			signer: canceller,
+		});
+
+		// Cancellations of rotated out signers are not counted
+		let approvals = cancellations
+			.iter()
+			.filter(|key| store.is_signer(key))
+			.count();
+		proposal_store.save_cancellations(id, cancellations);
+		if approvals < (store.get_threshold() as usize) {
+			return Ok(());
+		}
+	}
+
+	proposal.status = ProposalStatus::Cancelled;
+
+	casper_event_standard::emit(ProposalCancelled {
+		proposal_id: proposal.id,
+		canceller,
+	});
+	proposal_store.save_proposal(proposal);
+	Ok(())
+}
+
+/// This service is open, anyone can execute a queued proposal once its eta is reached
+/// Parameters:
+/// - proposal_id - required
+pub fn execute_proposal() -> TResult<()> {
+	let proposal_store = ProposalStore::instance();
+	let mut proposal = get_queued_proposal(&proposal_store)?;
+	let now: u64 = runtime::get_blocktime().into();
+
+	if now < proposal.eta {
+		return Err(AdministrationErrors::ProposalIsNotReady);
+	}
+	if now > proposal.eta + PROPOSAL_EXECUTION_WINDOW {
+		return Err(AdministrationErrors::ProposalIsStale);
+	}
+
+	execute_action(proposal.action.clone())?;
+
+	proposal.status = ProposalStatus::Executed;
+	casper_event_standard::emit(ProposalExecuted {
+		proposal_id: proposal.id,
+		action: proposal.action.to_string(),
+		executor: get_verified_caller(CallerVerificationType::All).unwrap(),
+	});
+	proposal_store.save_proposal(proposal);
+	Ok(())
+}
+
+/// Parameters:
+/// - proposal_id - required
+pub fn get_proposal() -> TResult<Proposal> {
+	let id: u64 = runtime::get_named_arg(
+		&AdministrationArgs::ProposalId.to_string()
+	);
+	ProposalStore::instance()
+		.get_proposal(id)
+		.ok_or(AdministrationErrors::ProposalNotFound)
+}
+
+pub fn get_min_delay() -> TResult<u64> {
+	let store = Store::instance();
+	Ok(store.get_min_delay())
+}
+
+/// Activates the timelock, once it is active the delay can be changed only by a proposal
+/// Parameters:
+/// - min_delay - required
+pub fn set_min_delay() -> TResult<()> {
+	ensure_direct_change_allowed()?;
+	let delay: u64 = runtime::get_named_arg(
+		&AdministrationArgs::MinDelay.to_string()
+	);
+	let store = Store::instance();
+	store.set_min_delay(delay);
+	Ok(())
+}
+
+/// Helpers
+
+/// Moves the proposal into the timelock queue once it has enough approvals (always, if multisig is not configured)
+pub(crate) fn queue_if_approved(store: &Store, proposal: &mut Proposal) -> () {
+	// Approvals of rotated out signers are not counted
+	let approvals = proposal.approvals
+		.iter()
+		.filter(|key| !store.is_multisig_active() || store.is_signer(key))
+		.count();
+	let threshold = store.get_threshold() as usize;
+	if proposal.status != ProposalStatus::Pending || approvals < threshold {
+		return;
+	}
+	let now: u64 = runtime::get_blocktime().into();
+	proposal.eta = now + store.get_min_delay();
+	proposal.status = ProposalStatus::Queued;
+
+	casper_event_standard::emit(ProposalQueued {
+		proposal_id: proposal.id,
+		action: proposal.action.to_string(),
+		proposer: proposal.proposer,
+		eta: proposal.eta,
+	});
+}
+
+pub(crate) fn ensure_caller_can_propose(action: &ProposalAction) -> TResult<Key> {
+	let store = Store::instance();
+	let caller = get_verified_caller(CallerVerificationType::All).unwrap();
+	if store.is_multisig_active() {
+		if !store.is_signer(&caller) {
+			return Err(AdministrationErrors::NotASigner);
+		}
+		return Ok(caller);
+	}
+	let has_action_role = action
+		.role()
+		.map(|role| has_role(role, caller))
+		.unwrap_or(false);
+	if !has_action_role {
+		ensure_caller_has_permission().map_err(
+			|_| AdministrationErrors::InvalidCaller
+		)?;
+	}
+	Ok(caller)
+}
+
+pub(crate) fn validate_signers(signers: &Vec<Key>, threshold: u8) -> TResult<()> {
+	let mut unique = signers.clone();
+	unique.sort();
+	unique.dedup();
+	if
+		threshold == 0 ||
+		(threshold as usize) > signers.len() ||
+		unique.len() != signers.len()
+	{
+		return Err(AdministrationErrors::InvalidThreshold);
+	}
+	Ok(())
+}
+
+fn validate_action(action: &ProposalAction) -> TResult<()> {
+	match action {
+		ProposalAction::SetSigners { signers, threshold } =>
+			validate_signers(signers, *threshold),
+		ProposalAction::SetExtensionConfig { config, .. } =>
+			extension_config::validate_extension_config(config),
+		_ => Ok(()),
+	}
+}
+
+fn get_queued_proposal(proposal_store: &ProposalStore) -> TResult<Proposal> {
+	let id: u64 = runtime::get_named_arg(
+		&AdministrationArgs::ProposalId.to_string()
+	);
+	let proposal = proposal_store
+		.get_proposal(id)
+		.ok_or(AdministrationErrors::ProposalNotFound)?;
+
+	if proposal.status != ProposalStatus::Queued {
+		return Err(AdministrationErrors::ProposalIsNotQueued);
+	}
+	Ok(proposal)
+}
+
+fn execute_action(action: ProposalAction) -> TResult<()> {
+	match action {
+		ProposalAction::SetAllowedExtensions { extensions } =>
+			extensions::apply_allowed_extensions(extensions),
+		ProposalAction::AddExtension { extension } =>
+			extensions::apply_add_extension(extension),
+		ProposalAction::RemoveExtension { extension } =>
+			extensions::apply_remove_extension(extension),
+		ProposalAction::SetCharsMinCount { extension, count } =>
+			limits::apply_chars_min_count(&extension, count),
+		ProposalAction::SetExtensionConfig { extension, config } =>
+			extension_config::apply_extension_config(&extension, config),
+		ProposalAction::SetListingLimit { kind, limit } =>
+			limits::apply_listing_limit(kind, limit),
+		ProposalAction::AddContract { kind, key, extension } =>
+			contracts::apply_add_contract(kind, key, extension),
+		ProposalAction::SetPrice {
+			extension,
+			price_type,
+			price,
+			price_mid,
+			chars_count_mid,
+			price_more,
+		} => {
+			// Price oracle accepts price changes only from the administration contract
+			runtime::call_contract::<()>(
+				get_price_oracle_hash()?,
+				ENDPOINT_PO_SET_PRICE,
+				runtime_args! {
+					PriceOracleArgs::Extension.to_string() => extension,
+					PriceOracleArgs::PriceType.to_string() => price_type,
+					PriceOracleArgs::Price.to_string() => price,
+					PriceOracleArgs::PriceMid.to_string() => price_mid,
+					PriceOracleArgs::CharsCount.to_string() => chars_count_mid,
+					PriceOracleArgs::PriceMore.to_string() => price_more,
+				}
+			);
+			Ok(())
+		}
+		ProposalAction::SetPriceSimpleOperations { extension, price } => {
+			runtime::call_contract::<()>(
+				get_price_oracle_hash()?,
+				ENDPOINT_PO_PRICE_SET_SIMPLE_OPERATIONS,
+				runtime_args! {
+					PriceOracleArgs::Extension.to_string() => extension,
+					PriceOracleArgs::Price.to_string() => price,
+				}
+			);
+			Ok(())
+		}
+		ProposalAction::SetMinDelay { delay } => {
+			let store = Store::instance();
+			store.set_min_delay(delay);
+			Ok(())
+		}
+		ProposalAction::SetSigners { signers, threshold } =>
+			multisig::apply_signers(signers, threshold),
+		ProposalAction::GrantRole { role, account } =>
+			roles::apply_grant_role(role, account),
+		ProposalAction::RevokeRole { role, account } =>
+			roles::apply_revoke_role(role, account),
+		ProposalAction::SetContractAuthorityList { contract_hash, authorities } =>
+			contract_authority::apply_contract_authority_list(
+				contract_hash,
+				authorities
+			),
+		ProposalAction::AddContractAuthority { contract_hash, authority } =>
+			contract_authority::apply_add_contract_authority(
+				contract_hash,
+				authority
+			),
+		ProposalAction::RemoveContractAuthority { contract_hash, authority } =>
+			contract_authority::apply_remove_contract_authority(
+				contract_hash,
+				authority
+			),
+	}
+}
+
+fn get_price_oracle_hash() -> TResult<ContractHash> {
+	let price_oracle: Key = Store::instance()
+		.get_simple_contract(ContractKind::PriceOracle)
+		.ok_or(AdministrationErrors::ContractNotFound)?;
+	Ok(
+		ContractHash::new(
+			price_oracle
+				.into_hash()
+				.unwrap_or_revert_with(AdministrationErrors::ContractNotFound)
+		)
+	)
+}
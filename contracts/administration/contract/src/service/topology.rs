@@ -0,0 +1,226 @@
+use alloc::{ string::{ String, ToString }, vec, vec::Vec };
+use casper_contract::contract_api::runtime;
+use casper_types::{ runtime_args, ContractHash, Key, RuntimeArgs };
+use common_lib::{
+	constants::common_keys::{ DatabaseEndpoints, RegistryEndpoints },
+	db::store::Store,
+	enums::{
+		contracts_enum::ContractKind,
+		roles::Role,
+		topology_issue_kind::TopologyIssueKind,
+	},
+	models::topology::TopologyIssue,
+	utils::contract::get_called_contract_hash,
+};
+
+use crate::{
+	db::{
+		allowed_extensions::AllowedExtensions,
+		contract_hash_list::ContractHashList,
+		standby::StandbyContracts,
+	},
+	service::contracts::{ is_compound, CONTRACT_KINDS },
+	types::TResult,
+};
+
+/// Simple contracts every registration goes through
+const REQUIRED_SIMPLE_CONTRACTS: [ContractKind; 6] = [
+	ContractKind::Main,
+	ContractKind::NFT,
+	ContractKind::PriceOracle,
+	ContractKind::Registry,
+	ContractKind::TransferFilter,
+	ContractKind::Treasury,
+];
+
+/// Contracts calling closed endpoints of each other, so they have to hold the SYSTEM_CONTRACT role.
+/// Administration contract is passed by its hash and NFTCore keeps its own ACL, neither is checked here.
+/// Standby shards get the role when they are promoted.
+const SYSTEM_CONTRACTS: [ContractKind; 6] = [
+	ContractKind::Main,
+	ContractKind::Database,
+	ContractKind::NFT,
+	ContractKind::PriceOracle,
+	ContractKind::TransferFilter,
+	ContractKind::Treasury,
+];
+
+/// Contracts writing routes to the registry contract
+const REGISTRY_WRITERS: [ContractKind; 2] = [
+	ContractKind::Main,
+	ContractKind::Administration,
+];
+
+/// Read-only check of the contract directory, nothing is changed.
+/// For every contract kind and allowed extension checks that:
+/// - the directory entries exist
+/// - contracts calling each other hold the SYSTEM_CONTRACT role
+/// - main and administration contracts are whitelisted in the registry contract
+/// - count of every database shard matches its `get_totals`, the shard is asked only when
+///   it holds the SYSTEM_CONTRACT role
+/// Returns the list of found problems, empty list means the topology is consistent
+pub fn verify_topology() -> TResult<Vec<TopologyIssue>> {
+	let store = Store::instance();
+	let extensions = store.get_allowed_extensions();
+	let mut issues: Vec<TopologyIssue> = Vec::new();
+
+	for kind in REQUIRED_SIMPLE_CONTRACTS {
+		if store.get_simple_contract(kind).is_none() {
+			issues.push(
+				TopologyIssue::new(TopologyIssueKind::MissingContract, kind, None, None)
+			);
+		}
+	}
+	for kind in CONTRACT_KINDS.iter().filter(|kind| is_compound(**kind)) {
+		for extension in extensions.iter() {
+			if store.get_compound_contracts(*kind, extension).is_empty() {
+				issues.push(
+					TopologyIssue::new(
+						TopologyIssueKind::MissingContract,
+						*kind,
+						Some(extension.clone()),
+						None
+					)
+				);
+			}
+		}
+	}
+
+	for kind in SYSTEM_CONTRACTS {
+		for (key, extension) in get_active_keys(&store, kind, &extensions) {
+			if !store.has_role(Role::SystemContract, &key) {
+				issues.push(
+					TopologyIssue::new(
+						TopologyIssueKind::MissingAuthority,
+						kind,
+						extension,
+						Some(key)
+					)
+				);
+			}
+		}
+	}
+
+	if let Some(registry) = store.get_simple_contract(ContractKind::Registry) {
+		if let Some(registry_hash) = to_contract_hash(registry) {
+			let whitelist: Vec<ContractHash> = runtime::call_contract(
+				registry_hash,
+				&RegistryEndpoints::GetWhitelist.to_string(),
+				runtime_args! {}
+			);
+			for writer_kind in REGISTRY_WRITERS {
+				for (writer, _) in get_directory_keys(&store, writer_kind, &extensions) {
+					let is_whitelisted = to_contract_hash(writer)
+						.map(|hash| whitelist.contains(&hash))
+						.unwrap_or(false);
+					if !is_whitelisted {
+						let mut issue = TopologyIssue::new(
+							TopologyIssueKind::NotWhitelisted,
+							ContractKind::Registry,
+							None,
+							Some(registry)
+						);
+						issue.related_key = Some(writer);
+						issues.push(issue);
+					}
+				}
+			}
+		}
+	}
+
+	for extension in extensions.iter() {
+		for shard in store.get_compound_contracts(ContractKind::Database, extension) {
+			let shard_hash = match to_contract_hash(shard.key) {
+				Some(hash) => hash,
+				None => {
+					continue;
+				}
+			};
+			if !store.has_role(Role::SystemContract, &shard.key) {
+				// Already reported as a missing role
+				continue;
+			}
+			let (domains_count, _): (u64, u64) = runtime::call_contract(
+				shard_hash,
+				&DatabaseEndpoints::GetTotals.to_string(),
+				runtime_args! {}
+			);
+			let stored_count = shard.count.unwrap_or(0) as u64;
+			if stored_count != domains_count {
+				let mut issue = TopologyIssue::new(
+					TopologyIssueKind::CountMismatch,
+					ContractKind::Database,
+					Some(extension.clone()),
+					Some(shard.key)
+				);
+				issue.stored_count = Some(stored_count);
+				issue.actual_count = Some(domains_count);
+				issues.push(issue);
+			}
+		}
+	}
+
+	Ok(issues)
+}
+
+/// Helpers
+/// Directory entries of the kind with their extensions, standby shards are not included
+fn get_active_keys(
+	store: &Store,
+	kind: ContractKind,
+	extensions: &Vec<String>
+) -> Vec<(Key, Option<String>)> {
+	if !is_compound(kind) {
+		return store
+			.get_simple_contract(kind)
+			.map(|key| vec![(key, None)])
+			.unwrap_or_default();
+	}
+	extensions
+		.iter()
+		.flat_map(|extension| {
+			store
+				.get_compound_contracts(kind, extension)
+				.into_iter()
+				.map(move |item| (item.key, Some(extension.clone())))
+		})
+		.collect()
+}
+
+/// Directory entries of the kind with their extensions, standby shards included.
+/// Administration contract is taken from the call stack when it is not in the directory.
+fn get_directory_keys(
+	store: &Store,
+	kind: ContractKind,
+	extensions: &Vec<String>
+) -> Vec<(Key, Option<String>)> {
+	if !is_compound(kind) {
+		let key = match kind {
+			ContractKind::Administration => get_administration_key(store),
+			_ => store.get_simple_contract(kind),
+		};
+		return key
+			.map(|key| vec![(key, None)])
+			.unwrap_or_default();
+	}
+	let mut keys: Vec<(Key, Option<String>)> = Vec::new();
+	for extension in extensions.iter() {
+		for item in store.get_compound_contracts(kind, extension) {
+			keys.push((item.key, Some(extension.clone())));
+		}
+		for key in store.get_standby_contracts(kind, extension) {
+			keys.push((key, Some(extension.clone())));
+		}
+	}
+	keys
+}
+
+fn get_administration_key(store: &Store) -> Option<Key> {
+	store
+		.get_simple_contract(ContractKind::Administration)
+		.or_else(|| get_called_contract_hash().map(Key::from))
+}
+
+fn to_contract_hash(key: Key) -> Option<ContractHash> {
+	key.into_hash().map(ContractHash::new)
+}
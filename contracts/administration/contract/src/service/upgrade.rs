@@ -0,0 +1,243 @@
+use alloc::{ format, string::{ String, ToString }, vec::Vec };
+use casper_contract::contract_api::runtime;
+use casper_types::{ ContractHash, Key };
+use common_lib::{
+	db::{ store::Store, traits::Storable },
+	enums::roles::Role,
+	errors::AdministrationErrors,
+	events::administration::ContractUpgraded,
+	schema,
+	constants::common_keys::{ AdministractionStoreKeys, AdministrationArgs },
+	utils::{ contract::get_called_contract_hash, upgrade::run_migrations },
+};
+
+use crate::{
+	db::{
+		allowed_extensions::AllowedExtensions,
+		contract_authorities::{ ContractAuthorities, ContractAuthoritiesStore },
+		contract_hash_list::ContractHashList,
+		proposals::ProposalStore,
+		timelock::Timelock,
+		extension_config::ExtensionConfigs,
+		standby::StandbyContracts,
+	},
+	service::{
+		contracts::{ is_compound, CONTRACT_KINDS },
+		extension_config::validate_extension_config,
+	},
+	types::TResult,
+};
+
+/// Schema version of the directory, authority lists, proposals and roles
+/// 2 - dictionaries and event schemas are created, contracts of the authority lists
+///     hold the SYSTEM_CONTRACT role
+/// 3 - chars min count of the extensions is kept in the extension config
+/// 4 - models are stored with their structural CLTypes, actions of the proposals keep
+///     their fields as bytes
+/// 5 - extensions of the compound contracts are listed per contract kind
+pub const SCHEMA_VERSION: u32 = 5;
+
+/// Runs right after a new version of the administration contract is added to the package
+/// This service is closed, only maintainer is passed
+/// Returns the schema version of the stored state
+pub fn migrate() -> TResult<u32> {
+	Ok(
+		run_migrations(SCHEMA_VERSION, |version| {
+			if version == 1 {
+				create_missing_stores();
+				grant_system_contract_role();
+			}
+			if version == 2 {
+				move_chars_min_count();
+			}
+			if version == 3 {
+				rewrite_models();
+			}
+			if version == 4 {
+				list_compound_extensions();
+			}
+		})
+	)
+}
+
+/// Called by the upgrade session of any contract in the directory.
+/// Every directory entry (simple, compound and standby) of the previous version is replaced,
+/// compound contracts keep the count of stored items.
+/// Authority list and SYSTEM_CONTRACT role of the previous version are moved to the new one
+/// and the previous version is replaced in the authority lists of the other contracts.
+/// This service is closed, only maintainer and SHARD_MANAGER role are passed
+/// Parameters:
+/// - previous_key - required
+/// - key - required
+pub fn contract_upgraded() -> TResult<()> {
+	let previous_key: Key = runtime::get_named_arg(
+		&AdministrationArgs::PreviousKey.to_string()
+	);
+	let key: Key = runtime::get_named_arg(&AdministrationArgs::Key.to_string());
+	let previous_hash = get_contract_hash(previous_key)?;
+	let contract_hash = get_contract_hash(key)?;
+
+	let store = Store::instance();
+	let extensions = store.get_allowed_extensions();
+	let mut is_replaced = false;
+
+	for kind in CONTRACT_KINDS {
+		if is_compound(kind) {
+			for extension in extensions.iter() {
+				is_replaced |= store.replace_compound_contract(
+					kind,
+					extension,
+					previous_key,
+					key
+				);
+				is_replaced |= store.replace_standby_contract(
+					kind,
+					extension,
+					previous_key,
+					key
+				);
+			}
+		} else if store.get_simple_contract(kind) == Some(previous_key) {
+			store.set_simple_contract(kind, key);
+			is_replaced = true;
+		}
+	}
+	let authorities = ContractAuthoritiesStore::instance();
+	// Contracts out of the directory (administration itself) are known by their authority list
+	let has_authority_list = !authorities
+		.get_contract_authority(previous_hash)
+		.is_empty();
+	if !is_replaced && !has_authority_list {
+		return Err(AdministrationErrors::UpgradedContractNotFound);
+	}
+
+	authorities.move_contract_authority_list(previous_hash, contract_hash);
+	for directory_key in get_directory_keys(&store, &extensions) {
+		if let Ok(directory_hash) = get_contract_hash(directory_key) {
+			authorities.replace_contract_authority(directory_hash, previous_key, key);
+		}
+	}
+	if store.has_role(Role::SystemContract, &previous_key) {
+		store.revoke_role(Role::SystemContract, &previous_key);
+		store.grant_role(Role::SystemContract, key);
+	}
+
+	casper_event_standard::emit(ContractUpgraded { previous_key, key });
+	Ok(())
+}
+
+/// Helpers
+/// Versions released before the `init` entry point keep neither the dictionaries nor the event schemas
+fn create_missing_stores() {
+	if runtime::get_key(&AdministractionStoreKeys::ContractAuthority.to_string()).is_none() {
+		ContractAuthoritiesStore::initialize();
+	}
+	if runtime::get_key(&AdministractionStoreKeys::Proposals.to_string()).is_none() {
+		ProposalStore::initialize();
+	}
+	if runtime::get_key(casper_event_standard::EVENTS_DICT).is_none() {
+		casper_event_standard::init(schema::administration::schema().events);
+	}
+}
+
+/// Extension configs and compound contracts were stored as `CLType::Any`
+fn rewrite_models() {
+	let store = Store::instance();
+	for extension in store.get_allowed_extensions() {
+		store.rewrite_extension_config(&extension);
+		for kind in CONTRACT_KINDS.iter().filter(|kind| is_compound(**kind)) {
+			let contracts = store.get_compound_contracts(*kind, &extension);
+			if !contracts.is_empty() {
+				store.init_compound_contracts(*kind, &extension, contracts);
+			}
+		}
+	}
+
+	let proposals = ProposalStore::instance();
+	for id in 0..store.get_proposal_count() {
+		proposals.rewrite_legacy_proposal(id);
+	}
+}
+
+/// Compound contracts were looked up only by the extension before the shards of every extension were listed
+fn list_compound_extensions() {
+	let store = Store::instance();
+	for extension in store.get_allowed_extensions() {
+		for kind in CONTRACT_KINDS.iter().filter(|kind| is_compound(**kind)) {
+			let contracts = store.get_compound_contracts(*kind, &extension);
+			if !contracts.is_empty() {
+				store.init_compound_contracts(*kind, &extension, contracts);
+			}
+		}
+	}
+}
+
+/// Contracts were allowed to call each other by the authority lists before the SYSTEM_CONTRACT role
+fn grant_system_contract_role() {
+	let store = Store::instance();
+	let authorities = ContractAuthoritiesStore::instance();
+	let mut callees = get_directory_keys(&store, &store.get_allowed_extensions());
+	// Named key of the contract hash is replaced only after the migration steps
+	if let Some(administration_hash) = get_called_contract_hash() {
+		callees.push(administration_hash.into());
+	}
+
+	for callee in callees {
+		let callee_hash = match get_contract_hash(callee) {
+			Ok(hash) => hash,
+			Err(_) => {
+				continue;
+			}
+		};
+		for caller in authorities.get_contract_authority(callee_hash) {
+			if caller.into_hash().is_some() {
+				store.grant_role(Role::SystemContract, caller);
+			}
+		}
+	}
+}
+
+/// Chars min count was kept under the "<extension>:chars_count" key before the extension config
+fn move_chars_min_count() {
+	let store = Store::instance();
+	for extension in store.get_allowed_extensions() {
+		let key = format!("{}:{}", extension, AdministractionStoreKeys::CharsCount);
+		if let Some(count) = store.get::<u8>(&key) {
+			let mut config = store.get_extension_config(&extension);
+			config.min_chars_count = count;
+			// A count the config doesn't accept is dropped, the config keeps its own
+			if validate_extension_config(&config).is_ok() {
+				store.set_extension_config(&extension, config);
+			}
+		}
+		runtime::remove_key(&key);
+	}
+}
+
+/// Every contract of the directory, including administration itself, may keep an authority list
+fn get_directory_keys(store: &Store, extensions: &Vec<String>) -> Vec<Key> {
+	let mut keys: Vec<Key> = Vec::new();
+	for kind in CONTRACT_KINDS {
+		if is_compound(kind) {
+			for extension in extensions.iter() {
+				keys.extend(
+					store
+						.get_compound_contracts(kind, extension)
+						.iter()
+						.map(|item| item.key)
+				);
+				keys.extend(store.get_standby_contracts(kind, extension));
+			}
+		} else if let Some(key) = store.get_simple_contract(kind) {
+			keys.push(key);
+		}
+	}
+	keys
+}
+
+fn get_contract_hash(key: Key) -> TResult<ContractHash> {
+	key
+		.into_hash()
+		.map(ContractHash::new)
+		.ok_or(AdministrationErrors::ContractNotFound)
+}
@@ -11,8 +11,14 @@ use common_lib::{
 	utils::registry::get_verified_caller,
 };
 
+use common_lib::db::store::Store;
+
 use crate::{
-	db::contract_authorities::{ ContractAuthorities, ContractAuthoritiesStore },
+	db::{
+		contract_authorities::{ ContractAuthorities, ContractAuthoritiesStore },
+		multisig::Multisig,
+		timelock::Timelock,
+	},
 	types::TResult,
 };
 
@@ -27,3 +33,15 @@ pub fn get_extension_arg() -> TResult<String> {
 		)
 	)
 }
+
+/// Once the timelock min delay or the multisig is configured critical changes are accepted only through the proposal queue
+pub fn ensure_direct_change_allowed() -> TResult<()> {
+	let store = Store::instance();
+	if store.is_timelock_active() {
+		return Err(AdministrationErrors::TimelockIsActive);
+	}
+	if store.is_multisig_active() {
+		return Err(AdministrationErrors::MultisigIsActive);
+	}
+	Ok(())
+}
@@ -0,0 +1,94 @@
+use std::{ fs, path::Path };
+
+use casper_types::{ ContractHash, ContractPackageHash, RuntimeArgs };
+use common_lib::schema::ContractSchema;
+
+use crate::error::ClientError;
+
+/// Stored contract the deploy calls
+#[derive(Debug, Clone, PartialEq)]
+pub enum CallTarget {
+	ContractHash(ContractHash),
+	/// `None` version calls the latest enabled version of the package
+	ContractPackageHash {
+		package_hash: ContractPackageHash,
+		version: Option<u32>,
+	},
+	/// Named key of the deploying account
+	ContractName(String),
+	PackageName {
+		name: String,
+		version: Option<u32>,
+	},
+}
+
+/// Stored-contract call, `session` of a deploy
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractCall {
+	pub target: CallTarget,
+	pub entry_point: String,
+	pub args: RuntimeArgs,
+}
+
+/// Session code call, e.g. the install of a contract
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionCall {
+	pub module_bytes: Vec<u8>,
+	pub args: RuntimeArgs,
+}
+
+impl ContractCall {
+	pub fn new(target: CallTarget, entry_point: String, args: RuntimeArgs) -> Self {
+		Self {
+			target,
+			entry_point,
+			args,
+		}
+	}
+
+	/// Checks the entry point and the arguments against the declaration of the contract
+	pub fn check(&self, contract_schema: &ContractSchema) -> Result<(), ClientError> {
+		let entry_point = contract_schema
+			.get_entry_point(&self.entry_point)
+			.ok_or_else(|| ClientError::UnknownEntryPoint(self.entry_point.clone()))?;
+
+		for named_arg in self.args.named_args() {
+			let declared = entry_point.args
+				.iter()
+				.find(|arg| arg.name == named_arg.name())
+				.ok_or_else(|| ClientError::UnexpectedArg(named_arg.name().to_string()))?;
+			if &declared.cl_type != named_arg.cl_value().cl_type() {
+				return Err(ClientError::ArgTypeMismatch {
+					name: declared.name.clone(),
+					expected: declared.cl_type.clone(),
+					found: named_arg.cl_value().cl_type().clone(),
+				});
+			}
+		}
+
+		match
+			entry_point.args
+				.iter()
+				.find(|arg| !arg.optional && self.args.get(&arg.name).is_none())
+		{
+			Some(missing) => Err(ClientError::MissingArg(missing.name.clone())),
+			None => Ok(()),
+		}
+	}
+}
+
+impl SessionCall {
+	pub fn new(module_bytes: Vec<u8>, args: RuntimeArgs) -> Self {
+		Self { module_bytes, args }
+	}
+
+	pub fn from_file<P: AsRef<Path>>(
+		path: P,
+		args: RuntimeArgs
+	) -> Result<Self, ClientError> {
+		let module_bytes = fs
+			::read(path)
+			.map_err(|error| ClientError::Io(error.to_string()))?;
+		Ok(Self::new(module_bytes, args))
+	}
+}
@@ -0,0 +1,232 @@
+use casper_types::{ account::AccountHash, ContractHash, Key };
+use common_lib::{
+	constants::common_keys::{
+		AdministrationArgs,
+		AdministrationEndpoints,
+		CommonArgs,
+		CommonEndpoints,
+	},
+	enums::{
+		contracts_enum::ContractKind,
+		registration_mode::RegistrationMode,
+		roles::Role,
+	},
+	models::proposal::ProposalAction,
+};
+
+contract_client! {
+	/// Directory of contracts, extension configs, roles, proposals and pause switches
+	pub struct AdministrationClient;
+	fn set_contract_authority_list(
+		contract_hash: ContractHash => AdministrationArgs::ContractHash,
+		contract_authorities: Vec<Key> => AdministrationArgs::ContractAuthorities
+	) => AdministrationEndpoints::SetContractAuthorityList;
+	fn add_contract_authority(
+		contract_hash: ContractHash => AdministrationArgs::ContractHash,
+		contract_authority: Key => AdministrationArgs::ContractAuthority
+	) => AdministrationEndpoints::AddContractAuthority;
+	fn get_contract_authority_list(
+		contract_hash: ContractHash => AdministrationArgs::ContractHash
+	) => AdministrationEndpoints::GetContractAuthorityList;
+	fn remove_contract_authority(
+		contract_hash: ContractHash => AdministrationArgs::ContractHash,
+		contract_authority: Key => AdministrationArgs::ContractAuthority
+	) => AdministrationEndpoints::RemoveContractAuthority;
+	fn get_contract(
+		contract_kind: ContractKind => AdministrationArgs::ContractKind,
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::GetContract;
+	fn add_contract(
+		contract_kind: ContractKind => AdministrationArgs::ContractKind,
+		key: Key => AdministrationArgs::Key,
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::AddContract;
+	fn increment_contract(
+		contract_kind: ContractKind => AdministrationArgs::ContractKind,
+		key: Key => AdministrationArgs::Key,
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::IncrementContract;
+	fn decrement_contract(
+		contract_kind: ContractKind => AdministrationArgs::ContractKind,
+		key: Key => AdministrationArgs::Key,
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::DecrementContract;
+	fn set_allowed_extensions(
+		allowed_extensions: Vec<String> => AdministrationArgs::AllowedExtensions
+	) => AdministrationEndpoints::SetAllowedExtensions;
+	fn get_allowed_extensions() => AdministrationEndpoints::GetAllowedExtensions;
+	fn add_extension(
+		allowed_extension: String => AdministrationArgs::AllowedExtension
+	) => AdministrationEndpoints::AddExtension;
+	fn remove_extension(
+		allowed_extension: String => AdministrationArgs::AllowedExtension
+	) => AdministrationEndpoints::RemoveExtension;
+	fn get_chars_min_count(
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::GetCharsMinCount;
+	fn set_chars_min_count(
+		chars_count: u8 => AdministrationArgs::CharsCount,
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::SetCharsMinCount;
+	fn get_extension_config(
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::GetExtensionConfig;
+	fn get_max_chars_count(
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::GetMaxCharsCount;
+	fn set_max_chars_count(
+		extension: Option<String> => AdministrationArgs::Extension,
+		max_chars_count: u8 => AdministrationArgs::MaxCharsCount
+	) => AdministrationEndpoints::SetMaxCharsCount;
+	fn get_max_subscription_years(
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::GetMaxSubscriptionYears;
+	fn set_max_subscription_years(
+		extension: Option<String> => AdministrationArgs::Extension,
+		max_subscription_years: u8 => AdministrationArgs::MaxSubscriptionYears
+	) => AdministrationEndpoints::SetMaxSubscriptionYears;
+	fn get_grace_period(
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::GetGracePeriod;
+	fn set_grace_period(
+		extension: Option<String> => AdministrationArgs::Extension,
+		grace_period: u64 => AdministrationArgs::GracePeriod
+	) => AdministrationEndpoints::SetGracePeriod;
+	fn get_subdomain_limit(
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::GetSubdomainLimit;
+	fn set_subdomain_limit(
+		extension: Option<String> => AdministrationArgs::Extension,
+		subdomain_limit: u8 => AdministrationArgs::SubdomainLimit
+	) => AdministrationEndpoints::SetSubdomainLimit;
+	fn get_registration_mode(
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::GetRegistrationMode;
+	fn set_registration_mode(
+		extension: Option<String> => AdministrationArgs::Extension,
+		registration_mode: RegistrationMode => AdministrationArgs::RegistrationMode
+	) => AdministrationEndpoints::SetRegistrationMode;
+	fn get_fee_recipient(
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::GetFeeRecipient;
+	fn set_fee_recipient(
+		extension: Option<String> => AdministrationArgs::Extension,
+		fee_recipient: Option<AccountHash> => AdministrationArgs::FeeRecipient
+	) => AdministrationEndpoints::SetFeeRecipient;
+	fn add_to_allowlist(
+		extension: Option<String> => AdministrationArgs::Extension,
+		account: Key => AdministrationArgs::Account
+	) => AdministrationEndpoints::AddToAllowlist;
+	fn remove_from_allowlist(
+		extension: Option<String> => AdministrationArgs::Extension,
+		account: Key => AdministrationArgs::Account
+	) => AdministrationEndpoints::RemoveFromAllowlist;
+	fn is_allowlisted(
+		extension: Option<String> => AdministrationArgs::Extension,
+		account: Key => AdministrationArgs::Account
+	) => AdministrationEndpoints::IsAllowlisted;
+	fn add_standby_contract(
+		contract_kind: ContractKind => AdministrationArgs::ContractKind,
+		extension: Option<String> => AdministrationArgs::Extension,
+		key: Key => AdministrationArgs::Key
+	) => AdministrationEndpoints::AddStandbyContract;
+	fn get_standby_contracts(
+		contract_kind: ContractKind => AdministrationArgs::ContractKind,
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::GetStandbyContracts;
+	fn provision_contract(
+		contract_kind: ContractKind => AdministrationArgs::ContractKind,
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::ProvisionContract;
+	fn migrate_domains(
+		from: Key => AdministrationArgs::From,
+		to: Key => AdministrationArgs::To,
+		extension: Option<String> => AdministrationArgs::Extension,
+		domain_names: Vec<String> => AdministrationArgs::DomainNames
+	) => AdministrationEndpoints::MigrateDomains;
+	fn contract_upgraded(
+		previous_key: Key => AdministrationArgs::PreviousKey,
+		key: Key => AdministrationArgs::Key
+	) => AdministrationEndpoints::ContractUpgraded;
+	fn verify_topology() => AdministrationEndpoints::VerifyTopology;
+	fn get_compound_contracts(
+		contract_kind: ContractKind => AdministrationArgs::ContractKind,
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::GetCompoundContracts;
+	fn get_listing_limit(
+		contract_kind: ContractKind => AdministrationArgs::ContractKind
+	) => AdministrationEndpoints::GetListingLimit;
+	fn set_listing_limit(
+		contract_kind: ContractKind => AdministrationArgs::ContractKind,
+		chars_count: u32 => AdministrationArgs::CharsCount
+	) => AdministrationEndpoints::SetListingLimit;
+	fn init() => AdministrationEndpoints::Init;
+	fn queue_proposal(
+		proposal_action: ProposalAction => AdministrationArgs::ProposalAction
+	) => AdministrationEndpoints::QueueProposal;
+	fn cancel_proposal(
+		proposal_id: u64 => AdministrationArgs::ProposalId
+	) => AdministrationEndpoints::CancelProposal;
+	fn execute_proposal(
+		proposal_id: u64 => AdministrationArgs::ProposalId
+	) => AdministrationEndpoints::ExecuteProposal;
+	fn get_proposal(
+		proposal_id: u64 => AdministrationArgs::ProposalId
+	) => AdministrationEndpoints::GetProposal;
+	fn get_min_delay() => AdministrationEndpoints::GetMinDelay;
+	fn set_min_delay(
+		min_delay: u64 => AdministrationArgs::MinDelay
+	) => AdministrationEndpoints::SetMinDelay;
+	fn approve_proposal(
+		proposal_id: u64 => AdministrationArgs::ProposalId
+	) => AdministrationEndpoints::ApproveProposal;
+	fn revoke_approval(
+		proposal_id: u64 => AdministrationArgs::ProposalId
+	) => AdministrationEndpoints::RevokeApproval;
+	fn get_signers() => AdministrationEndpoints::GetSigners;
+	fn set_signers(
+		signers: Vec<Key> => AdministrationArgs::Signers,
+		threshold: u8 => AdministrationArgs::Threshold
+	) => AdministrationEndpoints::SetSigners;
+	fn grant_role(
+		role: Role => AdministrationArgs::Role,
+		account: Key => AdministrationArgs::Account
+	) => AdministrationEndpoints::GrantRole;
+	fn revoke_role(
+		role: Role => AdministrationArgs::Role,
+		account: Key => AdministrationArgs::Account
+	) => AdministrationEndpoints::RevokeRole;
+	fn renounce_role(
+		role: Role => AdministrationArgs::Role
+	) => AdministrationEndpoints::RenounceRole;
+	fn has_role(
+		role: Role => AdministrationArgs::Role,
+		account: Key => AdministrationArgs::Account
+	) => AdministrationEndpoints::HasRole;
+	fn get_role_members(
+		role: Role => AdministrationArgs::Role
+	) => AdministrationEndpoints::GetRoleMembers;
+	fn pause(
+		contract_kind: Option<ContractKind> => AdministrationArgs::ContractKind,
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::Pause;
+	fn unpause(
+		contract_kind: Option<ContractKind> => AdministrationArgs::ContractKind,
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::Unpause;
+	fn is_paused(
+		contract_kind: ContractKind => AdministrationArgs::ContractKind,
+		extension: Option<String> => AdministrationArgs::Extension
+	) => AdministrationEndpoints::IsPaused;
+	fn set_authorities(
+		authorities: Vec<Key> => CommonArgs::Authorities
+	) => CommonEndpoints::SetAuthorities;
+	fn add_authority(
+		authority: Key => CommonArgs::Authority
+	) => CommonEndpoints::AddAuthority;
+	fn remove_authority(
+		authority: Key => CommonArgs::Authority
+	) => CommonEndpoints::RemoveAuthority;
+	fn get_authorities() => CommonEndpoints::GetAuthorities;
+	fn migrate() => CommonEndpoints::Migrate;
+}
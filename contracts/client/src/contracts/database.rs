@@ -0,0 +1,75 @@
+use casper_types::{ account::AccountHash, Key };
+use common_lib::{
+	constants::common_keys::{ CommonEndpoints, DatabaseArgs, DatabaseEndpoints },
+	enums::transfer_lock::TransferLock,
+	models::{ domain_migration::DomainMigrationEntry, DomainName, SubdomainName },
+};
+
+contract_client! {
+	/// Database shard, writes are accepted from the system contracts only
+	pub struct DatabaseClient;
+	fn save_domain_name(
+		domain_name: DomainName => DatabaseArgs::DomainName
+	) => DatabaseEndpoints::SaveDomainName;
+	fn save_subdomain_name(
+		domain_name: String => DatabaseArgs::DomainName,
+		subdomain_name: SubdomainName => DatabaseArgs::SubdomainName
+	) => DatabaseEndpoints::SaveSubdomainName;
+	fn remove_domain_name(
+		domain_name: String => DatabaseArgs::DomainName
+	) => DatabaseEndpoints::RemoveDomainName;
+	fn remove_subdomain_name(
+		domain_name: String => DatabaseArgs::DomainName,
+		subdomain_name: String => DatabaseArgs::SubdomainName
+	) => DatabaseEndpoints::RemoveSubdomainName;
+	fn set_domain_ownership(
+		domain_name: String => DatabaseArgs::DomainName,
+		owner: Key => DatabaseArgs::Owner
+	) => DatabaseEndpoints::SetDomainOwnership;
+	fn set_domain_expiration(
+		domain_name: String => DatabaseArgs::DomainName,
+		expiration_date: u64 => DatabaseArgs::ExpirationDate
+	) => DatabaseEndpoints::SetDomainExpiration;
+	fn set_domain_resolver(
+		domain_name: String => DatabaseArgs::DomainName,
+		resolver: AccountHash => DatabaseArgs::Resolver
+	) => DatabaseEndpoints::SetDomainResolver;
+	fn set_subdomain_resolver(
+		subdomain_name: String => DatabaseArgs::SubdomainName,
+		resolver: AccountHash => DatabaseArgs::Resolver
+	) => DatabaseEndpoints::SetSubdomainResolver;
+	fn get_domain_list_for_owner(
+		owner: AccountHash => DatabaseArgs::Owner
+	) => DatabaseEndpoints::GetDomainListForOwner;
+	fn get_domain_list(
+		page: u64 => DatabaseArgs::Page
+	) => DatabaseEndpoints::GetDomainList;
+	fn get_subdomain_list(
+		domain_name: String => DatabaseArgs::DomainName
+	) => DatabaseEndpoints::GetSubdomainList;
+	fn get_totals() => DatabaseEndpoints::GetTotals;
+	fn get_domain(
+		domain_name: String => DatabaseArgs::DomainName
+	) => DatabaseEndpoints::GetDomain;
+	fn get_subdomain(
+		subdomain_name: String => DatabaseArgs::SubdomainName
+	) => DatabaseEndpoints::GetSubdomain;
+	fn export_domains(
+		domain_names: Vec<String> => DatabaseArgs::DomainNames
+	) => DatabaseEndpoints::ExportDomains;
+	fn import_domains(
+		migration_entries: Vec<DomainMigrationEntry> => DatabaseArgs::MigrationEntries
+	) => DatabaseEndpoints::ImportDomains;
+	fn set_transfer_lock(
+		name: String => DatabaseArgs::Name,
+		transfer_lock: Option<TransferLock> => DatabaseArgs::TransferLock
+	) => DatabaseEndpoints::SetTransferLock;
+	fn burn_transfer_permission(
+		name: String => DatabaseArgs::Name
+	) => DatabaseEndpoints::BurnTransferPermission;
+	fn get_transfer_restrictions(
+		name: String => DatabaseArgs::Name
+	) => DatabaseEndpoints::GetTransferRestrictions;
+	fn init() => DatabaseEndpoints::Init;
+	fn migrate() => CommonEndpoints::Migrate;
+}
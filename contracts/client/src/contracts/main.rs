@@ -0,0 +1,74 @@
+use casper_types::{ account::AccountHash, ContractHash, URef, U512 };
+use common_lib::constants::common_keys::{
+	CommonEndpoints,
+	MainArgs,
+	MainEndpoints,
+	ENTRYPOINT_MAIN_SET_AUTHORITIES_CONTRACT_HASH,
+};
+
+contract_client! {
+	/// Registration, resolving and subdomains of the names
+	pub struct MainClient;
+	fn set_authorities_contract_hash(
+		price_oracle_contract_hash: ContractHash => MainArgs::PriceOracleContractHash
+	) => ENTRYPOINT_MAIN_SET_AUTHORITIES_CONTRACT_HASH;
+	fn init() => MainEndpoints::Init;
+	fn migrate() => CommonEndpoints::Migrate;
+	fn register_domain(
+		domain: String => MainArgs::Domain,
+		duration: u8 => MainArgs::Duration,
+		resolver_address: AccountHash => MainArgs::ResolverAddress,
+		register_amount: U512 => MainArgs::RegisterAmount,
+		customer_purse: URef => MainArgs::CustomerPurse
+	) => MainEndpoints::RegisterDomain;
+	/// The main contract package has to be approved as a spender of the amount
+	fn register_domain_with_token(
+		domain: String => MainArgs::Domain,
+		duration: u8 => MainArgs::Duration,
+		resolver_address: AccountHash => MainArgs::ResolverAddress,
+		register_amount: U512 => MainArgs::RegisterAmount,
+		payment_token: ContractHash => MainArgs::PaymentToken
+	) => MainEndpoints::RegisterDomain;
+	fn resolve_domain(
+		domain: String => MainArgs::Domain
+	) => MainEndpoints::ResolverDomain;
+	fn set_resolver_address_for_domain(
+		domain: String => MainArgs::Domain,
+		resolver_address: AccountHash => MainArgs::ResolverAddress
+	) => MainEndpoints::SetResolverAddressForDomain;
+	fn register_sub_domain(
+		subdomain: String => MainArgs::Subdomain,
+		resolver_address: AccountHash => MainArgs::ResolverAddress
+	) => MainEndpoints::RegisterSubDomain;
+	fn remove_subdomain(
+		subdomain: String => MainArgs::Subdomain
+	) => MainEndpoints::RemoveSubDomain;
+	fn set_resolver_address_for_subdomain(
+		subdomain: String => MainArgs::Subdomain,
+		resolver_address: AccountHash => MainArgs::ResolverAddress
+	) => MainEndpoints::SetResolverAddressForSubDomain;
+	fn get_subdomains_for_domain(
+		domain: String => MainArgs::Domain
+	) => MainEndpoints::GetSubdomainsForDomain;
+	fn get_domain_list(
+		domain_page: u8 => MainArgs::DomainPage
+	) => MainEndpoints::GetDomainList;
+	fn extend(
+		domain: String => MainArgs::Domain,
+		duration: u8 => MainArgs::Duration,
+		register_amount: U512 => MainArgs::RegisterAmount,
+		customer_purse: URef => MainArgs::CustomerPurse
+	) => MainEndpoints::Extend;
+	fn extend_with_token(
+		domain: String => MainArgs::Domain,
+		duration: u8 => MainArgs::Duration,
+		register_amount: U512 => MainArgs::RegisterAmount,
+		payment_token: ContractHash => MainArgs::PaymentToken
+	) => MainEndpoints::Extend;
+	fn burn_subdomain_transfer_permission(
+		subdomain: String => MainArgs::Subdomain
+	) => MainEndpoints::BurnSubdomainTransferPermission;
+	fn release_domain(
+		domain: String => MainArgs::Domain
+	) => MainEndpoints::ReleaseDomain;
+}
@@ -0,0 +1,8 @@
+pub mod administration;
+pub mod database;
+pub mod main;
+pub mod nft;
+pub mod price_oracle;
+pub mod registry;
+pub mod transfer_filter;
+pub mod treasury;
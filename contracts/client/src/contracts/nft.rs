@@ -0,0 +1,101 @@
+use casper_types::{ ContractHash, Key, URef, U512 };
+use common_lib::constants::common_keys::{
+	CommonArgs,
+	CommonEndpoints,
+	NFTContractArgs,
+	NFTContractEndpoints,
+};
+
+contract_client! {
+	/// Tokens of the names, their metadata, listings and royalties
+	pub struct NftClient;
+	fn init() => NFTContractEndpoints::Init;
+	fn mint(
+		domain_name: String => NFTContractArgs::DomainName,
+		owner: Key => NFTContractArgs::Owner,
+		expiration_date: u64 => NFTContractArgs::ExpirationDate
+	) => NFTContractEndpoints::Mint;
+	fn burn(
+		domain_name: String => NFTContractArgs::DomainName,
+		token_id: String => NFTContractArgs::TokenId
+	) => NFTContractEndpoints::Burn;
+	fn transfer(
+		domain_name: String => NFTContractArgs::DomainName,
+		source_key: Key => NFTContractArgs::SourceKey,
+		destination_key: Key => NFTContractArgs::DestinationKey
+	) => NFTContractEndpoints::Transfer;
+	fn list(
+		domain_name: String => NFTContractArgs::DomainName,
+		source_key: Key => NFTContractArgs::SourceKey,
+		token_price: U512 => NFTContractArgs::TokenPrice
+	) => NFTContractEndpoints::List;
+	fn un_list(
+		token_id: String => NFTContractArgs::TokenId
+	) => NFTContractEndpoints::UnList;
+	fn buy(
+		domain_name: String => NFTContractArgs::DomainName,
+		token_id: String => NFTContractArgs::TokenId,
+		source_key: Key => NFTContractArgs::SourceKey,
+		amount: U512 => NFTContractArgs::Amount,
+		customer_purse: URef => NFTContractArgs::CustomerPurse
+	) => NFTContractEndpoints::Buy;
+	/// The nft contract package has to be approved as a spender of the amount
+	fn buy_with_token(
+		domain_name: String => NFTContractArgs::DomainName,
+		token_id: String => NFTContractArgs::TokenId,
+		source_key: Key => NFTContractArgs::SourceKey,
+		amount: U512 => NFTContractArgs::Amount,
+		payment_token: ContractHash => NFTContractArgs::PaymentToken
+	) => NFTContractEndpoints::Buy;
+	fn set_token_expiration(
+		domain_name: String => NFTContractArgs::DomainName,
+		expiration_date: u64 => NFTContractArgs::ExpirationDate
+	) => NFTContractEndpoints::SetTokenExpiration;
+	fn set_royalty_policy(
+		extension: String => NFTContractArgs::Extension,
+		royalty_share: u32 => NFTContractArgs::RoyaltyShare
+	) => NFTContractEndpoints::SetRoyaltyPolicy;
+	fn set_token_royalty(
+		token_id: String => NFTContractArgs::TokenId,
+		royalty_share: u32 => NFTContractArgs::RoyaltyShare
+	) => NFTContractEndpoints::SetTokenRoyalty;
+	fn royalty_info(
+		token_id: String => NFTContractArgs::TokenId,
+		sale_price: U512 => NFTContractArgs::SalePrice
+	) => NFTContractEndpoints::RoyaltyInfo;
+	fn get_owned_tokens(
+		owner: Key => NFTContractArgs::Owner,
+		page: u64 => NFTContractArgs::Page
+	) => NFTContractEndpoints::GetOwnedTokens;
+	fn start_auction(
+		domain_name: String => NFTContractArgs::DomainName,
+		reserve_price: U512 => NFTContractArgs::ReservePrice,
+		min_bid_increment: U512 => NFTContractArgs::MinBidIncrement,
+		duration: u64 => NFTContractArgs::Duration
+	) => NFTContractEndpoints::StartAuction;
+	fn cancel_auction(
+		domain_name: String => NFTContractArgs::DomainName
+	) => NFTContractEndpoints::CancelAuction;
+	fn bid(
+		domain_name: String => NFTContractArgs::DomainName,
+		amount: U512 => NFTContractArgs::Amount,
+		customer_purse: URef => NFTContractArgs::CustomerPurse
+	) => NFTContractEndpoints::Bid;
+	fn settle_auction(
+		domain_name: String => NFTContractArgs::DomainName
+	) => NFTContractEndpoints::SettleAuction;
+	fn get_auction(
+		domain_name: String => NFTContractArgs::DomainName
+	) => NFTContractEndpoints::GetAuction;
+	fn set_authorities(
+		authorities: Vec<Key> => CommonArgs::Authorities
+	) => CommonEndpoints::SetAuthorities;
+	fn add_authority(
+		authority: Key => CommonArgs::Authority
+	) => CommonEndpoints::AddAuthority;
+	fn remove_authority(
+		authority: Key => CommonArgs::Authority
+	) => CommonEndpoints::RemoveAuthority;
+	fn get_authorities() => CommonEndpoints::GetAuthorities;
+	fn migrate() => CommonEndpoints::Migrate;
+}
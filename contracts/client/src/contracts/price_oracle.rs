@@ -0,0 +1,94 @@
+use casper_types::{
+	bytesrepr::ToBytes,
+	CLTyped,
+	ContractHash,
+	Key,
+	RuntimeArgs,
+	U512,
+};
+use common_lib::{
+	constants::common_keys::{
+		CommonEndpoints,
+		PriceOracleArgs,
+		ENDPOINT_PO_GET_PAYMENT_TOKEN,
+		ENDPOINT_PO_GET_PRICE,
+		ENDPOINT_PO_INIT,
+		ENDPOINT_PO_PRICE_GET_SIMPLE_OPERATIONS,
+		ENDPOINT_PO_PRICE_SET_SIMPLE_OPERATIONS,
+		ENDPOINT_PO_REMOVE_PAYMENT_TOKEN,
+		ENDPOINT_PO_SET_PAYMENT_TOKEN,
+		ENDPOINT_PO_SET_PRICE,
+	},
+	enums::price_oracle_contract::PriceType,
+};
+
+use crate::call::ContractCall;
+
+contract_client! {
+	/// Prices of the extensions and rates of the CEP-18 payment tokens
+	pub struct PriceOracleClient;
+	fn init() => ENDPOINT_PO_INIT;
+	fn get_price(
+		extension: String => PriceOracleArgs::Extension,
+		chars_count: u64 => PriceOracleArgs::CharsCount
+	) => ENDPOINT_PO_GET_PRICE;
+	fn get_price_simple_operations(
+		extension: String => PriceOracleArgs::Extension
+	) => ENDPOINT_PO_PRICE_GET_SIMPLE_OPERATIONS;
+	fn set_price_simple_operations(
+		extension: String => PriceOracleArgs::Extension,
+		price: U512 => PriceOracleArgs::Price
+	) => ENDPOINT_PO_PRICE_SET_SIMPLE_OPERATIONS;
+	fn set_payment_token(
+		payment_token: ContractHash => PriceOracleArgs::PaymentToken,
+		rate: U512 => PriceOracleArgs::Rate,
+		treasury: Key => PriceOracleArgs::Treasury
+	) => ENDPOINT_PO_SET_PAYMENT_TOKEN;
+	fn remove_payment_token(
+		payment_token: ContractHash => PriceOracleArgs::PaymentToken
+	) => ENDPOINT_PO_REMOVE_PAYMENT_TOKEN;
+	fn get_payment_token(
+		payment_token: ContractHash => PriceOracleArgs::PaymentToken
+	) => ENDPOINT_PO_GET_PAYMENT_TOKEN;
+	fn migrate() => CommonEndpoints::Migrate;
+}
+
+/// `set_price` reads the mid prices only for the dynamic price type.
+/// Only the administration contract passes, accounts queue a `ProposalAction::SetPrice` instead
+impl PriceOracleClient {
+	pub fn set_fixed_price(&self, extension: String, price: U512) -> ContractCall {
+		let mut args = RuntimeArgs::new();
+		insert_arg(&mut args, PriceOracleArgs::Extension, extension);
+		insert_arg(&mut args, PriceOracleArgs::PriceType, PriceType::Fixed);
+		insert_arg(&mut args, PriceOracleArgs::Price, price);
+		ContractCall::new(self.target.clone(), ENDPOINT_PO_SET_PRICE.to_string(), args)
+	}
+
+	pub fn set_dynamic_price(
+		&self,
+		extension: String,
+		price: U512,
+		price_mid: Vec<U512>,
+		chars_count_mid: Vec<u64>,
+		price_more: U512
+	) -> ContractCall {
+		let mut args = RuntimeArgs::new();
+		insert_arg(&mut args, PriceOracleArgs::Extension, extension);
+		insert_arg(&mut args, PriceOracleArgs::PriceType, PriceType::Dynamic);
+		insert_arg(&mut args, PriceOracleArgs::Price, price);
+		insert_arg(&mut args, PriceOracleArgs::PriceMid, price_mid);
+		insert_arg(&mut args, PriceOracleArgs::CharsCount, chars_count_mid);
+		insert_arg(&mut args, PriceOracleArgs::PriceMore, price_more);
+		ContractCall::new(self.target.clone(), ENDPOINT_PO_SET_PRICE.to_string(), args)
+	}
+}
+
+fn insert_arg<V: CLTyped + ToBytes>(
+	args: &mut RuntimeArgs,
+	key: PriceOracleArgs,
+	value: V
+) {
+	args
+		.insert(key.to_string(), value)
+		.expect("Error while converting the argument to cl_value");
+}
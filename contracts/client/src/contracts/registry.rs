@@ -0,0 +1,48 @@
+use casper_types::{ account::AccountHash, ContractHash };
+use common_lib::constants::common_keys::{
+	CommonEndpoints,
+	RegistryArgs,
+	RegistryEndpoints,
+};
+
+contract_client! {
+	/// Routes of the names to their database and nft contracts
+	pub struct RegistryClient;
+	fn map_domain_name_to_contract_hash(
+		domain_name: String => RegistryArgs::DomainName,
+		database_contract_hash: ContractHash => RegistryArgs::DatabaseContractHash,
+		nft_contract_hash: ContractHash => RegistryArgs::NftContractHash
+	) => RegistryEndpoints::MapDomainNameToContractHash;
+	fn unmap_domain_name(
+		domain_name: String => RegistryArgs::DomainName
+	) => RegistryEndpoints::UnmapDomainName;
+	fn get_contract_hash_for_domain_name(
+		domain_name: String => RegistryArgs::DomainName
+	) => RegistryEndpoints::GetContractHashForDomainName;
+	fn remap_domain_names(
+		domain_names: Vec<String> => RegistryArgs::DomainNames,
+		database_contract_hash: ContractHash => RegistryArgs::DatabaseContractHash
+	) => RegistryEndpoints::RemapDomainNames;
+	fn resolve_routes(
+		domain_names: Vec<String> => RegistryArgs::DomainNames
+	) => RegistryEndpoints::ResolveRoutes;
+	fn add_to_whitelist(
+		contract_hash: ContractHash => RegistryArgs::ContractHash
+	) => RegistryEndpoints::AddToWhitelist;
+	fn remove_from_whitelist(
+		contract_hash: ContractHash => RegistryArgs::ContractHash
+	) => RegistryEndpoints::RemoveFromWhitelist;
+	fn get_whitelist() => RegistryEndpoints::GetWhitelist;
+	fn add_operator(
+		operator: AccountHash => RegistryArgs::Operator
+	) => RegistryEndpoints::AddOperator;
+	fn remove_operator(
+		operator: AccountHash => RegistryArgs::Operator
+	) => RegistryEndpoints::RemoveOperator;
+	fn get_operators() => RegistryEndpoints::GetOperators;
+	fn get_name_for_token(
+		token_id: String => RegistryArgs::TokenId
+	) => RegistryEndpoints::GetNameForToken;
+	fn init() => RegistryEndpoints::Init;
+	fn migrate() => CommonEndpoints::Migrate;
+}
@@ -0,0 +1,20 @@
+use casper_types::Key;
+use common_lib::constants::common_keys::{
+	CommonEndpoints,
+	TransferFilterArgs,
+	TransferFilterEndpoints,
+};
+
+contract_client! {
+	/// Transfer filter of nft-core, `can_transfer` is called by nft-core itself
+	pub struct TransferFilterClient;
+	fn can_transfer(
+		source_key: Key => TransferFilterArgs::SourceKey,
+		target_key: Key => TransferFilterArgs::TargetKey,
+		token_id: String => TransferFilterArgs::TokenId
+	) => TransferFilterEndpoints::CanTransfer;
+	fn get_transfer_denial(
+		token_id: String => TransferFilterArgs::TokenId
+	) => TransferFilterEndpoints::GetTransferDenial;
+	fn migrate() => CommonEndpoints::Migrate;
+}
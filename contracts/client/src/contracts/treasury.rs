@@ -0,0 +1,38 @@
+use casper_types::{ account::AccountHash, URef, U512 };
+use common_lib::{
+	constants::common_keys::{ CommonEndpoints, TreasuryArgs, TreasuryEndpoints },
+	enums::revenue_stream::RevenueStream,
+	models::treasury::Beneficiary,
+};
+
+contract_client! {
+	/// Fees of the revenue streams split among their beneficiaries, `deposit` is called by main and nft
+	pub struct TreasuryClient;
+	fn init() => TreasuryEndpoints::Init;
+	fn deposit(
+		revenue_stream: RevenueStream => TreasuryArgs::RevenueStream,
+		amount: U512 => TreasuryArgs::Amount,
+		purse: URef => TreasuryArgs::Purse
+	) => TreasuryEndpoints::Deposit;
+	fn set_beneficiaries(
+		revenue_stream: RevenueStream => TreasuryArgs::RevenueStream,
+		beneficiaries: Vec<Beneficiary> => TreasuryArgs::Beneficiaries
+	) => TreasuryEndpoints::SetBeneficiaries;
+	fn get_beneficiaries(
+		revenue_stream: RevenueStream => TreasuryArgs::RevenueStream
+	) => TreasuryEndpoints::GetBeneficiaries;
+	fn get_revenue(
+		revenue_stream: RevenueStream => TreasuryArgs::RevenueStream
+	) => TreasuryEndpoints::GetRevenue;
+	fn get_balance(
+		revenue_stream: RevenueStream => TreasuryArgs::RevenueStream,
+		beneficiary: AccountHash => TreasuryArgs::Beneficiary
+	) => TreasuryEndpoints::GetBalance;
+	fn get_undistributed(
+		revenue_stream: RevenueStream => TreasuryArgs::RevenueStream
+	) => TreasuryEndpoints::GetUndistributed;
+	fn withdraw(
+		revenue_stream: RevenueStream => TreasuryArgs::RevenueStream
+	) => TreasuryEndpoints::Withdraw;
+	fn migrate() => CommonEndpoints::Migrate;
+}
@@ -0,0 +1,44 @@
+use casper_types::{ bytesrepr::FromBytes, checksummed_hex, CLTyped, CLValue };
+
+use crate::error::ClientError;
+
+/// Decodes a value returned by an entry point or read from a named key
+pub fn decode_cl_value<T: CLTyped + FromBytes>(
+	cl_value: CLValue
+) -> Result<T, ClientError> {
+	if cl_value.cl_type() != &T::cl_type() {
+		return Err(ClientError::TypeMismatch {
+			expected: T::cl_type(),
+			found: cl_value.cl_type().clone(),
+		});
+	}
+	decode_bytes(cl_value.inner_bytes())
+}
+
+/// Stored items are written as `T`, removed ones as `None::<T>` (`Store::remove`) or every
+/// item as `Option<T>` (`db::dictionary::Dictionary`), all of them are read as `Option<T>`
+pub fn decode_optional<T: CLTyped + FromBytes>(
+	cl_value: CLValue
+) -> Result<Option<T>, ClientError> {
+	if cl_value.cl_type() == &T::cl_type() {
+		return decode_cl_value::<T>(cl_value).map(Some);
+	}
+	decode_cl_value::<Option<T>>(cl_value)
+}
+
+/// Reads the whole buffer, bytes left after the value are an error
+pub fn decode_bytes<T: FromBytes>(bytes: &[u8]) -> Result<T, ClientError> {
+	let (value, remainder) = T::from_bytes(bytes)?;
+	if !remainder.is_empty() {
+		return Err(ClientError::LeftoverBytes(remainder.len()));
+	}
+	Ok(value)
+}
+
+/// `bytes` of the JSON form of a CLValue returned by the node
+pub fn decode_hex<T: FromBytes>(hex: &str) -> Result<T, ClientError> {
+	let bytes = checksummed_hex
+		::decode(hex)
+		.map_err(|error| ClientError::Hex(error.to_string()))?;
+	decode_bytes(&bytes)
+}
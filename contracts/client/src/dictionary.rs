@@ -0,0 +1,110 @@
+use std::marker::PhantomData;
+
+use casper_types::{
+	bytesrepr::FromBytes,
+	crypto::blake2b,
+	CLTyped,
+	CLValue,
+	ContractHash,
+	Key,
+	U512,
+};
+use common_lib::{
+	constants::common_keys::{
+		AdministractionStoreKeys,
+		DatabaseKeys,
+		NFTContractKeys,
+		PriceOracleKeys,
+		KEY_REGISTRY_DOMAIN_CONTRACT_HASH,
+		KEY_REGISTRY_TOKEN_NAME,
+	},
+	models::{
+		price::Price,
+		registry_contract_hash_pair::RegistryContractHashPair,
+		transfer_restrictions::TransferRestrictions,
+		DomainName,
+		SubdomainName,
+	},
+};
+
+use crate::{ decode::decode_optional, error::ClientError };
+
+/**
+ * Dictionary item of a contract, `dictionary_name` is the named key of the contract
+ * and `item_key` is the key of the item, both are passed to `state_get_dictionary_item`.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct DictionaryItem<T> {
+	pub dictionary_name: String,
+	pub item_key: String,
+	value_type: PhantomData<T>,
+}
+
+impl<T: CLTyped + FromBytes> DictionaryItem<T> {
+	pub fn new(dictionary_name: &str, item_key: &str) -> Self {
+		Self {
+			dictionary_name: dictionary_name.to_string(),
+			item_key: item_key.to_string(),
+			value_type: PhantomData,
+		}
+	}
+
+	/// `db::dictionary::Dictionary` stores `Option<T>`, removed items are `None`.
+	/// Items written by `upsert_dictionary_value_from_key` are stored as `T`.
+	pub fn decode(&self, cl_value: CLValue) -> Result<Option<T>, ClientError> {
+		decode_optional(cl_value)
+	}
+}
+
+/// Database contract
+pub fn domain(name: &str) -> DictionaryItem<DomainName> {
+	DictionaryItem::new(&DatabaseKeys::DictionaryDomain.to_string(), name)
+}
+
+/// Database contract
+pub fn subdomain(name: &str) -> DictionaryItem<SubdomainName> {
+	DictionaryItem::new(&DatabaseKeys::DictionarySubdomain.to_string(), name)
+}
+
+/// Database contract, subdomains of the domain
+pub fn subdomain_list(domain_name: &str) -> DictionaryItem<Vec<SubdomainName>> {
+	DictionaryItem::new(&DatabaseKeys::DictionarySubdomainList.to_string(), domain_name)
+}
+
+/// Database contract, names without an item are not restricted
+pub fn transfer_restrictions(name: &str) -> DictionaryItem<TransferRestrictions> {
+	DictionaryItem::new(&DatabaseKeys::DictionaryTransferRestrictions.to_string(), name)
+}
+
+/// Registry contract
+pub fn route(domain_name: &str) -> DictionaryItem<RegistryContractHashPair> {
+	DictionaryItem::new(KEY_REGISTRY_DOMAIN_CONTRACT_HASH, domain_name)
+}
+
+/// Registry contract, name of the nft-core token
+pub fn token_name(token_id: &str) -> DictionaryItem<String> {
+	DictionaryItem::new(KEY_REGISTRY_TOKEN_NAME, token_id)
+}
+
+/// Token of the name in nft-core, same as `utils::domain_name::get_token_id` of the contracts
+pub fn token_id(name: &str) -> String {
+	base16::encode_lower(&blake2b(name))
+}
+
+/// NFT contract, price of the listed token
+pub fn listing(token_id: &str) -> DictionaryItem<U512> {
+	DictionaryItem::new(&NFTContractKeys::Listing.to_string(), token_id)
+}
+
+/// Price oracle contract, price of the extension
+pub fn price(extension: &str) -> DictionaryItem<Price> {
+	DictionaryItem::new(&PriceOracleKeys::Main.to_string(), extension)
+}
+
+/// Administration contract, authority list of the contract
+pub fn contract_authorities(contract_hash: ContractHash) -> DictionaryItem<Vec<Key>> {
+	DictionaryItem::new(
+		&AdministractionStoreKeys::ContractAuthority.to_string(),
+		&contract_hash.to_string()
+	)
+}
@@ -0,0 +1,60 @@
+use std::fmt;
+
+use casper_types::{ bytesrepr, CLType };
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClientError {
+	/// Entry point is not declared by the contract schema
+	UnknownEntryPoint(String),
+	/// Required argument is not passed
+	MissingArg(String),
+	/// Argument is not declared by the entry point
+	UnexpectedArg(String),
+	ArgTypeMismatch {
+		name: String,
+		expected: CLType,
+		found: CLType,
+	},
+	TypeMismatch {
+		expected: CLType,
+		found: CLType,
+	},
+	Bytes(bytesrepr::Error),
+	/// Bytes left after the value is read
+	LeftoverBytes(usize),
+	Hex(String),
+	Io(String),
+}
+
+impl fmt::Display for ClientError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::UnknownEntryPoint(name) => write!(f, "unknown entry point {}", name),
+			Self::MissingArg(name) => write!(f, "missing argument {}", name),
+			Self::UnexpectedArg(name) => write!(f, "unexpected argument {}", name),
+			Self::ArgTypeMismatch { name, expected, found } =>
+				write!(
+					f,
+					"argument {} has type {:?}, expected {:?}",
+					name,
+					found,
+					expected
+				),
+			Self::TypeMismatch { expected, found } =>
+				write!(f, "value has type {:?}, expected {:?}", found, expected),
+			Self::Bytes(error) => write!(f, "bytes cannot be read: {}", error),
+			Self::LeftoverBytes(count) =>
+				write!(f, "{} bytes left after the value", count),
+			Self::Hex(error) => write!(f, "hex cannot be decoded: {}", error),
+			Self::Io(error) => write!(f, "{}", error),
+		}
+	}
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<bytesrepr::Error> for ClientError {
+	fn from(error: bytesrepr::Error) -> Self {
+		Self::Bytes(error)
+	}
+}
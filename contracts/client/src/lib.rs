@@ -0,0 +1,33 @@
+//! Host-side client of the name service.
+//!
+//! Builds the `RuntimeArgs` of every entry point with the arg names of `common_lib`,
+//! describes stored-contract and session calls for the deploys of the backend and
+//! decodes returned `CLValue`s and dictionary items.
+//!
+//! ```ignore
+//! let main = MainClient::new(CallTarget::ContractHash(main_hash));
+//! let call = main.set_resolver_address_for_domain(name, resolver);
+//! call.check(&schema::main::schema())?;
+//! ```
+
+#[macro_use]
+mod macros;
+
+pub mod call;
+pub mod contracts;
+pub mod decode;
+pub mod dictionary;
+pub mod error;
+
+pub use call::{ CallTarget, ContractCall, SessionCall };
+pub use contracts::{
+	administration::AdministrationClient,
+	database::DatabaseClient,
+	main::MainClient,
+	nft::NftClient,
+	price_oracle::PriceOracleClient,
+	registry::RegistryClient,
+	transfer_filter::TransferFilterClient,
+	treasury::TreasuryClient,
+};
+pub use error::ClientError;
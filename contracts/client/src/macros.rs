@@ -0,0 +1,47 @@
+/**
+ * Declares a client of a contract, every method builds the call of one entry point:
+ *
+ * fn method(arg: Type => ArgKey, ...) => EntryPoint;
+ *
+ * `ArgKey` and `EntryPoint` are the `common_keys` enums or constants.
+ */
+macro_rules! contract_client {
+	(
+		$(#[$meta:meta])*
+		pub struct $client:ident;
+		$(
+			$(#[$method_meta:meta])*
+			fn $method:ident($($arg:ident: $ty:ty => $key:expr),* $(,)?) => $entry_point:expr;
+		)*
+	) => {
+		$(#[$meta])*
+		#[derive(Debug, Clone, PartialEq)]
+		pub struct $client {
+			pub target: $crate::call::CallTarget,
+		}
+
+		impl $client {
+			pub fn new(target: $crate::call::CallTarget) -> Self {
+				Self { target }
+			}
+
+			$(
+				$(#[$method_meta])*
+				pub fn $method(&self, $($arg: $ty),*) -> $crate::call::ContractCall {
+					#[allow(unused_mut)]
+					let mut args = ::casper_types::RuntimeArgs::new();
+					$(
+						args
+							.insert($key.to_string(), $arg)
+							.expect("Error while converting the argument to cl_value");
+					)*
+					$crate::call::ContractCall::new(
+						self.target.clone(),
+						$entry_point.to_string(),
+						args
+					)
+				}
+			)*
+		}
+	};
+}
@@ -1,7 +1,5 @@
 // Common usage constants
 
-use core::fmt::write;
-
 use alloc::collections::BTreeMap;
 use alloc::fmt;
 use alloc::string::{ String, ToString };
@@ -20,24 +18,25 @@ pub struct CustomMetadataSchema {
 	properties: BTreeMap<String, MetadataSchemaProperty>,
 }
 
+/// Schema of `models::nft::Metadata`, passed as `json_schema` when nft-core is installed
 pub fn get_custom_metadata_schema() -> CustomMetadataSchema {
+	let attributes = [
+		("name", "The domain name."),
+		("extension", "The extension of the domain name."),
+		("label_length", "Characters count of the label."),
+		("registration_date", "Registration block time in millis."),
+		("expiration_date", "Expiration block time in millis."),
+		("character_set", "digits, letters, alphanumeric or mixed."),
+		("image", "SVG image data URI."),
+	];
 	let mut properties = BTreeMap::new();
-	properties.insert("name".to_string(), MetadataSchemaProperty {
-		name: "name".to_string(),
-		description: "The name of domain name.".to_string(),
-		required: true,
-	});
-	properties.insert("symbol".to_string(), MetadataSchemaProperty {
-		name: "symbol".to_string(),
-		description: "The symbol of the token.".to_string(),
-		required: true,
-	});
-
-	properties.insert("token_id".to_string(), MetadataSchemaProperty {
-		name: "token_id".to_string(),
-		description: "Calculated id of the domain name.".to_string(),
-		required: true,
-	});
+	for (name, description) in attributes {
+		properties.insert(name.to_string(), MetadataSchemaProperty {
+			name: name.to_string(),
+			description: description.to_string(),
+			required: true,
+		});
+	}
 	CustomMetadataSchema { properties }
 }
 
@@ -54,11 +53,28 @@ pub const CSPR_HASH: [u8; 32] = [
 
 pub const MAX_DOMAIN_NAME_COUNT_PER_DATABASE: u16 = 10_000;
 
-pub(crate) const GRACE_PERIOD: u64 = 1000 * 60 * 60 * 24 * 90;
 pub(crate) const EXTENSION: &str = "cspr";
 pub const YEAR_IN_MILLIS: u64 = 1000 * 60 * 60 * 24 * 365;
 pub const MAX_PAGE_SIZE: usize = 10;
-pub const MAX_SUBDOMAIN_COUNT: u8 = 50;
+
+// Defaults of the extension configuration, used until the extension is configured
+pub const DEFAULT_MIN_CHARS_COUNT: u8 = 3;
+pub const DEFAULT_MAX_CHARS_COUNT: u8 = 63;
+pub const DEFAULT_MAX_SUBSCRIPTION_YEARS: u8 = 3;
+pub const DEFAULT_GRACE_PERIOD: u64 = 1000 * 60 * 60 * 24 * 90;
+pub const DEFAULT_MAX_SUBDOMAIN_COUNT: u8 = 50;
+pub const MAX_MIGRATION_BATCH_SIZE: usize = 20;
+pub const MAX_RESOLVE_ROUTES_SIZE: usize = 50;
+// Royalty shares and treasury splits are in basis points
+pub const ROYALTY_BASIS_POINTS: u32 = 10_000;
+pub const MAX_PROTOCOL_ROYALTY_SHARE: u32 = 1_000;
+pub const MAX_CREATOR_ROYALTY_SHARE: u32 = 1_000;
+// Auction durations and the anti-sniping window are in millis
+pub const MIN_AUCTION_DURATION: u64 = 1000 * 60 * 60;
+pub const MAX_AUCTION_DURATION: u64 = 1000 * 60 * 60 * 24 * 30;
+pub const AUCTION_EXTENSION_WINDOW: u64 = 1000 * 60 * 10;
+// Rates of the payment tokens are in token units per CSPR
+pub const MOTES_PER_CSPR: u64 = 1_000_000_000;
 pub const DEFAULT_RESPONSE_ERROR_MESSAGE: &str = "Error while parsing argument";
 
 pub const MAIN_CONTRACT_NAME_WASM: &str = "main-contract.wasm";
@@ -88,13 +104,12 @@ pub const ARG_MAIN_DOMAIN_PAGE: &str = "arg_domain_page";
 pub const ARG_MAIN_PRICE_ORACLE_CONTRACT_HASH: &str =
 	"arg_price_oracle_contract_hash";
 pub const ARG_MAIN_REGISTER_AMOUNT: &str = "arg_amount";
-pub const ARG_MAIN_AUTHORITY: &str = "arg_authority";
 pub const ARG_MAIN_CUSTOMER_PURSE: &str = "arg_main_customer_purse";
 
 // KEYS
 pub const KEY_MAIN_MAINTAINER: &str = "key_main_maintainer";
-pub const KEY_MAIN_AUTHORITIES: &str = "key_main_authorities";
 pub const KEY_MAIN_DICTIONARY_DOMAIN_METADATA: &str = "key_domain_metadata";
+pub const KEY_MAIN_DICTIONARY_DOMAIN_LIST: &str = "key_main_domain_list";
 pub const KEY_MAIN_DICTIONARY_SUBDOMAIN_METADATA: &str =
 	"key_subdomain_metadata";
 pub const KEY_MAIN_SUBDOMAIN_METADATA: &str = "key_subdomain_metadata";
@@ -131,8 +146,6 @@ pub const ENTRYPOINT_MAIN_GET_DOMAIN_LIST: &str = "get_domain_list";
 pub const ENTRYPOINT_MAIN_SET_AUTHORITIES_CONTRACT_HASH: &str =
 	"set_authorities_contract_hash";
 pub const ENTRYPOINT_MAIN_INIT: &str = "init";
-pub const ENTRYPOINT_MAIN_ADD_AUTHORITY: &str = "add_authority";
-pub const ENTRYPOINT_MAIN_REMOVE_AUTHORITY: &str = "remove_authority";
 pub const ENTRYPOINT_MAIN_EXTEND: &str = "extend";
 
 // ******* Price Oracle Contract constants **********
@@ -147,6 +160,9 @@ pub const ENDPOINT_PO_PRICE_GET_SIMPLE_OPERATIONS: &str =
 pub const ENDPOINT_PO_PRICE_SET_SIMPLE_OPERATIONS: &str =
 	"set_price_simple_operations";
 pub const ENDPOINT_PO_INIT: &str = "init";
+pub const ENDPOINT_PO_SET_PAYMENT_TOKEN: &str = "set_payment_token";
+pub const ENDPOINT_PO_REMOVE_PAYMENT_TOKEN: &str = "remove_payment_token";
+pub const ENDPOINT_PO_GET_PAYMENT_TOKEN: &str = "get_payment_token";
 
 // ARGS
 pub const ARG_PO_PRICE_TYPE: &str = "arg_price_type";
@@ -255,6 +271,7 @@ pub const KEY_REGISTRY_OPERATORS: &str = "key_registry_operators";
 pub const KEY_REGISTRY_MAINTAINER: &str = "key_registry_maintainer";
 pub const KEY_REGISTRY_DOMAIN_CONTRACT_HASH: &str =
 	"key_registry_domain_contract_hash";
+pub const KEY_REGISTRY_TOKEN_NAME: &str = "key_registry_token_name";
 pub const KEY_REGISTRY_WHITELIST_CONTRACT_HASH: &str =
 	"key_registry_whitelist_contract_hash";
 pub const KEY_REGISTRY_CONTRACT_OPERATORS: &str =
@@ -295,6 +312,15 @@ pub enum AdministractionStoreKeys {
 	ContractNotExist,
 	Authorities,
 	ContractAuthority,
+	Proposals,
+	ProposalCount,
+	MinDelay,
+	Signers,
+	Threshold,
+	ExtensionConfig,
+	RegistrationAllowlist,
+	Standby,
+	CompoundExtensions,
 }
 
 impl fmt::Display for AdministractionStoreKeys {
@@ -306,6 +332,15 @@ impl fmt::Display for AdministractionStoreKeys {
 			Self::ContractNotExist => write!(f, "contract_not_exist"),
 			Self::Authorities => write!(f, "authorities"),
 			Self::ContractAuthority => write!(f, "contract_authority"),
+			Self::Proposals => write!(f, "proposals"),
+			Self::ProposalCount => write!(f, "proposal_count"),
+			Self::MinDelay => write!(f, "min_delay"),
+			Self::Signers => write!(f, "signers"),
+			Self::Threshold => write!(f, "threshold"),
+			Self::ExtensionConfig => write!(f, "extension_config"),
+			Self::RegistrationAllowlist => write!(f, "registration_allowlist"),
+			Self::Standby => write!(f, "standby"),
+			Self::CompoundExtensions => write!(f, "compound_extensions"),
 		}
 	}
 }
@@ -322,6 +357,24 @@ pub enum AdministrationArgs {
 	ContractHash,
 	ContractAuthorities,
 	ContractAuthority,
+	ProposalAction,
+	ProposalId,
+	MinDelay,
+	Signers,
+	Threshold,
+	Role,
+	Account,
+	MaxCharsCount,
+	MaxSubscriptionYears,
+	GracePeriod,
+	SubdomainLimit,
+	RegistrationMode,
+	FeeRecipient,
+	ExtensionConfig,
+	From,
+	To,
+	DomainNames,
+	PreviousKey,
 }
 
 impl fmt::Display for AdministrationArgs {
@@ -336,6 +389,24 @@ impl fmt::Display for AdministrationArgs {
 			Self::ContractHash => write!(f, "contract_hash"),
 			Self::ContractAuthorities => write!(f, "contract_authorities"),
 			Self::ContractAuthority => write!(f, "contract_authority"),
+			Self::ProposalAction => write!(f, "proposal_action"),
+			Self::ProposalId => write!(f, "proposal_id"),
+			Self::MinDelay => write!(f, "min_delay"),
+			Self::Signers => write!(f, "signers"),
+			Self::Threshold => write!(f, "threshold"),
+			Self::Role => write!(f, "role"),
+			Self::Account => write!(f, "account"),
+			Self::MaxCharsCount => write!(f, "max_chars_count"),
+			Self::MaxSubscriptionYears => write!(f, "max_subscription_years"),
+			Self::GracePeriod => write!(f, "grace_period"),
+			Self::SubdomainLimit => write!(f, "subdomain_limit"),
+			Self::RegistrationMode => write!(f, "registration_mode"),
+			Self::FeeRecipient => write!(f, "fee_recipient"),
+			Self::ExtensionConfig => write!(f, "extension_config"),
+			Self::From => write!(f, "from"),
+			Self::To => write!(f, "to"),
+			Self::DomainNames => write!(f, "domain_names"),
+			Self::PreviousKey => write!(f, "previous_key"),
 		}
 	}
 }
@@ -359,6 +430,48 @@ pub enum AdministrationEndpoints {
 	SetCharsMinCount,
 	GetListingLimit,
 	SetListingLimit,
+	Init,
+	QueueProposal,
+	CancelProposal,
+	ExecuteProposal,
+	GetProposal,
+	GetMinDelay,
+	SetMinDelay,
+	ApproveProposal,
+	RevokeApproval,
+	GetSigners,
+	SetSigners,
+	GrantRole,
+	RevokeRole,
+	RenounceRole,
+	HasRole,
+	GetRoleMembers,
+	Pause,
+	Unpause,
+	IsPaused,
+	GetExtensionConfig,
+	GetMaxCharsCount,
+	SetMaxCharsCount,
+	GetMaxSubscriptionYears,
+	SetMaxSubscriptionYears,
+	GetGracePeriod,
+	SetGracePeriod,
+	GetSubdomainLimit,
+	SetSubdomainLimit,
+	GetRegistrationMode,
+	SetRegistrationMode,
+	GetFeeRecipient,
+	SetFeeRecipient,
+	AddToAllowlist,
+	RemoveFromAllowlist,
+	IsAllowlisted,
+	AddStandbyContract,
+	GetStandbyContracts,
+	ProvisionContract,
+	MigrateDomains,
+	ContractUpgraded,
+	VerifyTopology,
+	GetCompoundContracts,
 }
 
 impl fmt::Display for AdministrationEndpoints {
@@ -382,6 +495,48 @@ impl fmt::Display for AdministrationEndpoints {
 			Self::SetCharsMinCount => write!(f, "set_chars_min_count"),
 			Self::GetListingLimit => write!(f, "get_listing_limit"),
 			Self::SetListingLimit => write!(f, "set_listing_limit"),
+			Self::Init => write!(f, "init"),
+			Self::QueueProposal => write!(f, "queue_proposal"),
+			Self::CancelProposal => write!(f, "cancel_proposal"),
+			Self::ExecuteProposal => write!(f, "execute_proposal"),
+			Self::GetProposal => write!(f, "get_proposal"),
+			Self::GetMinDelay => write!(f, "get_min_delay"),
+			Self::SetMinDelay => write!(f, "set_min_delay"),
+			Self::ApproveProposal => write!(f, "approve_proposal"),
+			Self::RevokeApproval => write!(f, "revoke_approval"),
+			Self::GetSigners => write!(f, "get_signers"),
+			Self::SetSigners => write!(f, "set_signers"),
+			Self::GrantRole => write!(f, "grant_role"),
+			Self::RevokeRole => write!(f, "revoke_role"),
+			Self::RenounceRole => write!(f, "renounce_role"),
+			Self::HasRole => write!(f, "has_role"),
+			Self::GetRoleMembers => write!(f, "get_role_members"),
+			Self::Pause => write!(f, "pause"),
+			Self::Unpause => write!(f, "unpause"),
+			Self::IsPaused => write!(f, "is_paused"),
+			Self::GetExtensionConfig => write!(f, "get_extension_config"),
+			Self::GetMaxCharsCount => write!(f, "get_max_chars_count"),
+			Self::SetMaxCharsCount => write!(f, "set_max_chars_count"),
+			Self::GetMaxSubscriptionYears => write!(f, "get_max_subscription_years"),
+			Self::SetMaxSubscriptionYears => write!(f, "set_max_subscription_years"),
+			Self::GetGracePeriod => write!(f, "get_grace_period"),
+			Self::SetGracePeriod => write!(f, "set_grace_period"),
+			Self::GetSubdomainLimit => write!(f, "get_subdomain_limit"),
+			Self::SetSubdomainLimit => write!(f, "set_subdomain_limit"),
+			Self::GetRegistrationMode => write!(f, "get_registration_mode"),
+			Self::SetRegistrationMode => write!(f, "set_registration_mode"),
+			Self::GetFeeRecipient => write!(f, "get_fee_recipient"),
+			Self::SetFeeRecipient => write!(f, "set_fee_recipient"),
+			Self::AddToAllowlist => write!(f, "add_to_allowlist"),
+			Self::RemoveFromAllowlist => write!(f, "remove_from_allowlist"),
+			Self::IsAllowlisted => write!(f, "is_allowlisted"),
+			Self::AddStandbyContract => write!(f, "add_standby_contract"),
+			Self::GetStandbyContracts => write!(f, "get_standby_contracts"),
+			Self::ProvisionContract => write!(f, "provision_contract"),
+			Self::MigrateDomains => write!(f, "migrate_domains"),
+			Self::ContractUpgraded => write!(f, "contract_upgraded"),
+			Self::VerifyTopology => write!(f, "verify_topology"),
+			Self::GetCompoundContracts => write!(f, "get_compound_contracts"),
 		}
 	}
 }
@@ -395,6 +550,11 @@ pub enum NFTContractKeys {
 	Operator,
 	NFTCoreContractHash,
 	NFTCoreContractHashCurrent,
+	TokenMetadata,
+	RoyaltyPolicies,
+	TokenRoyalties,
+	Auctions,
+	AuctionPurse,
 }
 
 impl fmt::Display for NFTContractKeys {
@@ -405,6 +565,11 @@ impl fmt::Display for NFTContractKeys {
 			Self::NFTCoreContractHash => write!(f, "nft_core_contract_hash"),
 			Self::NFTCoreContractHashCurrent =>
 				write!(f, "nft_core_contract_hash_current"),
+			Self::TokenMetadata => write!(f, "token_metadata"),
+			Self::RoyaltyPolicies => write!(f, "royalty_policies"),
+			Self::TokenRoyalties => write!(f, "token_royalties"),
+			Self::Auctions => write!(f, "auctions"),
+			Self::AuctionPurse => write!(f, "auction_purse"),
 		}
 	}
 }
@@ -413,24 +578,44 @@ impl fmt::Display for NFTContractKeys {
 #[repr(u8)]
 pub enum NFTContractArgs {
 	Owner,
-	Metadata,
-	NftCoreContractHash,
+	ExpirationDate,
 	TokenId,
 	SourceKey,
 	DestinationKey,
 	TokenPrice,
+	DomainName,
+	Extension,
+	RoyaltyShare,
+	SalePrice,
+	Amount,
+	CustomerPurse,
+	Page,
+	ReservePrice,
+	MinBidIncrement,
+	Duration,
+	PaymentToken,
 }
 
 impl fmt::Display for NFTContractArgs {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match self {
 			Self::Owner => write!(f, "owner"),
-			Self::Metadata => write!(f, "metadata"),
-			Self::NftCoreContractHash => write!(f, "contract_hash"),
+			Self::ExpirationDate => write!(f, "expiration_date"),
 			Self::TokenId => write!(f, "token_id"),
 			Self::SourceKey => write!(f, "source_key"),
 			Self::DestinationKey => write!(f, "destination_key"),
 			Self::TokenPrice => write!(f, "token_price"),
+			Self::DomainName => write!(f, "domain_name"),
+			Self::Extension => write!(f, "extension"),
+			Self::RoyaltyShare => write!(f, "royalty_share"),
+			Self::SalePrice => write!(f, "sale_price"),
+			Self::Amount => write!(f, "amount"),
+			Self::CustomerPurse => write!(f, "customer_purse"),
+			Self::Page => write!(f, "page"),
+			Self::ReservePrice => write!(f, "reserve_price"),
+			Self::MinBidIncrement => write!(f, "min_bid_increment"),
+			Self::Duration => write!(f, "duration"),
+			Self::PaymentToken => write!(f, "payment_token"),
 		}
 	}
 }
@@ -438,23 +623,45 @@ impl fmt::Display for NFTContractArgs {
 #[derive(Debug, Clone, Copy)]
 #[repr(u8)]
 pub enum NFTContractEndpoints {
+	Init,
 	Mint,
 	Transfer,
 	Burn,
 	List,
 	UnList,
 	Buy,
+	SetTokenExpiration,
+	SetRoyaltyPolicy,
+	SetTokenRoyalty,
+	RoyaltyInfo,
+	GetOwnedTokens,
+	StartAuction,
+	CancelAuction,
+	Bid,
+	SettleAuction,
+	GetAuction,
 }
 
 impl fmt::Display for NFTContractEndpoints {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match self {
+			Self::Init => write!(f, "init"),
 			Self::Mint => write!(f, "mint"),
 			Self::Transfer => write!(f, "transfer"),
 			Self::Burn => write!(f, "burn"),
 			Self::List => write!(f, "list"),
 			Self::UnList => write!(f, "un_list"),
 			Self::Buy => write!(f, "buy"),
+			Self::SetTokenExpiration => write!(f, "set_token_expiration"),
+			Self::SetRoyaltyPolicy => write!(f, "set_royalty_policy"),
+			Self::SetTokenRoyalty => write!(f, "set_token_royalty"),
+			Self::RoyaltyInfo => write!(f, "royalty_info"),
+			Self::GetOwnedTokens => write!(f, "get_owned_tokens"),
+			Self::StartAuction => write!(f, "start_auction"),
+			Self::CancelAuction => write!(f, "cancel_auction"),
+			Self::Bid => write!(f, "bid"),
+			Self::SettleAuction => write!(f, "settle_auction"),
+			Self::GetAuction => write!(f, "get_auction"),
 		}
 	}
 }
@@ -463,6 +670,14 @@ pub enum NFTCoreContractEndpoints {
 	Mint,
 	Transfer,
 	Burn,
+	SetTokenMetadata,
+	GetApproved,
+	IsApprovedForAll,
+	RegisterOwner,
+	GetOwnedTokens,
+	OwnerOf,
+	BalanceOf,
+	SetAclWhitelist,
 }
 
 impl fmt::Display for NFTCoreContractEndpoints {
@@ -471,6 +686,55 @@ impl fmt::Display for NFTCoreContractEndpoints {
 			Self::Mint => write!(f, "mint"),
 			Self::Transfer => write!(f, "transfer"),
 			Self::Burn => write!(f, "burn"),
+			Self::SetTokenMetadata => write!(f, "set_token_metadata"),
+			Self::GetApproved => write!(f, "get_approved"),
+			Self::IsApprovedForAll => write!(f, "is_approved_for_all"),
+			Self::RegisterOwner => write!(f, "register_owner"),
+			Self::GetOwnedTokens => write!(f, "get_owned_tokens"),
+			Self::OwnerOf => write!(f, "owner_of"),
+			Self::BalanceOf => write!(f, "balance_of"),
+			Self::SetAclWhitelist => write!(f, "set_acl_whitelist"),
+		}
+	}
+}
+
+/// CEP-18 tokens accepted as payments
+#[derive(Debug, Clone, Copy)]
+pub enum Cep18Endpoints {
+	Approve,
+	TransferFrom,
+	BalanceOf,
+	Allowance,
+}
+
+impl fmt::Display for Cep18Endpoints {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Approve => write!(f, "approve"),
+			Self::TransferFrom => write!(f, "transfer_from"),
+			Self::BalanceOf => write!(f, "balance_of"),
+			Self::Allowance => write!(f, "allowance"),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Cep18Args {
+	Owner,
+	Spender,
+	Recipient,
+	Amount,
+	Address,
+}
+
+impl fmt::Display for Cep18Args {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Owner => write!(f, "owner"),
+			Self::Spender => write!(f, "spender"),
+			Self::Recipient => write!(f, "recipient"),
+			Self::Amount => write!(f, "amount"),
+			Self::Address => write!(f, "address"),
 		}
 	}
 }
@@ -485,6 +749,7 @@ pub enum PriceOracleKeys {
 	PriceMore,
 	SimpleOperations,
 	Main,
+	PaymentTokens,
 }
 
 impl fmt::Display for PriceOracleKeys {
@@ -497,6 +762,7 @@ impl fmt::Display for PriceOracleKeys {
 			Self::PriceMore => write!(f, "price_more"),
 			Self::SimpleOperations => write!(f, "simple_operations"),
 			Self::Main => write!(f, "main"),
+			Self::PaymentTokens => write!(f, "payment_tokens"),
 		}
 	}
 }
@@ -510,6 +776,9 @@ pub enum PriceOracleArgs {
 	PriceMid,
 	CharsCount,
 	PriceMore,
+	PaymentToken,
+	Rate,
+	Treasury,
 }
 
 impl fmt::Display for PriceOracleArgs {
@@ -521,6 +790,9 @@ impl fmt::Display for PriceOracleArgs {
 			Self::CharsCount => write!(f, "chars_count"),
 			Self::PriceMore => write!(f, "price_more"),
 			Self::Extension => write!(f, "extension"),
+			Self::PaymentToken => write!(f, "payment_token"),
+			Self::Rate => write!(f, "rate"),
+			Self::Treasury => write!(f, "treasury"),
 		}
 	}
 }
@@ -535,6 +807,9 @@ pub enum CommonKeys {
 	ContractVersion,
 	Authorities,
 	AllowedContracts,
+	Roles,
+	Paused,
+	SchemaVersion,
 }
 
 impl fmt::Display for CommonKeys {
@@ -546,6 +821,9 @@ impl fmt::Display for CommonKeys {
 			Self::ContractVersion => write!(f, "contract_version"),
 			Self::Authorities => write!(f, "authorities"),
 			Self::AllowedContracts => write!(f, "allowed_contracts"),
+			Self::Roles => write!(f, "roles"),
+			Self::Paused => write!(f, "paused"),
+			Self::SchemaVersion => write!(f, "schema_version"),
 		}
 	}
 }
@@ -556,6 +834,7 @@ pub enum CommonArgs {
 	Authorities,
 	Authority,
 	AdministrationContract,
+	PackageHash,
 }
 
 impl fmt::Display for CommonArgs {
@@ -564,6 +843,7 @@ impl fmt::Display for CommonArgs {
 			Self::Authorities => write!(f, "authorities"),
 			Self::Authority => write!(f, "authority"),
 			Self::AdministrationContract => write!(f, "administration_contract"),
+			Self::PackageHash => write!(f, "package_hash"),
 		}
 	}
 }
@@ -575,6 +855,7 @@ pub enum CommonEndpoints {
 	AddAuthority,
 	RemoveAuthority,
 	GetAuthorities,
+	Migrate,
 }
 
 impl fmt::Display for CommonEndpoints {
@@ -584,6 +865,7 @@ impl fmt::Display for CommonEndpoints {
 			Self::AddAuthority => write!(f, "add_authority"),
 			Self::RemoveAuthority => write!(f, "remove_authority"),
 			Self::GetAuthorities => write!(f, "get_authorities"),
+			Self::Migrate => write!(f, "migrate"),
 		}
 	}
 }
@@ -592,16 +874,17 @@ impl fmt::Display for CommonEndpoints {
 
 #[derive(Debug, Clone, Copy)]
 pub enum DatabaseKeys {
-	DictionarySubdomain = "key_database_subdomain",
-	SubdomainCount = "key_database_subdomain_count",
-	DictionarySubdomainList = "key_database_subdomain_list",
-	DictionaryDomain = "key_database_domain",
-	DictionaryDomainMap = "key_database_domain_map",
-	DictionaryDomainList = "key_database_domain_list",
-	DomainListPagination = "key_database_domain_list_pagination",
-	TotalDomainCount = "key_database_totals_domain_count",
-	TotalSubdomainCount = "key_database_totals_subdomain_count",
-	DictionaryDomainOwer = "key_database_dictionary_owner_domain_list",
+	DictionarySubdomain,
+	SubdomainCount,
+	DictionarySubdomainList,
+	DictionaryDomain,
+	DictionaryDomainMap,
+	DictionaryDomainList,
+	DomainListPagination,
+	TotalDomainCount,
+	TotalSubdomainCount,
+	DictionaryDomainOwer,
+	DictionaryTransferRestrictions,
 }
 
 impl fmt::Display for DatabaseKeys {
@@ -620,6 +903,8 @@ impl fmt::Display for DatabaseKeys {
 				write!(f, "key_database_totals_subdomain_count"),
 			Self::DictionaryDomainOwer =>
 				write!(f, "key_database_dictionary_owner_domain_list"),
+			Self::DictionaryTransferRestrictions =>
+				write!(f, "key_database_transfer_restrictions"),
 		}
 	}
 }
@@ -632,6 +917,10 @@ pub enum DatabaseArgs {
 	ExpirationDate,
 	Owner,
 	Page,
+	DomainNames,
+	MigrationEntries,
+	Name,
+	TransferLock,
 }
 
 impl fmt::Display for DatabaseArgs {
@@ -643,6 +932,10 @@ impl fmt::Display for DatabaseArgs {
 			Self::ExpirationDate => write!(f, "arg_database_expiration_date"),
 			Self::Owner => write!(f, "arg_database_owner"),
 			Self::Page => write!(f, "arg_database_page"),
+			Self::DomainNames => write!(f, "arg_database_domain_names"),
+			Self::MigrationEntries => write!(f, "arg_database_migration_entries"),
+			Self::Name => write!(f, "arg_database_name"),
+			Self::TransferLock => write!(f, "arg_database_transfer_lock"),
 		}
 	}
 }
@@ -664,6 +957,11 @@ pub enum DatabaseEndpoints {
 	GetSubdomain,
 	Init,
 	GetDomainListForOwner,
+	ExportDomains,
+	ImportDomains,
+	SetTransferLock,
+	BurnTransferPermission,
+	GetTransferRestrictions,
 }
 
 impl fmt::Display for DatabaseEndpoints {
@@ -684,6 +982,11 @@ impl fmt::Display for DatabaseEndpoints {
 			Self::GetSubdomain => write!(f, "get_subdomain"),
 			Self::Init => write!(f, "init"),
 			Self::GetDomainListForOwner => write!(f, "get_domain_list_for_owner"),
+			Self::ExportDomains => write!(f, "export_domains"),
+			Self::ImportDomains => write!(f, "import_domains"),
+			Self::SetTransferLock => write!(f, "set_transfer_lock"),
+			Self::BurnTransferPermission => write!(f, "burn_transfer_permission"),
+			Self::GetTransferRestrictions => write!(f, "get_transfer_restrictions"),
 		}
 	}
 }
@@ -693,7 +996,18 @@ impl fmt::Display for DatabaseEndpoints {
 #[derive(Debug, Clone, Copy)]
 pub enum RegistryEndpoints {
 	MapDomainNameToContractHash,
+	UnmapDomainName,
 	GetContractHashForDomainName,
+	RemapDomainNames,
+	ResolveRoutes,
+	AddToWhitelist,
+	RemoveFromWhitelist,
+	GetWhitelist,
+	AddOperator,
+	RemoveOperator,
+	GetOperators,
+	Init,
+	GetNameForToken,
 }
 
 impl fmt::Display for RegistryEndpoints {
@@ -701,8 +1015,19 @@ impl fmt::Display for RegistryEndpoints {
 		match self {
 			Self::MapDomainNameToContractHash =>
 				write!(f, "map_domain_name_to_contract_hash"),
+			Self::UnmapDomainName => write!(f, "unmap_domain_name"),
 			Self::GetContractHashForDomainName =>
 				write!(f, "get_contract_hash_for_domain_name"),
+			Self::RemapDomainNames => write!(f, "remap_domain_names"),
+			Self::ResolveRoutes => write!(f, "resolve_routes"),
+			Self::AddToWhitelist => write!(f, "add_to_whitelist"),
+			Self::RemoveFromWhitelist => write!(f, "remove_from_whitelist"),
+			Self::GetWhitelist => write!(f, "get_whitelist"),
+			Self::AddOperator => write!(f, "add_operator"),
+			Self::RemoveOperator => write!(f, "remove_operator"),
+			Self::GetOperators => write!(f, "get_operators"),
+			Self::Init => write!(f, "init"),
+			Self::GetNameForToken => write!(f, "get_name_for_token"),
 		}
 	}
 }
@@ -712,6 +1037,10 @@ pub enum RegistryArgs {
 	DomainName,
 	DatabaseContractHash,
 	NftContractHash,
+	DomainNames,
+	ContractHash,
+	Operator,
+	TokenId,
 }
 
 impl fmt::Display for RegistryArgs {
@@ -721,13 +1050,17 @@ impl fmt::Display for RegistryArgs {
 			Self::DatabaseContractHash =>
 				write!(f, "arg_registry_database_contract_hash"),
 			Self::NftContractHash => write!(f, "arg_registry_nft_contract_hash"),
+			Self::DomainNames => write!(f, "arg_registry_domain_names"),
+			Self::ContractHash => write!(f, "arg_registry_contract_hash"),
+			Self::Operator => write!(f, "arg_registry_operator"),
+			Self::TokenId => write!(f, "arg_registry_token_id"),
 		}
 	}
 }
 
 #[derive(Debug, Clone, Copy)]
 pub enum RegistryKeys {
-	DomainContractHash = "key_registry_domain_contract_hash",
+	DomainContractHash,
 }
 
 impl fmt::Display for RegistryKeys {
@@ -753,6 +1086,8 @@ pub enum MainEndpoints {
 	GetDomainList,
 	Init,
 	Extend,
+	BurnSubdomainTransferPermission,
+	ReleaseDomain,
 }
 
 impl fmt::Display for MainEndpoints {
@@ -770,6 +1105,9 @@ impl fmt::Display for MainEndpoints {
 			Self::GetDomainList => write!(f, "get_domain_list"),
 			Self::Init => write!(f, "init"),
 			Self::Extend => write!(f, "extend"),
+			Self::BurnSubdomainTransferPermission =>
+				write!(f, "burn_subdomain_transfer_permission"),
+			Self::ReleaseDomain => write!(f, "release_domain"),
 		}
 	}
 }
@@ -784,6 +1122,7 @@ pub enum MainArgs {
 	PriceOracleContractHash,
 	RegisterAmount,
 	CustomerPurse,
+	PaymentToken,
 }
 
 impl fmt::Display for MainArgs {
@@ -798,6 +1137,7 @@ impl fmt::Display for MainArgs {
 				write!(f, "arg_price_oracle_contract_hash"),
 			Self::RegisterAmount => write!(f, "arg_amount"),
 			Self::CustomerPurse => write!(f, "arg_main_customer_purse"),
+			Self::PaymentToken => write!(f, "arg_payment_token"),
 		}
 	}
 }
@@ -831,3 +1171,109 @@ impl fmt::Display for MainKeys {
 		}
 	}
 }
+
+/// Transfer Filter Contract Keys
+
+#[derive(Debug, Clone, Copy)]
+pub enum TransferFilterEndpoints {
+	CanTransfer,
+	GetTransferDenial,
+}
+
+impl fmt::Display for TransferFilterEndpoints {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::CanTransfer => write!(f, "can_transfer"),
+			Self::GetTransferDenial => write!(f, "get_transfer_denial"),
+		}
+	}
+}
+
+/// `can_transfer` args are the ones nft-core passes to its transfer filter
+#[derive(Debug, Clone, Copy)]
+pub enum TransferFilterArgs {
+	SourceKey,
+	TargetKey,
+	TokenId,
+}
+
+impl fmt::Display for TransferFilterArgs {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::SourceKey => write!(f, "source_key"),
+			Self::TargetKey => write!(f, "target_key"),
+			Self::TokenId => write!(f, "token_id"),
+		}
+	}
+}
+
+/// Treasury Contract Keys
+/// Balances of a stream are kept in `balances_<stream>` keyed by the account hash of the beneficiary
+#[derive(Debug, Clone, Copy)]
+pub enum TreasuryKeys {
+	Purse,
+	Beneficiaries,
+	Revenue,
+	Balances,
+	Undistributed,
+}
+
+impl fmt::Display for TreasuryKeys {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Purse => write!(f, "treasury_purse"),
+			Self::Beneficiaries => write!(f, "beneficiaries"),
+			Self::Revenue => write!(f, "revenue"),
+			Self::Balances => write!(f, "balances"),
+			Self::Undistributed => write!(f, "undistributed"),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum TreasuryArgs {
+	RevenueStream,
+	Amount,
+	Purse,
+	Beneficiaries,
+	Beneficiary,
+}
+
+impl fmt::Display for TreasuryArgs {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::RevenueStream => write!(f, "revenue_stream"),
+			Self::Amount => write!(f, "amount"),
+			Self::Purse => write!(f, "purse"),
+			Self::Beneficiaries => write!(f, "beneficiaries"),
+			Self::Beneficiary => write!(f, "beneficiary"),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum TreasuryEndpoints {
+	Init,
+	Deposit,
+	SetBeneficiaries,
+	GetBeneficiaries,
+	GetRevenue,
+	GetBalance,
+	GetUndistributed,
+	Withdraw,
+}
+
+impl fmt::Display for TreasuryEndpoints {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Init => write!(f, "init"),
+			Self::Deposit => write!(f, "deposit"),
+			Self::SetBeneficiaries => write!(f, "set_beneficiaries"),
+			Self::GetBeneficiaries => write!(f, "get_beneficiaries"),
+			Self::GetRevenue => write!(f, "get_revenue"),
+			Self::GetBalance => write!(f, "get_balance"),
+			Self::GetUndistributed => write!(f, "get_undistributed"),
+			Self::Withdraw => write!(f, "withdraw"),
+		}
+	}
+}
@@ -4,4 +4,12 @@ pub trait Storable {
 	fn get<T: CLTyped + FromBytes>(&self, key: &str) -> Option<T>;
 	fn set<T: CLTyped + ToBytes>(&self, key: &str, value: T);
 	fn remove<T: CLTyped + ToBytes>(&self, key: &str);
+
+	/// Writes the stored value back, so it is kept with the CLType of the current model.
+	/// Used by the migrations of the models which were stored as `CLType::Any`
+	fn rewrite<T: CLTyped + FromBytes + ToBytes>(&self, key: &str) {
+		if let Some(value) = self.get::<T>(key) {
+			self.set(key, value);
+		}
+	}
 }
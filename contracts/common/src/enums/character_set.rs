@@ -0,0 +1,78 @@
+use alloc::{ vec, vec::Vec };
+use casper_types::{ bytesrepr::FromBytes, bytesrepr::ToBytes, CLTyped };
+use core::fmt;
+
+/**
+ * Characters the label of a name is made of:
+ * Digits - 0-9 only
+ * Letters - a-z only
+ * Alphanumeric - both digits and letters
+ * Mixed - anything else, hyphens or non ascii characters
+ */
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(u8)]
+pub enum CharacterSet {
+	Digits = 0,
+	Letters = 1,
+	Alphanumeric = 2,
+	Mixed = 3,
+}
+
+impl CharacterSet {
+	pub fn of(label: &str) -> Self {
+		let has_digits = label.chars().any(|c| c.is_ascii_digit());
+		let has_letters = label.chars().any(|c| c.is_ascii_lowercase());
+		let is_alphanumeric = label
+			.chars()
+			.all(|c| c.is_ascii_digit() || c.is_ascii_lowercase());
+
+		match (is_alphanumeric, has_digits, has_letters) {
+			(true, true, false) => CharacterSet::Digits,
+			(true, false, true) => CharacterSet::Letters,
+			(true, true, true) => CharacterSet::Alphanumeric,
+			_ => CharacterSet::Mixed,
+		}
+	}
+}
+
+impl fmt::Display for CharacterSet {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			CharacterSet::Digits => write!(f, "digits"),
+			CharacterSet::Letters => write!(f, "letters"),
+			CharacterSet::Alphanumeric => write!(f, "alphanumeric"),
+			CharacterSet::Mixed => write!(f, "mixed"),
+		}
+	}
+}
+
+impl FromBytes for CharacterSet {
+	fn from_bytes(
+		bytes: &[u8]
+	) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+		let value = u8::from_bytes(bytes)?;
+		match value.0 {
+			0 => Ok((CharacterSet::Digits, value.1)),
+			1 => Ok((CharacterSet::Letters, value.1)),
+			2 => Ok((CharacterSet::Alphanumeric, value.1)),
+			3 => Ok((CharacterSet::Mixed, value.1)),
+			_ => Err(casper_types::bytesrepr::Error::OutOfMemory),
+		}
+	}
+}
+
+impl CLTyped for CharacterSet {
+	fn cl_type() -> casper_types::CLType {
+		u8::cl_type()
+	}
+}
+
+impl ToBytes for CharacterSet {
+	fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+		Ok(vec![*self as u8])
+	}
+	fn serialized_length(&self) -> usize {
+		vec![*self as u8].len()
+	}
+}
@@ -18,6 +18,8 @@ pub enum ContractKind {
 	PriceOracle = 4, // single
 	Registry = 5, // single
 	Administration = 6, // single
+	TransferFilter = 7, // single
+	Treasury = 8, // single
 }
 
 impl Display for ContractKind {
@@ -30,6 +32,8 @@ impl Display for ContractKind {
 			Self::PriceOracle => write!(f, "PriceOracle"),
 			Self::Registry => write!(f, "Registry"),
 			Self::Administration => write!(f, "Administration"),
+			Self::TransferFilter => write!(f, "TransferFilter"),
+			Self::Treasury => write!(f, "Treasury"),
 		}
 	}
 }
@@ -49,6 +53,8 @@ impl FromBytes for ContractKind {
 			4 => Ok((ContractKind::PriceOracle, value.1)),
 			5 => Ok((ContractKind::Registry, value.1)),
 			6 => Ok((ContractKind::Administration, value.1)),
+			7 => Ok((ContractKind::TransferFilter, value.1)),
+			8 => Ok((ContractKind::Treasury, value.1)),
 			_ => Err(casper_types::bytesrepr::Error::OutOfMemory),
 		}
 	}
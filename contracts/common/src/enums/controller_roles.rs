@@ -1,6 +1,27 @@
+use core::fmt::Display;
+
+use super::{ contracts_enum::ContractKind, roles::Role };
+
+/// Access declared by every entry point, the caller passes if any of the listed checks passes
+#[derive(Clone, Copy, PartialEq)]
 pub enum ControllerRoles {
-	OnlyMaintainer = 0,
-	OnlyAuthorizedCallers = 1,
-	OnlyAuthorizedContracts = 2,
-	OnlyLocalOperators = 3,
+	OnlyMaintainer,
+	/// Contracts holding the SYSTEM_CONTRACT role and the administration contract
+	OnlyAuthorizedContracts,
+	OnlyLocalOperators,
+	OnlyRole(Role),
+	/// The simple contract of the kind registered in the administration directory
+	OnlyContract(ContractKind),
+}
+
+impl Display for ControllerRoles {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			Self::OnlyMaintainer => write!(f, "maintainer"),
+			Self::OnlyAuthorizedContracts => write!(f, "authorized_contracts"),
+			Self::OnlyLocalOperators => write!(f, "local_operators"),
+			Self::OnlyRole(role) => write!(f, "role:{}", role),
+			Self::OnlyContract(kind) => write!(f, "contract:{}", kind),
+		}
+	}
 }
@@ -1,16 +1,12 @@
-use casper_types::ApiError;
+use crate::error_codes::{ contract_errors, MAIN_ERROR_RANGE };
 
-#[repr(u16)]
-pub enum Error {
-    KeyAlreadyExists = 0,
-    KeyMismatch = 1,
-    InvalidDomainName = 2,
-    InvalidDurationForRegistration = 3,
-    InvalidResolverAddress = 4,
-}
-
-impl From<Error> for ApiError {
-    fn from(error: Error) -> Self {
-        ApiError::User(error as u16)
-    }
+// Legacy endpoints of the main contract, codes follow `MainContractErrors` in the main range
+contract_errors! {
+	pub enum Error in MAIN_ERROR_RANGE {
+		KeyAlreadyExists = 1290 => "Key already exists",
+		KeyMismatch = 1291 => "Key mismatch",
+		InvalidDomainName = 1292 => "Domain name is invalid",
+		InvalidDurationForRegistration = 1293 => "Duration is invalid for the registration",
+		InvalidResolverAddress = 1294 => "Resolver address is invalid",
+	}
 }
@@ -1,7 +1,15 @@
 pub mod caller_verification_type;
+pub mod character_set;
 pub mod contracts_enum;
 pub mod controller_roles;
 pub mod domain_name_actual_state;
 pub mod main_contract;
 pub mod mutation_type;
 pub mod price_oracle_contract;
+pub mod proposal_status;
+pub mod registration_mode;
+pub mod revenue_stream;
+pub mod roles;
+pub mod topology_issue_kind;
+pub mod transfer_filter;
+pub mod transfer_lock;
@@ -0,0 +1,47 @@
+use alloc::{ vec, vec::Vec };
+use casper_types::{ bytesrepr::FromBytes, bytesrepr::ToBytes, CLTyped };
+
+/**
+ * Lifecycle of a timelocked administration proposal:
+ * (Pending -> ) Queued -> Executed, or Cancelled before execution
+ * Pending - waiting for multisig approvals
+ */
+
+#[derive(Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum ProposalStatus {
+	Queued = 0,
+	Cancelled = 1,
+	Executed = 2,
+	Pending = 3,
+}
+
+impl FromBytes for ProposalStatus {
+	fn from_bytes(
+		bytes: &[u8]
+	) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+		let value = u8::from_bytes(bytes)?;
+		match value.0 {
+			0 => Ok((ProposalStatus::Queued, value.1)),
+			1 => Ok((ProposalStatus::Cancelled, value.1)),
+			2 => Ok((ProposalStatus::Executed, value.1)),
+			3 => Ok((ProposalStatus::Pending, value.1)),
+			_ => Err(casper_types::bytesrepr::Error::OutOfMemory),
+		}
+	}
+}
+
+impl CLTyped for ProposalStatus {
+	fn cl_type() -> casper_types::CLType {
+		u8::cl_type()
+	}
+}
+
+impl ToBytes for ProposalStatus {
+	fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+		Ok(vec![*self as u8])
+	}
+	fn serialized_length(&self) -> usize {
+		vec![*self as u8].len()
+	}
+}
@@ -0,0 +1,43 @@
+use alloc::{ vec, vec::Vec };
+use casper_types::{ bytesrepr::FromBytes, bytesrepr::ToBytes, CLTyped };
+
+/**
+ * Who is allowed to register names of the extension:
+ * Open - everyone
+ * Allowlisted - only accounts added to the extension's registration allowlist
+ */
+
+#[derive(Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum RegistrationMode {
+	Open = 0,
+	Allowlisted = 1,
+}
+
+impl FromBytes for RegistrationMode {
+	fn from_bytes(
+		bytes: &[u8]
+	) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+		let value = u8::from_bytes(bytes)?;
+		match value.0 {
+			0 => Ok((RegistrationMode::Open, value.1)),
+			1 => Ok((RegistrationMode::Allowlisted, value.1)),
+			_ => Err(casper_types::bytesrepr::Error::OutOfMemory),
+		}
+	}
+}
+
+impl CLTyped for RegistrationMode {
+	fn cl_type() -> casper_types::CLType {
+		u8::cl_type()
+	}
+}
+
+impl ToBytes for RegistrationMode {
+	fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+		Ok(vec![*self as u8])
+	}
+	fn serialized_length(&self) -> usize {
+		vec![*self as u8].len()
+	}
+}
@@ -0,0 +1,66 @@
+use core::fmt::Display;
+
+use alloc::{ vec, vec::Vec };
+use casper_types::{ bytesrepr::FromBytes, bytesrepr::ToBytes, CLTyped };
+
+/**
+ * Revenue streams of the treasury, every stream has its own beneficiaries and balances:
+ * Registration - fees of new registrations
+ * Renewal - fees of extended registrations
+ * MarketplaceCommission - protocol royalty of the CSPR sales of the nft contract
+ */
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum RevenueStream {
+	Registration = 0,
+	Renewal = 1,
+	MarketplaceCommission = 2,
+}
+
+impl RevenueStream {
+	pub const ALL: [RevenueStream; 3] = [
+		RevenueStream::Registration,
+		RevenueStream::Renewal,
+		RevenueStream::MarketplaceCommission,
+	];
+}
+
+impl Display for RevenueStream {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			Self::Registration => write!(f, "registration"),
+			Self::Renewal => write!(f, "renewal"),
+			Self::MarketplaceCommission => write!(f, "marketplace_commission"),
+		}
+	}
+}
+
+impl FromBytes for RevenueStream {
+	fn from_bytes(
+		bytes: &[u8]
+	) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+		let value = u8::from_bytes(bytes)?;
+		match value.0 {
+			0 => Ok((RevenueStream::Registration, value.1)),
+			1 => Ok((RevenueStream::Renewal, value.1)),
+			2 => Ok((RevenueStream::MarketplaceCommission, value.1)),
+			_ => Err(casper_types::bytesrepr::Error::OutOfMemory),
+		}
+	}
+}
+
+impl CLTyped for RevenueStream {
+	fn cl_type() -> casper_types::CLType {
+		u8::cl_type()
+	}
+}
+
+impl ToBytes for RevenueStream {
+	fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+		Ok(vec![*self as u8])
+	}
+	fn serialized_length(&self) -> usize {
+		vec![*self as u8].len()
+	}
+}
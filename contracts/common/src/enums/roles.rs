@@ -0,0 +1,74 @@
+use core::fmt::Display;
+
+use alloc::{ vec, vec::Vec };
+use casper_types::{ bytesrepr::FromBytes, bytesrepr::ToBytes, CLTyped };
+
+/**
+ * Named roles kept by the administration contract.
+ * Admin - grants and revokes other roles (maintainer always has it implicitly)
+ * Guardian - pauses and unpauses the system during incidents
+ * TreasuryManager - sets the beneficiaries of the treasury revenue streams
+ * SystemContract - contracts of the system calling closed endpoints of each other
+ */
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum Role {
+	Admin = 0,
+	PriceSetter = 1,
+	ExtensionManager = 2,
+	ShardManager = 3,
+	MarketplaceOperator = 4,
+	Guardian = 5,
+	TreasuryManager = 6,
+	SystemContract = 7,
+}
+
+impl Display for Role {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			Self::Admin => write!(f, "ADMIN"),
+			Self::PriceSetter => write!(f, "PRICE_SETTER"),
+			Self::ExtensionManager => write!(f, "EXTENSION_MANAGER"),
+			Self::ShardManager => write!(f, "SHARD_MANAGER"),
+			Self::MarketplaceOperator => write!(f, "MARKETPLACE_OPERATOR"),
+			Self::Guardian => write!(f, "GUARDIAN"),
+			Self::TreasuryManager => write!(f, "TREASURY_MANAGER"),
+			Self::SystemContract => write!(f, "SYSTEM_CONTRACT"),
+		}
+	}
+}
+
+impl FromBytes for Role {
+	fn from_bytes(
+		bytes: &[u8]
+	) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+		let value = u8::from_bytes(bytes)?;
+		match value.0 {
+			0 => Ok((Role::Admin, value.1)),
+			1 => Ok((Role::PriceSetter, value.1)),
+			2 => Ok((Role::ExtensionManager, value.1)),
+			3 => Ok((Role::ShardManager, value.1)),
+			4 => Ok((Role::MarketplaceOperator, value.1)),
+			5 => Ok((Role::Guardian, value.1)),
+			6 => Ok((Role::TreasuryManager, value.1)),
+			7 => Ok((Role::SystemContract, value.1)),
+			_ => Err(casper_types::bytesrepr::Error::OutOfMemory),
+		}
+	}
+}
+
+impl CLTyped for Role {
+	fn cl_type() -> casper_types::CLType {
+		u8::cl_type()
+	}
+}
+
+impl ToBytes for Role {
+	fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+		Ok(vec![*self as u8])
+	}
+	fn serialized_length(&self) -> usize {
+		vec![*self as u8].len()
+	}
+}
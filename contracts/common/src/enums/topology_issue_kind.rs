@@ -0,0 +1,49 @@
+use alloc::{ vec, vec::Vec };
+use casper_types::{ bytesrepr::FromBytes, bytesrepr::ToBytes, CLTyped };
+
+/**
+ * Problems found by the administration contract's topology check:
+ * MissingContract - directory has no entry for the contract kind (and extension)
+ * MissingAuthority - contract calling other contracts does not hold the SYSTEM_CONTRACT role
+ * CountMismatch - stored count of a compound contract differs from the shard's totals
+ * NotWhitelisted - caller is not allowed to write routes in the registry contract
+ */
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(u8)]
+pub enum TopologyIssueKind {
+	MissingContract = 0,
+	MissingAuthority = 1,
+	CountMismatch = 2,
+	NotWhitelisted = 3,
+}
+
+impl FromBytes for TopologyIssueKind {
+	fn from_bytes(
+		bytes: &[u8]
+	) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+		let value = u8::from_bytes(bytes)?;
+		match value.0 {
+			0 => Ok((TopologyIssueKind::MissingContract, value.1)),
+			1 => Ok((TopologyIssueKind::MissingAuthority, value.1)),
+			2 => Ok((TopologyIssueKind::CountMismatch, value.1)),
+			3 => Ok((TopologyIssueKind::NotWhitelisted, value.1)),
+			_ => Err(casper_types::bytesrepr::Error::OutOfMemory),
+		}
+	}
+}
+
+impl CLTyped for TopologyIssueKind {
+	fn cl_type() -> casper_types::CLType {
+		u8::cl_type()
+	}
+}
+
+impl ToBytes for TopologyIssueKind {
+	fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+		Ok(vec![*self as u8])
+	}
+	fn serialized_length(&self) -> usize {
+		vec![*self as u8].len()
+	}
+}
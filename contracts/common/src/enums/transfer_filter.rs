@@ -0,0 +1,66 @@
+use alloc::{ vec, vec::Vec };
+use casper_types::{ bytesrepr::FromBytes, bytesrepr::ToBytes, CLTyped };
+
+/**
+ * `can_transfer` result of the transfer filter, mirrors
+ * `TransferFilterContractResult` of nft-core which reads it as u8
+ */
+
+#[derive(Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum TransferFilterResult {
+	DenyTransfer = 0,
+	ProceedTransfer = 1,
+}
+
+/**
+ * Why the transfer filter denies the transfer of a token:
+ * NameNotFound - the token is not mapped to a name or the name is not stored
+ * GracePeriod - the domain expired and is in its grace period
+ * Expired - the domain expired and its grace period is over
+ * EscrowLock / DisputeLock - the name is locked, see `TransferLock`
+ * TransferPermissionBurned - the subdomain can't be transferred anymore
+ */
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(u8)]
+pub enum TransferDenial {
+	NameNotFound = 0,
+	GracePeriod = 1,
+	Expired = 2,
+	EscrowLock = 3,
+	DisputeLock = 4,
+	TransferPermissionBurned = 5,
+}
+
+impl FromBytes for TransferDenial {
+	fn from_bytes(
+		bytes: &[u8]
+	) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+		let value = u8::from_bytes(bytes)?;
+		match value.0 {
+			0 => Ok((TransferDenial::NameNotFound, value.1)),
+			1 => Ok((TransferDenial::GracePeriod, value.1)),
+			2 => Ok((TransferDenial::Expired, value.1)),
+			3 => Ok((TransferDenial::EscrowLock, value.1)),
+			4 => Ok((TransferDenial::DisputeLock, value.1)),
+			5 => Ok((TransferDenial::TransferPermissionBurned, value.1)),
+			_ => Err(casper_types::bytesrepr::Error::OutOfMemory),
+		}
+	}
+}
+
+impl CLTyped for TransferDenial {
+	fn cl_type() -> casper_types::CLType {
+		u8::cl_type()
+	}
+}
+
+impl ToBytes for TransferDenial {
+	fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+		Ok(vec![*self as u8])
+	}
+	fn serialized_length(&self) -> usize {
+		vec![*self as u8].len()
+	}
+}
@@ -0,0 +1,43 @@
+use alloc::{ vec, vec::Vec };
+use casper_types::{ bytesrepr::FromBytes, bytesrepr::ToBytes, CLTyped };
+
+/**
+ * Lock which holds a name in place while a third party decides on it:
+ * Escrow - the name is kept by a pending escrow deal
+ * Dispute - the ownership of the name is disputed
+ */
+
+#[derive(Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum TransferLock {
+	Escrow = 0,
+	Dispute = 1,
+}
+
+impl FromBytes for TransferLock {
+	fn from_bytes(
+		bytes: &[u8]
+	) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+		let value = u8::from_bytes(bytes)?;
+		match value.0 {
+			0 => Ok((TransferLock::Escrow, value.1)),
+			1 => Ok((TransferLock::Dispute, value.1)),
+			_ => Err(casper_types::bytesrepr::Error::OutOfMemory),
+		}
+	}
+}
+
+impl CLTyped for TransferLock {
+	fn cl_type() -> casper_types::CLType {
+		u8::cl_type()
+	}
+}
+
+impl ToBytes for TransferLock {
+	fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+		Ok(vec![*self as u8])
+	}
+	fn serialized_length(&self) -> usize {
+		vec![*self as u8].len()
+	}
+}
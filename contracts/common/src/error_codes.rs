@@ -0,0 +1,236 @@
+use core::fmt;
+
+use casper_types::ApiError;
+
+use crate::{
+	enums::main_contract::Error as MainContractLegacyErrors,
+	errors::{
+		AdministrationErrors,
+		AuthorityErrors,
+		CommonError,
+		DatabaseErrors,
+		MainContractErrors,
+		NFTErrors,
+		PriceOracleContractErrors,
+		RegistryErrors,
+		TreasuryErrors,
+	},
+};
+
+/**
+ * User error codes are shared by every contract of a deploy, a revert of a nested call
+ * reaches the execution result as is. Every contract reverts only with codes of its own range,
+ * so a code always points to a single contract.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ErrorRange {
+	pub contract: &'static str,
+	pub start: u16,
+	pub end: u16,
+}
+
+impl ErrorRange {
+	pub const fn contains(&self, code: u16) -> bool {
+		code >= self.start && code <= self.end
+	}
+}
+
+/// nft-core keeps the upstream CEP-78 codes
+pub const NFT_CORE_ERROR_RANGE: ErrorRange = ErrorRange {
+	contract: "nft-core",
+	start: 1,
+	end: 999,
+};
+pub const ADMINISTRATION_ERROR_RANGE: ErrorRange = ErrorRange {
+	contract: "administration",
+	start: 1000,
+	end: 1099,
+};
+pub const COMMON_ERROR_RANGE: ErrorRange = ErrorRange {
+	contract: "common",
+	start: 1100,
+	end: 1199,
+};
+pub const MAIN_ERROR_RANGE: ErrorRange = ErrorRange {
+	contract: "main",
+	start: 1200,
+	end: 1299,
+};
+pub const PRICE_ORACLE_ERROR_RANGE: ErrorRange = ErrorRange {
+	contract: "price-oracle",
+	start: 1300,
+	end: 1399,
+};
+pub const DATABASE_ERROR_RANGE: ErrorRange = ErrorRange {
+	contract: "database",
+	start: 1400,
+	end: 1499,
+};
+pub const NFT_ERROR_RANGE: ErrorRange = ErrorRange {
+	contract: "nft",
+	start: 1500,
+	end: 1599,
+};
+pub const REGISTRY_ERROR_RANGE: ErrorRange = ErrorRange {
+	contract: "registry",
+	start: 1600,
+	end: 1699,
+};
+pub const AUTHORITY_ERROR_RANGE: ErrorRange = ErrorRange {
+	contract: "authority",
+	start: 1700,
+	end: 1799,
+};
+pub const TREASURY_ERROR_RANGE: ErrorRange = ErrorRange {
+	contract: "treasury",
+	start: 1800,
+	end: 1899,
+};
+
+pub const ERROR_RANGES: [ErrorRange; 10] = [
+	NFT_CORE_ERROR_RANGE,
+	ADMINISTRATION_ERROR_RANGE,
+	COMMON_ERROR_RANGE,
+	MAIN_ERROR_RANGE,
+	PRICE_ORACLE_ERROR_RANGE,
+	DATABASE_ERROR_RANGE,
+	NFT_ERROR_RANGE,
+	REGISTRY_ERROR_RANGE,
+	AUTHORITY_ERROR_RANGE,
+	TREASURY_ERROR_RANGE,
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ErrorEntry {
+	pub code: u16,
+	pub contract: &'static str,
+	pub variant: &'static str,
+	pub message: &'static str,
+}
+
+/// code -> (contract, variant, message), generated by `contract_errors!` for every error enum
+pub const ERROR_TABLES: [&[ErrorEntry]; 10] = [
+	AdministrationErrors::TABLE,
+	CommonError::TABLE,
+	MainContractErrors::TABLE,
+	MainContractLegacyErrors::TABLE,
+	PriceOracleContractErrors::TABLE,
+	DatabaseErrors::TABLE,
+	NFTErrors::TABLE,
+	RegistryErrors::TABLE,
+	AuthorityErrors::TABLE,
+	TreasuryErrors::TABLE,
+];
+
+pub fn error_table() -> impl Iterator<Item = &'static ErrorEntry> {
+	ERROR_TABLES.iter().flat_map(|table| table.iter())
+}
+
+pub fn get_error_entry(code: u16) -> Option<&'static ErrorEntry> {
+	error_table().find(|entry| entry.code == code)
+}
+
+pub fn get_error_range(code: u16) -> Option<&'static ErrorRange> {
+	ERROR_RANGES.iter().find(|range| range.contains(code))
+}
+
+/// Revert code of an execution result in a readable form
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodedError {
+	/// Code of the workspace error enums
+	Known(&'static ErrorEntry),
+	/// Code of a reserved range which is not in the table (nft-core codes)
+	Reserved {
+		contract: &'static str,
+		code: u16,
+	},
+	/// User code out of every reserved range
+	Unknown(u16),
+	/// Error raised by the host, not by a contract
+	System(ApiError),
+}
+
+impl fmt::Display for DecodedError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Known(entry) =>
+				write!(
+					f,
+					"{}::{} ({}): {}",
+					entry.contract,
+					entry.variant,
+					entry.code,
+					entry.message
+				),
+			Self::Reserved { contract, code } => write!(f, "{} error ({})", contract, code),
+			Self::Unknown(code) => write!(f, "unknown user error ({})", code),
+			Self::System(error) => write!(f, "system error: {:?}", error),
+		}
+	}
+}
+
+/// Host side decoder, `error` is the `ApiError` of a failed execution result
+pub fn decode_api_error(error: ApiError) -> DecodedError {
+	match error {
+		ApiError::User(code) =>
+			match get_error_entry(code) {
+				Some(entry) => DecodedError::Known(entry),
+				None =>
+					match get_error_range(code) {
+						Some(range) =>
+							DecodedError::Reserved { contract: range.contract, code },
+						None => DecodedError::Unknown(code),
+					}
+			}
+		error => DecodedError::System(error),
+	}
+}
+
+/// Same as `decode_api_error` for the numeric form printed by nodes (user errors start from 65536)
+pub fn decode_error_code(value: u32) -> DecodedError {
+	decode_api_error(ApiError::from(value))
+}
+
+/// Declares an error enum of a contract with its table entries.
+/// Codes out of the contract's range fail the build.
+macro_rules! contract_errors {
+	(
+		$(#[$meta:meta])*
+		pub enum $name:ident in $range:path {
+			$($variant:ident = $code:literal => $message:literal,)*
+		}
+	) => {
+		$(#[$meta])*
+		#[repr(u16)]
+		#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+		pub enum $name {
+			$($variant = $code,)*
+		}
+
+		impl $name {
+			pub const RANGE: $crate::error_codes::ErrorRange = $range;
+			pub const TABLE: &'static [$crate::error_codes::ErrorEntry] = &[
+				$(
+					$crate::error_codes::ErrorEntry {
+						code: $code,
+						contract: $range.contract,
+						variant: stringify!($variant),
+						message: $message,
+					},
+				)*
+			];
+		}
+
+		const _: () = {
+			$(assert!($range.contains($code));)*
+		};
+
+		impl From<$name> for casper_types::ApiError {
+			fn from(e: $name) -> Self {
+				casper_types::ApiError::User(e as u16)
+			}
+		}
+	};
+}
+
+pub(crate) use contract_errors;
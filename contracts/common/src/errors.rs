@@ -1,174 +1,223 @@
-use casper_types::ApiError;
-
-#[repr(u16)]
-#[derive(Copy, Clone)]
-pub enum CommonError {
-	UnexpectedKeyVariant = 1,
-	MissingStorageUref = 2,
-	InvalidStorageUref = 3,
-	NoAuthority = 4,
-	ItemNotFound = 5,
-	NoContractHashWasFoundInAuthoritiesContract = 6,
-	InvalidMaintainer = 7,
-	InvalidCaller = 8,
-	InvalidKey = 9,
-	UnknowError = 10,
-	MissingMaintainer = 11,
-	MissingRegistryHash = 12,
-	MissingContractHash = 13,
-	FailedToConvertToAccountHash = 14,
-	NoAdministrationContractHashStored = 15,
-	MissingAdministrationContractHash = 16,
-}
-
-impl From<CommonError> for ApiError {
-	fn from(e: CommonError) -> Self {
-		ApiError::User(e as u16)
+use crate::enums::transfer_filter::TransferDenial;
+use crate::error_codes::{
+	contract_errors,
+	ADMINISTRATION_ERROR_RANGE,
+	AUTHORITY_ERROR_RANGE,
+	COMMON_ERROR_RANGE,
+	DATABASE_ERROR_RANGE,
+	MAIN_ERROR_RANGE,
+	NFT_ERROR_RANGE,
+	PRICE_ORACLE_ERROR_RANGE,
+	REGISTRY_ERROR_RANGE,
+	TREASURY_ERROR_RANGE,
+};
+
+// Every enum owns a reserved range of codes, see `error_codes`
+
+contract_errors! {
+	pub enum CommonError in COMMON_ERROR_RANGE {
+		UnexpectedKeyVariant = 1101 => "Key has an unexpected variant",
+		MissingStorageUref = 1102 => "Storage uref is missing",
+		InvalidStorageUref = 1103 => "Storage uref is invalid",
+		NoAuthority = 1104 => "Authority list is not set",
+		ItemNotFound = 1105 => "Item is not found",
+		NoContractHashWasFoundInAuthoritiesContract = 1106 => "Contract hash is not found in the authorities contract",
+		InvalidMaintainer = 1107 => "Maintainer is invalid",
+		InvalidCaller = 1108 => "Caller has no access",
+		InvalidKey = 1109 => "Key is invalid",
+		UnknowError = 1110 => "Unknown error",
+		MissingMaintainer = 1111 => "Maintainer is not set",
+		MissingRegistryHash = 1112 => "Registry contract hash is not set",
+		MissingContractHash = 1113 => "Contract hash is not set",
+		FailedToConvertToAccountHash = 1114 => "Key cannot be converted to an account hash",
+		NoAdministrationContractHashStored = 1115 => "Administration contract hash is not stored",
+		MissingAdministrationContractHash = 1116 => "Administration contract hash is not set",
+		ContractIsPaused = 1117 => "Contract is paused",
+		MissingContractPackage = 1118 => "Contract package is not found",
+		SchemaDowngrade = 1119 => "Stored schema version is newer than the contract",
+		PaymentTokenIsNotAccepted = 1120 => "Payment token is not accepted",
+		PaymentAmountOverflow = 1121 => "Payment amount does not fit into a CEP-18 amount",
 	}
 }
 
-#[repr(u16)]
-#[derive(Clone, Copy)]
-pub enum MainContractErrors {
-	InvalidName = 21,
-	DomainAlreadyExists = 22,
-	InvalidDuration = 23,
-	PriceDiscrepancy = 24,
-	NoDictionaryDomainMetadata = 25,
-	NoDictionaryDomainList = 26,
-	DomainNotExists = 27,
-	InvalidSubdomain = 28,
-	SubdomainParseError = 29,
-	InvalidOwner = 30,
-	SubdomainMaxCountExceeded = 31,
-	SubdomainNotExists = 32,
-	PriceOracleContractHashNotFound = 33,
-	DomainNameIsBusy = 34,
-	DomainNameIsInGracePeriod = 35,
-	UserHasNoAccessToRegister = 36,
-	OnlyMaintainerHasAccess = 37,
-	CannotAddMaintainer = 38,
-	AuthorityHasAlreadyTaken = 39,
-	CannotRemoveMaintainer = 40,
-	UserHasNoAccess = 41,
-	InvalidCreator = 42,
-	InvalidExtension = 43,
-	AllowedExtensionsNotConfigured = 44,
-	MaintainerNotConfigured = 45,
-	DatabaseFulfilledOrNotConfigured = 46,
-	MaintainerPurseNotConfigured = 47,
-	InsufficientCustomerBalance = 48,
-	AuthoritiesContractHashNotConfigured = 49,
-	RegistryContractHashNotConfigured = 50,
-}
-
-impl From<MainContractErrors> for ApiError {
-	fn from(e: MainContractErrors) -> Self {
-		ApiError::User(e as u16)
+contract_errors! {
+	pub enum MainContractErrors in MAIN_ERROR_RANGE {
+		InvalidName = 1201 => "Domain name is invalid",
+		DomainAlreadyExists = 1202 => "Domain name already exists",
+		InvalidDuration = 1203 => "Duration is invalid",
+		PriceDiscrepancy = 1204 => "Paid amount differs from the price",
+		NoDictionaryDomainMetadata = 1205 => "Domain metadata dictionary is missing",
+		NoDictionaryDomainList = 1206 => "Domain list dictionary is missing",
+		DomainNotExists = 1207 => "Domain name does not exist",
+		InvalidSubdomain = 1208 => "Subdomain name is invalid",
+		SubdomainParseError = 1209 => "Subdomain name cannot be parsed",
+		InvalidOwner = 1210 => "Caller is not the owner",
+		SubdomainMaxCountExceeded = 1211 => "Subdomain limit is exceeded",
+		SubdomainNotExists = 1212 => "Subdomain name does not exist",
+		PriceOracleContractHashNotFound = 1213 => "Price oracle contract hash is not found",
+		DomainNameIsBusy = 1214 => "Domain name is registered",
+		DomainNameIsInGracePeriod = 1215 => "Domain name is in the grace period",
+		UserHasNoAccessToRegister = 1216 => "Account is not allowed to register names of the extension",
+		OnlyMaintainerHasAccess = 1217 => "Only maintainer has access",
+		CannotAddMaintainer = 1218 => "Maintainer cannot be added as an authority",
+		AuthorityHasAlreadyTaken = 1219 => "Authority is already added",
+		CannotRemoveMaintainer = 1220 => "Maintainer cannot be removed",
+		UserHasNoAccess = 1221 => "Account has no access",
+		InvalidCreator = 1222 => "Creator is invalid",
+		InvalidExtension = 1223 => "Extension is not allowed",
+		AllowedExtensionsNotConfigured = 1224 => "Allowed extensions are not configured",
+		MaintainerNotConfigured = 1225 => "Maintainer is not configured",
+		DatabaseFulfilledOrNotConfigured = 1226 => "Database contract is filled or not configured",
+		MaintainerPurseNotConfigured = 1227 => "Maintainer purse is not configured",
+		InsufficientCustomerBalance = 1228 => "Customer balance is insufficient",
+		AuthoritiesContractHashNotConfigured = 1229 => "Authorities contract hash is not configured",
+		RegistryContractHashNotConfigured = 1230 => "Registry contract hash is not configured",
+		PaymentIsNotProvided = 1231 => "Neither a purse nor a payment token is provided",
+		DomainNameIsExpired = 1232 => "Domain name is expired, it can only be registered again",
+		DomainNameIsNotExpired = 1233 => "Domain name is not expired",
 	}
 }
 
-#[repr(u16)]
-#[derive(Clone, Copy)]
-pub enum PriceOracleContractErrors {
-	PriceTypeMismatch = 40,
-	PriceMidLengthAndMidCharsCountMismatch = 41,
-	PriceForCharsCountNotFound = 42,
-	PriceTypeIsNotFound = 43,
-	PriceIsNotSet = 44,
-	PriceMoreIsNotSet = 45,
-	PriceMidIsNotSet = 46,
-	PriceCannotAddMaintainer = 47,
-	PriceCannotRemoveMaintainer = 48,
-	PriceAuthorityHasAlreadyTaken = 49,
-	PriceOnlyMaintainerHasAccess = 50,
-	PriceUserHasNoAccess = 51,
-	PriceSimpleOperationsIsNotSet = 52,
-	PricePriceIsNotSetForExtension = 53,
-}
-
-impl From<PriceOracleContractErrors> for ApiError {
-	fn from(e: PriceOracleContractErrors) -> Self {
-		ApiError::User(e as u16)
+contract_errors! {
+	pub enum PriceOracleContractErrors in PRICE_ORACLE_ERROR_RANGE {
+		PriceTypeMismatch = 1301 => "Price type mismatch",
+		PriceMidLengthAndMidCharsCountMismatch = 1302 => "Mid prices and mid chars counts differ in length",
+		PriceForCharsCountNotFound = 1303 => "Price for the chars count is not found",
+		PriceTypeIsNotFound = 1304 => "Price type is not found",
+		PriceIsNotSet = 1305 => "Price is not set",
+		PriceMoreIsNotSet = 1306 => "Price for longer names is not set",
+		PriceMidIsNotSet = 1307 => "Mid prices are not set",
+		PriceCannotAddMaintainer = 1308 => "Maintainer cannot be added as an authority",
+		PriceCannotRemoveMaintainer = 1309 => "Maintainer cannot be removed",
+		PriceAuthorityHasAlreadyTaken = 1310 => "Authority is already added",
+		PriceOnlyMaintainerHasAccess = 1311 => "Only maintainer has access",
+		PriceUserHasNoAccess = 1312 => "Account has no access",
+		PriceSimpleOperationsIsNotSet = 1313 => "Price of simple operations is not set",
+		PricePriceIsNotSetForExtension = 1314 => "Price is not set for the extension",
+		PaymentTokenRateIsZero = 1315 => "Rate of the payment token is zero",
+		PaymentTokenAmountOverflow = 1316 => "Price converted by the rate of the payment token overflows",
 	}
 }
 
-#[repr(u16)]
-#[derive(Clone, Copy)]
-pub enum DatabaseErrors {
-	DatabaseSubdomainMaxCountExceeded = 60,
-	DatabaseSubdomainAlreadyExists = 61,
-	DatabaseSubdomainDoesntExist = 62,
-	DatabaseDomainDoesntExist = 63,
-	DatabaseUnexpected = 64,
-}
-
-impl From<DatabaseErrors> for ApiError {
-	fn from(e: DatabaseErrors) -> Self {
-		ApiError::User(e as u16)
+contract_errors! {
+	pub enum DatabaseErrors in DATABASE_ERROR_RANGE {
+		DatabaseSubdomainMaxCountExceeded = 1401 => "Subdomain limit is exceeded",
+		DatabaseSubdomainAlreadyExists = 1402 => "Subdomain name already exists",
+		DatabaseSubdomainDoesntExist = 1403 => "Subdomain name does not exist",
+		DatabaseDomainDoesntExist = 1404 => "Domain name does not exist",
+		DatabaseUnexpected = 1405 => "Unexpected database error",
+		DatabaseMigrationBatchTooLarge = 1406 => "Migration batch is too large",
 	}
 }
 
-#[repr(u16)]
-#[derive(Clone, Copy)]
-pub enum AuthorityErrors {
-	AuthorityInvalidCaller = 100,
-	AuthorityMaintainerIsNotSet = 101,
-}
-
-impl From<AuthorityErrors> for ApiError {
-	fn from(e: AuthorityErrors) -> Self {
-		ApiError::User(e as u16)
+contract_errors! {
+	pub enum AuthorityErrors in AUTHORITY_ERROR_RANGE {
+		AuthorityInvalidCaller = 1701 => "Caller has no access",
+		AuthorityMaintainerIsNotSet = 1702 => "Maintainer is not set",
 	}
 }
 
-#[repr(u16)]
-#[derive(Clone, Copy)]
-pub enum NFTErrors {
-	NFTCoreHashIsNotSet = 80,
-	NFTIsNotListed = 81,
-	NFTContractHashIsNotSet = 82,
-}
-
-impl From<NFTErrors> for ApiError {
-	fn from(e: NFTErrors) -> Self {
-		ApiError::User(e as u16)
+contract_errors! {
+	pub enum NFTErrors in NFT_ERROR_RANGE {
+		NFTCoreHashIsNotSet = 1501 => "NFT core contract hash is not set",
+		NFTIsNotListed = 1502 => "Token is not listed",
+		NFTContractHashIsNotSet = 1503 => "NFT contract hash is not set",
+		TransferDeniedNameNotFound = 1504 => "Transfer is denied, the token has no name",
+		TransferDeniedGracePeriod = 1505 => "Transfer is denied, the name is in its grace period",
+		TransferDeniedExpired = 1506 => "Transfer is denied, the name is expired",
+		TransferDeniedEscrowLock = 1507 => "Transfer is denied, the name is locked by an escrow",
+		TransferDeniedDisputeLock = 1508 => "Transfer is denied, the name is disputed",
+		TransferDeniedPermissionBurned = 1509 => "Transfer is denied, the subdomain transfer permission is burned",
+		TokenMetadataNotFound = 1510 => "Token metadata is not found",
+		RoyaltyShareTooHigh = 1511 => "Royalty share is above the allowed maximum",
+		CallerIsNotTokenCreator = 1512 => "Caller is not the creator of the token",
+		PriceDiscrepancy = 1513 => "Amount does not match the listing price",
+		InsufficientBuyerBalance = 1514 => "Buyer balance is insufficient",
+		SellerIsNotAccount = 1515 => "Seller is not an account, sale proceeds can't be paid",
+		UnknownNFTCoreContract = 1516 => "NFT core contract is not in the directory of the extension",
+		CallerIsNotOwnerOrApproved = 1517 => "Caller is neither the owner of the token nor an approved operator",
+		SourceIsNotTokenOwner = 1518 => "Source key is not the owner of the token",
+		TokenIsListed = 1519 => "Token is listed at a fixed price",
+		TokenIsInAuction = 1520 => "Token is in an auction",
+		AuctionNotFound = 1521 => "Auction is not found",
+		AuctionIsOver = 1522 => "Auction is over",
+		AuctionIsNotOver = 1523 => "Auction is not over yet",
+		BidTooLow = 1524 => "Bid is below the reserve price or the minimum increment",
+		AuctionHasBids = 1525 => "Auction has bids and can't be cancelled",
+		CallerIsNotSeller = 1526 => "Caller is not the seller of the auction",
+		InvalidAuctionDuration = 1527 => "Auction duration is out of the allowed range or past the expiration of the name",
+		PaymentIsNotProvided = 1528 => "Neither a purse nor a payment token is provided",
+		SellerCannotBid = 1529 => "Seller can't bid on its own auction",
+		RoyaltyAmountOverflow = 1530 => "Royalty amount of the sale price overflows",
 	}
 }
 
-#[repr(u16)]
-#[derive(Clone, Copy)]
-pub enum RegistryErrors {
-	OperatorAlreadyExists = 200,
-	OperatorDoesntExist = 201,
-	MaintainerIsNotSet = 202,
-	RegistryObjectNotFound = 203,
-	InvalidContractHash = 204,
-	ContractHashCountExceeded = 205,
-	ContractHashNotFouond = 206,
-	InvalidCaller = 207,
+contract_errors! {
+	pub enum RegistryErrors in REGISTRY_ERROR_RANGE {
+		OperatorAlreadyExists = 1601 => "Operator already exists",
+		OperatorDoesntExist = 1602 => "Operator does not exist",
+		MaintainerIsNotSet = 1603 => "Maintainer is not set",
+		RegistryObjectNotFound = 1604 => "Route is not found",
+		InvalidContractHash = 1605 => "Contract hash is invalid",
+		ContractHashCountExceeded = 1606 => "Contract hash count is exceeded",
+		ContractHashNotFouond = 1607 => "Contract hash is not found",
+		InvalidCaller = 1608 => "Caller is not allowed to write routes",
+		TooManyRoutesRequested = 1609 => "Too many routes are requested",
+	}
 }
 
-impl From<RegistryErrors> for ApiError {
-	fn from(e: RegistryErrors) -> Self {
-		ApiError::User(e as u16)
+contract_errors! {
+	// Administration errors
+	pub enum AdministrationErrors in ADMINISTRATION_ERROR_RANGE {
+		ExtensionNotExist = 1000 => "Extension does not exist",
+		ExtensionAlreadyExist = 1001 => "Extension already exists",
+		ContractNotFound = 1002 => "Contract is not found in the directory",
+		ProvideExtensionArgument = 1003 => "Extension argument is required",
+		ContractIsFilled = 1004 => "Contract is filled",
+		InvalidCaller = 1005 => "Caller has no access",
+		TimelockIsActive = 1006 => "Change has to be queued as a proposal",
+		ProposalNotFound = 1007 => "Proposal is not found",
+		ProposalIsNotQueued = 1008 => "Proposal is not queued",
+		ProposalIsNotReady = 1009 => "Proposal delay has not passed",
+		ProposalIsStale = 1010 => "Proposal is stale",
+		MultisigIsActive = 1011 => "Change has to be approved by signers",
+		NotASigner = 1012 => "Account is not a signer",
+		ProposalAlreadyApproved = 1013 => "Proposal is already approved by the signer",
+		ApprovalNotFound = 1014 => "Approval is not found",
+		InvalidThreshold = 1015 => "Threshold is invalid",
+		InvalidPauseScope = 1016 => "Pause scope is invalid",
+		InvalidExtensionConfig = 1017 => "Extension config is invalid",
+		ContractIsNotFilled = 1018 => "Active contracts still have free space",
+		InvalidMigrationTarget = 1019 => "Migration target is invalid",
+		MigrationBatchTooLarge = 1020 => "Migration batch is too large",
+		UpgradedContractNotFound = 1021 => "Upgraded contract is not found in the directory",
 	}
 }
 
-// Administration errors
-#[repr(u16)]
-#[derive(Clone, Copy)]
-pub enum AdministrationErrors {
-	ExtensionNotExist = 1000,
-	ExtensionAlreadyExist = 1001,
-	ContractNotFound = 1002,
-	ProvideExtensionArgument = 1003,
-	ContractIsFilled = 1004,
-	InvalidCaller = 1005,
+contract_errors! {
+	pub enum TreasuryErrors in TREASURY_ERROR_RANGE {
+		NoBeneficiaries = 1801 => "Beneficiary list is empty",
+		InvalidShares = 1802 => "Beneficiary shares do not add up to 10000 basis points",
+		ZeroShare = 1803 => "Beneficiary share is zero",
+		DuplicateBeneficiary = 1804 => "Beneficiary is listed twice",
+		ZeroDeposit = 1805 => "Deposit amount is zero",
+		NothingToWithdraw = 1806 => "Beneficiary balance is zero",
+		MissingTreasuryPurse = 1807 => "Treasury purse is not set",
+		DepositPurseBalanceMismatch = 1808 => "Deposit purse holds more or less than the amount",
+	}
 }
 
-impl From<AdministrationErrors> for ApiError {
-	fn from(e: AdministrationErrors) -> Self {
-		ApiError::User(e as u16)
+/// Denial of the transfer filter surfaced by the NFT contract
+impl From<TransferDenial> for NFTErrors {
+	fn from(denial: TransferDenial) -> Self {
+		match denial {
+			TransferDenial::NameNotFound => NFTErrors::TransferDeniedNameNotFound,
+			TransferDenial::GracePeriod => NFTErrors::TransferDeniedGracePeriod,
+			TransferDenial::Expired => NFTErrors::TransferDeniedExpired,
+			TransferDenial::EscrowLock => NFTErrors::TransferDeniedEscrowLock,
+			TransferDenial::DisputeLock => NFTErrors::TransferDeniedDisputeLock,
+			TransferDenial::TransferPermissionBurned =>
+				NFTErrors::TransferDeniedPermissionBurned,
+		}
 	}
 }
@@ -0,0 +1,102 @@
+use alloc::{ string::String, vec::Vec };
+use casper_event_standard::Event;
+use casper_types::Key;
+
+/// Timelock events
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ProposalQueued {
+	pub proposal_id: u64,
+	pub action: String,
+	pub proposer: Key,
+	pub eta: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ProposalCancelled {
+	pub proposal_id: u64,
+	pub canceller: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ProposalExecuted {
+	pub proposal_id: u64,
+	pub action: String,
+	pub executor: Key,
+}
+
+/// Multisig events
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ProposalApproved {
+	pub proposal_id: u64,
+	pub signer: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ApprovalRevoked {
+	pub proposal_id: u64,
+	pub signer: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct CancellationApproved {
+	pub proposal_id: u64,
+	pub signer: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct SignersChanged {
+	pub signers: Vec<Key>,
+	pub threshold: u8,
+}
+
+/// Role events
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct RoleGranted {
+	pub role: String,
+	pub account: Key,
+	pub sender: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct RoleRevoked {
+	pub role: String,
+	pub account: Key,
+	pub sender: Key,
+}
+
+/// Pause events, scope is "global", contract kind or "ext:<extension>"
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Paused {
+	pub scope: String,
+	pub sender: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Unpaused {
+	pub scope: String,
+	pub sender: Key,
+}
+
+/// Standby shard was promoted into the compound contract list
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ContractProvisioned {
+	pub contract_kind: String,
+	pub extension: String,
+	pub key: Key,
+}
+
+/// Batch of domain names was moved from one database shard to another
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct DomainsMigrated {
+	pub extension: String,
+	pub from: Key,
+	pub to: Key,
+	pub domain_names: Vec<String>,
+}
+
+/// Directory entries and authority lists were moved to the new contract version
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ContractUpgraded {
+	pub previous_key: Key,
+	pub key: Key,
+}
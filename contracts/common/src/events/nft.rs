@@ -0,0 +1,29 @@
+use alloc::string::String;
+use casper_event_standard::Event;
+use casper_types::{ Key, U512 };
+
+/// Royalty share of a secondary sale was paid to the receiver
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct RoyaltyPaid {
+	pub token_id: String,
+	pub receiver: Key,
+	pub amount: U512,
+	pub sale_price: U512,
+}
+
+/// Bid became the highest bid of the auction, the end time is after the anti-sniping extension
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct BidPlaced {
+	pub token_id: String,
+	pub bidder: Key,
+	pub amount: U512,
+	pub end_time: u64,
+}
+
+/// Auction was closed, auctions without a winner are settled with zero price
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AuctionSettled {
+	pub token_id: String,
+	pub winner: Option<Key>,
+	pub price: U512,
+}
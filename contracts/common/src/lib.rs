@@ -7,9 +7,12 @@ pub mod constants;
 pub mod controllers;
 pub mod db;
 pub mod enums;
+pub mod error_codes;
 pub mod errors;
+pub mod events;
 pub mod service;
 pub mod store_ext;
 pub mod models;
+pub mod schema;
 pub mod types;
 pub mod utils;
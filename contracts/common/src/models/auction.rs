@@ -0,0 +1,54 @@
+use casper_types::{ account::AccountHash, U512 };
+use cspr_names_derive::{ CLTyped, FromBytes, ToBytes };
+
+use crate::constants::common_keys::AUCTION_EXTENSION_WINDOW;
+
+/// English auction of a token, the highest bid is escrowed in the auction purse of the contract
+/// Times are block times in millis
+#[derive(Clone, ToBytes, FromBytes, CLTyped)]
+pub struct Auction {
+	pub seller: AccountHash,
+	pub reserve_price: U512,
+	pub min_bid_increment: U512,
+	pub end_time: u64,
+	pub highest_bidder: Option<AccountHash>,
+	pub highest_bid: U512,
+}
+
+impl Auction {
+	pub fn new(
+		seller: AccountHash,
+		reserve_price: U512,
+		min_bid_increment: U512,
+		end_time: u64
+	) -> Self {
+		Self {
+			seller,
+			reserve_price,
+			min_bid_increment,
+			end_time,
+			highest_bidder: None,
+			highest_bid: U512::zero(),
+		}
+	}
+
+	/// The first bid has to reach the reserve price, the next ones outbid by the increment
+	pub fn min_next_bid(&self) -> U512 {
+		match self.highest_bidder {
+			Some(_) => self.highest_bid + self.min_bid_increment,
+			None => self.reserve_price,
+		}
+	}
+
+	pub fn is_ended(&self, now: u64) -> bool {
+		now >= self.end_time
+	}
+
+	/// Bids in the last window push the end time, so the auction can't be sniped
+	pub fn extend_end_time(&mut self, now: u64) {
+		let end_time = now.saturating_add(AUCTION_EXTENSION_WINDOW);
+		if end_time > self.end_time {
+			self.end_time = end_time;
+		}
+	}
+}
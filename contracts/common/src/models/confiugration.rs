@@ -1,8 +1,21 @@
 use alloc::{ vec::Vec, string::String };
-use casper_types::{ bytesrepr::{ allocate_buffer, Error, FromBytes, ToBytes }, CLType, CLTyped };
+use casper_types::account::AccountHash;
+use cspr_names_derive::{ CLTyped, FromBytes, ToBytes };
 use serde::{ Deserialize, Serialize };
 
-#[derive(Serialize, Deserialize, Clone)]
+use crate::{
+	constants::common_keys::{
+		DEFAULT_GRACE_PERIOD,
+		DEFAULT_MAX_CHARS_COUNT,
+		DEFAULT_MAX_SUBDOMAIN_COUNT,
+		DEFAULT_MAX_SUBSCRIPTION_YEARS,
+		DEFAULT_MIN_CHARS_COUNT,
+	},
+	enums::registration_mode::RegistrationMode,
+	errors::MainContractErrors,
+};
+
+#[derive(Serialize, Deserialize, Clone, ToBytes, FromBytes, CLTyped)]
 pub struct Configuration {
 	pub min_allowed_chars_count: u8,
 	pub max_enitity_count_per_contract: u16,
@@ -11,48 +24,49 @@ pub struct Configuration {
 	pub max_subscription_years: u8,
 }
 
-impl ToBytes for Configuration {
-	fn to_bytes(&self) -> Result<Vec<u8>, Error> {
-		let mut result = allocate_buffer(self)?;
-		result.extend(self.min_allowed_chars_count.to_bytes()?);
-		result.extend(self.max_enitity_count_per_contract.to_bytes()?);
-		result.extend(self.allowed_extensions.to_bytes()?);
-		result.extend(self.domains_per_page.to_bytes()?);
-		result.extend(self.max_subscription_years.to_bytes()?);
-
-		Ok(result)
-	}
-
-	fn serialized_length(&self) -> usize {
-		self.min_allowed_chars_count.serialized_length() +
-			self.max_enitity_count_per_contract.serialized_length() +
-			self.allowed_extensions.serialized_length() +
-			self.domains_per_page.serialized_length() +
-			self.max_subscription_years.serialized_length()
-	}
+/**
+ * Per-extension configuration kept by the administration contract.
+ * fee_recipient - not used for payments, every fee is deposited to the treasury and paid out
+ *   to the beneficiaries of its revenue streams
+ */
+#[derive(Clone, ToBytes, FromBytes, CLTyped)]
+pub struct ExtensionConfig {
+	pub min_chars_count: u8,
+	pub max_chars_count: u8,
+	pub max_subscription_years: u8,
+	pub grace_period: u64,
+	pub max_subdomain_count: u8,
+	pub registration_mode: RegistrationMode,
+	pub fee_recipient: Option<AccountHash>,
 }
 
-impl FromBytes for Configuration {
-	fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
-		let (min_allowed_chars_count, remainder) = u8::from_bytes(bytes)?;
-		let (max_enitity_count_per_contract, remainder) = u16::from_bytes(remainder)?;
-		let (allowed_extensions, remainder) = Vec::<String>::from_bytes(remainder)?;
-		let (max_subscription_years, remainder) = u8::from_bytes(remainder)?;
-		let (domains_per_page, remainder) = u8::from_bytes(remainder)?;
-
-		let result = Configuration {
-			min_allowed_chars_count,
-			max_enitity_count_per_contract,
-			allowed_extensions,
-			domains_per_page,
-			max_subscription_years,
-		};
-		Ok((result, remainder))
+impl Default for ExtensionConfig {
+	fn default() -> Self {
+		Self {
+			min_chars_count: DEFAULT_MIN_CHARS_COUNT,
+			max_chars_count: DEFAULT_MAX_CHARS_COUNT,
+			max_subscription_years: DEFAULT_MAX_SUBSCRIPTION_YEARS,
+			grace_period: DEFAULT_GRACE_PERIOD,
+			max_subdomain_count: DEFAULT_MAX_SUBDOMAIN_COUNT,
+			registration_mode: RegistrationMode::Open,
+			fee_recipient: None,
+		}
 	}
 }
 
-impl CLTyped for Configuration {
-	fn cl_type() -> CLType {
-		CLType::Any
+impl ExtensionConfig {
+	/// Names shorter than the min chars count are reserved for the maintainer
+	pub fn validate_name_length(
+		&self,
+		name_len: usize,
+		is_maintainer: bool
+	) -> Result<(), MainContractErrors> {
+		if name_len > self.max_chars_count.into() {
+			return Err(MainContractErrors::InvalidName);
+		}
+		if name_len < self.min_chars_count.into() && !is_maintainer {
+			return Err(MainContractErrors::InvalidCreator);
+		}
+		Ok(())
 	}
 }
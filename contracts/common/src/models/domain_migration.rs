@@ -0,0 +1,11 @@
+use alloc::vec::Vec;
+use cspr_names_derive::{CLTyped, FromBytes, ToBytes};
+
+use super::{DomainName, SubdomainName};
+
+/// Domain name with its subdomains, moved between database shards as one unit
+#[derive(Clone, ToBytes, FromBytes, CLTyped)]
+pub struct DomainMigrationEntry {
+    pub domain: DomainName,
+    pub subdomains: Vec<SubdomainName>,
+}
@@ -2,13 +2,21 @@ pub use domain_name::DomainName;
 pub use local_metadata::LocalMetadata;
 pub use subdomain_name::SubdomainName;
 
+pub mod auction;
 pub mod confiugration;
 pub mod contract_hash_item;
+pub mod domain_migration;
 pub mod domain_name;
 pub mod local_metadata;
 pub mod nft;
+pub mod payment_token;
 pub mod price;
+pub mod proposal;
 pub mod registry_contract_hash_list;
 pub mod registry_contract_hash_pair;
 pub mod registry_pointer;
+pub mod royalty;
 pub mod subdomain_name;
+pub mod topology;
+pub mod transfer_restrictions;
+pub mod treasury;
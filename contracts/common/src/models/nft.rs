@@ -1,19 +1,83 @@
-use alloc::string::{String, ToString};
-use serde::{Deserialize, Serialize};
+use alloc::{ collections::BTreeMap, format, string::{ String, ToString } };
+use cspr_names_derive::{ CLTyped, FromBytes, ToBytes };
 
-#[derive(Serialize, Deserialize, Clone)]
+use crate::enums::character_set::CharacterSet;
+
+/// Metadata of a domain token, nft-core validates it against `get_custom_metadata_schema`.
+/// Dates are block times in millis
+#[derive(Clone, ToBytes, FromBytes, CLTyped)]
 pub struct Metadata {
-    name: String,
-    symbol: String,
-    token_id: String,
+	pub name: String,
+	pub extension: String,
+	pub label_length: u32,
+	pub registration_date: u64,
+	pub expiration_date: u64,
+	pub character_set: CharacterSet,
 }
 
 impl Metadata {
-    pub fn new(name: String, token_id: String) -> Self {
-        Self {
-            name,
-            token_id,
-            symbol: "casper-names-token".to_string(),
-        }
-    }
+	pub fn new(name: &str, registration_date: u64, expiration_date: u64) -> Self {
+		let label = name.split('.').next().unwrap_or_default();
+		let extension = name.rsplit('.').next().unwrap_or_default();
+
+		Self {
+			name: name.to_string(),
+			extension: extension.to_string(),
+			label_length: label.chars().count() as u32,
+			registration_date,
+			expiration_date,
+			character_set: CharacterSet::of(label),
+		}
+	}
+
+	/// Custom validated metadata of nft-core is a flat map of strings
+	pub fn to_json(&self) -> String {
+		let mut attributes: BTreeMap<&str, String> = BTreeMap::new();
+		attributes.insert("name", self.name.clone());
+		attributes.insert("extension", self.extension.clone());
+		attributes.insert("label_length", self.label_length.to_string());
+		attributes.insert(
+			"registration_date",
+			self.registration_date.to_string()
+		);
+		attributes.insert("expiration_date", self.expiration_date.to_string());
+		attributes.insert("character_set", self.character_set.to_string());
+		attributes.insert("image", self.image());
+
+		serde_json_wasm
+			::to_string(&attributes)
+			.expect("Error while converting metadata to json")
+	}
+
+	/// SVG data URI built only from the name, so every node renders the same image
+	pub fn image(&self) -> String {
+		let font_size = match self.name.chars().count() {
+			0..=12 => 24,
+			len => core::cmp::max(10, 288 / len),
+		};
+		let svg = format!(
+			"<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"270\" height=\"270\" viewBox=\"0 0 270 270\">\
+			<rect width=\"270\" height=\"270\" fill=\"#FF2D2E\"/>\
+			<text x=\"135\" y=\"145\" text-anchor=\"middle\" font-family=\"sans-serif\" font-size=\"{}\" fill=\"#FFFFFF\">{}</text>\
+			</svg>",
+			font_size,
+			escape_xml(&self.name)
+		);
+		format!("data:image/svg+xml;base64,{}", base64::encode(svg))
+	}
+}
+
+fn escape_xml(value: &str) -> String {
+	let mut result = String::new();
+	for c in value.chars() {
+		match c {
+			'&' => result.push_str("&amp;"),
+			'<' => result.push_str("&lt;"),
+			'>' => result.push_str("&gt;"),
+			'"' => result.push_str("&quot;"),
+			'\'' => result.push_str("&apos;"),
+			_ => result.push(c),
+		}
+	}
+	result
 }
@@ -0,0 +1,35 @@
+use casper_types::{ Key, U256, U512 };
+use cspr_names_derive::{ CLTyped, FromBytes, ToBytes };
+
+use crate::{
+	constants::common_keys::MOTES_PER_CSPR,
+	errors::PriceOracleContractErrors,
+};
+
+/// CEP-18 token accepted as a payment, the rate is in token units per CSPR
+/// Proceeds of the token go to its treasury
+#[derive(Clone, ToBytes, FromBytes, CLTyped)]
+pub struct PaymentToken {
+	pub rate: U512,
+	pub treasury: Key,
+}
+
+impl PaymentToken {
+	/// Rounds down, prices are set in motes
+	/// Prices too large for the rate of the token fail instead of wrapping around
+	pub fn convert(&self, motes: U512) -> Result<U512, PriceOracleContractErrors> {
+		motes
+			.checked_mul(self.rate)
+			.map(|amount| amount / U512::from(MOTES_PER_CSPR))
+			.ok_or(PriceOracleContractErrors::PaymentTokenAmountOverflow)
+	}
+}
+
+/// CEP-18 amounts are U256, prices are U512
+pub fn to_cep18_amount(amount: U512) -> Option<U256> {
+	let U512(words) = amount;
+	if words[4..].iter().any(|word| *word != 0) {
+		return None;
+	}
+	Some(U256([words[0], words[1], words[2], words[3]]))
+}
@@ -0,0 +1,429 @@
+use alloc::{ boxed::Box, string::String, vec::Vec };
+use core::fmt::Display;
+use casper_types::{
+	bytesrepr::{ allocate_buffer, Bytes, Error, FromBytes, ToBytes, U32_SERIALIZED_LENGTH },
+	CLType,
+	CLTyped,
+	ContractHash,
+	Key,
+	U512,
+};
+use cspr_names_derive::{ CLTyped, FromBytes, ToBytes };
+
+use crate::{
+	enums::{
+		contracts_enum::ContractKind,
+		price_oracle_contract::PriceType,
+		proposal_status::ProposalStatus,
+		roles::Role,
+	},
+	models::confiugration::ExtensionConfig,
+};
+
+/**
+ * Critical administration changes which have to pass through the timelock queue.
+ * Every variant mirrors the arguments of the endpoint it wraps.
+ * Serialized as the tag of the variant followed by its fields as bytes, `Tuple2(U8, List(U8))`
+ */
+#[derive(Clone)]
+pub enum ProposalAction {
+	SetAllowedExtensions {
+		extensions: Vec<String>,
+	},
+	AddExtension {
+		extension: String,
+	},
+	RemoveExtension {
+		extension: String,
+	},
+	SetCharsMinCount {
+		extension: String,
+		count: u8,
+	},
+	SetListingLimit {
+		kind: ContractKind,
+		limit: u32,
+	},
+	AddContract {
+		kind: ContractKind,
+		key: Key,
+		extension: Option<String>,
+	},
+	SetPrice {
+		extension: String,
+		price_type: PriceType,
+		price: U512,
+		price_mid: Vec<U512>,
+		chars_count_mid: Vec<u64>,
+		price_more: U512,
+	},
+	SetMinDelay {
+		delay: u64,
+	},
+	SetSigners {
+		signers: Vec<Key>,
+		threshold: u8,
+	},
+	SetExtensionConfig {
+		extension: String,
+		config: ExtensionConfig,
+	},
+	SetPriceSimpleOperations {
+		extension: String,
+		price: U512,
+	},
+	GrantRole {
+		role: Role,
+		account: Key,
+	},
+	RevokeRole {
+		role: Role,
+		account: Key,
+	},
+	SetContractAuthorityList {
+		contract_hash: ContractHash,
+		authorities: Vec<Key>,
+	},
+	AddContractAuthority {
+		contract_hash: ContractHash,
+		authority: Key,
+	},
+	RemoveContractAuthority {
+		contract_hash: ContractHash,
+		authority: Key,
+	},
+}
+
+impl ProposalAction {
+	fn tag(&self) -> u8 {
+		match self {
+			Self::SetAllowedExtensions { .. } => 0,
+			Self::AddExtension { .. } => 1,
+			Self::RemoveExtension { .. } => 2,
+			Self::SetCharsMinCount { .. } => 3,
+			Self::SetListingLimit { .. } => 4,
+			Self::AddContract { .. } => 5,
+			Self::SetPrice { .. } => 6,
+			Self::SetMinDelay { .. } => 7,
+			Self::SetSigners { .. } => 8,
+			Self::SetExtensionConfig { .. } => 9,
+			Self::SetPriceSimpleOperations { .. } => 10,
+			Self::GrantRole { .. } => 11,
+			Self::RevokeRole { .. } => 12,
+			Self::SetContractAuthorityList { .. } => 13,
+			Self::AddContractAuthority { .. } => 14,
+			Self::RemoveContractAuthority { .. } => 15,
+		}
+	}
+
+	/// Besides the local operators, holders of the role guarding the wrapped endpoint can propose the action
+	pub fn role(&self) -> Option<Role> {
+		match self {
+			Self::SetAllowedExtensions { .. } |
+			Self::AddExtension { .. } |
+			Self::RemoveExtension { .. } |
+			Self::SetCharsMinCount { .. } |
+			Self::SetExtensionConfig { .. } => Some(Role::ExtensionManager),
+			Self::SetListingLimit { .. } |
+			Self::AddContract { .. } |
+			Self::SetContractAuthorityList { .. } |
+			Self::AddContractAuthority { .. } |
+			Self::RemoveContractAuthority { .. } => Some(Role::ShardManager),
+			Self::GrantRole { .. } | Self::RevokeRole { .. } => Some(Role::Admin),
+			Self::SetPrice { .. } | Self::SetPriceSimpleOperations { .. } =>
+				Some(Role::PriceSetter),
+			Self::SetMinDelay { .. } | Self::SetSigners { .. } => None,
+		}
+	}
+}
+
+impl Display for ProposalAction {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::SetAllowedExtensions { .. } => write!(f, "set_allowed_extensions"),
+			Self::AddExtension { .. } => write!(f, "add_extension"),
+			Self::RemoveExtension { .. } => write!(f, "remove_extension"),
+			Self::SetCharsMinCount { .. } => write!(f, "set_chars_min_count"),
+			Self::SetListingLimit { .. } => write!(f, "set_listing_limit"),
+			Self::AddContract { .. } => write!(f, "add_contract"),
+			Self::SetPrice { .. } => write!(f, "set_price"),
+			Self::SetMinDelay { .. } => write!(f, "set_min_delay"),
+			Self::SetSigners { .. } => write!(f, "set_signers"),
+			Self::SetExtensionConfig { .. } => write!(f, "set_extension_config"),
+			Self::SetPriceSimpleOperations { .. } =>
+				write!(f, "set_price_simple_operations"),
+			Self::GrantRole { .. } => write!(f, "grant_role"),
+			Self::RevokeRole { .. } => write!(f, "revoke_role"),
+			Self::SetContractAuthorityList { .. } =>
+				write!(f, "set_contract_authority_list"),
+			Self::AddContractAuthority { .. } => write!(f, "add_contract_authority"),
+			Self::RemoveContractAuthority { .. } =>
+				write!(f, "remove_contract_authority"),
+		}
+	}
+}
+
+impl ProposalAction {
+	/// Actions were serialized with their fields right after the tag before `SCHEMA_VERSION` 4
+	/// of the administration contract
+	pub fn from_legacy_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+		let (tag, remainder) = u8::from_bytes(bytes)?;
+		Self::from_fields(tag, remainder)
+	}
+
+	fn fields_to_bytes(&self) -> Result<Vec<u8>, Error> {
+		let mut result: Vec<u8> = Vec::with_capacity(self.fields_serialized_length());
+		match self {
+			Self::SetAllowedExtensions { extensions } => {
+				result.extend(extensions.to_bytes()?);
+			}
+			Self::AddExtension { extension } | Self::RemoveExtension { extension } => {
+				result.extend(extension.to_bytes()?);
+			}
+			Self::SetCharsMinCount { extension, count } => {
+				result.extend(extension.to_bytes()?);
+				result.extend(count.to_bytes()?);
+			}
+			Self::SetListingLimit { kind, limit } => {
+				result.extend(kind.to_bytes()?);
+				result.extend(limit.to_bytes()?);
+			}
+			Self::AddContract { kind, key, extension } => {
+				result.extend(kind.to_bytes()?);
+				result.extend(key.to_bytes()?);
+				result.extend(extension.to_bytes()?);
+			}
+			Self::SetPrice {
+				extension,
+				price_type,
+				price,
+				price_mid,
+				chars_count_mid,
+				price_more,
+			} => {
+				result.extend(extension.to_bytes()?);
+				result.extend(price_type.to_bytes()?);
+				result.extend(price.to_bytes()?);
+				result.extend(price_mid.to_bytes()?);
+				result.extend(chars_count_mid.to_bytes()?);
+				result.extend(price_more.to_bytes()?);
+			}
+			Self::SetMinDelay { delay } => {
+				result.extend(delay.to_bytes()?);
+			}
+			Self::SetSigners { signers, threshold } => {
+				result.extend(signers.to_bytes()?);
+				result.extend(threshold.to_bytes()?);
+			}
+			Self::SetExtensionConfig { extension, config } => {
+				result.extend(extension.to_bytes()?);
+				result.extend(config.to_bytes()?);
+			}
+			Self::SetPriceSimpleOperations { extension, price } => {
+				result.extend(extension.to_bytes()?);
+				result.extend(price.to_bytes()?);
+			}
+			Self::GrantRole { role, account } | Self::RevokeRole { role, account } => {
+				result.extend(role.to_bytes()?);
+				result.extend(account.to_bytes()?);
+			}
+			Self::SetContractAuthorityList { contract_hash, authorities } => {
+				result.extend(contract_hash.to_bytes()?);
+				result.extend(authorities.to_bytes()?);
+			}
+			Self::AddContractAuthority { contract_hash, authority } |
+			Self::RemoveContractAuthority { contract_hash, authority } => {
+				result.extend(contract_hash.to_bytes()?);
+				result.extend(authority.to_bytes()?);
+			}
+		}
+
+		Ok(result)
+	}
+
+	fn fields_serialized_length(&self) -> usize {
+		match self {
+			Self::SetAllowedExtensions { extensions } =>
+				extensions.serialized_length(),
+			Self::AddExtension { extension } | Self::RemoveExtension { extension } =>
+				extension.serialized_length(),
+			Self::SetCharsMinCount { extension, count } =>
+				extension.serialized_length() + count.serialized_length(),
+			Self::SetListingLimit { kind, limit } =>
+				kind.serialized_length() + limit.serialized_length(),
+			Self::AddContract { kind, key, extension } =>
+				kind.serialized_length() +
+					key.serialized_length() +
+					extension.serialized_length(),
+			Self::SetPrice {
+				extension,
+				price_type,
+				price,
+				price_mid,
+				chars_count_mid,
+				price_more,
+			} =>
+				extension.serialized_length() +
+					price_type.serialized_length() +
+					price.serialized_length() +
+					price_mid.serialized_length() +
+					chars_count_mid.serialized_length() +
+					price_more.serialized_length(),
+			Self::SetMinDelay { delay } => delay.serialized_length(),
+			Self::SetSigners { signers, threshold } =>
+				signers.serialized_length() + threshold.serialized_length(),
+			Self::SetExtensionConfig { extension, config } =>
+				extension.serialized_length() + config.serialized_length(),
+			Self::SetPriceSimpleOperations { extension, price } =>
+				extension.serialized_length() + price.serialized_length(),
+			Self::GrantRole { role, account } | Self::RevokeRole { role, account } =>
+				role.serialized_length() + account.serialized_length(),
+			Self::SetContractAuthorityList { contract_hash, authorities } =>
+				contract_hash.serialized_length() + authorities.serialized_length(),
+			Self::AddContractAuthority { contract_hash, authority } |
+			Self::RemoveContractAuthority { contract_hash, authority } =>
+				contract_hash.serialized_length() + authority.serialized_length(),
+		}
+	}
+
+	fn from_fields(tag: u8, remainder: &[u8]) -> Result<(Self, &[u8]), Error> {
+		match tag {
+			0 => {
+				let (extensions, remainder) = Vec::<String>::from_bytes(remainder)?;
+				Ok((Self::SetAllowedExtensions { extensions }, remainder))
+			}
+			1 => {
+				let (extension, remainder) = String::from_bytes(remainder)?;
+				Ok((Self::AddExtension { extension }, remainder))
+			}
+			2 => {
+				let (extension, remainder) = String::from_bytes(remainder)?;
+				Ok((Self::RemoveExtension { extension }, remainder))
+			}
+			3 => {
+				let (extension, remainder) = String::from_bytes(remainder)?;
+				let (count, remainder) = u8::from_bytes(remainder)?;
+				Ok((Self::SetCharsMinCount { extension, count }, remainder))
+			}
+			4 => {
+				let (kind, remainder) = ContractKind::from_bytes(remainder)?;
+				let (limit, remainder) = u32::from_bytes(remainder)?;
+				Ok((Self::SetListingLimit { kind, limit }, remainder))
+			}
+			5 => {
+				let (kind, remainder) = ContractKind::from_bytes(remainder)?;
+				let (key, remainder) = Key::from_bytes(remainder)?;
+				let (extension, remainder) = Option::<String>::from_bytes(remainder)?;
+				Ok((Self::AddContract { kind, key, extension }, remainder))
+			}
+			6 => {
+				let (extension, remainder) = String::from_bytes(remainder)?;
+				let (price_type, remainder) = PriceType::from_bytes(remainder)?;
+				let (price, remainder) = U512::from_bytes(remainder)?;
+				let (price_mid, remainder) = Vec::<U512>::from_bytes(remainder)?;
+				let (chars_count_mid, remainder) = Vec::<u64>::from_bytes(remainder)?;
+				let (price_more, remainder) = U512::from_bytes(remainder)?;
+				Ok((
+					Self::SetPrice {
+						extension,
+						price_type,
+						price,
+						price_mid,
+						chars_count_mid,
+						price_more,
+					},
+					remainder,
+				))
+			}
+			7 => {
+				let (delay, remainder) = u64::from_bytes(remainder)?;
+				Ok((Self::SetMinDelay { delay }, remainder))
+			}
+			8 => {
+				let (signers, remainder) = Vec::<Key>::from_bytes(remainder)?;
+				let (threshold, remainder) = u8::from_bytes(remainder)?;
+				Ok((Self::SetSigners { signers, threshold }, remainder))
+			}
+			9 => {
+				let (extension, remainder) = String::from_bytes(remainder)?;
+				let (config, remainder) = ExtensionConfig::from_bytes(remainder)?;
+				Ok((Self::SetExtensionConfig { extension, config }, remainder))
+			}
+			10 => {
+				let (extension, remainder) = String::from_bytes(remainder)?;
+				let (price, remainder) = U512::from_bytes(remainder)?;
+				Ok((Self::SetPriceSimpleOperations { extension, price }, remainder))
+			}
+			11 => {
+				let (role, remainder) = Role::from_bytes(remainder)?;
+				let (account, remainder) = Key::from_bytes(remainder)?;
+				Ok((Self::GrantRole { role, account }, remainder))
+			}
+			12 => {
+				let (role, remainder) = Role::from_bytes(remainder)?;
+				let (account, remainder) = Key::from_bytes(remainder)?;
+				Ok((Self::RevokeRole { role, account }, remainder))
+			}
+			13 => {
+				let (contract_hash, remainder) = ContractHash::from_bytes(remainder)?;
+				let (authorities, remainder) = Vec::<Key>::from_bytes(remainder)?;
+				Ok((Self::SetContractAuthorityList { contract_hash, authorities }, remainder))
+			}
+			14 => {
+				let (contract_hash, remainder) = ContractHash::from_bytes(remainder)?;
+				let (authority, remainder) = Key::from_bytes(remainder)?;
+				Ok((Self::AddContractAuthority { contract_hash, authority }, remainder))
+			}
+			15 => {
+				let (contract_hash, remainder) = ContractHash::from_bytes(remainder)?;
+				let (authority, remainder) = Key::from_bytes(remainder)?;
+				Ok((Self::RemoveContractAuthority { contract_hash, authority }, remainder))
+			}
+			_ => Err(Error::Formatting),
+		}
+	}
+}
+
+impl ToBytes for ProposalAction {
+	fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+		let mut result = allocate_buffer(self)?;
+		result.extend(self.tag().to_bytes()?);
+		result.extend(Bytes::from(self.fields_to_bytes()?).to_bytes()?);
+
+		Ok(result)
+	}
+
+	fn serialized_length(&self) -> usize {
+		self.tag().serialized_length() +
+			U32_SERIALIZED_LENGTH +
+			self.fields_serialized_length()
+	}
+}
+
+impl FromBytes for ProposalAction {
+	fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+		let (tag, remainder) = u8::from_bytes(bytes)?;
+		let (fields, remainder) = Bytes::from_bytes(remainder)?;
+		let (action, fields_remainder) = Self::from_fields(tag, &fields)?;
+		if !fields_remainder.is_empty() {
+			return Err(Error::LeftOverBytes);
+		}
+		Ok((action, remainder))
+	}
+}
+
+impl CLTyped for ProposalAction {
+	fn cl_type() -> CLType {
+		CLType::Tuple2([Box::new(CLType::U8), Box::new(Bytes::cl_type())])
+	}
+}
+
+#[derive(Clone, ToBytes, FromBytes, CLTyped)]
+pub struct Proposal {
+	pub id: u64,
+	pub action: ProposalAction,
+	pub proposer: Key,
+	pub eta: u64,
+	pub status: ProposalStatus,
+	pub approvals: Vec<Key>,
+}
@@ -0,0 +1,38 @@
+use casper_types::{ account::AccountHash, U512 };
+use cspr_names_derive::{ CLTyped, FromBytes, ToBytes };
+
+use crate::{ constants::common_keys::ROYALTY_BASIS_POINTS, errors::NFTErrors };
+
+/// Protocol share of the secondary sales of an extension, share is in basis points
+/// The share is paid to the treasury the sale is paid in, the policy has no receiver of its own
+#[derive(Clone, ToBytes, FromBytes, CLTyped)]
+pub struct RoyaltyPolicy {
+	pub share: u32,
+}
+
+/// Creator share of the secondary sales of a token, the creator is the owner the token was minted to
+#[derive(Clone, ToBytes, FromBytes, CLTyped)]
+pub struct TokenRoyalty {
+	pub creator: AccountHash,
+	pub share: u32,
+}
+
+impl RoyaltyPolicy {
+	pub fn amount(&self, sale_price: U512) -> Result<U512, NFTErrors> {
+		get_royalty_amount(sale_price, self.share)
+	}
+}
+
+impl TokenRoyalty {
+	pub fn amount(&self, sale_price: U512) -> Result<U512, NFTErrors> {
+		get_royalty_amount(sale_price, self.share)
+	}
+}
+
+/// Rounds down, the remainder stays with the seller
+pub fn get_royalty_amount(sale_price: U512, share: u32) -> Result<U512, NFTErrors> {
+	sale_price
+		.checked_mul(U512::from(share))
+		.map(|amount| amount / U512::from(ROYALTY_BASIS_POINTS))
+		.ok_or(NFTErrors::RoyaltyAmountOverflow)
+}
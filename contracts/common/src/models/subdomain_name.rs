@@ -1,44 +1,10 @@
-use alloc::{string::String, vec::Vec};
-use casper_types::{
-    account::AccountHash,
-    bytesrepr::{allocate_buffer, Error, FromBytes, ToBytes},
-    CLType, CLTyped,
-};
+use alloc::string::String;
+use casper_types::account::AccountHash;
+use cspr_names_derive::{CLTyped, FromBytes, ToBytes};
 use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Serialize, Clone)]
+#[derive(Deserialize, Serialize, Clone, ToBytes, FromBytes, CLTyped)]
 pub struct SubdomainName {
     pub name: String,
     pub resolver: AccountHash,
 }
-
-impl ToBytes for SubdomainName {
-    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
-        let mut result = allocate_buffer(self)?;
-
-        result.extend(self.name.to_bytes()?);
-        result.extend(self.resolver.to_bytes()?);
-
-        Ok(result)
-    }
-
-    fn serialized_length(&self) -> usize {
-        self.name.serialized_length() + self.resolver.serialized_length()
-    }
-}
-
-impl FromBytes for SubdomainName {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
-        let (name, remainder) = String::from_bytes(bytes)?;
-        let (resolver, remainder) = AccountHash::from_bytes(remainder)?;
-
-        let result = SubdomainName { name, resolver };
-        Ok((result, remainder))
-    }
-}
-
-impl CLTyped for SubdomainName {
-    fn cl_type() -> CLType {
-        CLType::Any
-    }
-}
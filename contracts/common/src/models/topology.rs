@@ -0,0 +1,44 @@
+use alloc::string::String;
+use casper_types::Key;
+use cspr_names_derive::{ CLTyped, FromBytes, ToBytes };
+
+use crate::enums::{
+	contracts_enum::ContractKind,
+	topology_issue_kind::TopologyIssueKind,
+};
+
+/**
+ * Single problem of the contract directory:
+ * key - contract the problem is found in (none when the directory entry is missing)
+ * related_key - caller which is expected in the registry whitelist
+ * stored_count, actual_count - compound contract count against the shard's totals
+ */
+#[derive(Clone, ToBytes, FromBytes, CLTyped)]
+pub struct TopologyIssue {
+	pub kind: TopologyIssueKind,
+	pub contract_kind: ContractKind,
+	pub extension: Option<String>,
+	pub key: Option<Key>,
+	pub related_key: Option<Key>,
+	pub stored_count: Option<u64>,
+	pub actual_count: Option<u64>,
+}
+
+impl TopologyIssue {
+	pub fn new(
+		kind: TopologyIssueKind,
+		contract_kind: ContractKind,
+		extension: Option<String>,
+		key: Option<Key>
+	) -> Self {
+		Self {
+			kind,
+			contract_kind,
+			extension,
+			key,
+			related_key: None,
+			stored_count: None,
+			actual_count: None,
+		}
+	}
+}
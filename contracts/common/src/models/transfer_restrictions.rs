@@ -0,0 +1,11 @@
+use cspr_names_derive::{ CLTyped, FromBytes, ToBytes };
+
+use crate::enums::transfer_lock::TransferLock;
+
+/// Transfer state of a domain or subdomain kept by the database shard
+#[derive(Clone, Default, ToBytes, FromBytes, CLTyped)]
+pub struct TransferRestrictions {
+	pub lock: Option<TransferLock>,
+	/// Subdomains only, a burned permission can't be restored
+	pub transfer_permission_burned: bool,
+}
@@ -0,0 +1,66 @@
+use alloc::vec::Vec;
+use casper_types::{ account::AccountHash, U512 };
+use cspr_names_derive::{ CLTyped, FromBytes, ToBytes };
+
+use crate::{
+	constants::common_keys::ROYALTY_BASIS_POINTS,
+	errors::TreasuryErrors,
+};
+
+/// Beneficiary of a revenue stream, share is in basis points of every deposit
+#[derive(Clone, ToBytes, FromBytes, CLTyped)]
+pub struct Beneficiary {
+	pub account: AccountHash,
+	pub share: u32,
+}
+
+/// Shares are positive, an account is listed once and the shares add up to the whole deposit
+pub fn validate_beneficiaries(
+	beneficiaries: &[Beneficiary]
+) -> Result<(), TreasuryErrors> {
+	if beneficiaries.is_empty() {
+		return Err(TreasuryErrors::NoBeneficiaries);
+	}
+	let mut total: u64 = 0;
+	for (index, beneficiary) in beneficiaries.iter().enumerate() {
+		if beneficiary.share == 0 {
+			return Err(TreasuryErrors::ZeroShare);
+		}
+		if
+			beneficiaries[..index]
+				.iter()
+				.any(|other| other.account == beneficiary.account)
+		{
+			return Err(TreasuryErrors::DuplicateBeneficiary);
+		}
+		total += u64::from(beneficiary.share);
+	}
+	if total != u64::from(ROYALTY_BASIS_POINTS) {
+		return Err(TreasuryErrors::InvalidShares);
+	}
+	Ok(())
+}
+
+/// Amounts of the deposit per beneficiary, shares round down and
+/// the remainder goes to the first beneficiary, so nothing stays unassigned
+pub fn split_deposit(
+	amount: U512,
+	beneficiaries: &[Beneficiary]
+) -> Vec<(AccountHash, U512)> {
+	let mut parts: Vec<(AccountHash, U512)> = beneficiaries
+		.iter()
+		.map(|beneficiary| {
+			let part =
+				(amount * U512::from(beneficiary.share)) /
+				U512::from(ROYALTY_BASIS_POINTS);
+			(beneficiary.account, part)
+		})
+		.collect();
+	let assigned = parts
+		.iter()
+		.fold(U512::zero(), |total, (_, part)| total + *part);
+	if let Some((_, first)) = parts.first_mut() {
+		*first += amount - assigned;
+	}
+	parts
+}
@@ -0,0 +1,660 @@
+use alloc::{ string::String, vec, vec::Vec };
+use casper_types::{ account::AccountHash, CLType, CLTyped, ContractHash, Key };
+
+use crate::{
+	constants::common_keys::{
+		AdministrationArgs,
+		AdministrationEndpoints,
+		CommonEndpoints,
+	},
+	enums::{
+		contracts_enum::ContractKind,
+		controller_roles::ControllerRoles,
+		registration_mode::RegistrationMode,
+		roles::Role,
+	},
+	errors::{ AdministrationErrors, AuthorityErrors, CommonError },
+	events::administration::{
+		ApprovalRevoked,
+		CancellationApproved,
+		ContractProvisioned,
+		ContractUpgraded,
+		DomainsMigrated,
+		Paused,
+		ProposalApproved,
+		ProposalCancelled,
+		ProposalExecuted,
+		ProposalQueued,
+		RoleGranted,
+		RoleRevoked,
+		SignersChanged,
+		Unpaused,
+	},
+	models::{
+		confiugration::ExtensionConfig,
+		proposal::{ Proposal, ProposalAction },
+		topology::TopologyIssue,
+	},
+};
+
+use super::{ ContractSchema, EntryPointSchema };
+
+/// Administration keeps the directory of contracts, roles, proposals and pause switches.
+/// Contract authority lists are kept for the deployed tooling, calls between contracts
+/// are checked by the SYSTEM_CONTRACT role
+pub fn schema() -> ContractSchema {
+	ContractSchema::new("administration")
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::SetContractAuthorityList,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::ContractHash, ContractHash::cl_type())
+				.arg(
+					AdministrationArgs::ContractAuthorities,
+					Vec::<Key>::cl_type()
+				)
+				.access(vec![ControllerRoles::OnlyRole(Role::ShardManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::AddContractAuthority,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::ContractHash, ContractHash::cl_type())
+				.arg(AdministrationArgs::ContractAuthority, Key::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::ShardManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GetContractAuthorityList,
+				Vec::<Key>::cl_type()
+			)
+				.arg(AdministrationArgs::ContractHash, ContractHash::cl_type())
+				.access(
+					vec![
+						ControllerRoles::OnlyLocalOperators,
+						ControllerRoles::OnlyAuthorizedContracts
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::RemoveContractAuthority,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::ContractHash, ContractHash::cl_type())
+				.arg(AdministrationArgs::ContractAuthority, Key::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::ShardManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GetContract,
+				<(Key, Option<u32>)>::cl_type()
+			)
+				.arg(AdministrationArgs::ContractKind, ContractKind::cl_type())
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.access(
+					vec![
+						ControllerRoles::OnlyLocalOperators,
+						ControllerRoles::OnlyAuthorizedContracts
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::AddContract,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::ContractKind, ContractKind::cl_type())
+				.arg(AdministrationArgs::Key, Key::cl_type())
+				.optional_arg(
+					AdministrationArgs::Extension,
+					Option::<String>::cl_type()
+				)
+				.access(vec![ControllerRoles::OnlyRole(Role::ShardManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::IncrementContract,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::ContractKind, ContractKind::cl_type())
+				.arg(AdministrationArgs::Key, Key::cl_type())
+				.optional_arg(
+					AdministrationArgs::Extension,
+					Option::<String>::cl_type()
+				)
+				.access(
+					vec![
+						ControllerRoles::OnlyLocalOperators,
+						ControllerRoles::OnlyAuthorizedContracts
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::DecrementContract,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::ContractKind, ContractKind::cl_type())
+				.arg(AdministrationArgs::Key, Key::cl_type())
+				.optional_arg(
+					AdministrationArgs::Extension,
+					Option::<String>::cl_type()
+				)
+				.access(
+					vec![
+						ControllerRoles::OnlyLocalOperators,
+						ControllerRoles::OnlyAuthorizedContracts
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::SetAllowedExtensions,
+				CLType::Unit
+			)
+				.arg(
+					AdministrationArgs::AllowedExtensions,
+					Vec::<String>::cl_type()
+				)
+				.access(vec![ControllerRoles::OnlyRole(Role::ExtensionManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GetAllowedExtensions,
+				Vec::<String>::cl_type()
+			)
+				.access(
+					vec![
+						ControllerRoles::OnlyLocalOperators,
+						ControllerRoles::OnlyAuthorizedContracts
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::AddExtension,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::AllowedExtension, String::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::ExtensionManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::RemoveExtension,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::AllowedExtension, String::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::ExtensionManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GetCharsMinCount,
+				u8::cl_type()
+			)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.access(
+					vec![
+						ControllerRoles::OnlyLocalOperators,
+						ControllerRoles::OnlyAuthorizedContracts
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::SetCharsMinCount,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::CharsCount, u8::cl_type())
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::ExtensionManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GetExtensionConfig,
+				ExtensionConfig::cl_type()
+			)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.access(
+					vec![
+						ControllerRoles::OnlyLocalOperators,
+						ControllerRoles::OnlyAuthorizedContracts
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GetMaxCharsCount,
+				u8::cl_type()
+			)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.access(
+					vec![
+						ControllerRoles::OnlyLocalOperators,
+						ControllerRoles::OnlyAuthorizedContracts
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::SetMaxCharsCount,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.arg(AdministrationArgs::MaxCharsCount, u8::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::ExtensionManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GetMaxSubscriptionYears,
+				u8::cl_type()
+			)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.access(
+					vec![
+						ControllerRoles::OnlyLocalOperators,
+						ControllerRoles::OnlyAuthorizedContracts
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::SetMaxSubscriptionYears,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.arg(AdministrationArgs::MaxSubscriptionYears, u8::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::ExtensionManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GetGracePeriod,
+				u64::cl_type()
+			)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.access(
+					vec![
+						ControllerRoles::OnlyLocalOperators,
+						ControllerRoles::OnlyAuthorizedContracts
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::SetGracePeriod,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.arg(AdministrationArgs::GracePeriod, u64::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::ExtensionManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GetSubdomainLimit,
+				u8::cl_type()
+			)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.access(
+					vec![
+						ControllerRoles::OnlyLocalOperators,
+						ControllerRoles::OnlyAuthorizedContracts
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::SetSubdomainLimit,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.arg(AdministrationArgs::SubdomainLimit, u8::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::ExtensionManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GetRegistrationMode,
+				RegistrationMode::cl_type()
+			)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.access(
+					vec![
+						ControllerRoles::OnlyLocalOperators,
+						ControllerRoles::OnlyAuthorizedContracts
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::SetRegistrationMode,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.arg(
+					AdministrationArgs::RegistrationMode,
+					RegistrationMode::cl_type()
+				)
+				.access(vec![ControllerRoles::OnlyRole(Role::ExtensionManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GetFeeRecipient,
+				Option::<AccountHash>::cl_type()
+			)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.access(
+					vec![
+						ControllerRoles::OnlyLocalOperators,
+						ControllerRoles::OnlyAuthorizedContracts
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::SetFeeRecipient,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.arg(
+					AdministrationArgs::FeeRecipient,
+					Option::<AccountHash>::cl_type()
+				)
+				.access(vec![ControllerRoles::OnlyRole(Role::ExtensionManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::AddToAllowlist,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.arg(AdministrationArgs::Account, Key::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::ExtensionManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::RemoveFromAllowlist,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.arg(AdministrationArgs::Account, Key::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::ExtensionManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::IsAllowlisted,
+				CLType::Bool
+			)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.arg(AdministrationArgs::Account, Key::cl_type())
+				.access(
+					vec![
+						ControllerRoles::OnlyLocalOperators,
+						ControllerRoles::OnlyAuthorizedContracts
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::AddStandbyContract,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::ContractKind, ContractKind::cl_type())
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.arg(AdministrationArgs::Key, Key::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::ShardManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GetStandbyContracts,
+				Vec::<Key>::cl_type()
+			)
+				.arg(AdministrationArgs::ContractKind, ContractKind::cl_type())
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.access(
+					vec![
+						ControllerRoles::OnlyLocalOperators,
+						ControllerRoles::OnlyAuthorizedContracts
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::ProvisionContract,
+				Key::cl_type()
+			)
+				.arg(AdministrationArgs::ContractKind, ContractKind::cl_type())
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::MigrateDomains,
+				CLType::U32
+			)
+				.arg(AdministrationArgs::From, Key::cl_type())
+				.arg(AdministrationArgs::To, Key::cl_type())
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.arg(AdministrationArgs::DomainNames, Vec::<String>::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::ShardManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::ContractUpgraded,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::PreviousKey, Key::cl_type())
+				.arg(AdministrationArgs::Key, Key::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::ShardManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::VerifyTopology,
+				Vec::<TopologyIssue>::cl_type()
+			)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GetCompoundContracts,
+				Vec::<Key>::cl_type()
+			)
+				.arg(AdministrationArgs::ContractKind, ContractKind::cl_type())
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.access(
+					vec![
+						ControllerRoles::OnlyLocalOperators,
+						ControllerRoles::OnlyAuthorizedContracts
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GetListingLimit,
+				u32::cl_type()
+			)
+				.arg(AdministrationArgs::ContractKind, ContractKind::cl_type())
+				.access(
+					vec![
+						ControllerRoles::OnlyLocalOperators,
+						ControllerRoles::OnlyAuthorizedContracts
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::SetListingLimit,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::ContractKind, ContractKind::cl_type())
+				.arg(AdministrationArgs::CharsCount, u32::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::ShardManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(AdministrationEndpoints::Init, CLType::Unit)
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::QueueProposal,
+				u64::cl_type()
+			)
+				.arg(
+					AdministrationArgs::ProposalAction,
+					ProposalAction::cl_type()
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::CancelProposal,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::ProposalId, u64::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::ExecuteProposal,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::ProposalId, u64::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GetProposal,
+				Proposal::cl_type()
+			)
+				.arg(AdministrationArgs::ProposalId, u64::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GetMinDelay,
+				u64::cl_type()
+			)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::SetMinDelay,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::MinDelay, u64::cl_type())
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::ApproveProposal,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::ProposalId, u64::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::RevokeApproval,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::ProposalId, u64::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GetSigners,
+				<(Vec<Key>, u8)>::cl_type()
+			)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::SetSigners,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::Signers, Vec::<Key>::cl_type())
+				.arg(AdministrationArgs::Threshold, u8::cl_type())
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GrantRole,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::Role, Role::cl_type())
+				.arg(AdministrationArgs::Account, Key::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::Admin)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::RevokeRole,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::Role, Role::cl_type())
+				.arg(AdministrationArgs::Account, Key::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::Admin)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::RenounceRole,
+				CLType::Unit
+			)
+				.arg(AdministrationArgs::Role, Role::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::HasRole,
+				CLType::Bool
+			)
+				.arg(AdministrationArgs::Role, Role::cl_type())
+				.arg(AdministrationArgs::Account, Key::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::GetRoleMembers,
+				Vec::<Key>::cl_type()
+			)
+				.arg(AdministrationArgs::Role, Role::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(AdministrationEndpoints::Pause, CLType::Unit)
+				.arg(
+					AdministrationArgs::ContractKind,
+					Option::<ContractKind>::cl_type()
+				)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::Guardian)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::Unpause,
+				CLType::Unit
+			)
+				.arg(
+					AdministrationArgs::ContractKind,
+					Option::<ContractKind>::cl_type()
+				)
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::Guardian)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				AdministrationEndpoints::IsPaused,
+				CLType::Bool
+			)
+				.arg(AdministrationArgs::ContractKind, ContractKind::cl_type())
+				.arg(AdministrationArgs::Extension, Option::<String>::cl_type())
+		)
+		.with_authorities()
+		.entry_point(
+			EntryPointSchema::new(CommonEndpoints::Migrate, CLType::U32)
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.event::<ProposalQueued>()
+		.event::<ProposalCancelled>()
+		.event::<ProposalExecuted>()
+		.event::<ProposalApproved>()
+		.event::<ApprovalRevoked>()
+		.event::<CancellationApproved>()
+		.event::<SignersChanged>()
+		.event::<RoleGranted>()
+		.event::<RoleRevoked>()
+		.event::<Paused>()
+		.event::<Unpaused>()
+		.event::<ContractProvisioned>()
+		.event::<DomainsMigrated>()
+		.event::<ContractUpgraded>()
+		.errors(AdministrationErrors::TABLE)
+		.errors(CommonError::TABLE)
+		.errors(AuthorityErrors::TABLE)
+}
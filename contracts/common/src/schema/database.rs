@@ -0,0 +1,202 @@
+use alloc::{ string::String, vec, vec::Vec };
+use casper_types::{ account::AccountHash, CLType, CLTyped, Key };
+
+use crate::{
+	constants::common_keys::{ CommonEndpoints, DatabaseArgs, DatabaseEndpoints },
+	enums::{
+		controller_roles::ControllerRoles,
+		roles::Role,
+		transfer_lock::TransferLock,
+	},
+	errors::{ CommonError, DatabaseErrors },
+	models::{
+		domain_migration::DomainMigrationEntry,
+		transfer_restrictions::TransferRestrictions,
+		DomainName,
+		SubdomainName,
+	},
+};
+
+use super::{ ContractSchema, EntryPointSchema };
+
+/// Database shards store domains and subdomains, only the system contracts write to them
+pub fn schema() -> ContractSchema {
+	ContractSchema::new("database")
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::SaveDomainName,
+				CLType::Unit
+			)
+				.arg(DatabaseArgs::DomainName, DomainName::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::SaveSubdomainName,
+				CLType::Unit
+			)
+				.arg(DatabaseArgs::DomainName, String::cl_type())
+				.arg(DatabaseArgs::SubdomainName, SubdomainName::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::RemoveDomainName,
+				CLType::Unit
+			)
+				.arg(DatabaseArgs::DomainName, String::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::RemoveSubdomainName,
+				CLType::Unit
+			)
+				.arg(DatabaseArgs::DomainName, String::cl_type())
+				.arg(DatabaseArgs::SubdomainName, String::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::SetDomainOwnership,
+				CLType::Unit
+			)
+				.arg(DatabaseArgs::DomainName, String::cl_type())
+				.arg(DatabaseArgs::Owner, Key::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::SetDomainExpiration,
+				CLType::Unit
+			)
+				.arg(DatabaseArgs::DomainName, String::cl_type())
+				.arg(DatabaseArgs::ExpirationDate, u64::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::SetDomainResolver,
+				CLType::Unit
+			)
+				.arg(DatabaseArgs::DomainName, String::cl_type())
+				.arg(DatabaseArgs::Resolver, AccountHash::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::SetSubdomainResolver,
+				CLType::Unit
+			)
+				.arg(DatabaseArgs::SubdomainName, String::cl_type())
+				.arg(DatabaseArgs::Resolver, AccountHash::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::GetDomainListForOwner,
+				Vec::<String>::cl_type()
+			)
+				.arg(DatabaseArgs::Owner, AccountHash::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::GetDomainList,
+				Vec::<String>::cl_type()
+			)
+				.arg(DatabaseArgs::Page, u64::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::GetSubdomainList,
+				Vec::<String>::cl_type()
+			)
+				.arg(DatabaseArgs::DomainName, String::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::GetTotals,
+				<(u64, u64)>::cl_type()
+			)
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::GetDomain,
+				Option::<DomainName>::cl_type()
+			)
+				.arg(DatabaseArgs::DomainName, String::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::GetSubdomain,
+				Option::<SubdomainName>::cl_type()
+			)
+				.arg(DatabaseArgs::SubdomainName, String::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::ExportDomains,
+				Vec::<DomainMigrationEntry>::cl_type()
+			)
+				.arg(DatabaseArgs::DomainNames, Vec::<String>::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::ImportDomains,
+				CLType::U32
+			)
+				.arg(
+					DatabaseArgs::MigrationEntries,
+					Vec::<DomainMigrationEntry>::cl_type()
+				)
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::SetTransferLock,
+				CLType::Unit
+			)
+				.arg(DatabaseArgs::Name, String::cl_type())
+				.arg(DatabaseArgs::TransferLock, Option::<TransferLock>::cl_type())
+				.access(
+					vec![
+						ControllerRoles::OnlyAuthorizedContracts,
+						ControllerRoles::OnlyRole(Role::MarketplaceOperator),
+						ControllerRoles::OnlyRole(Role::Guardian)
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::BurnTransferPermission,
+				CLType::Unit
+			)
+				.arg(DatabaseArgs::Name, String::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				DatabaseEndpoints::GetTransferRestrictions,
+				TransferRestrictions::cl_type()
+			)
+				.arg(DatabaseArgs::Name, String::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(DatabaseEndpoints::Init, CLType::Unit)
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.entry_point(
+			EntryPointSchema::new(CommonEndpoints::Migrate, CLType::U32)
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.errors(DatabaseErrors::TABLE)
+		.errors(CommonError::TABLE)
+}
@@ -0,0 +1,131 @@
+use alloc::{ string::String, vec, vec::Vec };
+use casper_types::{
+	account::AccountHash,
+	CLType,
+	CLTyped,
+	ContractHash,
+	URef,
+	U512,
+};
+
+use crate::{
+	constants::common_keys::{
+		CommonEndpoints,
+		MainArgs,
+		MainEndpoints,
+		ENTRYPOINT_MAIN_SET_AUTHORITIES_CONTRACT_HASH,
+	},
+	enums::{
+		controller_roles::ControllerRoles,
+		main_contract::Error as MainContractLegacyErrors,
+		roles::Role,
+	},
+	errors::{ CommonError, MainContractErrors },
+	models::{ DomainName, SubdomainName },
+};
+
+use super::{ ContractSchema, EntryPointSchema };
+
+/// Main contract registers names, owners check themselves inside the entry points
+pub fn schema() -> ContractSchema {
+	ContractSchema::new("main")
+		.entry_point(
+			EntryPointSchema::new(
+				ENTRYPOINT_MAIN_SET_AUTHORITIES_CONTRACT_HASH,
+				CLType::Unit
+			)
+				.arg(MainArgs::PriceOracleContractHash, ContractHash::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::Admin)])
+		)
+		.entry_point(EntryPointSchema::new(MainEndpoints::Init, CLType::Unit))
+		.entry_point(
+			EntryPointSchema::new(CommonEndpoints::Migrate, CLType::U32)
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				MainEndpoints::RegisterDomain,
+				DomainName::cl_type()
+			)
+				.arg(MainArgs::Domain, String::cl_type())
+				.arg(MainArgs::Duration, u8::cl_type())
+				.arg(MainArgs::ResolverAddress, AccountHash::cl_type())
+				.arg(MainArgs::RegisterAmount, U512::cl_type())
+				// Either the purse or the accepted CEP-18 token pays
+				.optional_arg(MainArgs::CustomerPurse, URef::cl_type())
+				.optional_arg(MainArgs::PaymentToken, ContractHash::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				MainEndpoints::ResolverDomain,
+				AccountHash::cl_type()
+			)
+				.arg(MainArgs::Domain, String::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				MainEndpoints::SetResolverAddressForDomain,
+				CLType::Unit
+			)
+				.arg(MainArgs::Domain, String::cl_type())
+				.arg(MainArgs::ResolverAddress, AccountHash::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				MainEndpoints::RegisterSubDomain,
+				CLType::Unit
+			)
+				.arg(MainArgs::Subdomain, String::cl_type())
+				.arg(MainArgs::ResolverAddress, AccountHash::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(MainEndpoints::RemoveSubDomain, CLType::Unit)
+				.arg(MainArgs::Subdomain, String::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				MainEndpoints::SetResolverAddressForSubDomain,
+				CLType::Unit
+			)
+				.arg(MainArgs::Subdomain, String::cl_type())
+				.arg(MainArgs::ResolverAddress, AccountHash::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				MainEndpoints::GetSubdomainsForDomain,
+				Option::<Vec<SubdomainName>>::cl_type()
+			)
+				.arg(MainArgs::Domain, String::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				MainEndpoints::GetDomainList,
+				Vec::<String>::cl_type()
+			)
+				.arg(MainArgs::DomainPage, u8::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(MainEndpoints::Extend, CLType::Unit)
+				.arg(MainArgs::Domain, String::cl_type())
+				.arg(MainArgs::Duration, u8::cl_type())
+				.arg(MainArgs::RegisterAmount, U512::cl_type())
+				.optional_arg(MainArgs::CustomerPurse, URef::cl_type())
+				.optional_arg(MainArgs::PaymentToken, ContractHash::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				MainEndpoints::BurnSubdomainTransferPermission,
+				CLType::Unit
+			)
+				.arg(MainArgs::Subdomain, String::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(MainEndpoints::ReleaseDomain, CLType::Unit).arg(
+				MainArgs::Domain,
+				String::cl_type()
+			)
+		)
+		.errors(MainContractErrors::TABLE)
+		.errors(MainContractLegacyErrors::TABLE)
+		.errors(CommonError::TABLE)
+}
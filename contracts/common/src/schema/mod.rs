@@ -0,0 +1,170 @@
+use alloc::{ string::{ String, ToString }, vec, vec::Vec };
+use casper_event_standard::{ EventInstance, Schemas };
+use casper_types::{
+	CLType,
+	CLTyped,
+	EntryPoint,
+	EntryPointAccess,
+	EntryPointType,
+	EntryPoints,
+	Key,
+	Parameter,
+};
+
+use crate::{
+	constants::common_keys::{ CommonArgs, CommonEndpoints },
+	enums::controller_roles::ControllerRoles,
+	error_codes::ErrorEntry,
+};
+
+pub mod administration;
+pub mod database;
+pub mod main;
+pub mod nft;
+pub mod price_oracle;
+pub mod registry;
+pub mod transfer_filter;
+pub mod treasury;
+
+/**
+ * Entry points of every contract are declared once here.
+ * `call()` of the contract installs `EntryPoints` built from the declaration and
+ * the schema generator writes it to `documentation/schema/<contract>.json`
+ * for the SDKs and the front-end.
+ */
+#[derive(Clone)]
+pub struct ContractSchema {
+	pub contract: &'static str,
+	pub entry_points: Vec<EntryPointSchema>,
+	pub events: Schemas,
+	pub errors: Vec<&'static [ErrorEntry]>,
+}
+
+/// access - the caller passes if any of the checks passes, empty list means the entry point is public
+#[derive(Clone)]
+pub struct EntryPointSchema {
+	pub name: String,
+	pub args: Vec<ArgSchema>,
+	pub ret: CLType,
+	pub access: Vec<ControllerRoles>,
+}
+
+/// optional - the service reads the argument only when it is passed
+#[derive(Clone)]
+pub struct ArgSchema {
+	pub name: String,
+	pub cl_type: CLType,
+	pub optional: bool,
+}
+
+impl ContractSchema {
+	pub fn new(contract: &'static str) -> Self {
+		Self {
+			contract,
+			entry_points: Vec::new(),
+			events: Schemas::new(),
+			errors: Vec::new(),
+		}
+	}
+
+	pub fn entry_point(mut self, entry_point: EntryPointSchema) -> Self {
+		self.entry_points.push(entry_point);
+		self
+	}
+
+	pub fn event<T: EventInstance>(mut self) -> Self {
+		self.events.add::<T>();
+		self
+	}
+
+	pub fn errors(mut self, table: &'static [ErrorEntry]) -> Self {
+		self.errors.push(table);
+		self
+	}
+
+	/// Entry points of `controllers::authorities`, local operators manage the list
+	pub fn with_authorities(self) -> Self {
+		let access = vec![ControllerRoles::OnlyLocalOperators];
+		self.entry_point(
+			EntryPointSchema::new(CommonEndpoints::SetAuthorities, CLType::Unit)
+				.arg(CommonArgs::Authorities, Vec::<Key>::cl_type())
+				.access(access.clone())
+		)
+			.entry_point(
+				EntryPointSchema::new(CommonEndpoints::AddAuthority, CLType::Unit)
+					.arg(CommonArgs::Authority, Key::cl_type())
+					.access(access.clone())
+			)
+			.entry_point(
+				EntryPointSchema::new(CommonEndpoints::RemoveAuthority, CLType::Unit)
+					.arg(CommonArgs::Authority, Key::cl_type())
+					.access(access.clone())
+			)
+			.entry_point(
+				EntryPointSchema::new(
+					CommonEndpoints::GetAuthorities,
+					Vec::<Key>::cl_type()
+				).access(access)
+			)
+	}
+
+	pub fn get_entry_point(&self, name: &str) -> Option<&EntryPointSchema> {
+		self.entry_points.iter().find(|entry_point| entry_point.name == name)
+	}
+
+	/// Entry points installed with the contract, access is checked by the services
+	pub fn to_entry_points(&self) -> EntryPoints {
+		let mut entry_points = EntryPoints::new();
+		for entry_point in self.entry_points.iter() {
+			entry_points.add_entry_point(entry_point.to_entry_point());
+		}
+		entry_points
+	}
+}
+
+impl EntryPointSchema {
+	pub fn new<N: ToString>(name: N, ret: CLType) -> Self {
+		Self {
+			name: name.to_string(),
+			args: Vec::new(),
+			ret,
+			access: Vec::new(),
+		}
+	}
+
+	pub fn arg<N: ToString>(mut self, name: N, cl_type: CLType) -> Self {
+		self.args.push(ArgSchema {
+			name: name.to_string(),
+			cl_type,
+			optional: false,
+		});
+		self
+	}
+
+	pub fn optional_arg<N: ToString>(mut self, name: N, cl_type: CLType) -> Self {
+		self.args.push(ArgSchema {
+			name: name.to_string(),
+			cl_type,
+			optional: true,
+		});
+		self
+	}
+
+	pub fn access(mut self, access: Vec<ControllerRoles>) -> Self {
+		self.access = access;
+		self
+	}
+
+	pub fn to_entry_point(&self) -> EntryPoint {
+		EntryPoint::new(
+			&self.name,
+			self.args
+				.iter()
+				.map(|arg| Parameter::new(&arg.name, arg.cl_type.clone()))
+				.collect(),
+			self.ret.clone(),
+			EntryPointAccess::Public,
+			EntryPointType::Contract
+		)
+	}
+}
@@ -0,0 +1,168 @@
+use alloc::{ string::String, vec, vec::Vec };
+use casper_types::{
+	CLType,
+	CLTyped,
+	ContractHash,
+	Key,
+	URef,
+	U512,
+};
+
+use crate::{
+	constants::common_keys::{
+		CommonEndpoints,
+		NFTContractArgs,
+		NFTContractEndpoints,
+	},
+	enums::{
+		contracts_enum::ContractKind,
+		controller_roles::ControllerRoles,
+		roles::Role,
+	},
+	errors::{ AuthorityErrors, CommonError, NFTErrors },
+	events::nft::{ AuctionSettled, BidPlaced, RoyaltyPaid },
+	models::auction::Auction,
+};
+
+use super::{ ContractSchema, EntryPointSchema };
+
+/// NFT contract wraps nft-core and keeps the listings, the token metadata and the royalties
+pub fn schema() -> ContractSchema {
+	ContractSchema::new("nft")
+		.entry_point(
+			EntryPointSchema::new(NFTContractEndpoints::Init, CLType::Unit)
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.entry_point(
+			EntryPointSchema::new(NFTContractEndpoints::Mint, CLType::Unit)
+				.arg(NFTContractArgs::DomainName, String::cl_type())
+				.arg(NFTContractArgs::Owner, Key::cl_type())
+				.arg(NFTContractArgs::ExpirationDate, u64::cl_type())
+				.access(vec![ControllerRoles::OnlyContract(ContractKind::Main)])
+		)
+		.entry_point(
+			EntryPointSchema::new(NFTContractEndpoints::Burn, CLType::Unit)
+				.arg(NFTContractArgs::DomainName, String::cl_type())
+				.arg(NFTContractArgs::TokenId, String::cl_type())
+				.access(vec![ControllerRoles::OnlyContract(ContractKind::Main)])
+		)
+		// Public, the service checks the owner or an approved operator
+		.entry_point(
+			EntryPointSchema::new(NFTContractEndpoints::Transfer, CLType::Unit)
+				.arg(NFTContractArgs::DomainName, String::cl_type())
+				.arg(NFTContractArgs::SourceKey, Key::cl_type())
+				.arg(NFTContractArgs::DestinationKey, Key::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(NFTContractEndpoints::List, CLType::Unit)
+				.arg(NFTContractArgs::DomainName, String::cl_type())
+				.arg(NFTContractArgs::SourceKey, Key::cl_type())
+				.arg(NFTContractArgs::TokenPrice, U512::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(NFTContractEndpoints::UnList, CLType::Unit)
+				.arg(NFTContractArgs::TokenId, String::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(NFTContractEndpoints::Buy, CLType::Unit)
+				.arg(NFTContractArgs::DomainName, String::cl_type())
+				.arg(NFTContractArgs::TokenId, String::cl_type())
+				.arg(NFTContractArgs::SourceKey, Key::cl_type())
+				.arg(NFTContractArgs::Amount, U512::cl_type())
+				// Either the purse or the accepted CEP-18 token pays
+				.optional_arg(NFTContractArgs::CustomerPurse, URef::cl_type())
+				.optional_arg(NFTContractArgs::PaymentToken, ContractHash::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				NFTContractEndpoints::SetTokenExpiration,
+				CLType::Unit
+			)
+				.arg(NFTContractArgs::DomainName, String::cl_type())
+				.arg(NFTContractArgs::ExpirationDate, u64::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				NFTContractEndpoints::SetRoyaltyPolicy,
+				CLType::Unit
+			)
+				.arg(NFTContractArgs::Extension, String::cl_type())
+				.arg(NFTContractArgs::RoyaltyShare, u32::cl_type())
+				.access(
+					vec![
+						ControllerRoles::OnlyMaintainer,
+						ControllerRoles::OnlyRole(Role::MarketplaceOperator)
+					]
+				)
+		)
+		// The creator of the token is checked by the service
+		.entry_point(
+			EntryPointSchema::new(
+				NFTContractEndpoints::SetTokenRoyalty,
+				CLType::Unit
+			)
+				.arg(NFTContractArgs::TokenId, String::cl_type())
+				.arg(NFTContractArgs::RoyaltyShare, u32::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				NFTContractEndpoints::RoyaltyInfo,
+				Vec::<(Key, U512)>::cl_type()
+			)
+				.arg(NFTContractArgs::TokenId, String::cl_type())
+				.arg(NFTContractArgs::SalePrice, U512::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				NFTContractEndpoints::GetOwnedTokens,
+				Vec::<(String, Option<String>)>::cl_type()
+			)
+				.arg(NFTContractArgs::Owner, Key::cl_type())
+				.arg(NFTContractArgs::Page, u64::cl_type())
+		)
+		// Public, the service checks the owner or an approved operator
+		.entry_point(
+			EntryPointSchema::new(NFTContractEndpoints::StartAuction, CLType::Unit)
+				.arg(NFTContractArgs::DomainName, String::cl_type())
+				.arg(NFTContractArgs::ReservePrice, U512::cl_type())
+				.arg(NFTContractArgs::MinBidIncrement, U512::cl_type())
+				.arg(NFTContractArgs::Duration, u64::cl_type())
+		)
+		// Public, the service checks the seller or an approved operator
+		.entry_point(
+			EntryPointSchema::new(NFTContractEndpoints::CancelAuction, CLType::Unit)
+				.arg(NFTContractArgs::DomainName, String::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(NFTContractEndpoints::Bid, CLType::Unit)
+				.arg(NFTContractArgs::DomainName, String::cl_type())
+				.arg(NFTContractArgs::Amount, U512::cl_type())
+				.arg(NFTContractArgs::CustomerPurse, URef::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(NFTContractEndpoints::SettleAuction, CLType::Unit)
+				.arg(NFTContractArgs::DomainName, String::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				NFTContractEndpoints::GetAuction,
+				Option::<Auction>::cl_type()
+			)
+				.arg(NFTContractArgs::DomainName, String::cl_type())
+		)
+		.with_authorities()
+		.entry_point(
+			EntryPointSchema::new(CommonEndpoints::Migrate, CLType::U32)
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.event::<RoyaltyPaid>()
+		.event::<BidPlaced>()
+		.event::<AuctionSettled>()
+		.errors(NFTErrors::TABLE)
+		.errors(CommonError::TABLE)
+		.errors(AuthorityErrors::TABLE)
+}
@@ -0,0 +1,104 @@
+use alloc::{ string::String, vec, vec::Vec };
+use casper_types::{ CLType, CLTyped, ContractHash, Key, U512 };
+
+use crate::{
+	constants::common_keys::{
+		CommonEndpoints,
+		PriceOracleArgs,
+		ENDPOINT_PO_GET_PAYMENT_TOKEN,
+		ENDPOINT_PO_GET_PRICE,
+		ENDPOINT_PO_INIT,
+		ENDPOINT_PO_PRICE_GET_SIMPLE_OPERATIONS,
+		ENDPOINT_PO_PRICE_SET_SIMPLE_OPERATIONS,
+		ENDPOINT_PO_REMOVE_PAYMENT_TOKEN,
+		ENDPOINT_PO_SET_PAYMENT_TOKEN,
+		ENDPOINT_PO_SET_PRICE,
+	},
+	enums::{
+		contracts_enum::ContractKind,
+		controller_roles::ControllerRoles,
+		price_oracle_contract::PriceType,
+		roles::Role,
+	},
+	errors::{ CommonError, PriceOracleContractErrors },
+	models::payment_token::PaymentToken,
+};
+
+use super::{ ContractSchema, EntryPointSchema };
+
+/// Price oracle keeps the prices per extension, `get_price` takes the chars count as `u64`
+/// and the rates of the accepted CEP-18 payment tokens.
+/// Prices are changed only by the administration contract executing a timelocked proposal
+pub fn schema() -> ContractSchema {
+	ContractSchema::new("price-oracle")
+		.entry_point(
+			EntryPointSchema::new(ENDPOINT_PO_INIT, CLType::Unit)
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.entry_point(
+			EntryPointSchema::new(ENDPOINT_PO_SET_PRICE, CLType::Unit)
+				.arg(PriceOracleArgs::Extension, String::cl_type())
+				.arg(PriceOracleArgs::PriceType, PriceType::cl_type())
+				.arg(PriceOracleArgs::Price, U512::cl_type())
+				.optional_arg(PriceOracleArgs::PriceMid, Vec::<U512>::cl_type())
+				.optional_arg(
+					PriceOracleArgs::CharsCount,
+					Vec::<u64>::cl_type()
+				)
+				.optional_arg(PriceOracleArgs::PriceMore, U512::cl_type())
+				.access(
+					vec![ControllerRoles::OnlyContract(ContractKind::Administration)]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(ENDPOINT_PO_GET_PRICE, U512::cl_type())
+				.arg(PriceOracleArgs::Extension, String::cl_type())
+				.arg(PriceOracleArgs::CharsCount, u64::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				ENDPOINT_PO_PRICE_GET_SIMPLE_OPERATIONS,
+				U512::cl_type()
+			)
+				.arg(PriceOracleArgs::Extension, String::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				ENDPOINT_PO_PRICE_SET_SIMPLE_OPERATIONS,
+				CLType::Unit
+			)
+				.arg(PriceOracleArgs::Extension, String::cl_type())
+				.arg(PriceOracleArgs::Price, U512::cl_type())
+				.access(
+					vec![ControllerRoles::OnlyContract(ContractKind::Administration)]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(ENDPOINT_PO_SET_PAYMENT_TOKEN, CLType::Unit)
+				.arg(PriceOracleArgs::PaymentToken, ContractHash::cl_type())
+				.arg(PriceOracleArgs::Rate, U512::cl_type())
+				.arg(PriceOracleArgs::Treasury, Key::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::PriceSetter)])
+		)
+		.entry_point(
+			EntryPointSchema::new(ENDPOINT_PO_REMOVE_PAYMENT_TOKEN, CLType::Unit)
+				.arg(PriceOracleArgs::PaymentToken, ContractHash::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::PriceSetter)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				ENDPOINT_PO_GET_PAYMENT_TOKEN,
+				Option::<PaymentToken>::cl_type()
+			)
+				.arg(PriceOracleArgs::PaymentToken, ContractHash::cl_type())
+				.access(vec![ControllerRoles::OnlyAuthorizedContracts])
+		)
+		.entry_point(
+			EntryPointSchema::new(CommonEndpoints::Migrate, CLType::U32)
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.errors(PriceOracleContractErrors::TABLE)
+		.errors(CommonError::TABLE)
+}
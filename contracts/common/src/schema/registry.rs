@@ -0,0 +1,118 @@
+use alloc::{ string::String, vec, vec::Vec };
+use casper_types::{ account::AccountHash, CLType, CLTyped, ContractHash };
+
+use crate::{
+	constants::common_keys::{ CommonEndpoints, RegistryArgs, RegistryEndpoints },
+	enums::controller_roles::ControllerRoles,
+	errors::{ CommonError, RegistryErrors },
+	models::registry_contract_hash_pair::RegistryContractHashPair,
+};
+
+use super::{ ContractSchema, EntryPointSchema };
+
+/// Registry routes names to their database and nft contracts
+pub fn schema() -> ContractSchema {
+	ContractSchema::new("registry")
+		.entry_point(
+			EntryPointSchema::new(
+				RegistryEndpoints::MapDomainNameToContractHash,
+				CLType::Unit
+			)
+				.arg(RegistryArgs::DomainName, String::cl_type())
+				.arg(
+					RegistryArgs::DatabaseContractHash,
+					ContractHash::cl_type()
+				)
+				.arg(RegistryArgs::NftContractHash, ContractHash::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				RegistryEndpoints::UnmapDomainName,
+				CLType::Unit
+			)
+				.arg(RegistryArgs::DomainName, String::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				RegistryEndpoints::GetContractHashForDomainName,
+				Option::<RegistryContractHashPair>::cl_type()
+			)
+				.arg(RegistryArgs::DomainName, String::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				RegistryEndpoints::RemapDomainNames,
+				CLType::Unit
+			)
+				.arg(RegistryArgs::DomainNames, Vec::<String>::cl_type())
+				.arg(
+					RegistryArgs::DatabaseContractHash,
+					ContractHash::cl_type()
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				RegistryEndpoints::ResolveRoutes,
+				Vec::<Option<RegistryContractHashPair>>::cl_type()
+			)
+				.arg(RegistryArgs::DomainNames, Vec::<String>::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				RegistryEndpoints::AddToWhitelist,
+				CLType::Unit
+			)
+				.arg(RegistryArgs::ContractHash, ContractHash::cl_type())
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				RegistryEndpoints::RemoveFromWhitelist,
+				CLType::Unit
+			)
+				.arg(RegistryArgs::ContractHash, ContractHash::cl_type())
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				RegistryEndpoints::GetWhitelist,
+				Vec::<ContractHash>::cl_type()
+			)
+		)
+		.entry_point(
+			EntryPointSchema::new(RegistryEndpoints::AddOperator, CLType::Unit)
+				.arg(RegistryArgs::Operator, AccountHash::cl_type())
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				RegistryEndpoints::RemoveOperator,
+				CLType::Unit
+			)
+				.arg(RegistryArgs::Operator, AccountHash::cl_type())
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				RegistryEndpoints::GetOperators,
+				Vec::<AccountHash>::cl_type()
+			)
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				RegistryEndpoints::GetNameForToken,
+				Option::<String>::cl_type()
+			)
+				.arg(RegistryArgs::TokenId, String::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(RegistryEndpoints::Init, CLType::Unit)
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.entry_point(
+			EntryPointSchema::new(CommonEndpoints::Migrate, CLType::U32)
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.errors(RegistryErrors::TABLE)
+		.errors(CommonError::TABLE)
+}
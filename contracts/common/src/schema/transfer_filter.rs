@@ -0,0 +1,37 @@
+use alloc::{ string::String, vec };
+use casper_types::{ CLType, CLTyped, Key };
+
+use crate::{
+	constants::common_keys::{
+		CommonEndpoints,
+		TransferFilterArgs,
+		TransferFilterEndpoints,
+	},
+	enums::{ controller_roles::ControllerRoles, transfer_filter::TransferDenial },
+	errors::CommonError,
+};
+
+use super::{ ContractSchema, EntryPointSchema };
+
+/// Transfer filter of nft-core, denies transfers of expired, locked and fused names
+pub fn schema() -> ContractSchema {
+	ContractSchema::new("transfer-filter")
+		.entry_point(
+			EntryPointSchema::new(TransferFilterEndpoints::CanTransfer, CLType::U8)
+				.arg(TransferFilterArgs::SourceKey, Key::cl_type())
+				.arg(TransferFilterArgs::TargetKey, Key::cl_type())
+				.arg(TransferFilterArgs::TokenId, String::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				TransferFilterEndpoints::GetTransferDenial,
+				Option::<TransferDenial>::cl_type()
+			)
+				.arg(TransferFilterArgs::TokenId, String::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(CommonEndpoints::Migrate, CLType::U32)
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.errors(CommonError::TABLE)
+}
@@ -0,0 +1,74 @@
+use alloc::{ vec, vec::Vec };
+use casper_types::{ account::AccountHash, CLType, CLTyped, URef, U512 };
+
+use crate::{
+	constants::common_keys::{ CommonEndpoints, TreasuryArgs, TreasuryEndpoints },
+	enums::{
+		contracts_enum::ContractKind,
+		controller_roles::ControllerRoles,
+		revenue_stream::RevenueStream,
+		roles::Role,
+	},
+	errors::{ CommonError, TreasuryErrors },
+	models::treasury::Beneficiary,
+};
+
+use super::{ ContractSchema, EntryPointSchema };
+
+/// Treasury keeps the fees in its purse, splits every deposit among the beneficiaries of the revenue stream
+/// (or holds it until they are set) and lets them withdraw their balances
+pub fn schema() -> ContractSchema {
+	ContractSchema::new("treasury")
+		.entry_point(
+			EntryPointSchema::new(TreasuryEndpoints::Init, CLType::Unit)
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.entry_point(
+			EntryPointSchema::new(TreasuryEndpoints::Deposit, CLType::Unit)
+				.arg(TreasuryArgs::RevenueStream, RevenueStream::cl_type())
+				.arg(TreasuryArgs::Amount, U512::cl_type())
+				.arg(TreasuryArgs::Purse, URef::cl_type())
+				.access(
+					vec![
+						ControllerRoles::OnlyContract(ContractKind::Main),
+						ControllerRoles::OnlyContract(ContractKind::NFT)
+					]
+				)
+		)
+		.entry_point(
+			EntryPointSchema::new(TreasuryEndpoints::SetBeneficiaries, CLType::Unit)
+				.arg(TreasuryArgs::RevenueStream, RevenueStream::cl_type())
+				.arg(TreasuryArgs::Beneficiaries, Vec::<Beneficiary>::cl_type())
+				.access(vec![ControllerRoles::OnlyRole(Role::TreasuryManager)])
+		)
+		.entry_point(
+			EntryPointSchema::new(
+				TreasuryEndpoints::GetBeneficiaries,
+				Vec::<Beneficiary>::cl_type()
+			)
+				.arg(TreasuryArgs::RevenueStream, RevenueStream::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(TreasuryEndpoints::GetRevenue, U512::cl_type())
+				.arg(TreasuryArgs::RevenueStream, RevenueStream::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(TreasuryEndpoints::GetBalance, U512::cl_type())
+				.arg(TreasuryArgs::RevenueStream, RevenueStream::cl_type())
+				.arg(TreasuryArgs::Beneficiary, AccountHash::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(TreasuryEndpoints::GetUndistributed, U512::cl_type())
+				.arg(TreasuryArgs::RevenueStream, RevenueStream::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(TreasuryEndpoints::Withdraw, U512::cl_type())
+				.arg(TreasuryArgs::RevenueStream, RevenueStream::cl_type())
+		)
+		.entry_point(
+			EntryPointSchema::new(CommonEndpoints::Migrate, CLType::U32)
+				.access(vec![ControllerRoles::OnlyMaintainer])
+		)
+		.errors(CommonError::TABLE)
+		.errors(TreasuryErrors::TABLE)
+}
@@ -1,8 +1,5 @@
 use alloc::{ vec::Vec, string::ToString };
-use casper_contract::{
-	contract_api::runtime,
-	unwrap_or_revert::UnwrapOrRevert,
-};
+use casper_contract::contract_api::runtime;
 use casper_types::Key;
 
 use crate::{
@@ -10,7 +7,7 @@ use crate::{
 	db::store::Store,
 	utils::authority::ensure_caller_has_permission,
 	errors::CommonError,
-	types::types::CResult,
+	types::CResult,
 };
 
 pub fn set_authorites() -> CResult<()> {
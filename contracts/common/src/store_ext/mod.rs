@@ -1 +1,2 @@
 pub mod maintainer;
+pub mod roles;
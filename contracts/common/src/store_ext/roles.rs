@@ -0,0 +1,39 @@
+use alloc::{ format, vec::Vec, vec };
+use casper_types::Key;
+
+use crate::{
+	db::{ store::Store, traits::Storable },
+	constants::common_keys::CommonKeys,
+	enums::roles::Role,
+};
+
+/// Role members are stored by the administration contract only
+impl Store {
+	pub fn get_role_members(&self, role: Role) -> Vec<Key> {
+		self.get(&to_role_key(role)).unwrap_or(vec![])
+	}
+
+	pub fn has_role(&self, role: Role, account: &Key) -> bool {
+		self.get_role_members(role).contains(account)
+	}
+
+	pub fn grant_role(&self, role: Role, account: Key) {
+		let mut members = self.get_role_members(role);
+		if !members.contains(&account) {
+			members.push(account);
+			self.set(&to_role_key(role), members);
+		}
+	}
+
+	pub fn revoke_role(&self, role: Role, account: &Key) {
+		let mut members = self.get_role_members(role);
+		if let Some(position) = members.iter().position(|item| item == account) {
+			members.remove(position);
+			self.set(&to_role_key(role), members);
+		}
+	}
+}
+
+fn to_role_key(role: Role) -> alloc::string::String {
+	format!("{}:{}", CommonKeys::Roles, role)
+}
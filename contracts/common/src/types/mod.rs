@@ -1 +1,3 @@
-pub mod types;
+use crate::errors::CommonError;
+
+pub type CResult<T> = Result<T, CommonError>;
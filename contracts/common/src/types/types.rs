@@ -1,3 +0,0 @@
-use crate::errors::CommonError;
-
-pub type CResult<T> = Result<T, CommonError>;
@@ -50,15 +50,8 @@ pub fn account_has_access_for_contract(operators_inclusively: bool) -> bool {
                 ARG_REGISTRY_OPERATOR_TYPE => caller,
             }
 		);
-		match operators.iter().position(|x| x == &caller.into()) {
-			Some(_) => {
-				return true;
-			}
-			None => {
-				return false;
-			}
-		}
+		return operators.iter().any(|x| x == &caller.into());
 	}
 
-	return false;
+	false
 }
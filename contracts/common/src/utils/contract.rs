@@ -1,4 +1,4 @@
-use alloc::{ string::ToString, vec::Vec };
+use alloc::string::{ String, ToString };
 use casper_contract::{
 	contract_api::{ runtime, storage },
 	unwrap_or_revert::UnwrapOrRevert,
@@ -6,13 +6,16 @@ use casper_contract::{
 use casper_types::{
 	contracts::{ NamedKeys, Parameters },
 	runtime_args,
+	system::CallStackElement,
 	CLType,
 	ContractHash,
+	ContractPackageHash,
 	EntryPoint,
 	EntryPointAccess,
 	EntryPointType,
 	EntryPoints,
 	Key,
+	RuntimeArgs,
 };
 
 use crate::{
@@ -23,11 +26,21 @@ use crate::{
 		KEY_CONTRACT_ACCESS,
 		KEY_CONTRACT_PACKAGE_NAME,
 	},
-	enums::caller_verification_type::CallerVerificationType,
+	db::store::Store,
+	enums::{
+		caller_verification_type::CallerVerificationType,
+		contracts_enum::ContractKind,
+		roles::Role,
+	},
 	errors::CommonError,
 };
 
-use super::{ maintainer::is_caller_maintainer, registry::get_verified_caller };
+use super::{
+	maintainer::is_caller_maintainer,
+	registry::get_verified_caller,
+	roles::has_role,
+	upgrade::{ is_upgrade, register_contract_package, upgrade_contract },
+};
 
 pub fn create_entrypoint(
 	key: &str,
@@ -51,10 +64,15 @@ pub fn create_contract(
 	)
 }
 
+/// Installs a new contract package, or adds a new version to the package passed by `package_hash` argument
 pub fn setup_contract_info(
 	entrypoints: EntryPoints,
 	mut named_keys: NamedKeys
 ) {
+	if is_upgrade() {
+		return upgrade_contract(entrypoints);
+	}
+
 	let maintainer_uref = storage::new_uref(runtime::get_caller());
 	named_keys.insert(CommonKeys::Maintainer.to_string(), maintainer_uref.into());
 
@@ -73,54 +91,97 @@ pub fn setup_contract_info(
 		&CommonKeys::ContractVersion.to_string(),
 		contract_version_uref.into()
 	);
+
+	let package_hash = runtime
+		::get_key(KEY_CONTRACT_PACKAGE_NAME)
+		.and_then(|key| key.into_hash())
+		.map(ContractPackageHash::new)
+		.unwrap_or_revert_with(CommonError::MissingContractPackage);
+	register_contract_package(package_hash, contract_hash, KEY_CONTRACT_ACCESS);
 }
 
 pub fn get_current_contract_hash() -> ContractHash {
 	runtime
 		::get_key(&CommonKeys::ContractHash.to_string())
 		.unwrap_or_revert_with(CommonError::MissingContractHash)
-		.into()
+		.into_hash()
+		.map(ContractHash::new)
+		.unwrap_or_revert_with(CommonError::MissingContractHash)
 }
 
+/// Hash of the contract being executed, taken from the call stack
+pub fn get_called_contract_hash() -> Option<ContractHash> {
+	match runtime::get_call_stack().last() {
+		Some(CallStackElement::StoredContract { contract_hash, .. }) =>
+			Some(*contract_hash),
+		_ => None,
+	}
+}
+
+/// Contracts keep the administration hash passed on install under `CommonKeys::AdministrationContract`
 pub fn get_administration_contract_hash() -> ContractHash {
-	runtime
-		::get_key(&CommonKeys::AdministrationContract.to_string())
+	Store::instance()
+		.get_administration_contract_hash()
 		.unwrap_or_revert_with(CommonError::MissingAdministrationContractHash)
-		.into()
 }
 
-pub fn ensure_caller_has_permission_external(is_contract: Option<bool>) {
-	if !is_caller_maintainer() {
-		let administration_contract_hash: ContractHash = runtime
-			::get_key(&CommonKeys::AdministrationContract.to_string())
-			.unwrap_or_revert_with(CommonError::MissingAdministrationContractHash)
-			.into();
-
-		let current_hash = get_current_contract_hash();
-
-		let authorities: Vec<Key> = runtime::call_contract(
-			administration_contract_hash,
-			&AdministrationEndpoints::GetContractAuthorityList.to_string(),
-			runtime_args! {
-			AdministrationArgs::ContractHash => current_hash
+/// The caller has to be the contract the administration directory holds for the simple contract kind,
+/// unlike the authority list it can't be widened by adding more contracts
+pub fn is_caller_contract(kind: ContractKind) -> bool {
+	let caller = match get_verified_caller(CallerVerificationType::OnlyContractHash) {
+		Ok(caller) => caller,
+		Err(_) => {
+			return false;
 		}
-		);
+	};
+	// Administration is not listed in its own directory, every contract keeps its hash
+	if kind == ContractKind::Administration {
+		return caller == get_administration_contract_hash().into();
+	}
+	let (key, _): (Key, Option<u32>) = runtime::call_contract(
+		get_administration_contract_hash(),
+		&AdministrationEndpoints::GetContract.to_string(),
+		runtime_args! {
+			AdministrationArgs::ContractKind.to_string() => kind,
+			AdministrationArgs::Extension.to_string() => Option::<String>::None,
+		}
+	);
+	key == caller
+}
 
-		let mut ver_type = CallerVerificationType::All;
+pub fn ensure_caller_has_permission_external(is_contract: Option<bool>) {
+	if !caller_has_permission_external(is_contract) {
+		runtime::revert(CommonError::InvalidCaller);
+	}
+}
 
-		if let Some(v) = is_contract {
-			ver_type = if v {
-				CallerVerificationType::OnlyContractHash
-			} else {
-				CallerVerificationType::OnlyAccountHash
-			};
-		}
+/// Contract-to-contract wiring: the caller has to hold the SYSTEM_CONTRACT role kept by the administration contract,
+/// the administration contract itself is always passed. Roles are read from the administration contract on every check (see `utils::roles`).
+pub fn caller_has_permission_external(is_contract: Option<bool>) -> bool {
+	if is_caller_maintainer() {
+		return true;
+	}
 
-		let caller = get_verified_caller(ver_type).unwrap();
+	let mut ver_type = CallerVerificationType::All;
 
-		authorities
-			.iter()
-			.find(|key| *key == &caller)
-			.unwrap_or_revert_with(CommonError::InvalidCaller);
+	if let Some(v) = is_contract {
+		ver_type = if v {
+			CallerVerificationType::OnlyContractHash
+		} else {
+			CallerVerificationType::OnlyAccountHash
+		};
 	}
+
+	let caller = match get_verified_caller(ver_type) {
+		Ok(caller) => caller,
+		Err(_) => {
+			return false;
+		}
+	};
+
+	let is_administration = Store::instance()
+		.get_administration_contract_hash()
+		.map(|hash| Key::from(hash) == caller)
+		.unwrap_or(false);
+	is_administration || has_role(Role::SystemContract, caller)
 }
@@ -1,5 +1,5 @@
 use crate::{
-    constants::common_keys::{EXTENSION, GRACE_PERIOD, YEAR_IN_MILLIS},
+    constants::common_keys::{EXTENSION, YEAR_IN_MILLIS},
     enums::domain_name_actual_state::DomainNameActualState,
 };
 use alloc::{string::String, vec::Vec};
@@ -40,20 +40,27 @@ pub fn year_to_millis(year: u8) -> u64 {
     (year as u64) * YEAR_IN_MILLIS
 }
 
-pub fn is_extension_duration_correct(actual_end_time: u64, duration: u64) -> bool {
+pub fn is_extension_duration_correct(
+    actual_end_time: u64,
+    duration: u64,
+    max_subscription_years: u8,
+) -> bool {
     let current_time: u64 = runtime::get_blocktime().into();
 
     let diff = actual_end_time + duration - current_time;
 
-    diff < 3 * YEAR_IN_MILLIS
+    diff < year_to_millis(max_subscription_years)
 }
 
-pub fn get_end_time_actual_state(end_time: Option<u64>) -> DomainNameActualState {
+pub fn get_end_time_actual_state(
+    end_time: Option<u64>,
+    grace_period: u64,
+) -> DomainNameActualState {
     if let Some(et) = end_time {
         let current_time: u64 = runtime::get_blocktime().into();
         if et >= current_time {
             return DomainNameActualState::Busy;
-        } else if et < current_time && et + GRACE_PERIOD > current_time {
+        } else if et < current_time && et + grace_period > current_time {
             return DomainNameActualState::GracePeriod;
         }
     }
@@ -65,3 +72,12 @@ pub fn get_domain_name_chars_count(domain: &str) -> usize {
     let main_part = split.first().unwrap();
     main_part.len()
 }
+
+pub fn get_domain_name_extension(domain: &str) -> Option<String> {
+    domain.rsplit('.').next().map(String::from)
+}
+
+/// Token of the name in nft-core (hash identifier mode), also kept in `DomainName::token_id`
+pub fn get_token_id(name: &str) -> String {
+    base16::encode_lower(&runtime::blake2b(name))
+}
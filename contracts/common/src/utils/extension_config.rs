@@ -0,0 +1,32 @@
+use alloc::string::ToString;
+use casper_contract::contract_api::runtime;
+use casper_types::{ runtime_args, Key, RuntimeArgs };
+
+use crate::{
+	constants::common_keys::{ AdministrationArgs, AdministrationEndpoints },
+	models::confiugration::ExtensionConfig,
+};
+
+use super::contract::get_administration_contract_hash;
+
+/// Fetches configuration of the extension from the administration contract
+pub fn get_extension_config(extension: &str) -> ExtensionConfig {
+	runtime::call_contract(
+		get_administration_contract_hash(),
+		&AdministrationEndpoints::GetExtensionConfig.to_string(),
+		runtime_args! {
+			AdministrationArgs::Extension.to_string() => Some(extension.to_string()),
+		}
+	)
+}
+
+pub fn is_allowlisted(extension: &str, account: Key) -> bool {
+	runtime::call_contract(
+		get_administration_contract_hash(),
+		&AdministrationEndpoints::IsAllowlisted.to_string(),
+		runtime_args! {
+			AdministrationArgs::Extension.to_string() => Some(extension.to_string()),
+			AdministrationArgs::Account.to_string() => account,
+		}
+	)
+}
@@ -5,6 +5,7 @@ use crate::{
 		CONTRACT_MAINTAINER_KEY,
 		CONTRACT_PACKAGE_NAME_KEY,
 		CONTRACT_VERSION_KEY,
+		CommonKeys,
 	},
 	errors::CommonError,
 };
@@ -13,7 +14,7 @@ use casper_contract::{
 	contract_api::{ runtime, storage },
 	unwrap_or_revert::UnwrapOrRevert,
 };
-use casper_types::{ EntryPoints, Key };
+use casper_types::{ account::AccountHash, EntryPoints, Key };
 
 use super::storage::{ get_stored_value_from_key, store_value_for_key };
 
@@ -37,11 +38,15 @@ pub fn create_new_contract(
 	store_value_for_key(CONTRACT_VERSION_KEY, contract_version);
 }
 
+/// Contracts installed by `setup_contract_info` keep the maintainer account under `CommonKeys::Maintainer`,
+/// the ones installed by `create_new_contract` under `CONTRACT_MAINTAINER_KEY`
 pub fn is_maintainer(key: &Key) -> bool {
-	let maintainer = get_stored_value_from_key::<Key>(
-		CONTRACT_MAINTAINER_KEY
-	).unwrap_or_revert_with(CommonError::NoAuthority);
-	&maintainer == key
+	let maintainer = get_stored_value_from_key::<AccountHash>(
+		&CommonKeys::Maintainer.to_string()
+	)
+		.or_else(|| get_stored_value_from_key::<AccountHash>(CONTRACT_MAINTAINER_KEY))
+		.unwrap_or_revert_with(CommonError::NoAuthority);
+	&Key::from(maintainer) == key
 }
 
 pub fn is_caller_maintainer() -> bool {
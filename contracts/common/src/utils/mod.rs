@@ -2,8 +2,15 @@ pub mod access;
 pub mod authority;
 pub mod contract;
 pub mod domain_name;
+pub mod extension_config;
 pub mod helpers;
 pub mod maintainer;
+pub mod pause;
+pub mod payment;
 pub mod registry;
 pub mod response;
+pub mod routes;
 pub mod storage;
+pub mod treasury;
+pub mod upgrade;
+pub mod roles;
@@ -0,0 +1,37 @@
+use alloc::string::{ String, ToString };
+use casper_contract::contract_api::runtime;
+use casper_types::{ runtime_args, RuntimeArgs };
+
+use crate::{
+	constants::common_keys::{ AdministrationArgs, AdministrationEndpoints },
+	db::store::Store,
+	enums::contracts_enum::ContractKind,
+	errors::CommonError,
+};
+
+use super::response::response_error;
+
+/// Reverts with CommonError::ContractIsPaused if the whole system, the contract kind or the extension is paused.
+/// Must be called by mutating entry points only, read-only queries stay available during a pause
+pub fn ensure_not_paused(kind: ContractKind, extension: Option<String>) {
+	if is_paused(kind, extension) {
+		response_error(CommonError::ContractIsPaused);
+	}
+}
+
+/// Read on every check, so a pause set earlier in the same call is seen
+pub fn is_paused(kind: ContractKind, extension: Option<String>) -> bool {
+	match Store::instance().get_administration_contract_hash() {
+		Some(administration_contract_hash) =>
+			runtime::call_contract(
+				administration_contract_hash,
+				&AdministrationEndpoints::IsPaused.to_string(),
+				runtime_args! {
+					AdministrationArgs::ContractKind.to_string() => kind,
+					AdministrationArgs::Extension.to_string() => extension,
+				}
+			),
+		// Contract is not wired with the administration yet
+		None => false,
+	}
+}
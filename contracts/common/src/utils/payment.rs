@@ -0,0 +1,62 @@
+use alloc::string::{ String, ToString };
+use casper_contract::{ contract_api::runtime, unwrap_or_revert::UnwrapOrRevert };
+use casper_types::{ runtime_args, ContractHash, Key, RuntimeArgs, U512 };
+
+use crate::{
+	constants::common_keys::{
+		AdministrationArgs,
+		AdministrationEndpoints,
+		Cep18Args,
+		Cep18Endpoints,
+		PriceOracleArgs,
+		ENDPOINT_PO_GET_PAYMENT_TOKEN,
+	},
+	enums::contracts_enum::ContractKind,
+	errors::CommonError,
+	models::payment_token::{ to_cep18_amount, PaymentToken },
+};
+
+use super::contract::get_administration_contract_hash;
+
+/// Only the tokens the price oracle holds a rate for are accepted
+pub fn get_payment_token(token: ContractHash) -> PaymentToken {
+	let payment_token: Option<PaymentToken> = runtime::call_contract(
+		get_price_oracle_contract_hash(),
+		ENDPOINT_PO_GET_PAYMENT_TOKEN,
+		runtime_args! {
+			PriceOracleArgs::PaymentToken.to_string() => token,
+		}
+	);
+	payment_token.unwrap_or_revert_with(CommonError::PaymentTokenIsNotAccepted)
+}
+
+/// Moves the tokens the owner approved the calling contract to spend, CEP-18 reverts if the allowance or the balance is short
+pub fn transfer_from(token: ContractHash, owner: Key, recipient: Key, amount: U512) {
+	let amount = to_cep18_amount(amount).unwrap_or_revert_with(
+		CommonError::PaymentAmountOverflow
+	);
+	runtime::call_contract::<()>(
+		token,
+		&Cep18Endpoints::TransferFrom.to_string(),
+		runtime_args! {
+			Cep18Args::Owner.to_string() => owner,
+			Cep18Args::Recipient.to_string() => recipient,
+			Cep18Args::Amount.to_string() => amount,
+		}
+	);
+}
+
+pub fn get_price_oracle_contract_hash() -> ContractHash {
+	let (key, _): (Key, Option<u32>) = runtime::call_contract(
+		get_administration_contract_hash(),
+		&AdministrationEndpoints::GetContract.to_string(),
+		runtime_args! {
+			AdministrationArgs::ContractKind.to_string() => ContractKind::PriceOracle,
+			AdministrationArgs::Extension.to_string() => Option::<String>::None,
+		}
+	);
+	key
+		.into_hash()
+		.map(ContractHash::new)
+		.unwrap_or_revert_with(CommonError::UnexpectedKeyVariant)
+}
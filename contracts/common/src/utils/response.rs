@@ -10,8 +10,9 @@ use crate::{
 
 use super::{
 	authority::ensure_caller_has_permission,
-	contract::ensure_caller_has_permission_external,
+	contract::{ caller_has_permission_external, is_caller_contract },
 	maintainer::is_caller_maintainer,
+	roles::caller_has_role,
 };
 
 pub fn response_success_default<T: ToBytes + CLTyped>(arg: T) {
@@ -30,27 +31,9 @@ pub fn response_error<T: Into<ApiError>>(error: T) {
 pub fn controller<F, T, E>(handler: F, access: Vec<ControllerRoles>)
 	where F: Fn() -> Result<T, E>, T: ToBytes + CLTyped, E: Into<ApiError>
 {
-	let mut passed = false;
-	if access.contains(&ControllerRoles::OnlyMaintainer) {
-		if !is_caller_maintainer() {
-			response_error(CommonError::InvalidCaller);
-			return;
-		}
-		passed = true;
-	}
-
-	if !passed && access.contains(&ControllerRoles::OnlyAuthorizedCallers) {
-		ensure_caller_has_permission_external(None);
-		passed = true;
-	}
-
-	if !passed && access.contains(&ControllerRoles::OnlyAuthorizedContracts) {
-		ensure_caller_has_permission_external(Some(true));
-		passed = true;
-	}
-
-	if !passed && access.contains(&ControllerRoles::OnlyLocalOperators) {
-		ensure_caller_has_permission().unwrap();
+	if !access.is_empty() && !access.iter().any(|role| has_access(*role)) {
+		response_error(CommonError::InvalidCaller);
+		return;
 	}
 
 	match handler() {
@@ -58,3 +41,17 @@ pub fn controller<F, T, E>(handler: F, access: Vec<ControllerRoles>)
 		Err(err) => { response_error(err) }
 	}
 }
+
+/// Every check is independent from the others, so the order of declared roles does not matter
+fn has_access(role: ControllerRoles) -> bool {
+	match role {
+		ControllerRoles::OnlyMaintainer => is_caller_maintainer(),
+		ControllerRoles::OnlyAuthorizedContracts =>
+			caller_has_permission_external(Some(true)),
+		ControllerRoles::OnlyLocalOperators =>
+			ensure_caller_has_permission().is_ok(),
+		ControllerRoles::OnlyRole(role) =>
+			is_caller_maintainer() || caller_has_role(role),
+		ControllerRoles::OnlyContract(kind) => is_caller_contract(kind),
+	}
+}
@@ -0,0 +1,37 @@
+use alloc::string::ToString;
+use casper_contract::{
+	contract_api::runtime,
+	unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{ runtime_args, Key, RuntimeArgs };
+
+use crate::{
+	constants::common_keys::{ AdministrationArgs, AdministrationEndpoints },
+	db::store::Store,
+	enums::{ caller_verification_type::CallerVerificationType, roles::Role },
+};
+
+use super::registry::get_verified_caller;
+
+pub fn caller_has_role(role: Role) -> bool {
+	let caller = get_verified_caller(CallerVerificationType::All).unwrap_or_revert();
+	has_role(role, caller)
+}
+
+/// Read on every check, so a grant or revoke earlier in the same call is seen
+pub fn has_role(role: Role, account: Key) -> bool {
+	let store = Store::instance();
+	match store.get_administration_contract_hash() {
+		Some(administration_contract_hash) =>
+			runtime::call_contract(
+				administration_contract_hash,
+				&AdministrationEndpoints::HasRole.to_string(),
+				runtime_args! {
+					AdministrationArgs::Role.to_string() => role,
+					AdministrationArgs::Account.to_string() => account,
+				}
+			),
+		// Administration contract itself keeps the roles
+		None => store.has_role(role, &account),
+	}
+}
@@ -0,0 +1,79 @@
+use alloc::string::{ String, ToString };
+use casper_contract::contract_api::runtime;
+use casper_types::{ runtime_args, ContractHash, Key, RuntimeArgs };
+
+use crate::{
+	constants::common_keys::{
+		AdministrationArgs,
+		AdministrationEndpoints,
+		RegistryArgs,
+		RegistryEndpoints,
+	},
+	enums::contracts_enum::ContractKind,
+	errors::CommonError,
+	models::registry_contract_hash_pair::RegistryContractHashPair,
+};
+
+use super::contract::get_administration_contract_hash;
+
+/// Registry hash is cached for the lifetime of a single call (wasm instance is not reused between calls),
+/// routes are not, a call can write or remove the route it has read
+static mut REGISTRY_CONTRACT_HASH_CACHE: Option<ContractHash> = None;
+
+/// Registry contract is the only router of names, its hash is kept in the administration directory
+pub fn get_registry_contract_hash() -> ContractHash {
+	if let Some(cached) = unsafe { REGISTRY_CONTRACT_HASH_CACHE } {
+		return cached;
+	}
+	let (key, _): (Key, Option<u32>) = runtime::call_contract(
+		get_administration_contract_hash(),
+		&AdministrationEndpoints::GetContract.to_string(),
+		runtime_args! {
+			AdministrationArgs::ContractKind.to_string() => ContractKind::Registry,
+			AdministrationArgs::Extension.to_string() => Option::<String>::None,
+		}
+	);
+	let contract_hash = key
+		.into_hash()
+		.map(ContractHash::new)
+		.unwrap_or_else(|| runtime::revert(CommonError::MissingRegistryHash));
+	unsafe {
+		REGISTRY_CONTRACT_HASH_CACHE = Some(contract_hash);
+	}
+	contract_hash
+}
+
+/// Database and NFT contract hashes the name is stored in
+pub fn get_route(name: &str) -> Option<RegistryContractHashPair> {
+	runtime::call_contract(
+		get_registry_contract_hash(),
+		&RegistryEndpoints::GetContractHashForDomainName.to_string(),
+		runtime_args! {
+			RegistryArgs::DomainName.to_string() => name.to_string(),
+		}
+	)
+}
+
+/// Caller has to be whitelisted in the registry contract
+pub fn set_route(name: &str, route: RegistryContractHashPair) {
+	runtime::call_contract::<()>(
+		get_registry_contract_hash(),
+		&RegistryEndpoints::MapDomainNameToContractHash.to_string(),
+		runtime_args! {
+			RegistryArgs::DomainName.to_string() => name.to_string(),
+			RegistryArgs::DatabaseContractHash.to_string() => route.db_contract_hash,
+			RegistryArgs::NftContractHash.to_string() => route.nft_contract_hash,
+		}
+	);
+}
+
+/// Caller has to be whitelisted in the registry contract
+pub fn remove_route(name: &str) {
+	runtime::call_contract::<()>(
+		get_registry_contract_hash(),
+		&RegistryEndpoints::UnmapDomainName.to_string(),
+		runtime_args! {
+			RegistryArgs::DomainName.to_string() => name.to_string(),
+		}
+	);
+}
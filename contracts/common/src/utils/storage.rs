@@ -33,10 +33,7 @@ pub fn get_stored_value_from_key<T: CLTyped + FromBytes>(name: &str) -> Option<T
 	match runtime::get_key(name) {
 		Some(key) => {
 			let key_ref = key.try_into().unwrap_or_revert();
-			match storage::read(key_ref) {
-				Ok(result) => result,
-				Err(_) => None,
-			}
+			storage::read(key_ref).unwrap_or_default()
 		}
 		None => None,
 	}
@@ -0,0 +1,48 @@
+use alloc::string::{ String, ToString };
+use casper_contract::{ contract_api::{ runtime, system }, unwrap_or_revert::UnwrapOrRevert };
+use casper_types::{ runtime_args, ContractHash, Key, RuntimeArgs, URef, U512 };
+
+use crate::{
+	constants::common_keys::{
+		AdministrationArgs,
+		AdministrationEndpoints,
+		TreasuryArgs,
+		TreasuryEndpoints,
+	},
+	enums::{ contracts_enum::ContractKind, revenue_stream::RevenueStream },
+	errors::CommonError,
+};
+
+use super::contract::get_administration_contract_hash;
+
+/// Moves the amount from the purse to the treasury and credits the beneficiaries of the stream
+/// The amount is moved to a purse created for the deposit first, the treasury never gets the purse of the payment
+/// (the customer purse or the auction escrow), only the main and nft contracts can deposit
+pub fn deposit(stream: RevenueStream, purse: URef, amount: U512) {
+	let deposit_purse = system::create_purse();
+	system::transfer_from_purse_to_purse(purse, deposit_purse, amount, None).unwrap_or_revert();
+	runtime::call_contract::<()>(
+		get_treasury_contract_hash(),
+		&TreasuryEndpoints::Deposit.to_string(),
+		runtime_args! {
+			TreasuryArgs::RevenueStream.to_string() => stream,
+			TreasuryArgs::Amount.to_string() => amount,
+			TreasuryArgs::Purse.to_string() => deposit_purse,
+		}
+	);
+}
+
+pub fn get_treasury_contract_hash() -> ContractHash {
+	let (key, _): (Key, Option<u32>) = runtime::call_contract(
+		get_administration_contract_hash(),
+		&AdministrationEndpoints::GetContract.to_string(),
+		runtime_args! {
+			AdministrationArgs::ContractKind.to_string() => ContractKind::Treasury,
+			AdministrationArgs::Extension.to_string() => Option::<String>::None,
+		}
+	);
+	key
+		.into_hash()
+		.map(ContractHash::new)
+		.unwrap_or_revert_with(CommonError::UnexpectedKeyVariant)
+}
@@ -0,0 +1,186 @@
+use alloc::{ format, string::{ String, ToString }, vec::Vec };
+use casper_contract::{
+	contract_api::{ self, runtime, storage },
+	ext_ffi,
+	unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+	api_error,
+	bytesrepr::{ self, FromBytes },
+	contracts::NamedKeys,
+	runtime_args,
+	ContractHash,
+	ContractPackageHash,
+	EntryPoints,
+	Key,
+	RuntimeArgs,
+};
+
+use crate::{
+	constants::common_keys::{
+		AdministrationArgs,
+		AdministrationEndpoints,
+		CommonArgs,
+		CommonEndpoints,
+		CommonKeys,
+		KEY_CONTRACT_ACCESS,
+	},
+	errors::CommonError,
+};
+
+use super::{
+	contract::get_called_contract_hash,
+	storage::{ get_stored_value_from_key, store_value_for_key },
+};
+
+/// Stored state written before schema versions were introduced
+pub const INITIAL_SCHEMA_VERSION: u32 = 1;
+
+/// Session code installs a new package unless the package hash argument is passed
+pub fn is_upgrade() -> bool {
+	get_named_arg_size(&CommonArgs::PackageHash.to_string()).is_some()
+}
+
+/// Account keys are suffixed by the package hash, so installing another package
+/// under the shared key names does not lose the access uref of the previous one
+pub fn register_contract_package(
+	package_hash: ContractPackageHash,
+	contract_hash: ContractHash,
+	access_key_name: &str
+) {
+	let access_key = runtime
+		::get_key(access_key_name)
+		.unwrap_or_revert_with(CommonError::MissingContractPackage);
+	runtime::put_key(&to_package_key(KEY_CONTRACT_ACCESS, package_hash), access_key);
+	runtime::put_key(
+		&to_package_key(&CommonKeys::ContractHash.to_string(), package_hash),
+		contract_hash.into()
+	);
+}
+
+/// Adds a new contract version to the existing package and runs its `migrate` entry point.
+/// Named keys of the previous version are carried over, so the stored state stays in place.
+/// The administration contract is notified when its hash is passed, the upgrading account
+/// needs the SHARD_MANAGER role there.
+/// Parameters:
+/// - package_hash - required
+/// - administration_contract - optional
+pub fn upgrade_contract(entrypoints: EntryPoints) {
+	let package_hash: ContractPackageHash = runtime::get_named_arg(
+		&CommonArgs::PackageHash.to_string()
+	);
+	let package_contract_key = to_package_key(
+		&CommonKeys::ContractHash.to_string(),
+		package_hash
+	);
+	let previous_hash = runtime
+		::get_key(&package_contract_key)
+		.and_then(|key| key.into_hash())
+		.map(ContractHash::new);
+
+	let (contract_hash, contract_version) = storage::add_contract_version(
+		package_hash,
+		entrypoints,
+		NamedKeys::new()
+	);
+	runtime::call_contract::<u32>(
+		contract_hash,
+		&CommonEndpoints::Migrate.to_string(),
+		runtime_args! {}
+	);
+
+	runtime::put_key(&package_contract_key, contract_hash.into());
+	runtime::put_key(
+		&CommonKeys::ContractHash.to_string(),
+		storage::new_uref(contract_hash).into()
+	);
+	runtime::put_key(
+		&CommonKeys::ContractVersion.to_string(),
+		storage::new_uref(contract_version).into()
+	);
+
+	let administration_contract = get_optional_named_arg::<ContractHash>(
+		&CommonArgs::AdministrationContract.to_string()
+	);
+	if let (Some(previous_hash), Some(administration_contract)) = (
+		previous_hash,
+		administration_contract,
+	) {
+		runtime::call_contract::<()>(
+			administration_contract,
+			&AdministrationEndpoints::ContractUpgraded.to_string(),
+			runtime_args! {
+				AdministrationArgs::PreviousKey.to_string() => Key::from(previous_hash),
+				AdministrationArgs::Key.to_string() => Key::from(contract_hash)
+			}
+		);
+	}
+}
+
+/// Runs in the contract context. Every step transforms the stored state from the given
+/// schema version to the next one, stored state without a version is treated as the initial one.
+/// Returns the schema version the state was migrated to
+pub fn run_migrations<F: Fn(u32)>(current_version: u32, step: F) -> u32 {
+	let stored_version: u32 = get_stored_value_from_key(
+		&CommonKeys::SchemaVersion.to_string()
+	).unwrap_or(INITIAL_SCHEMA_VERSION);
+
+	if stored_version > current_version {
+		runtime::revert(CommonError::SchemaDowngrade);
+	}
+	for version in stored_version..current_version {
+		step(version);
+	}
+	store_value_for_key(&CommonKeys::SchemaVersion.to_string(), current_version);
+
+	// Carried over key still points to the previous version
+	if let Some(contract_hash) = get_called_contract_hash() {
+		runtime::put_key(
+			&CommonKeys::ContractHash.to_string(),
+			contract_hash.into()
+		);
+	}
+	current_version
+}
+
+pub fn get_optional_named_arg<T: FromBytes>(name: &str) -> Option<T> {
+	let arg_size = get_named_arg_size(name)?;
+	let arg_bytes = if arg_size > 0 {
+		let data_non_null_ptr = contract_api::alloc_bytes(arg_size);
+		let ret = unsafe {
+			ext_ffi::casper_get_named_arg(
+				name.as_bytes().as_ptr(),
+				name.len(),
+				data_non_null_ptr.as_ptr(),
+				arg_size
+			)
+		};
+		let data = unsafe {
+			Vec::from_raw_parts(data_non_null_ptr.as_ptr(), arg_size, arg_size)
+		};
+		api_error::result_from(ret).map(|_| data).unwrap_or_revert()
+	} else {
+		Vec::new()
+	};
+	bytesrepr::deserialize(arg_bytes).ok()
+}
+
+/// Helpers
+fn get_named_arg_size(name: &str) -> Option<usize> {
+	let mut arg_size: usize = 0;
+	let ret = unsafe {
+		ext_ffi::casper_get_named_arg_size(
+			name.as_bytes().as_ptr(),
+			name.len(),
+			&mut arg_size as *mut usize
+		)
+	};
+	match api_error::result_from(ret) {
+		Ok(_) => Some(arg_size),
+		Err(_) => None,
+	}
+}
+
+fn to_package_key(name: &str, package_hash: ContractPackageHash) -> String {
+	format!("{}_{}", name, package_hash)
+}
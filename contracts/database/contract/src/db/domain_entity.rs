@@ -1,6 +1,7 @@
+use alloc::string::ToString;
 use casper_types::{ account::AccountHash, Key };
 use common_lib::{
-	constants::common_keys::KEY_DATABASE_DICTIONARY_DOMAIN,
+	constants::common_keys::DatabaseKeys,
 	db::{ dictionary::Dictionary, traits::Storable },
 	errors::DatabaseErrors,
 	models::DomainName,
@@ -13,12 +14,12 @@ pub(crate) struct DomainEntityStore {
 impl DomainEntityStore {
 	pub fn instance() -> Self {
 		Self {
-			store: Dictionary::instance(KEY_DATABASE_DICTIONARY_DOMAIN),
+			store: Dictionary::instance(&DatabaseKeys::DictionaryDomain.to_string()),
 		}
 	}
 
 	pub fn initialize() {
-		Dictionary::init(KEY_DATABASE_DICTIONARY_DOMAIN);
+		Dictionary::init(&DatabaseKeys::DictionaryDomain.to_string());
 	}
 
 	fn update_domain_name<F: FnOnce(DomainName) -> DomainName>(
@@ -49,6 +50,10 @@ impl DomainEntityStore {
 		self.store.get(name)
 	}
 
+	pub fn rewrite(&self, name: &str) {
+		self.store.rewrite::<DomainName>(name)
+	}
+
 	pub fn update_owner(&self, name: &str, new_owner: Key) -> Result<(), DatabaseErrors> {
 		self.update_domain_name(name, DatabaseErrors::DatabaseDomainDoesntExist, |mut arg| {
 			arg.owner = new_owner;
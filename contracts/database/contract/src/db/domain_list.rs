@@ -5,8 +5,7 @@ use common_lib::{
 	db::{ dictionary::Dictionary, store::Store, traits::Storable },
 	errors::DatabaseErrors,
 	constants::common_keys::{
-		KEY_DATABASE_DICTIONARY_DOMAIN_LIST,
-		KEY_DATABASE_DOMAIN_LIST_PAGINATION,
+		DatabaseKeys,
 		MAX_PAGE_SIZE,
 	},
 };
@@ -19,13 +18,13 @@ pub(crate) struct DomainListStore {
 impl DomainListStore {
 	pub fn instance() -> Self {
 		Self {
-			store: Dictionary::instance(KEY_DATABASE_DICTIONARY_DOMAIN_LIST),
+			store: Dictionary::instance(&DatabaseKeys::DictionaryDomainList.to_string()),
 			state: Store::instance(),
 		}
 	}
 
 	pub fn initialize() {
-		Dictionary::init(KEY_DATABASE_DICTIONARY_DOMAIN_LIST);
+		Dictionary::init(&DatabaseKeys::DictionaryDomainList.to_string());
 	}
 
 	pub fn add(&self, name: &str) -> Result<u64, DatabaseErrors> {
@@ -41,7 +40,7 @@ impl DomainListStore {
 						pagination.remove(0);
 					}
 					self.state.set(
-						KEY_DATABASE_DOMAIN_LIST_PAGINATION,
+						&DatabaseKeys::DomainListPagination.to_string(),
 						pagination.clone()
 					);
 				}
@@ -76,7 +75,7 @@ impl DomainListStore {
 					pagination.push(page);
 					pagination.sort();
 				}
-				self.state.set(KEY_DATABASE_DOMAIN_LIST_PAGINATION, pagination);
+				self.state.set(&DatabaseKeys::DomainListPagination.to_string(), pagination);
 				Ok(())
 			})
 	}
@@ -85,9 +84,14 @@ impl DomainListStore {
 		self.store.get::<Vec<String>>(page).unwrap_or(Vec::<String>::new())
 	}
 
+	/// Pages below the current one may have free slots, none is above it
+	pub fn get_last_page(&self) -> u64 {
+		self.get_pagination().into_iter().max().unwrap_or(0)
+	}
+
 	fn get_pagination(&self) -> Vec<u64> {
 		self.state
-			.get::<Vec<u64>>(KEY_DATABASE_DOMAIN_LIST_PAGINATION)
+			.get::<Vec<u64>>(&DatabaseKeys::DomainListPagination.to_string())
 			.unwrap_or(vec![0u64])
 	}
 }
@@ -1,7 +1,8 @@
+use alloc::string::ToString;
 use casper_contract::unwrap_or_revert::UnwrapOrRevert;
 use common_lib::{
 	db::{ dictionary::Dictionary, traits::Storable },
-	constants::common_keys::KEY_DATABASE_DICTIONARY_DOMAIN_MAP,
+	constants::common_keys::DatabaseKeys,
 	errors::DatabaseErrors,
 };
 
@@ -12,12 +13,12 @@ pub(crate) struct DomainPaginationMapStore {
 impl DomainPaginationMapStore {
 	pub fn instance() -> Self {
 		Self {
-			store: Dictionary::instance(KEY_DATABASE_DICTIONARY_DOMAIN_MAP),
+			store: Dictionary::instance(&DatabaseKeys::DictionaryDomainMap.to_string()),
 		}
 	}
 
 	pub fn initialize() {
-		Dictionary::init(KEY_DATABASE_DICTIONARY_DOMAIN_MAP)
+		Dictionary::init(&DatabaseKeys::DictionaryDomainMap.to_string())
 	}
 
 	pub fn map(&self, name: &str, page: u64) {
@@ -6,3 +6,4 @@ pub mod owner_domain_list;
 pub mod state;
 pub mod subdomain_list;
 pub mod subdomain_entity;
+pub mod transfer_restrictions;
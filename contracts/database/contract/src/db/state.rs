@@ -1,7 +1,5 @@
-use common_lib::constants::common_keys::{
-	KEY_DATABASE_TOTALS_DOMAIN_COUNT,
-	KEY_DATABASE_TOTALS_SUBDOMAIN_COUNT,
-};
+use alloc::string::ToString;
+use common_lib::constants::common_keys::DatabaseKeys;
 use common_lib::db::store::Store;
 use common_lib::db::traits::Storable;
 
@@ -21,9 +19,9 @@ impl TotalState {
 	}
 
 	pub fn increment_domains_count_by(&self, by: u64) {
-		let mut count = self.store.get::<u64>(KEY_DATABASE_TOTALS_DOMAIN_COUNT).unwrap_or(0);
+		let mut count = self.store.get::<u64>(&DatabaseKeys::TotalDomainCount.to_string()).unwrap_or(0);
 		count += by;
-		self.store.set(KEY_DATABASE_TOTALS_DOMAIN_COUNT, count);
+		self.store.set(&DatabaseKeys::TotalDomainCount.to_string(), count);
 	}
 
 	pub fn decrement_domains_count(&self) {
@@ -31,11 +29,11 @@ impl TotalState {
 	}
 
 	pub fn decrement_domains_count_by(&self, by: u64) {
-		let mut count = self.store.get::<u64>(KEY_DATABASE_TOTALS_DOMAIN_COUNT).unwrap_or(0);
+		let mut count = self.store.get::<u64>(&DatabaseKeys::TotalDomainCount.to_string()).unwrap_or(0);
 		if count != 0 {
 			count -= by;
 		}
-		self.store.set(KEY_DATABASE_TOTALS_DOMAIN_COUNT, count);
+		self.store.set(&DatabaseKeys::TotalDomainCount.to_string(), count);
 	}
 
 	pub fn increment_subdomains_count(&self) {
@@ -43,9 +41,9 @@ impl TotalState {
 	}
 
 	pub fn increment_subdomains_count_by(&self, by: u64) {
-		let mut count = self.store.get::<u64>(KEY_DATABASE_TOTALS_SUBDOMAIN_COUNT).unwrap_or(0);
+		let mut count = self.store.get::<u64>(&DatabaseKeys::TotalSubdomainCount.to_string()).unwrap_or(0);
 		count += by;
-		self.store.set(KEY_DATABASE_TOTALS_SUBDOMAIN_COUNT, count);
+		self.store.set(&DatabaseKeys::TotalSubdomainCount.to_string(), count);
 	}
 
 	pub fn decrement_subdomains_count(&self) {
@@ -53,16 +51,16 @@ impl TotalState {
 	}
 
 	pub fn decrement_subdomains_count_by(&self, by: u64) {
-		let mut count = self.store.get::<u64>(KEY_DATABASE_TOTALS_SUBDOMAIN_COUNT).unwrap_or(0);
+		let mut count = self.store.get::<u64>(&DatabaseKeys::TotalSubdomainCount.to_string()).unwrap_or(0);
 		if count != 0 {
 			count -= by;
 		}
-		self.store.set(KEY_DATABASE_TOTALS_SUBDOMAIN_COUNT, count);
+		self.store.set(&DatabaseKeys::TotalSubdomainCount.to_string(), count);
 	}
 
 	pub fn get_totals(&self) -> (u64, u64) {
-		let domains_count = self.store.get::<u64>(KEY_DATABASE_TOTALS_DOMAIN_COUNT).unwrap_or(0);
-		let subdomains_count = self.store.get::<u64>(KEY_DATABASE_TOTALS_SUBDOMAIN_COUNT).unwrap_or(0);
+		let domains_count = self.store.get::<u64>(&DatabaseKeys::TotalDomainCount.to_string()).unwrap_or(0);
+		let subdomains_count = self.store.get::<u64>(&DatabaseKeys::TotalSubdomainCount.to_string()).unwrap_or(0);
 		(domains_count, subdomains_count)
 	}
 }
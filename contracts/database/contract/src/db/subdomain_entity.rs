@@ -1,5 +1,6 @@
 use casper_types::account::AccountHash;
-use common_lib::constants::common_keys::KEY_DATABASE_DICTIONARY_SUBDOMAIN;
+use alloc::string::ToString;
+use common_lib::constants::common_keys::DatabaseKeys;
 use common_lib::db::dictionary::Dictionary;
 use common_lib::db::traits::Storable;
 use common_lib::errors::DatabaseErrors;
@@ -12,12 +13,12 @@ pub(crate) struct SubdomainEntityStore {
 impl SubdomainEntityStore {
 	pub fn instance() -> Self {
 		Self {
-			store: Dictionary::instance(KEY_DATABASE_DICTIONARY_SUBDOMAIN),
+			store: Dictionary::instance(&DatabaseKeys::DictionarySubdomain.to_string()),
 		}
 	}
 
 	pub fn initialize() {
-		Dictionary::init(KEY_DATABASE_DICTIONARY_SUBDOMAIN)
+		Dictionary::init(&DatabaseKeys::DictionarySubdomain.to_string())
 	}
 
 	pub fn save(&self, sub_domain: SubdomainName) {
@@ -42,4 +43,8 @@ impl SubdomainEntityStore {
 	pub fn get(&self, name: &str) -> Option<SubdomainName> {
 		self.store.get(name)
 	}
+
+	pub fn rewrite(&self, name: &str) {
+		self.store.rewrite::<SubdomainName>(name)
+	}
 }
@@ -3,7 +3,7 @@ use alloc::vec;
 use alloc::vec::Vec;
 use common_lib::{
 	db::{ dictionary::Dictionary, traits::Storable },
-	constants::common_keys::{ KEY_DATABASE_DICTIONARY_SUBDOMAIN_LIST, MAX_PAGE_SIZE },
+	constants::common_keys::{ DatabaseKeys, MAX_PAGE_SIZE },
 };
 use common_lib::errors::DatabaseErrors;
 use common_lib::models::SubdomainName;
@@ -15,12 +15,12 @@ pub(crate) struct SubdomainList {
 impl SubdomainList {
 	pub fn instance() -> Self {
 		Self {
-			store: Dictionary::instance(KEY_DATABASE_DICTIONARY_SUBDOMAIN_LIST),
+			store: Dictionary::instance(&DatabaseKeys::DictionarySubdomainList.to_string()),
 		}
 	}
 
 	pub fn initialize() {
-		Dictionary::init(KEY_DATABASE_DICTIONARY_SUBDOMAIN_LIST)
+		Dictionary::init(&DatabaseKeys::DictionarySubdomainList.to_string())
 	}
 
 	pub fn add(&self, name: &str, subdomain_name: &SubdomainName) -> Result<(), DatabaseErrors> {
@@ -56,6 +56,14 @@ impl SubdomainList {
 			})
 	}
 
+	pub fn get_subdomain_entities(&self, name: &str) -> Vec<SubdomainName> {
+		self.store.get::<Vec<SubdomainName>>(name).unwrap_or(vec![])
+	}
+
+	pub fn rewrite(&self, name: &str) {
+		self.store.rewrite::<Vec<SubdomainName>>(name)
+	}
+
 	pub fn get_subdomains(&self, name: &str) -> Vec<String> {
 		let subdomains = match self.store.get::<Vec<SubdomainName>>(name) {
 			Some(res) => res,
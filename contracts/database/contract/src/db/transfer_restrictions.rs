@@ -0,0 +1,54 @@
+use alloc::string::ToString;
+use casper_contract::contract_api::runtime;
+use common_lib::{
+	constants::common_keys::DatabaseKeys,
+	db::{ dictionary::Dictionary, traits::Storable },
+	enums::transfer_lock::TransferLock,
+	models::transfer_restrictions::TransferRestrictions,
+};
+
+/// Name <-> transfer restrictions, names without an entry are not restricted
+pub(crate) struct TransferRestrictionsStore {
+	store: Dictionary,
+}
+
+impl TransferRestrictionsStore {
+	pub fn instance() -> Self {
+		Self {
+			store: Dictionary::instance(
+				&DatabaseKeys::DictionaryTransferRestrictions.to_string()
+			),
+		}
+	}
+
+	pub fn initialize() {
+		Dictionary::init(&DatabaseKeys::DictionaryTransferRestrictions.to_string())
+	}
+
+	/// Store was added with schema version 2, shards installed before keep it missing until migrated
+	pub fn initialize_if_missing() {
+		if !runtime::has_key(&DatabaseKeys::DictionaryTransferRestrictions.to_string()) {
+			Self::initialize()
+		}
+	}
+
+	pub fn get(&self, name: &str) -> TransferRestrictions {
+		self.store.get(name).unwrap_or_default()
+	}
+
+	pub fn set_lock(&self, name: &str, lock: Option<TransferLock>) {
+		let mut restrictions = self.get(name);
+		restrictions.lock = lock;
+		self.store.set(name, restrictions);
+	}
+
+	pub fn burn_transfer_permission(&self, name: &str) {
+		let mut restrictions = self.get(name);
+		restrictions.transfer_permission_burned = true;
+		self.store.set(name, restrictions);
+	}
+
+	pub fn remove(&self, name: &str) {
+		self.store.remove::<TransferRestrictions>(name);
+	}
+}
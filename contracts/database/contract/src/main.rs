@@ -11,85 +11,32 @@ compile_error!(
 extern crate alloc;
 mod types;
 mod db;
-mod init_call;
 mod service;
 
-use alloc::string::{ String, ToString };
+use alloc::string::ToString;
 use alloc::vec;
 
-use casper_contract::{
-	contract_api::{ runtime, storage },
-	unwrap_or_revert::UnwrapOrRevert,
-};
-use casper_types::{
-	Key,
-	CLType,
-	CLTyped,
-	account::AccountHash,
-	contracts::{
-		EntryPoint,
-		EntryPoints,
-		EntryPointType,
-		EntryPointAccess,
-		Parameter,
-		NamedKeys,
-	},
-};
-
-use common_lib::constants::common_keys::{
-	DatabaseArgs,
-	DatabaseEndpoints,
-	ARG_DATABASE_DOMAIN_NAME,
-	ARG_DATABASE_EXPIRATION_DATE,
-	ARG_DATABASE_OWNER,
-	ARG_DATABASE_PAGE,
-	ARG_DATABASE_RESOLVER,
-	ARG_DATABASE_SUBDOMAIN_NAME,
-	ENDPOINT_DATABASE_GET_DOMAIN,
-	ENDPOINT_DATABASE_GET_DOMAIN_LIST,
-	ENDPOINT_DATABASE_GET_DOMAIN_LIST_FOR_OWNER,
-	ENDPOINT_DATABASE_GET_SUBDOMAIN,
-	ENDPOINT_DATABASE_GET_SUBDOMAIN_LIST,
-	ENDPOINT_DATABASE_GET_TOTALS,
-	ENDPOINT_DATABASE_INIT,
-	ENDPOINT_DATABASE_REMOVE_DOMAIN_NAME,
-	ENDPOINT_DATABASE_REMOVE_SUBDOMAIN_NAME,
-	ENDPOINT_DATABASE_SAVE_DOMAIN_NAME,
-	ENDPOINT_DATABASE_SAVE_SUBDOMAIN_NAME,
-	ENDPOINT_DATABASE_SET_DOMAIN_EXPIRATION,
-	ENDPOINT_DATABASE_SET_DOMAIN_OWNERSHIP,
-	ENDPOINT_DATABASE_SET_DOMAIN_RESOLVER,
-	ENDPOINT_DATABASE_SET_SUBDOMAIN_RESOLVER,
-	KEY_DATABASE_CONTRACT_ACCESS_UREF,
-	KEY_DATABASE_CONTRACT_HASH,
-	KEY_DATABASE_CONTRACT_PACKAGE_NAME,
-	KEY_DATABASE_CONTRACT_VERSION,
-	KEY_DATABASE_TOTALS_DOMAIN_COUNT,
-	KEY_DATABASE_TOTALS_SUBDOMAIN_COUNT,
-	KEY_MAINTAINER,
-};
-use common_lib::enums::controller_roles::ControllerRoles;
-use common_lib::errors::DatabaseErrors;
-use common_lib::models::{ SubdomainName, DomainName };
-use common_lib::utils::contract::{ create_entrypoint, setup_contract_info };
-use common_lib::utils::response::{
-	controller,
-	response_error,
-	response_success,
-};
+use casper_contract::contract_api::{ runtime, storage };
+use casper_types::{ contracts::NamedKeys, ContractHash };
 
-use crate::db::state::TotalState;
-use db::{
-	domain_list::DomainListStore,
-	domain_entity::DomainEntityStore,
-	domain_pagination_map::DomainPaginationMapStore,
-	subdomain_list::SubdomainList,
-	subdomain_entity::SubdomainEntityStore,
-	owner_domain_list::OwnerDomainList,
+use common_lib::{
+	constants::common_keys::{ CommonArgs, CommonKeys, DatabaseKeys },
+	enums::{
+		contracts_enum::ContractKind,
+		controller_roles::ControllerRoles,
+		roles::Role,
+	},
+	schema,
+	utils::{
+		contract::setup_contract_info,
+		pause::ensure_not_paused,
+		response::controller,
+	},
 };
 
 #[no_mangle]
 pub extern "C" fn save_domain_name() {
+	ensure_not_paused(ContractKind::Database, None);
 	controller(
 		service::save_domain_name::save_domain_name,
 		vec![ControllerRoles::OnlyAuthorizedContracts]
@@ -98,6 +45,7 @@ pub extern "C" fn save_domain_name() {
 
 #[no_mangle]
 pub extern "C" fn save_subdomain_name() {
+	ensure_not_paused(ContractKind::Database, None);
 	controller(
 		service::save_subdomain_name::save_subdomain_name,
 		vec![ControllerRoles::OnlyAuthorizedContracts]
@@ -106,6 +54,7 @@ pub extern "C" fn save_subdomain_name() {
 
 #[no_mangle]
 pub extern "C" fn remove_domain_name() {
+	ensure_not_paused(ContractKind::Database, None);
 	controller(
 		service::remove_domain_name::remove_domain_name,
 		vec![ControllerRoles::OnlyAuthorizedContracts]
@@ -114,6 +63,7 @@ pub extern "C" fn remove_domain_name() {
 
 #[no_mangle]
 pub extern "C" fn remove_subdomain_name() {
+	ensure_not_paused(ContractKind::Database, None);
 	controller(
 		service::remove_subdomain_name::remove_subdomain_name,
 		vec![ControllerRoles::OnlyAuthorizedContracts]
@@ -122,6 +72,7 @@ pub extern "C" fn remove_subdomain_name() {
 
 #[no_mangle]
 pub extern "C" fn set_domain_ownership() {
+	ensure_not_paused(ContractKind::Database, None);
 	controller(
 		service::set_domain_ownership::set_domain_ownership,
 		vec![ControllerRoles::OnlyAuthorizedContracts]
@@ -130,6 +81,7 @@ pub extern "C" fn set_domain_ownership() {
 
 #[no_mangle]
 pub extern "C" fn set_domain_expiration() {
+	ensure_not_paused(ContractKind::Database, None);
 	controller(
 		service::set_domain_expiration::set_domain_expiration,
 		vec![ControllerRoles::OnlyAuthorizedContracts]
@@ -138,6 +90,7 @@ pub extern "C" fn set_domain_expiration() {
 
 #[no_mangle]
 pub extern "C" fn set_domain_resolver() {
+	ensure_not_paused(ContractKind::Database, None);
 	controller(
 		service::set_domain_resolver::set_domain_resolver,
 		vec![ControllerRoles::OnlyAuthorizedContracts]
@@ -146,6 +99,7 @@ pub extern "C" fn set_domain_resolver() {
 
 #[no_mangle]
 pub extern "C" fn set_subdomain_resolver() {
+	ensure_not_paused(ContractKind::Database, None);
 	controller(
 		service::set_subdomain_resolver::set_subdomain_resolver,
 		vec![ControllerRoles::OnlyAuthorizedContracts]
@@ -200,10 +154,65 @@ pub extern "C" fn get_subdomain() {
 	)
 }
 
+#[no_mangle]
+pub extern "C" fn export_domains() {
+	ensure_not_paused(ContractKind::Database, None);
+	controller(
+		service::export_domains::export_domains,
+		vec![ControllerRoles::OnlyAuthorizedContracts]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn import_domains() {
+	ensure_not_paused(ContractKind::Database, None);
+	controller(
+		service::import_domains::import_domains,
+		vec![ControllerRoles::OnlyAuthorizedContracts]
+	)
+}
+
+/// Escrow and dispute locks are also set by marketplace operators and guardians
+#[no_mangle]
+pub extern "C" fn set_transfer_lock() {
+	ensure_not_paused(ContractKind::Database, None);
+	controller(
+		service::set_transfer_lock::set_transfer_lock,
+		vec![
+			ControllerRoles::OnlyAuthorizedContracts,
+			ControllerRoles::OnlyRole(Role::MarketplaceOperator),
+			ControllerRoles::OnlyRole(Role::Guardian)
+		]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn burn_transfer_permission() {
+	ensure_not_paused(ContractKind::Database, None);
+	controller(
+		service::burn_transfer_permission::burn_transfer_permission,
+		vec![ControllerRoles::OnlyAuthorizedContracts]
+	)
+}
+
+#[no_mangle]
+pub extern "C" fn get_transfer_restrictions() {
+	controller(
+		service::get_transfer_restrictions::get_transfer_restrictions,
+		vec![ControllerRoles::OnlyAuthorizedContracts]
+	)
+}
+
 #[no_mangle]
 pub extern "C" fn init() {
+	controller(service::init::init, vec![ControllerRoles::OnlyMaintainer])
+}
+
+/// Runs once right after an upgrade, see `utils::upgrade`
+#[no_mangle]
+pub extern "C" fn migrate() {
 	controller(
-		service::get_subdomain::get_subdomain,
+		service::migrate::migrate,
 		vec![ControllerRoles::OnlyMaintainer]
 	)
 }
@@ -223,248 +232,40 @@ pub extern "C" fn init() {
  * 11. get_totals
  * 12. get_domain
  * 13. get_subdomain
+ * 14. export_domains
+ * 15. import_domains
+ * 16. set_transfer_lock
+ * 17. burn_transfer_permission
+ * 18. get_transfer_restrictions
+ * 19. init
+ * 20. migrate
  */
 #[no_mangle]
 pub extern "C" fn call() {
-	let mut entrypoints = EntryPoints::new();
-
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&DatabaseEndpoints::SaveDomainName.to_string(),
-			vec![
-				Parameter::new(
-					&DatabaseArgs::DomainName.to_string(),
-					DomainName::cl_type()
-				)
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&DatabaseEndpoints::SaveSubdomainName.to_string(),
-			vec![
-				Parameter::new(
-					&DatabaseArgs::DomainName.to_string(),
-					String::cl_type()
-				),
-				Parameter::new(
-					&DatabaseArgs::SubdomainName.to_string(),
-					SubdomainName::cl_type()
-				)
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		EntryPoint::new(
-			&DatabaseEndpoints::RemoveDomainName.to_string(),
-			vec![
-				Parameter::new(&DatabaseArgs::DomainName.to_string(), String::cl_type())
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&DatabaseEndpoints::RemoveSubdomainName.to_string(),
-			vec![
-				Parameter::new(
-					&DatabaseArgs::DomainName.to_string(),
-					String::cl_type()
-				),
-				Parameter::new(
-					&DatabaseArgs::SubdomainName.to_string(),
-					String::cl_type()
-				)
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&DatabaseEndpoints::SetDomainOwnership.to_string(),
-			vec![
-				Parameter::new(
-					&DatabaseArgs::DomainName.to_string(),
-					String::cl_type()
-				),
-				Parameter::new(&DatabaseArgs::Owner.to_string(), AccountHash::cl_type())
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&DatabaseEndpoints::SetDomainExpiration.to_string(),
-			vec![
-				Parameter::new(
-					&DatabaseArgs::DomainName.to_string(),
-					String::cl_type()
-				),
-				Parameter::new(
-					&DatabaseArgs::ExpirationDate.to_string(),
-					u64::cl_type()
-				)
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&DatabaseEndpoints::SetDomainResolver.to_string(),
-			vec![
-				Parameter::new(
-					&DatabaseArgs::DomainName.to_string(),
-					String::cl_type()
-				),
-				Parameter::new(
-					&DatabaseArgs::Resolver.to_string(),
-					AccountHash::cl_type()
-				)
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&DatabaseEndpoints::SetSubdomainResolver.to_string(),
-			vec![
-				Parameter::new(
-					&DatabaseArgs::SubdomainName.to_string(),
-					String::cl_type()
-				),
-				Parameter::new(
-					&DatabaseArgs::Resolver.to_string(),
-					AccountHash::cl_type()
-				)
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&DatabaseEndpoints::GetDomainListForOwner.to_string(),
-			vec![
-				Parameter::new(&DatabaseArgs::Owner.to_string(), AccountHash::cl_type())
-			],
-			CLType::Any,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&DatabaseEndpoints::GetDomainList.to_string(),
-			vec![Parameter::new(&DatabaseArgs::Page.to_string(), u64::cl_type())],
-			CLType::Any,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&DatabaseEndpoints::GetSubdomainList.to_string(),
-			vec![
-				Parameter::new(&DatabaseArgs::DomainName.to_string(), String::cl_type())
-			],
-			CLType::Any,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&DatabaseEndpoints::GetTotals.to_string(),
-			vec![],
-			CLType::Any,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&DatabaseEndpoints::GetDomain.to_string(),
-			vec![
-				Parameter::new(&DatabaseArgs::DomainName.to_string(), String::cl_type())
-			],
-			CLType::Any,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&DatabaseEndpoints::GetSubdomain.to_string(),
-			vec![
-				Parameter::new(
-					&DatabaseArgs::SubdomainName.to_string(),
-					String::cl_type()
-				)
-			],
-			CLType::Any,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&DatabaseEndpoints::Init.to_string(),
-			vec![],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
+	let entrypoints = schema::database::schema().to_entry_points();
 
 	let mut database_named_keys = NamedKeys::new();
-	let maintainer_uref = storage::new_uref(runtime::get_caller());
-	database_named_keys.insert(
-		KEY_MAINTAINER.to_string(),
-		maintainer_uref.into()
-	);
 
-	let domains_count_uref = storage::new_uref(0);
+	let domains_count_uref = storage::new_uref(0u64);
 	database_named_keys.insert(
-		KEY_DATABASE_TOTALS_DOMAIN_COUNT.to_string(),
+		DatabaseKeys::TotalDomainCount.to_string(),
 		domains_count_uref.into()
 	);
 
-	let subdomains_count_uref = storage::new_uref(0);
+	let subdomains_count_uref = storage::new_uref(0u64);
 	database_named_keys.insert(
-		KEY_DATABASE_TOTALS_SUBDOMAIN_COUNT.to_string(),
+		DatabaseKeys::TotalSubdomainCount.to_string(),
 		subdomains_count_uref.into()
 	);
 
+	// Authorized contracts and the administration contract itself (migrations) are checked against it
+	let administration_contract_hash: ContractHash = runtime::get_named_arg(
+		&CommonArgs::AdministrationContract.to_string()
+	);
+	database_named_keys.insert(
+		CommonKeys::AdministrationContract.to_string(),
+		storage::new_uref(administration_contract_hash).into()
+	);
+
 	setup_contract_info(entrypoints, database_named_keys);
 }
@@ -0,0 +1,24 @@
+use alloc::string::{ String, ToString };
+use casper_contract::contract_api::runtime;
+use common_lib::{ constants::common_keys::DatabaseArgs, errors::DatabaseErrors };
+
+use crate::{
+	db::{
+		subdomain_entity::SubdomainEntityStore,
+		transfer_restrictions::TransferRestrictionsStore,
+	},
+	types::TResult,
+};
+
+/// Subdomain stays with its current owner for good, the permission can't be restored
+/// Parameters:
+/// - name - required, subdomain name
+pub fn burn_transfer_permission() -> TResult<()> {
+	let name: String = runtime::get_named_arg(&DatabaseArgs::Name.to_string());
+
+	if SubdomainEntityStore::instance().get(&name).is_none() {
+		return Err(DatabaseErrors::DatabaseSubdomainDoesntExist);
+	}
+	TransferRestrictionsStore::instance().burn_transfer_permission(&name);
+	Ok(())
+}
@@ -0,0 +1,44 @@
+use alloc::{ string::{ String, ToString }, vec::Vec };
+use casper_contract::contract_api::runtime;
+use common_lib::{
+	constants::common_keys::{ DatabaseArgs, MAX_MIGRATION_BATCH_SIZE },
+	errors::DatabaseErrors,
+	models::domain_migration::DomainMigrationEntry,
+};
+
+use crate::{
+	db::{ domain_entity::DomainEntityStore, subdomain_list::SubdomainList },
+	service::remove_domain_name::purge_domain_name,
+	types::TResult,
+};
+
+/// Takes domain names out of this shard for migration, the names which are not stored here are skipped,
+/// so a batch which was already moved can be sent again
+/// Parameters:
+/// - domain_names - required
+/// Returns moved domain names with their subdomains
+pub fn export_domains() -> TResult<Vec<DomainMigrationEntry>> {
+	let domain_names: Vec<String> = runtime::get_named_arg(
+		&DatabaseArgs::DomainNames.to_string()
+	);
+	if domain_names.len() > MAX_MIGRATION_BATCH_SIZE {
+		return Err(DatabaseErrors::DatabaseMigrationBatchTooLarge);
+	}
+
+	let domain_store = DomainEntityStore::instance();
+	let subdomain_list = SubdomainList::instance();
+	let mut entries: Vec<DomainMigrationEntry> = Vec::new();
+	for domain_name in domain_names.iter() {
+		let domain = match domain_store.get(domain_name) {
+			Some(res) => res,
+			None => {
+				continue;
+			}
+		};
+		let subdomains = subdomain_list.get_subdomain_entities(domain_name);
+		purge_domain_name(&domain)?;
+		entries.push(DomainMigrationEntry { domain, subdomains });
+	}
+
+	Ok(entries)
+}
@@ -1,3 +1,4 @@
+use alloc::string::{ String, ToString };
 use casper_contract::contract_api::runtime;
 use common_lib::{ constants::common_keys::DatabaseArgs, models::SubdomainName };
 
@@ -1,4 +1,4 @@
-use alloc::{ string::String, vec::Vec };
+use alloc::{ string::{ String, ToString }, vec::Vec };
 use casper_contract::contract_api::runtime;
 use common_lib::constants::common_keys::DatabaseArgs;
 
@@ -0,0 +1,18 @@
+use alloc::string::{ String, ToString };
+use casper_contract::contract_api::runtime;
+use common_lib::{
+	constants::common_keys::DatabaseArgs,
+	models::transfer_restrictions::TransferRestrictions,
+};
+
+use crate::{
+	db::transfer_restrictions::TransferRestrictionsStore,
+	types::TResult,
+};
+
+/// Parameters:
+/// - name - required, domain or subdomain name
+pub fn get_transfer_restrictions() -> TResult<TransferRestrictions> {
+	let name: String = runtime::get_named_arg(&DatabaseArgs::Name.to_string());
+	Ok(TransferRestrictionsStore::instance().get(&name))
+}
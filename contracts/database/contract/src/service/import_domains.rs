@@ -0,0 +1,45 @@
+use alloc::{ string::ToString, vec::Vec };
+use casper_contract::contract_api::runtime;
+use common_lib::{
+	constants::common_keys::{ DatabaseArgs, MAX_MIGRATION_BATCH_SIZE },
+	errors::DatabaseErrors,
+	models::domain_migration::DomainMigrationEntry,
+};
+
+use crate::{
+	db::domain_entity::DomainEntityStore,
+	service::{
+		save_domain_name::store_domain_name,
+		save_subdomain_name::store_subdomain_name,
+	},
+	types::TResult,
+};
+
+/// Stores domain names exported from another shard, already stored names are skipped
+/// Parameters:
+/// - migration_entries - required
+/// Returns count of stored domain names
+pub fn import_domains() -> TResult<u32> {
+	let entries: Vec<DomainMigrationEntry> = runtime::get_named_arg(
+		&DatabaseArgs::MigrationEntries.to_string()
+	);
+	if entries.len() > MAX_MIGRATION_BATCH_SIZE {
+		return Err(DatabaseErrors::DatabaseMigrationBatchTooLarge);
+	}
+
+	let domain_store = DomainEntityStore::instance();
+	let mut count: u32 = 0;
+	for entry in entries {
+		if domain_store.get(&entry.domain.name).is_some() {
+			continue;
+		}
+		let domain_name = entry.domain.name.clone();
+		store_domain_name(entry.domain)?;
+		for subdomain in entry.subdomains {
+			store_subdomain_name(&domain_name, subdomain)?;
+		}
+		count += 1;
+	}
+
+	Ok(count)
+}
@@ -6,6 +6,7 @@ use crate::{
 		owner_domain_list::OwnerDomainList,
 		subdomain_entity::SubdomainEntityStore,
 		subdomain_list::SubdomainList,
+		transfer_restrictions::TransferRestrictionsStore,
 	},
 	types::TResult,
 };
@@ -17,5 +18,6 @@ pub fn init() -> TResult<()> {
 	OwnerDomainList::initialize();
 	SubdomainList::initialize();
 	SubdomainEntityStore::initialize();
+	TransferRestrictionsStore::initialize();
 	Ok(())
 }
@@ -0,0 +1,54 @@
+use alloc::string::ToString;
+use common_lib::utils::upgrade::run_migrations;
+
+use crate::{
+	db::{
+		domain_entity::DomainEntityStore,
+		domain_list::DomainListStore,
+		subdomain_entity::SubdomainEntityStore,
+		subdomain_list::SubdomainList,
+		transfer_restrictions::TransferRestrictionsStore,
+	},
+	types::TResult,
+};
+
+/// Schema version of domain and subdomain dictionaries this code works with
+pub const SCHEMA_VERSION: u32 = 3;
+
+/// Runs right after a new contract version is added to the package.
+/// A step is added here along with the version bump, it moves the state from `version` to `version + 1`
+/// This service is closed, only maintainer is passed
+/// 1 -> 2: transfer restrictions of the names
+/// 2 -> 3: domains and subdomains are stored with their structural CLTypes instead of `CLType::Any`
+/// Returns the schema version of the stored state
+pub fn migrate() -> TResult<u32> {
+	Ok(
+		run_migrations(SCHEMA_VERSION, |version| {
+			if version == 1 {
+				TransferRestrictionsStore::initialize_if_missing();
+			}
+			if version == 2 {
+				rewrite_names();
+			}
+		})
+	)
+}
+
+/// Every name of the domain list is written back along with its subdomains,
+/// the shard holds no more names than the listing limit of the administration contract
+fn rewrite_names() {
+	let domain_list = DomainListStore::instance();
+	let domains = DomainEntityStore::instance();
+	let subdomain_list = SubdomainList::instance();
+	let subdomains = SubdomainEntityStore::instance();
+
+	for page in 0..=domain_list.get_last_page() {
+		for name in domain_list.get_domain_list(&page.to_string()) {
+			domains.rewrite(&name);
+			for subdomain in subdomain_list.get_subdomain_entities(&name) {
+				subdomains.rewrite(&subdomain.name);
+			}
+			subdomain_list.rewrite(&name);
+		}
+	}
+}
@@ -13,3 +13,9 @@ pub mod get_totals;
 pub mod get_domain;
 pub mod get_subdomain;
 pub mod init;
+pub mod export_domains;
+pub mod import_domains;
+pub mod migrate;
+pub mod set_transfer_lock;
+pub mod burn_transfer_permission;
+pub mod get_transfer_restrictions;
@@ -1,8 +1,12 @@
 use alloc::string::{ String, ToString };
-use casper_contract::contract_api::runtime;
+use casper_contract::{
+	contract_api::runtime,
+	unwrap_or_revert::UnwrapOrRevert,
+};
 use common_lib::{
 	constants::common_keys::DatabaseArgs,
 	errors::DatabaseErrors,
+	models::DomainName,
 };
 
 use crate::{
@@ -12,7 +16,9 @@ use crate::{
 		domain_pagination_map::DomainPaginationMapStore,
 		owner_domain_list::OwnerDomainList,
 		state::TotalState,
+		subdomain_entity::SubdomainEntityStore,
 		subdomain_list::SubdomainList,
+		transfer_restrictions::TransferRestrictionsStore,
 	},
 	types::TResult,
 };
@@ -21,34 +27,44 @@ pub fn remove_domain_name() -> TResult<()> {
 	let domain_name: String = runtime::get_named_arg(
 		&DatabaseArgs::DomainName.to_string()
 	);
-	let domain_map = DomainEntityStore::instance();
-	let domain = domain_map.get(&domain_name).expect("Domain is not found");
-	domain_map.remove(&domain_name);
+	let domain = DomainEntityStore::instance()
+		.get(&domain_name)
+		.ok_or(DatabaseErrors::DatabaseDomainDoesntExist)?;
+	purge_domain_name(&domain)
+}
+
+/// Removes the domain with its subdomains, list, pagination, owner index and transfer restriction entries
+pub(crate) fn purge_domain_name(domain: &DomainName) -> TResult<()> {
+	let domain_name = &domain.name;
+	let transfer_restrictions = TransferRestrictionsStore::instance();
+	DomainEntityStore::instance().remove(domain_name);
+	transfer_restrictions.remove(domain_name);
 
 	let domain_pagination_map = DomainPaginationMapStore::instance();
-	let page_binding = &domain_pagination_map.get_page(&domain_name);
+	let page_binding = &domain_pagination_map.get_page(domain_name);
 	let page = match page_binding {
 		Ok(res) => res,
 		Err(e) => {
-			return response_error(*e);
+			return Err(*e);
 		}
 	};
-	match DomainListStore::instance().remove(*page, &domain_name) {
+	match DomainListStore::instance().remove(*page, domain_name) {
 		Ok(()) => {}
 		Err(e) => {
 			return Err(e);
 		}
 	}
-	let _ = &domain_pagination_map.remove(&domain_name);
+	let _ = &domain_pagination_map.remove(domain_name);
 	let subdomain_list = SubdomainList::instance();
-	let subdomains = &subdomain_list.get_subdomains(&domain_name);
+	let subdomains = &subdomain_list.get_subdomains(domain_name);
 	subdomains.iter().for_each(|x| {
 		SubdomainEntityStore::instance().remove(x);
+		transfer_restrictions.remove(x);
 		let _ = &subdomain_list
-			.remove(&domain_name, x)
+			.remove(domain_name, x)
 			.unwrap_or_revert_with(DatabaseErrors::DatabaseUnexpected);
 	});
-	OwnerDomainList::instance().remove_domain_name(domain.owner, &domain_name);
+	OwnerDomainList::instance().remove_domain_name(domain.owner, domain_name);
 	let total_state = TotalState::instance();
 	total_state.decrement_domains_count();
 	let count = subdomains.len() as u64;
@@ -7,6 +7,7 @@ use crate::{
 		state::TotalState,
 		subdomain_entity::SubdomainEntityStore,
 		subdomain_list::SubdomainList,
+		transfer_restrictions::TransferRestrictionsStore,
 	},
 	types::TResult,
 };
@@ -19,6 +20,7 @@ pub fn remove_subdomain_name() -> TResult<()> {
 		&DatabaseArgs::SubdomainName.to_string()
 	);
 	SubdomainEntityStore::instance().remove(&subdomain_name);
+	TransferRestrictionsStore::instance().remove(&subdomain_name);
 	match SubdomainList::instance().remove(&domain_name, &subdomain_name) {
 		Ok(()) => {}
 		Err(e) => {
@@ -1,6 +1,14 @@
 use alloc::string::ToString;
 use casper_contract::contract_api::runtime;
-use common_lib::{ constants::common_keys::DatabaseArgs, models::DomainName };
+use common_lib::{
+	constants::common_keys::DatabaseArgs,
+	enums::contracts_enum::ContractKind,
+	models::DomainName,
+	utils::{
+		domain_name::get_domain_name_extension,
+		pause::ensure_not_paused,
+	},
+};
 
 use crate::{
 	db::{
@@ -19,6 +27,16 @@ pub fn save_domain_name() -> TResult<()> {
 		&DatabaseArgs::DomainName.to_string()
 	);
 
+	ensure_not_paused(
+		ContractKind::Database,
+		get_domain_name_extension(&domain_name.name)
+	);
+
+	store_domain_name(domain_name)
+}
+
+/// Saves the domain with its list, pagination and owner index entries
+pub(crate) fn store_domain_name(domain_name: DomainName) -> TResult<()> {
 	DomainEntityStore::instance().save(domain_name.clone());
 	let page = match DomainListStore::instance().add(&domain_name.name) {
 		Ok(page) => page,
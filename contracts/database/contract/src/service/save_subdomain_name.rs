@@ -18,8 +18,16 @@ pub fn save_subdomain_name() -> TResult<()> {
 	let subdomain_name: SubdomainName = runtime::get_named_arg(
 		&DatabaseArgs::SubdomainName.to_string()
 	);
+	store_subdomain_name(&domain_name, subdomain_name)
+}
+
+/// Saves the subdomain and appends it to the subdomain list of the domain
+pub(crate) fn store_subdomain_name(
+	domain_name: &str,
+	subdomain_name: SubdomainName
+) -> TResult<()> {
 	SubdomainEntityStore::instance().save(subdomain_name.clone());
-	match SubdomainList::instance().add(&domain_name, &subdomain_name) {
+	match SubdomainList::instance().add(domain_name, &subdomain_name) {
 		Ok(()) => {}
 		Err(e) => {
 			return Err(e);
@@ -1,4 +1,4 @@
-use alloc::string::ToString;
+use alloc::string::{ String, ToString };
 use casper_contract::contract_api::runtime;
 use casper_types::Key;
 use common_lib::constants::common_keys::DatabaseArgs;
@@ -1,5 +1,6 @@
-use alloc::string::ToString;
+use alloc::string::{ String, ToString };
 use casper_contract::contract_api::runtime;
+use casper_types::account::AccountHash;
 use common_lib::constants::common_keys::DatabaseArgs;
 
 use crate::{ db::domain_entity::DomainEntityStore, types::TResult };
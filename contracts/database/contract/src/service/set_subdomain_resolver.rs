@@ -1,5 +1,6 @@
-use alloc::string::ToString;
+use alloc::string::{ String, ToString };
 use casper_contract::contract_api::runtime;
+use casper_types::account::AccountHash;
 use common_lib::constants::common_keys::DatabaseArgs;
 
 use crate::{ db::subdomain_entity::SubdomainEntityStore, types::TResult };
@@ -16,7 +17,9 @@ pub fn set_subdomain_resolver() -> TResult<()> {
 		SubdomainEntityStore::instance().update_resolver(&subdomain_name, resolver)
 	{
 		Ok(()) => {}
-		Err(e) => response_error(e),
+		Err(e) => {
+			return Err(e);
+		}
 	}
 	Ok(())
 }
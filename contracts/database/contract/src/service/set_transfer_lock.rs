@@ -0,0 +1,37 @@
+use alloc::string::{ String, ToString };
+use casper_contract::contract_api::runtime;
+use common_lib::{
+	constants::common_keys::DatabaseArgs,
+	enums::transfer_lock::TransferLock,
+	errors::DatabaseErrors,
+};
+
+use crate::{
+	db::{
+		domain_entity::DomainEntityStore,
+		subdomain_entity::SubdomainEntityStore,
+		transfer_restrictions::TransferRestrictionsStore,
+	},
+	types::TResult,
+};
+
+/// Locks the domain or subdomain for escrow deals and disputes, `None` releases the lock.
+/// The transfer filter denies transfers of locked names
+/// Parameters:
+/// - name - required
+/// - transfer_lock - required, optional value
+pub fn set_transfer_lock() -> TResult<()> {
+	let name: String = runtime::get_named_arg(&DatabaseArgs::Name.to_string());
+	let lock: Option<TransferLock> = runtime::get_named_arg(
+		&DatabaseArgs::TransferLock.to_string()
+	);
+
+	if
+		DomainEntityStore::instance().get(&name).is_none() &&
+		SubdomainEntityStore::instance().get(&name).is_none()
+	{
+		return Err(DatabaseErrors::DatabaseDomainDoesntExist);
+	}
+	TransferRestrictionsStore::instance().set_lock(&name, lock);
+	Ok(())
+}
@@ -0,0 +1,213 @@
+//! Derive macros for the models shared by the contracts.
+//!
+//! Fields are serialized one after another in the declaration order.
+//! `CLTyped` describes the same bytes as right-nested tuples, so the struct
+//! `{ a, b, c, d }` has the type `Tuple3(a, b, Tuple2(c, d))`.
+//! `u16` has no CLType of its own and is described by its two little-endian bytes, `ByteArray(2)`.
+//! Generated code refers to `::alloc`, the crate using the derives has to declare `extern crate alloc`.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::{ Span, TokenStream as TokenStream2 };
+use quote::{ format_ident, quote };
+use syn::{ parse_macro_input, Data, DeriveInput, Error, Fields, Ident, Index, Type };
+
+#[proc_macro_derive(ToBytes)]
+pub fn derive_to_bytes(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	expand(&input, expand_to_bytes)
+}
+
+#[proc_macro_derive(FromBytes)]
+pub fn derive_from_bytes(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	expand(&input, expand_from_bytes)
+}
+
+#[proc_macro_derive(CLTyped)]
+pub fn derive_cl_typed(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	expand(&input, expand_cl_typed)
+}
+
+/// Field of the derived struct, `member` is the name or the index of a tuple struct field
+struct Field {
+	member: TokenStream2,
+	binding: Ident,
+	ty: Type,
+}
+
+fn expand(
+	input: &DeriveInput,
+	expand_fn: fn(&DeriveInput, &Fields, &[Field]) -> TokenStream2
+) -> TokenStream {
+	let fields = match &input.data {
+		Data::Struct(data) => &data.fields,
+		_ => {
+			return Error::new(
+				Span::call_site(),
+				"only structs are supported, enums implement the traits by hand"
+			)
+				.to_compile_error()
+				.into();
+		}
+	};
+	let items = fields
+		.iter()
+		.enumerate()
+		.map(|(index, field)| Field {
+			member: match &field.ident {
+				Some(ident) => quote!(#ident),
+				None => {
+					let index = Index::from(index);
+					quote!(#index)
+				}
+			},
+			binding: format_ident!("field_{}", index),
+			ty: field.ty.clone(),
+		})
+		.collect::<Vec<Field>>();
+
+	expand_fn(input, fields, &items).into()
+}
+
+fn expand_to_bytes(
+	input: &DeriveInput,
+	_fields: &Fields,
+	items: &[Field]
+) -> TokenStream2 {
+	let name = &input.ident;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+	let members = items
+		.iter()
+		.map(|field| &field.member)
+		.collect::<Vec<&TokenStream2>>();
+
+	quote! {
+		impl #impl_generics ::casper_types::bytesrepr::ToBytes for #name #ty_generics #where_clause {
+			fn to_bytes(
+				&self
+			) -> ::core::result::Result<::alloc::vec::Vec<u8>, ::casper_types::bytesrepr::Error> {
+				let mut result = ::casper_types::bytesrepr::allocate_buffer(self)?;
+				::casper_types::bytesrepr::ToBytes::write_bytes(self, &mut result)?;
+				Ok(result)
+			}
+
+			fn serialized_length(&self) -> usize {
+				0 #(+ ::casper_types::bytesrepr::ToBytes::serialized_length(&self.#members))*
+			}
+
+			fn write_bytes(
+				&self,
+				writer: &mut ::alloc::vec::Vec<u8>
+			) -> ::core::result::Result<(), ::casper_types::bytesrepr::Error> {
+				#(::casper_types::bytesrepr::ToBytes::write_bytes(&self.#members, writer)?;)*
+				Ok(())
+			}
+		}
+	}
+}
+
+fn expand_from_bytes(
+	input: &DeriveInput,
+	fields: &Fields,
+	items: &[Field]
+) -> TokenStream2 {
+	let name = &input.ident;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+	let reads = items.iter().map(|field| {
+		let binding = &field.binding;
+		let ty = &field.ty;
+		quote! {
+			let (#binding, remainder) = <#ty as ::casper_types::bytesrepr::FromBytes>::from_bytes(
+				remainder
+			)?;
+		}
+	});
+	let bindings = items.iter().map(|field| &field.binding);
+	let result = match fields {
+		Fields::Named(_) => {
+			let members = items.iter().map(|field| &field.member);
+			quote!(Self { #(#members: #bindings,)* })
+		}
+		Fields::Unnamed(_) => quote!(Self(#(#bindings,)*)),
+		Fields::Unit => quote!(Self),
+	};
+
+	quote! {
+		impl #impl_generics ::casper_types::bytesrepr::FromBytes for #name #ty_generics #where_clause {
+			fn from_bytes(
+				bytes: &[u8]
+			) -> ::core::result::Result<(Self, &[u8]), ::casper_types::bytesrepr::Error> {
+				let remainder = bytes;
+				#(#reads)*
+				Ok((#result, remainder))
+			}
+		}
+	}
+}
+
+fn expand_cl_typed(
+	input: &DeriveInput,
+	_fields: &Fields,
+	items: &[Field]
+) -> TokenStream2 {
+	let name = &input.ident;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+	let types = items
+		.iter()
+		.map(|field| &field.ty)
+		.collect::<Vec<&Type>>();
+	let cl_type = nested_tuple(&types);
+
+	quote! {
+		impl #impl_generics ::casper_types::CLTyped for #name #ty_generics #where_clause {
+			fn cl_type() -> ::casper_types::CLType {
+				#cl_type
+			}
+		}
+	}
+}
+
+/// Tuples of the CLType are limited to three elements, the last one nests the remaining fields
+fn nested_tuple(types: &[&Type]) -> TokenStream2 {
+	let boxed = |ty: &Type| {
+		let cl_type = field_cl_type(ty);
+		quote!(::alloc::boxed::Box::new(#cl_type))
+	};
+	match types {
+		[] => quote!(::casper_types::CLType::Unit),
+		[a] => {
+			let a = boxed(a);
+			quote!(::casper_types::CLType::Tuple1([#a]))
+		}
+		[a, b] => {
+			let (a, b) = (boxed(a), boxed(b));
+			quote!(::casper_types::CLType::Tuple2([#a, #b]))
+		}
+		[a, b, c] => {
+			let (a, b, c) = (boxed(a), boxed(b), boxed(c));
+			quote!(::casper_types::CLType::Tuple3([#a, #b, #c]))
+		}
+		[a, b, rest @ ..] => {
+			let (a, b) = (boxed(a), boxed(b));
+			let rest = nested_tuple(rest);
+			quote! {
+				::casper_types::CLType::Tuple3([#a, #b, ::alloc::boxed::Box::new(#rest)])
+			}
+		}
+	}
+}
+
+fn field_cl_type(ty: &Type) -> TokenStream2 {
+	let is_u16 = match ty {
+		Type::Path(path) => path.qself.is_none() && path.path.is_ident("u16"),
+		_ => false,
+	};
+	if is_u16 {
+		quote!(::casper_types::CLType::ByteArray(2))
+	} else {
+		quote!(<#ty as ::casper_types::CLTyped>::cl_type())
+	}
+}
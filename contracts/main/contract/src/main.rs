@@ -6,8 +6,6 @@ compile_error!(
 	"target arch should be wasm32: compile with '--target wasm32-unknown-unknown'"
 );
 
-mod config_db;
-mod name_contract_hash_db;
 mod names_validator;
 mod utils;
 mod service;
@@ -21,86 +19,46 @@ use alloc::{ format, string::{ String, ToString }, vec, vec::Vec };
 
 use common_lib::{
 	constants::common_keys::{
-		ARG_DATABASE_DOMAIN_NAME,
-		ARG_DATABASE_RESOLVER,
-		ARG_MAIN_AUTHORITY,
-		ARG_MAIN_CUSTOMER_PURSE,
+		CommonArgs,
+		CommonKeys,
+		DatabaseArgs,
+		DatabaseEndpoints,
 		ARG_MAIN_DOMAIN,
 		ARG_MAIN_DOMAIN_PAGE,
-		ARG_MAIN_DURATION,
 		ARG_MAIN_PRICE_ORACLE_CONTRACT_HASH,
-		ARG_MAIN_REGISTER_AMOUNT,
 		ARG_MAIN_RESOLVER_ADDRESS,
-		ARG_MAIN_SUBDOMAIN,
-		ARG_NFT_METADATA,
-		ARG_NFT_TOKEN_OWNER,
-		ARG_PO_PRICE_TYPE_CHARS_COUNT,
-		ARG_REGISTRY_CONTRACT_HASH,
-		ARG_REGISTRY_OPERATOR_TYPE,
-		ENDPOINT_DATABASE_GET_DOMAIN,
-		ENDPOINT_DATABASE_SAVE_DOMAIN_NAME,
-		ENDPOINT_PO_GET_PRICE,
-		ENTRYPOINT_MAIN_ADD_AUTHORITY,
-		ENTRYPOINT_MAIN_EXTEND,
-		ENTRYPOINT_MAIN_GET_SUBDOMAINS_FOR_DOMAIN,
-		ENTRYPOINT_MAIN_REGISTER_DOMAIN,
-		ENTRYPOINT_MAIN_REGISTER_SUB_DOMAIN,
-		ENTRYPOINT_MAIN_REMOVE_AUTHORITY,
-		ENTRYPOINT_MAIN_REMOVE_SUBDOMAIN,
-		ENTRYPOINT_MAIN_RESOLVE_DOMAIN,
-		ENTRYPOINT_MAIN_SET_AUTHORITIES_CONTRACT_HASH,
-		ENTRYPOINT_MAIN_SET_RESOLVER_ADDRESS_FOR_DOMAIN,
-		ENTRYPOINT_MAIN_SET_RESOLVER_ADDRESS_FOR_SUBDOMAIN,
-		KEY_DATABASE_DICTIONARY_DOMAIN,
-		KEY_DATABASE_DICTIONARY_DOMAIN_LIST,
-		KEY_DATABASE_DICTIONARY_SUBDOMAIN,
-		KEY_MAIN_AUTHORITIES,
-		KEY_MAIN_AUTHORITIES_CONTRACT_HASH,
 		KEY_MAIN_CONTRACT_ACCESS_UREF,
 		KEY_MAIN_CONTRACT_HASH,
 		KEY_MAIN_CONTRACT_PACKAGE_NAME,
 		KEY_MAIN_CONTRACT_VERSION,
+		KEY_MAIN_DICTIONARY_DOMAIN_LIST,
 		KEY_MAIN_DICTIONARY_DOMAIN_METADATA,
-		KEY_MAIN_MAINTAINER,
-		KEY_MAIN_MAINTAINER_PURSE,
 		KEY_MAIN_PRICE_ORACLE_CONTRACT_HASH,
 		MAX_PAGE_SIZE,
-		MAX_SUBDOMAIN_COUNT,
 	},
 	enums::{
-		caller_verification_type::CallerVerificationType,
 		contracts_enum::ContractKind,
+		controller_roles::ControllerRoles,
 		domain_name_actual_state::DomainNameActualState,
 		main_contract::Error,
+		roles::Role,
 	},
 	errors::{ CommonError, MainContractErrors },
-	models::{ DomainName, LocalMetadata, SubdomainName },
+	models::{ DomainName, LocalMetadata },
+	schema,
 	utils::{
-		domain_name::{
-			calculate_domain_name_end_date,
-			get_end_time_actual_state,
-			is_domain_name_valid,
-			is_extension_duration_correct,
-			is_sub_domain_name_valid,
-			year_to_millis,
-		},
-		registry::{ has_authority, is_maintainer },
-		response::controller,
+		domain_name::{ get_end_time_actual_state, is_domain_name_valid },
+		pause::ensure_not_paused,
+		response::{ controller, response_error, response_success },
+		routes::get_route,
 		storage::{
 			get_dictionary_value_from_key,
-			get_stored_value_from_key,
-			store_value_for_key,
 			upsert_dictionary_value_from_key,
 		},
+		upgrade::{ is_upgrade, register_contract_package, upgrade_contract },
 	},
 };
 
-use casper_contract::contract_api::runtime::call_contract;
-use casper_contract::contract_api::system::{
-	create_purse,
-	get_purse_balance,
-	transfer_from_purse_to_purse,
-};
 use casper_contract::{
 	contract_api::{ runtime, storage },
 	unwrap_or_revert::UnwrapOrRevert,
@@ -110,33 +68,14 @@ use casper_types::{
 	account::AccountHash,
 	contracts::NamedKeys,
 	runtime_args,
-	CLType,
-	CLTyped,
 	CLValue,
 	ContractHash,
-	EntryPoint,
-	EntryPointAccess,
-	EntryPointType,
-	EntryPoints,
-	Key,
-	Parameter,
+	ContractPackageHash,
 	RuntimeArgs,
-	URef,
-	U512,
-};
-use common_lib::constants::common_keys::{
-	ENDPOINT_DATABASE_SET_DOMAIN_RESOLVER,
-	ENDPOINT_NFT_MINT,
 };
-use service::test_macro;
 
-use crate::config_db::ConfigDb;
-use crate::name_contract_hash_db::NameContractHashDb;
-use crate::names_validator::NamesValidator;
-use common_lib::utils::registry::get_contract_hash_from_authority_contract;
-use common_lib::utils::response::{ response_error, response_success };
+use crate::utils::{ get_config_for_name, is_owner_or_approved };
 
-use service;
 /**
  * Registers a new domain name
  * Steps:
@@ -152,9 +91,9 @@ use service;
  * 9. Retrieve domain name object
  *
  */
-
 #[no_mangle]
 pub extern "C" fn register_domain() {
+	ensure_not_paused(ContractKind::Main, None);
 	controller(service::register_domain::register_domain, vec![]);
 }
 
@@ -177,27 +116,28 @@ pub extern "C" fn resolve_domain() {
 		runtime::revert(Error::InvalidDomainName);
 	}
 
-	let db_contract_hash = match
-		NameContractHashDb::instance().get_contract_hash_for_domain_name(
-			&domain_name
-		)
-	{
-		Some(res) => res,
+	let db_contract_hash = match get_route(&domain_name) {
+		Some(res) => res.db_contract_hash,
 		None => {
 			return response_error(MainContractErrors::DomainNotExists);
 		}
 	};
 
-	let db_domain_name = call_contract::<Option<DomainName>>(
+	let db_domain_name = runtime::call_contract::<Option<DomainName>>(
 		db_contract_hash,
-		ENDPOINT_DATABASE_GET_DOMAIN,
+		&DatabaseEndpoints::GetDomain.to_string(),
 		runtime_args! {
-            ARG_DATABASE_DOMAIN_NAME => domain_name
-        }
+			DatabaseArgs::DomainName.to_string() => domain_name,
+		}
 	);
 
 	if let Some(domain) = db_domain_name {
-		match get_end_time_actual_state(Some(domain.end_time)) {
+		match
+			get_end_time_actual_state(
+				Some(domain.end_time),
+				get_config_for_name(&domain.name).grace_period
+			)
+		{
 			DomainNameActualState::Available => {
 				runtime::revert(MainContractErrors::DomainNotExists)
 			}
@@ -227,6 +167,7 @@ pub extern "C" fn resolve_domain() {
  */
 #[no_mangle]
 pub extern "C" fn set_resolver_address_for_domain() {
+	ensure_not_paused(ContractKind::Main, None);
 	let domain_name: String = runtime::get_named_arg(ARG_MAIN_DOMAIN);
 	let resolver_address: AccountHash = runtime::get_named_arg(
 		ARG_MAIN_RESOLVER_ADDRESS
@@ -235,27 +176,28 @@ pub extern "C" fn set_resolver_address_for_domain() {
 		runtime::revert(Error::InvalidDomainName);
 	}
 
-	let db_contract_hash = match
-		NameContractHashDb::instance().get_contract_hash_for_domain_name(
-			&domain_name
-		)
-	{
-		Some(res) => res,
+	let db_contract_hash = match get_route(&domain_name) {
+		Some(res) => res.db_contract_hash,
 		None => {
 			return response_error(MainContractErrors::DomainNotExists);
 		}
 	};
 
-	let db_domain_name = call_contract::<Option<DomainName>>(
-		db_contract_hash.clone(),
-		ENDPOINT_DATABASE_GET_DOMAIN,
+	let db_domain_name = runtime::call_contract::<Option<DomainName>>(
+		db_contract_hash,
+		&DatabaseEndpoints::GetDomain.to_string(),
 		runtime_args! {
-            ARG_DATABASE_DOMAIN_NAME => domain_name
-        }
+			DatabaseArgs::DomainName.to_string() => domain_name.clone(),
+		}
 	);
 
 	if let Some(domain) = db_domain_name {
-		match get_end_time_actual_state(Some(domain.end_time)) {
+		match
+			get_end_time_actual_state(
+				Some(domain.end_time),
+				get_config_for_name(&domain.name).grace_period
+			)
+		{
 			DomainNameActualState::Available => {
 				runtime::revert(MainContractErrors::DomainNotExists)
 			}
@@ -263,14 +205,14 @@ pub extern "C" fn set_resolver_address_for_domain() {
 				runtime::revert(MainContractErrors::DomainNameIsInGracePeriod)
 			}
 			DomainNameActualState::Busy => {
-				if domain.owner == runtime::get_caller() {
-					call_contract::<()>(
+				if is_owner_or_approved(&domain) {
+					runtime::call_contract::<()>(
 						db_contract_hash,
-						ENDPOINT_DATABASE_SET_DOMAIN_RESOLVER,
+						&DatabaseEndpoints::SetDomainResolver.to_string(),
 						runtime_args! {
-                            ARG_DATABASE_DOMAIN_NAME => domain_name,
-                            ARG_DATABASE_RESOLVER => resolver_address
-                        }
+							DatabaseArgs::DomainName.to_string() => domain_name,
+							DatabaseArgs::Resolver.to_string() => resolver_address,
+						}
 					)
 				}
 			}
@@ -285,200 +227,49 @@ pub extern "C" fn set_resolver_address_for_domain() {
  *
  * Steps:
  * 1. Check validity of the sub domain name
- * 2. Get domain of the subdomain
- * 3. Check wether domain exists or not, if not return corresponding error
- * 4. Get metadata for subdomains
- * 5. Store subdomain
- * 6. Update metadata and save it
+ * 2. Find the parent domain through its registry route and database shard
+ * 3. Check the caller owns the parent domain or is approved for it
+ * 4. Check the subdomain limit of the extension
+ * 5. Store subdomain in the database shard of the parent domain
  */
 #[no_mangle]
 pub extern "C" fn register_sub_domain() {
-	let subdomain_name: String = runtime::get_named_arg(ARG_MAIN_SUBDOMAIN);
-	let resolver_address: AccountHash = runtime::get_named_arg(
-		ARG_MAIN_RESOLVER_ADDRESS
-	);
-	let caller = runtime::get_caller();
-
-	let (is_valid, domain_name) = is_sub_domain_name_valid(&subdomain_name);
-
-	if !is_valid {
-		runtime::revert(MainContractErrors::InvalidSubdomain);
-	}
-
-	if let Some(domain) = domain_name {
-		let found_domain: Option<DomainName> = get_dictionary_value_from_key(
-			KEY_DATABASE_DICTIONARY_DOMAIN,
-			&domain
-		);
-		if let Some(dm) = found_domain {
-			if dm.owner == caller {
-				let subdomains: Option<Vec<SubdomainName>> =
-					get_dictionary_value_from_key(
-						KEY_DATABASE_DICTIONARY_SUBDOMAIN,
-						&domain
-					);
-				if let Some(mut sd) = subdomains {
-					if sd.len() < MAX_SUBDOMAIN_COUNT.into() {
-						sd.push(SubdomainName {
-							name: subdomain_name,
-							resolver: resolver_address,
-						});
-						upsert_dictionary_value_from_key(
-							KEY_DATABASE_DICTIONARY_SUBDOMAIN,
-							&domain,
-							sd
-						);
-					} else {
-						runtime::revert(MainContractErrors::SubdomainMaxCountExceeded);
-					}
-				} else {
-					upsert_dictionary_value_from_key(
-						KEY_DATABASE_DICTIONARY_SUBDOMAIN,
-						&domain,
-						vec![SubdomainName {
-							name: subdomain_name,
-							resolver: resolver_address,
-						}]
-					);
-				}
-			} else {
-				runtime::revert(MainContractErrors::InvalidOwner);
-			}
-		} else {
-			runtime::revert(MainContractErrors::DomainNotExists);
-		}
-	} else {
-		runtime::revert(MainContractErrors::SubdomainParseError);
-	}
+	ensure_not_paused(ContractKind::Main, None);
+	controller(service::subdomains::register_sub_domain, vec![]);
 }
 
 #[no_mangle]
 pub extern "C" fn remove_subdomain() {
-	let subdomain_name: String = runtime::get_named_arg(ARG_MAIN_SUBDOMAIN);
-	let caller = runtime::get_caller();
-
-	let (is_valid, domain_name) = is_sub_domain_name_valid(&subdomain_name);
-
-	if !is_valid {
-		runtime::revert(MainContractErrors::InvalidSubdomain);
-	}
-	if let Some(domain) = domain_name {
-		let found_domain: Option<DomainName> = get_dictionary_value_from_key(
-			KEY_DATABASE_DICTIONARY_DOMAIN,
-			&domain
-		);
-
-		if let Some(dm) = found_domain {
-			if dm.owner == caller {
-				let subdomains: Option<Vec<SubdomainName>> =
-					get_dictionary_value_from_key(
-						KEY_DATABASE_DICTIONARY_SUBDOMAIN,
-						&domain
-					);
-				if let Some(mut sd) = subdomains {
-					let pos = sd
-						.iter()
-						.position(|x| x.name == subdomain_name)
-						.unwrap_or_revert_with(MainContractErrors::SubdomainNotExists);
-					sd.remove(pos);
-					upsert_dictionary_value_from_key(
-						KEY_DATABASE_DICTIONARY_SUBDOMAIN,
-						&domain,
-						sd
-					);
-				}
-			} else {
-				runtime::revert(MainContractErrors::InvalidOwner);
-			}
-		} else {
-			runtime::revert(MainContractErrors::DomainNotExists);
-		}
-	}
+	ensure_not_paused(ContractKind::Main, None);
+	controller(service::subdomains::remove_subdomain, vec![]);
 }
 
 /**
  *
  * 1. Check validity of the sub domain name
- * 2. Get Price from PriceOracle and check payment amount
- * 3. Store data
+ * 2. Check the caller owns the parent domain or is approved for it
+ * 3. Store the resolver in the database shard of the parent domain
  *
  */
 #[no_mangle]
 pub extern "C" fn set_resolver_address_for_subdomain() {
-	let subdomain_name: String = runtime::get_named_arg(ARG_MAIN_SUBDOMAIN);
-	let resolver_address: AccountHash = runtime::get_named_arg(
-		ARG_MAIN_RESOLVER_ADDRESS
+	ensure_not_paused(ContractKind::Main, None);
+	controller(
+		service::subdomains::set_resolver_address_for_subdomain,
+		vec![]
 	);
-	let caller = runtime::get_caller();
-
-	let (is_valid, domain_name) = is_sub_domain_name_valid(&subdomain_name);
-
-	if !is_valid {
-		runtime::revert(MainContractErrors::InvalidSubdomain);
-	}
-	if let Some(domain) = domain_name {
-		let found_domain: Option<DomainName> = get_dictionary_value_from_key(
-			KEY_DATABASE_DICTIONARY_DOMAIN,
-			&domain
-		);
-
-		if let Some(dm) = found_domain {
-			if dm.owner == caller {
-				let subdomains: Option<Vec<SubdomainName>> =
-					get_dictionary_value_from_key(
-						KEY_DATABASE_DICTIONARY_SUBDOMAIN,
-						&domain
-					);
-				if let Some(sd) = subdomains {
-					let mapped = sd
-						.iter()
-						.map(|x| {
-							if x.name == subdomain_name {
-								return SubdomainName {
-									name: x.name.to_string(),
-									resolver: resolver_address,
-								};
-							}
-							(*x).clone()
-						})
-						.collect::<Vec<SubdomainName>>();
-					upsert_dictionary_value_from_key(
-						KEY_DATABASE_DICTIONARY_SUBDOMAIN,
-						&domain,
-						mapped
-					);
-				}
-			} else {
-				runtime::revert(MainContractErrors::InvalidOwner);
-			}
-		} else {
-			runtime::revert(MainContractErrors::DomainNotExists);
-		}
-	}
 }
 
 #[no_mangle]
 pub extern "C" fn get_sudomains_for_domain() {
-	let domain_name: String = runtime::get_named_arg(ARG_MAIN_DOMAIN);
-	if is_domain_name_valid(&domain_name) {
-		let subdomains: Option<Vec<SubdomainName>> = get_dictionary_value_from_key(
-			KEY_DATABASE_DICTIONARY_SUBDOMAIN,
-			&domain_name
-		);
-		let result = CLValue::from_t(subdomains).expect(
-			"Error while converting subdomains to cl_value"
-		);
-		runtime::ret(result);
-	} else {
-		runtime::revert(MainContractErrors::InvalidName);
-	}
+	controller(service::subdomains::get_subdomains_for_domain, vec![]);
 }
 
 #[no_mangle]
 pub extern "C" fn get_domain_list() {
 	let page: u8 = runtime::get_named_arg(ARG_MAIN_DOMAIN_PAGE);
 	let domains: Option<Vec<String>> = get_dictionary_value_from_key(
-		KEY_DATABASE_DICTIONARY_DOMAIN_LIST,
+		KEY_MAIN_DICTIONARY_DOMAIN_LIST,
 		&format!("{}:{}", page, MAX_PAGE_SIZE)
 	);
 	if let Some(list) = domains {
@@ -494,95 +285,48 @@ pub extern "C" fn get_domain_list() {
 	}
 }
 
+/**
+ * Renews the domain name
+ *
+ * Steps:
+ * 1. Check the duration against the max subscription years of the extension
+ * 2. Move the expiration date in the database
+ * 3. Move the expiration date in the token metadata
+ */
 #[no_mangle]
-pub extern "C" fn add_authority() {
-	let caller = runtime::get_caller();
-	let maintainer = get_stored_value_from_key::<AccountHash>(
-		KEY_MAIN_MAINTAINER
-	).unwrap_or_revert_with(CommonError::NoAuthority);
-	if caller != maintainer {
-		runtime::revert(MainContractErrors::OnlyMaintainerHasAccess);
-	}
-
-	let authority: AccountHash = runtime::get_named_arg(ARG_MAIN_AUTHORITY);
-
-	if authority == maintainer {
-		runtime::revert(MainContractErrors::CannotAddMaintainer);
-	}
-	let has_access = is_maintainer_or_has_authority(&authority);
-	if has_access {
-		runtime::revert(MainContractErrors::AuthorityHasAlreadyTaken);
-	}
-	let mut authorities = get_stored_value_from_key::<Vec<AccountHash>>(
-		KEY_MAIN_AUTHORITIES
-	).unwrap_or_revert_with(CommonError::NoAuthority);
-	authorities.push(authority);
-	store_value_for_key(KEY_MAIN_AUTHORITIES, authorities);
+pub extern "C" fn extend() {
+	ensure_not_paused(ContractKind::Main, None);
+	controller(service::extend::extend, vec![]);
 }
 
+/**
+ * Burns the transfer permission of a subdomain, the parent domain owner has access
+ *
+ * Steps:
+ * 1. Check the caller owns the parent domain or is approved for it
+ * 2. Burn the permission in the database, the transfer filter denies the later transfers
+ */
 #[no_mangle]
-pub extern "C" fn remove_authority() {
-	let caller = runtime::get_caller();
-	let maintainer = get_stored_value_from_key::<AccountHash>(
-		KEY_MAIN_MAINTAINER
-	).unwrap_or_revert_with(CommonError::NoAuthority);
-	if caller != maintainer {
-		runtime::revert(MainContractErrors::OnlyMaintainerHasAccess);
-	}
-
-	let authority: AccountHash = runtime::get_named_arg(ARG_MAIN_AUTHORITY);
-
-	if authority == maintainer {
-		runtime::revert(MainContractErrors::CannotRemoveMaintainer);
-	}
-	let has_access = is_maintainer_or_has_authority(&authority);
-	if !has_access {
-		runtime::revert(MainContractErrors::UserHasNoAccess);
-	}
-	let mut authorities = get_stored_value_from_key::<Vec<AccountHash>>(
-		KEY_MAIN_AUTHORITIES
-	).unwrap_or_revert_with(CommonError::NoAuthority);
-	let index = authorities
-		.iter()
-		.position(|item| item == &authority)
-		.unwrap();
-	authorities.remove(index);
-	store_value_for_key(KEY_MAIN_AUTHORITIES, authorities);
+pub extern "C" fn burn_subdomain_transfer_permission() {
+	ensure_not_paused(ContractKind::Main, None);
+	controller(
+		service::burn_transfer_permission::burn_transfer_permission,
+		vec![]
+	);
 }
 
+/**
+ * Releases a name past the grace period, anyone has access
+ *
+ * Steps:
+ * 1. Check the name is past the grace period
+ * 2. Burn the token of the previous holder through the NFT contract
+ * 3. Remove the name with its subdomains from the database
+ */
 #[no_mangle]
-pub extern "C" fn extend() {
-	let domain: String = runtime::get_named_arg(ARG_MAIN_DOMAIN);
-	let duration: u8 = runtime::get_named_arg(ARG_MAIN_DURATION);
-
-	if !is_domain_name_valid(&domain) {
-		runtime::revert(MainContractErrors::InvalidName);
-	}
-
-	let store_domain_optional: Option<DomainName> = get_dictionary_value_from_key(
-		KEY_DATABASE_DICTIONARY_DOMAIN,
-		&domain
-	);
-	if let Some(mut domain) = store_domain_optional {
-		let result = is_extension_duration_correct(
-			domain.end_time,
-			duration.into()
-		);
-
-		if result {
-			let binding = year_to_millis(duration);
-			domain.end_time += binding;
-			upsert_dictionary_value_from_key(
-				KEY_DATABASE_DICTIONARY_DOMAIN,
-				&domain.name,
-				domain.clone()
-			);
-		} else {
-			runtime::revert(MainContractErrors::InvalidDuration)
-		}
-	} else {
-		runtime::revert(MainContractErrors::DomainNotExists)
-	}
+pub extern "C" fn release_domain() {
+	ensure_not_paused(ContractKind::Main, None);
+	controller(service::release::release_domain, vec![]);
 }
 
 /**
@@ -597,29 +341,32 @@ pub extern "C" fn set_data() {}
 #[no_mangle]
 pub extern "C" fn get_data() {}
 
+/**
+ * Runs once right after an upgrade, only maintainer has access
+ */
+#[no_mangle]
+pub extern "C" fn migrate() {
+	controller(service::migrate::migrate, vec![ControllerRoles::OnlyMaintainer])
+}
+
+/**
+ * Only maintainer and ADMIN role
+ */
 #[no_mangle]
 pub extern "C" fn set_authorities_contract_hash() {
-	let authorities_contract_hash: ContractHash = runtime::get_named_arg(
-		ARG_MAIN_PRICE_ORACLE_CONTRACT_HASH
-	);
-	store_value_for_key(
-		KEY_MAIN_AUTHORITIES_CONTRACT_HASH,
-		authorities_contract_hash
-	);
+	controller(
+		service::authorities::set_authorities_contract_hash,
+		vec![ControllerRoles::OnlyRole(Role::Admin)]
+	)
 }
 
 #[no_mangle]
 pub extern "C" fn init() {
-	storage::new_dictionary(KEY_DATABASE_DICTIONARY_DOMAIN).unwrap_or_revert();
-	storage::new_dictionary(KEY_DATABASE_DICTIONARY_SUBDOMAIN).unwrap_or_revert();
-	storage
-		::new_dictionary(KEY_MAIN_DICTIONARY_DOMAIN_METADATA)
-		.unwrap_or_revert();
 	storage
-		::new_dictionary(KEY_DATABASE_DICTIONARY_DOMAIN_LIST)
+		::new_dictionary(KEY_MAIN_DICTIONARY_DOMAIN_LIST)
 		.unwrap_or_revert();
 	upsert_dictionary_value_from_key::<Vec<String>>(
-		KEY_DATABASE_DICTIONARY_DOMAIN_LIST,
+		KEY_MAIN_DICTIONARY_DOMAIN_LIST,
 		"0",
 		vec![]
 	);
@@ -643,193 +390,30 @@ pub extern "C" fn init() {
  * 6. set_resolver_address_for_subdomain -> sub.bakhrom.cspr -> address1
  * 7. get_sudomains_for_domain
  * 8. get_domain_list
- * 9. add authority
- * 10. remove authority
- * 11. set_data
- * 12. get_data
+ * 9. set_data
+ * 10. get_data
+ * 11. migrate
+ * 12. extend
+ * 13. burn_subdomain_transfer_permission
+ * 14. release_domain
  */
 #[no_mangle]
 pub extern "C" fn call() {
-	let mut entrypoints = EntryPoints::new();
-
-	entrypoints.add_entry_point(
-		EntryPoint::new(
-			ENTRYPOINT_MAIN_SET_AUTHORITIES_CONTRACT_HASH,
-			vec![
-				Parameter::new(
-					ARG_MAIN_PRICE_ORACLE_CONTRACT_HASH,
-					ContractHash::cl_type()
-				)
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		EntryPoint::new(
-			"init",
-			vec![],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
+	let entrypoints = schema::main::schema().to_entry_points();
 
-	entrypoints.add_entry_point(
-		EntryPoint::new(
-			ENTRYPOINT_MAIN_REGISTER_DOMAIN,
-			vec![
-				Parameter::new(ARG_MAIN_DOMAIN, String::cl_type()),
-				Parameter::new(ARG_MAIN_DURATION, u8::cl_type()),
-				Parameter::new(ARG_MAIN_RESOLVER_ADDRESS, AccountHash::cl_type())
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		EntryPoint::new(
-			ENTRYPOINT_MAIN_RESOLVE_DOMAIN,
-			vec![Parameter::new(ARG_MAIN_DOMAIN, String::cl_type())],
-			CLType::Any,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		EntryPoint::new(
-			ENTRYPOINT_MAIN_SET_RESOLVER_ADDRESS_FOR_DOMAIN,
-			vec![
-				Parameter::new(ARG_MAIN_DOMAIN, String::cl_type()),
-				Parameter::new(ARG_MAIN_RESOLVER_ADDRESS, AccountHash::cl_type())
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		EntryPoint::new(
-			ENTRYPOINT_MAIN_RESOLVE_DOMAIN,
-			vec![
-				Parameter::new(ARG_MAIN_DOMAIN, String::cl_type()),
-				Parameter::new(ARG_MAIN_RESOLVER_ADDRESS, String::cl_type())
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		EntryPoint::new(
-			ENTRYPOINT_MAIN_REGISTER_SUB_DOMAIN,
-			vec![
-				Parameter::new(ARG_MAIN_SUBDOMAIN, String::cl_type()),
-				Parameter::new(ARG_MAIN_RESOLVER_ADDRESS, AccountHash::cl_type())
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		EntryPoint::new(
-			ENTRYPOINT_MAIN_REMOVE_SUBDOMAIN,
-			vec![Parameter::new(ARG_MAIN_SUBDOMAIN, String::cl_type())],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		EntryPoint::new(
-			ENTRYPOINT_MAIN_SET_RESOLVER_ADDRESS_FOR_SUBDOMAIN,
-			vec![
-				Parameter::new(ARG_MAIN_SUBDOMAIN, String::cl_type()),
-				Parameter::new(ARG_MAIN_RESOLVER_ADDRESS, AccountHash::cl_type())
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		EntryPoint::new(
-			ENTRYPOINT_MAIN_GET_SUBDOMAINS_FOR_DOMAIN,
-			vec![Parameter::new(ARG_MAIN_DOMAIN, String::cl_type())],
-			CLType::Any,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		EntryPoint::new(
-			ENTRYPOINT_MAIN_ADD_AUTHORITY,
-			vec![Parameter::new(ARG_MAIN_AUTHORITY, AccountHash::cl_type())],
-			CLType::Any,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		EntryPoint::new(
-			ENTRYPOINT_MAIN_REMOVE_AUTHORITY,
-			vec![Parameter::new(ARG_MAIN_AUTHORITY, AccountHash::cl_type())],
-			CLType::Any,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		EntryPoint::new(
-			ENTRYPOINT_MAIN_ADD_AUTHORITY,
-			vec![Parameter::new(ARG_MAIN_DOMAIN_PAGE, u8::cl_type())],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
-
-	entrypoints.add_entry_point(
-		EntryPoint::new(
-			ENTRYPOINT_MAIN_EXTEND,
-			vec![
-				Parameter::new(ARG_MAIN_DOMAIN, String::cl_type()),
-				Parameter::new(ARG_MAIN_DURATION, u8::cl_type())
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
+	if is_upgrade() {
+		return upgrade_contract(entrypoints);
+	}
 
 	let mut main_named_keys = NamedKeys::new();
 
+	// Kept where `is_caller_maintainer` looks, the maintainer registers names shorter than the minimum
 	let maintainer_uref = storage::new_uref(runtime::get_caller());
 	main_named_keys.insert(
-		KEY_MAIN_MAINTAINER.to_string(),
+		CommonKeys::Maintainer.to_string(),
 		maintainer_uref.into()
 	);
 
-	let authorities_uref = storage::new_uref(Vec::<AccountHash>::new());
-	main_named_keys.insert(
-		KEY_MAIN_AUTHORITIES.to_string(),
-		authorities_uref.into()
-	);
-
 	let price_oracle_contract_hash: ContractHash = runtime::get_named_arg(
 		ARG_MAIN_PRICE_ORACLE_CONTRACT_HASH
 	);
@@ -841,8 +425,14 @@ pub extern "C" fn call() {
 		price_oracle_contract_hash_uref.into()
 	);
 
-	let purse = create_purse();
-	main_named_keys.insert(KEY_MAIN_MAINTAINER_PURSE.to_string(), purse.into());
+	// Shards, prices and configs are looked up in the administration directory
+	let administration_contract_hash: ContractHash = runtime::get_named_arg(
+		&CommonArgs::AdministrationContract.to_string()
+	);
+	main_named_keys.insert(
+		CommonKeys::AdministrationContract.to_string(),
+		storage::new_uref(administration_contract_hash).into()
+	);
 
 	let (contract_hash, version) = storage::new_contract(
 		entrypoints,
@@ -856,11 +446,15 @@ pub extern "C" fn call() {
 
 	let contract_version_uref = storage::new_uref(version);
 	runtime::put_key(KEY_MAIN_CONTRACT_VERSION, contract_version_uref.into());
-}
 
-fn is_maintainer_or_has_authority(account: &AccountHash) -> bool {
-	let caller_has_authority = has_authority(KEY_MAIN_AUTHORITIES, account);
-	let is_maintainer = is_maintainer(KEY_MAIN_MAINTAINER, account);
-
-	is_maintainer || caller_has_authority
+	let package_hash = runtime
+		::get_key(KEY_MAIN_CONTRACT_PACKAGE_NAME)
+		.and_then(|key| key.into_hash())
+		.map(ContractPackageHash::new)
+		.unwrap_or_revert_with(CommonError::MissingContractPackage);
+	register_contract_package(
+		package_hash,
+		contract_hash,
+		KEY_MAIN_CONTRACT_ACCESS_UREF
+	);
 }
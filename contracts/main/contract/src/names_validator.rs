@@ -1,11 +1,8 @@
 use alloc::format;
 use alloc::string::{ String, ToString };
 use alloc::vec::Vec;
-use casper_contract::contract_api::runtime;
-use casper_types::account::AccountHash;
 use common_lib::errors::MainContractErrors;
-
-pub const PUBLIC_ALLOWED_NAME_COUNT: usize = 3;
+use common_lib::utils::extension_config::get_extension_config;
 
 pub struct NamesValidator {
 	allowed_names: Vec<String>,
@@ -36,10 +33,10 @@ impl NamesValidator {
 			return Err(MainContractErrors::InvalidName);
 		}
 
-		let caller = runtime::get_caller();
-		if model.name.len() <= 3 && !self.is_maintainer {
-			return Err(MainContractErrors::InvalidCreator);
-		}
+		get_extension_config(&model.extension).validate_name_length(
+			model.get_name_len(),
+			self.is_maintainer
+		)?;
 
 		Ok(model)
 	}
@@ -63,7 +60,7 @@ impl From<String> for NamesModel {
 		let mut extension: String = "".to_string();
 
 		if split.len() == 2 {
-			name = split.get(0).unwrap().to_string();
+			name = split.first().unwrap().to_string();
 			extension = split.get(1).unwrap().to_string();
 		} else if split.len() > 2 {
 			extension = split.last().unwrap().to_string();
@@ -87,11 +84,11 @@ impl From<String> for NamesModel {
 	}
 }
 
-impl Into<String> for NamesModel {
-	fn into(self) -> String {
-		let mut result = format!("{}.{}", self.name, self.extension);
-		if self.sub_name.is_some() {
-			result = format!("{}.{}", self.sub_name.unwrap(), result);
+impl From<NamesModel> for String {
+	fn from(model: NamesModel) -> Self {
+		let mut result = format!("{}.{}", model.name, model.extension);
+		if let Some(sub_name) = model.sub_name {
+			result = format!("{}.{}", sub_name, result);
 		}
 
 		result
@@ -0,0 +1,25 @@
+use casper_contract::contract_api::runtime;
+use casper_types::ContractHash;
+use common_lib::{
+	constants::common_keys::{
+		ARG_MAIN_PRICE_ORACLE_CONTRACT_HASH,
+		KEY_MAIN_AUTHORITIES_CONTRACT_HASH,
+	},
+	utils::storage::store_value_for_key,
+};
+
+use crate::types::TResult;
+
+/// This service is closed, only maintainer and ADMIN role are passed
+/// Parameters:
+/// - price_oracle_contract_hash - required, the contract resolving database contracts of the names
+pub fn set_authorities_contract_hash() -> TResult<()> {
+	let authorities_contract_hash: ContractHash = runtime::get_named_arg(
+		ARG_MAIN_PRICE_ORACLE_CONTRACT_HASH
+	);
+	store_value_for_key(
+		KEY_MAIN_AUTHORITIES_CONTRACT_HASH,
+		authorities_contract_hash
+	);
+	Ok(())
+}
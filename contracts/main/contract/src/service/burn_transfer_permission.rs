@@ -0,0 +1,55 @@
+use alloc::string::{ String, ToString };
+use casper_contract::contract_api::runtime;
+use casper_types::{ runtime_args, RuntimeArgs };
+use common_lib::{
+	constants::common_keys::{ DatabaseArgs, DatabaseEndpoints, MainArgs },
+	enums::contracts_enum::ContractKind,
+	errors::MainContractErrors,
+	models::DomainName,
+	utils::{
+		domain_name::{ get_domain_name_extension, is_sub_domain_name_valid },
+		pause::ensure_not_paused,
+		routes::get_route,
+	},
+};
+
+use crate::{ types::TResult, utils::is_owner_or_approved };
+
+/// Subdomain stays with its current holder for good, the transfer filter denies every later transfer
+/// Only the owner of the parent domain or an operator approved in nft-core can burn the permission
+pub fn burn_transfer_permission() -> TResult<()> {
+	let subdomain: String = runtime::get_named_arg(
+		&MainArgs::Subdomain.to_string()
+	);
+
+	let (is_valid, domain) = is_sub_domain_name_valid(&subdomain);
+	if !is_valid {
+		return Err(MainContractErrors::InvalidSubdomain);
+	}
+	let domain = domain.ok_or(MainContractErrors::SubdomainParseError)?;
+
+	// Subdomains of the extension might be paused
+	ensure_not_paused(ContractKind::Main, get_domain_name_extension(&domain));
+
+	let route = get_route(&domain).ok_or(MainContractErrors::DomainNotExists)?;
+	let store_domain: Option<DomainName> = runtime::call_contract(
+		route.db_contract_hash,
+		&DatabaseEndpoints::GetDomain.to_string(),
+		runtime_args! {
+			DatabaseArgs::DomainName.to_string() => domain,
+		}
+	);
+	let store_domain = store_domain.ok_or(MainContractErrors::DomainNotExists)?;
+	if !is_owner_or_approved(&store_domain) {
+		return Err(MainContractErrors::InvalidOwner);
+	}
+
+	runtime::call_contract::<()>(
+		route.db_contract_hash,
+		&DatabaseEndpoints::BurnTransferPermission.to_string(),
+		runtime_args! {
+			DatabaseArgs::Name.to_string() => subdomain,
+		}
+	);
+	Ok(())
+}
@@ -0,0 +1,121 @@
+use alloc::string::{ String, ToString };
+use casper_contract::contract_api::runtime;
+use casper_types::{ runtime_args, RuntimeArgs, U512 };
+use common_lib::{
+	constants::common_keys::{
+		DatabaseArgs,
+		DatabaseEndpoints,
+		MainArgs,
+		NFTContractArgs,
+		NFTContractEndpoints,
+	},
+	enums::{
+		contracts_enum::ContractKind,
+		domain_name_actual_state::DomainNameActualState,
+		revenue_stream::RevenueStream,
+	},
+	errors::MainContractErrors,
+	models::DomainName,
+	utils::{
+		domain_name::{
+			get_domain_name_chars_count,
+			get_domain_name_extension,
+			get_end_time_actual_state,
+			is_domain_name_valid,
+			is_extension_duration_correct,
+			year_to_millis,
+		},
+		pause::ensure_not_paused,
+		routes::get_route,
+	},
+};
+
+use crate::{
+	types::TResult,
+	utils::{
+		collect_payment,
+		get_config_for_name,
+		get_nft_contract_hash,
+		get_price,
+		is_owner_or_approved,
+	},
+};
+
+/// Renews the domain, the expiration moves in the database shard and in the token metadata
+/// The owner or an operator approved in nft-core can renew the name
+/// Renewals are paid like registrations, the yearly price of the name times the duration
+/// Names past the grace period are not renewed, they can only be registered again
+pub fn extend() -> TResult<()> {
+	let domain: String = runtime::get_named_arg(&MainArgs::Domain.to_string());
+	let duration: u8 = runtime::get_named_arg(&MainArgs::Duration.to_string());
+
+	if !is_domain_name_valid(&domain) {
+		return Err(MainContractErrors::InvalidName);
+	}
+
+	let extension = get_domain_name_extension(&domain).ok_or(
+		MainContractErrors::InvalidExtension
+	)?;
+	// Renewals of the extension might be paused
+	ensure_not_paused(ContractKind::Main, Some(extension.clone()));
+
+	let route = get_route(&domain).ok_or(MainContractErrors::DomainNotExists)?;
+	let store_domain: Option<DomainName> = runtime::call_contract(
+		route.db_contract_hash,
+		&DatabaseEndpoints::GetDomain.to_string(),
+		runtime_args! {
+			DatabaseArgs::DomainName.to_string() => domain.clone(),
+		}
+	);
+	let store_domain = store_domain.ok_or(MainContractErrors::DomainNotExists)?;
+	if !is_owner_or_approved(&store_domain) {
+		return Err(MainContractErrors::InvalidOwner);
+	}
+
+	let config = get_config_for_name(&domain);
+	if
+		matches!(
+			get_end_time_actual_state(
+				Some(store_domain.end_time),
+				config.grace_period
+			),
+			DomainNameActualState::Available
+		)
+	{
+		return Err(MainContractErrors::DomainNameIsExpired);
+	}
+
+	let duration_millis = year_to_millis(duration);
+	if
+		!is_extension_duration_correct(
+			store_domain.end_time,
+			duration_millis,
+			config.max_subscription_years
+		)
+	{
+		return Err(MainContractErrors::InvalidDuration);
+	}
+	let end_time = store_domain.end_time + duration_millis;
+
+	let price = get_price(&extension, get_domain_name_chars_count(&domain));
+	collect_payment(U512::from(duration) * price, RevenueStream::Renewal)?;
+
+	runtime::call_contract::<()>(
+		route.db_contract_hash,
+		&DatabaseEndpoints::SetDomainExpiration.to_string(),
+		runtime_args! {
+			DatabaseArgs::DomainName.to_string() => domain.clone(),
+			DatabaseArgs::ExpirationDate.to_string() => end_time,
+		}
+	);
+
+	runtime::call_contract::<()>(
+		get_nft_contract_hash(),
+		&NFTContractEndpoints::SetTokenExpiration.to_string(),
+		runtime_args! {
+			NFTContractArgs::DomainName.to_string() => domain,
+			NFTContractArgs::ExpirationDate.to_string() => end_time,
+		}
+	);
+	Ok(())
+}
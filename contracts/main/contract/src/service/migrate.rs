@@ -0,0 +1,25 @@
+use common_lib::{
+	constants::common_keys::KEY_MAIN_DICTIONARY_DOMAIN_METADATA,
+	db::{ store::Store, traits::Storable },
+	models::LocalMetadata,
+	utils::upgrade::run_migrations,
+};
+
+use crate::types::TResult;
+
+/// Schema version of the domain metadata and authorities kept by the main contract
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Runs right after a new contract version is added to the package,
+/// a step moves the stored state from `version` to `version + 1`
+/// 1 -> 2: domain metadata is stored with its structural CLType instead of `CLType::Any`
+/// Returns the schema version of the stored state
+pub fn migrate() -> TResult<u32> {
+	Ok(
+		run_migrations(SCHEMA_VERSION, |version| {
+			if version == 1 {
+				Store::instance().rewrite::<LocalMetadata>(KEY_MAIN_DICTIONARY_DOMAIN_METADATA);
+			}
+		})
+	)
+}
@@ -1 +1,7 @@
+pub mod authorities;
+pub mod burn_transfer_permission;
+pub mod extend;
+pub mod migrate;
 pub mod register_domain;
+pub mod release;
+pub mod subdomains;
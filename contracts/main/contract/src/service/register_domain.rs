@@ -1,21 +1,50 @@
 use alloc::string::{ String, ToString };
 use casper_contract::contract_api::runtime;
-use casper_types::account::AccountHash;
+use casper_types::{ account::AccountHash, runtime_args, Key, RuntimeArgs, U512 };
 use common_lib::{
-	constants::common_keys::MainArgs,
-	enums::domain_name_actual_state::DomainNameActualState,
+	constants::common_keys::{
+		DatabaseArgs,
+		DatabaseEndpoints,
+		MainArgs,
+		NFTContractArgs,
+		NFTContractEndpoints,
+	},
+	enums::{
+		contracts_enum::ContractKind,
+		domain_name_actual_state::DomainNameActualState,
+		registration_mode::RegistrationMode,
+		revenue_stream::RevenueStream,
+	},
 	errors::MainContractErrors,
-	models::DomainName,
+	models::{
+		registry_contract_hash_pair::RegistryContractHashPair,
+		DomainName,
+	},
 	utils::{
-		domain_name::get_end_time_actual_state,
-		maintainer::{ is_caller_maintainer, is_maintainer },
+		domain_name::{
+			calculate_domain_name_end_date,
+			get_end_time_actual_state,
+			get_token_id,
+		},
+		extension_config::{ get_extension_config, is_allowlisted },
+		maintainer::is_caller_maintainer,
+		pause::ensure_not_paused,
+		routes::{ get_route, set_route },
 	},
 };
 
 use crate::{
 	names_validator::NamesValidator,
 	types::TResult,
-	utils::get_allowed_extensions,
+	utils::{
+		collect_payment,
+		decrement_shard,
+		get_allowed_extensions,
+		get_nft_contract_hash,
+		get_price,
+		get_shard,
+		increment_shard,
+	},
 };
 
 pub fn register_domain() -> TResult<DomainName> {
@@ -24,12 +53,6 @@ pub fn register_domain() -> TResult<DomainName> {
 	let resolver_address: AccountHash = runtime::get_named_arg(
 		&MainArgs::ResolverAddress.to_string()
 	);
-	let amount: U512 = runtime::get_named_arg(
-		&MainArgs::RegisterAmount.to_string()
-	);
-	let customer_purse: URef = runtime::get_named_arg(
-		&MainArgs::CustomerPurse.to_string()
-	);
 
 	let extensions = get_allowed_extensions();
 	if extensions.is_empty() {
@@ -37,190 +60,129 @@ pub fn register_domain() -> TResult<DomainName> {
 	}
 
 	// Validation
-	let validator = NamesValidator::instance(
-		extensions.unwrap(),
-		is_caller_maintainer()
-	);
+	let validator = NamesValidator::instance(extensions, is_caller_maintainer());
+	let model = validator.validate_name(domain.to_string())?;
 
-	let model = match validator.validate_name(domain.to_string()) {
-		Ok(res) => res,
-		Err(e) => {
-			return Err(e);
-		}
-	};
+	// Registrations of the extension might be paused
+	ensure_not_paused(ContractKind::Main, Some(model.extension.to_string()));
+
+	let config = get_extension_config(&model.extension);
+
+	// Allowlisted extensions are open only for allowlisted accounts
+	if
+		config.registration_mode == RegistrationMode::Allowlisted &&
+		!is_caller_maintainer() &&
+		!is_allowlisted(&model.extension, Key::Account(runtime::get_caller()))
+	{
+		return Err(MainContractErrors::UserHasNoAccessToRegister);
+	}
 
 	// Duration check
-	if duration > 3 {
+	if duration == 0 || duration > config.max_subscription_years {
 		return Err(MainContractErrors::InvalidDuration);
 	}
 
-	// Checking for existence in db
-	let db_contract_hash =
-		NameContractHashDb::instance().get_contract_hash_for_domain_name(&domain);
-
-	if let Some(hash) = db_contract_hash {
-		let store_domain_optional: Option<DomainName> = call_contract(
-			hash,
-			ENDPOINT_DATABASE_GET_DOMAIN,
+	// Checking for existence in db, an expired registration is dropped from its shard before the new one is saved
+	let mut released: Option<(RegistryContractHashPair, DomainName)> = None;
+	if let Some(route) = get_route(&domain) {
+		let store_domain: Option<DomainName> = runtime::call_contract(
+			route.db_contract_hash,
+			&DatabaseEndpoints::GetDomain.to_string(),
 			runtime_args! {
-                ARG_DATABASE_DOMAIN_NAME => domain.to_string()
-            }
+				DatabaseArgs::DomainName.to_string() => domain.clone(),
+			}
 		);
 
-		if let Some(store_domain) = store_domain_optional {
-			let actual_state = get_end_time_actual_state(Some(store_domain.end_time));
-			match actual_state {
+		if let Some(store_domain) = store_domain {
+			match
+				get_end_time_actual_state(
+					Some(store_domain.end_time),
+					config.grace_period
+				)
+			{
 				DomainNameActualState::Busy => {
 					return Err(MainContractErrors::DomainNameIsBusy);
 				}
 				DomainNameActualState::GracePeriod => {
 					return Err(MainContractErrors::DomainNameIsInGracePeriod);
 				}
-				DomainNameActualState::Available => {}
+				DomainNameActualState::Available => {
+					released = Some((route, store_domain));
+				}
 			}
 		}
 	}
 
-	// Checking with price oracle
-	let authorities_hash: Option<ContractHash> = get_stored_value_from_key(
-		KEY_MAIN_AUTHORITIES_CONTRACT_HASH
-	);
-	if authorities_hash.is_none() {
-		return response_error(
-			MainContractErrors::AuthoritiesContractHashNotConfigured
+	// Payment process, in CSPR or in an accepted CEP-18 token
+	let price = get_price(&model.extension, model.get_name_len());
+	collect_payment(U512::from(duration) * price, RevenueStream::Registration)?;
+
+	// Database and NFT core shards of the extension
+	let db_contract_hash = get_shard(ContractKind::Database, &model.extension)?;
+	let nft_core_contract_hash = get_shard(
+		ContractKind::NFTCore,
+		&model.extension
+	)?;
+	let nft_contract_hash = get_nft_contract_hash();
+
+	// Burnt and removed through the previous route, the name might have moved to another shard since
+	if let Some((route, store_domain)) = released {
+		runtime::call_contract::<()>(
+			nft_contract_hash,
+			&NFTContractEndpoints::Burn.to_string(),
+			runtime_args! {
+				NFTContractArgs::DomainName.to_string() => domain.clone(),
+				NFTContractArgs::TokenId.to_string() => store_domain.token_id,
+			}
 		);
-	}
-
-	let chars_count = model.get_name_len();
-
-	let price_oracle_contract_hash: ContractHash = match
-		get_contract_hash_from_authority_contract(
-			authorities_hash.unwrap(),
-			ContractKind::PriceOracle,
-			None
-		)
-	{
-		Ok(res) =>
-			match res {
-				Some(res) => res,
-				None => {
-					return response_error(
-						CommonError::NoContractHashWasFoundInAuthoritiesContract
-					);
-				}
+		runtime::call_contract::<()>(
+			route.db_contract_hash,
+			&DatabaseEndpoints::RemoveDomainName.to_string(),
+			runtime_args! {
+				DatabaseArgs::DomainName.to_string() => domain.clone(),
 			}
-		Err(e) => {
-			return response_error(e);
-		}
-	};
-
-	let price: U512 = runtime::call_contract(
-		price_oracle_contract_hash,
-		ENDPOINT_PO_GET_PRICE,
-		runtime_args! {
-            ARG_PO_PRICE_TYPE_CHARS_COUNT => chars_count as u64
-        }
-	);
-
-	// Checking price
-	if U512::from(duration) * price != amount {
-		runtime::revert(MainContractErrors::PriceDiscrepancy);
+		);
+		decrement_shard(ContractKind::Database, route.db_contract_hash, &model.extension);
+		decrement_shard(ContractKind::NFTCore, route.nft_contract_hash, &model.extension);
 	}
 
-	// Payment process
-	let purse: URef = get_stored_value_from_key(
-		KEY_MAIN_MAINTAINER_PURSE
-	).unwrap_or_revert_with(MainContractErrors::MaintainerPurseNotConfigured);
-
-	let balance = get_purse_balance(customer_purse).unwrap_or_revert();
-
-	if balance < amount {
-		return response_error(MainContractErrors::InsufficientCustomerBalance);
-	}
-	transfer_from_purse_to_purse(
-		customer_purse,
-		purse,
-		amount,
-		None
-	).unwrap_or_revert();
-
-	// Mint NFT
-	let nft_contract_hash = match
-		get_contract_hash_from_authority_contract(
-			authorities_hash.unwrap(),
-			ContractKind::NFT,
-			None
-		)
-	{
-		Ok(res) =>
-			match res {
-				Some(res) => res,
-				None => {
-					return response_error(
-						CommonError::NoContractHashWasFoundInAuthoritiesContract
-					);
-				}
-			}
-		Err(e) => {
-			return response_error(e);
-		}
-	};
+	// Registry is the only router, the NFT contract resolves the core contract through it
+	set_route(&domain, RegistryContractHashPair {
+		db_contract_hash,
+		nft_contract_hash: nft_core_contract_hash,
+	});
 
-	let token_id = base16::encode_lower(&runtime::blake2b(&domain));
+	// Mint NFT, the same token hash is minted again after the burn
+	let end_time = calculate_domain_name_end_date(duration);
+	let caller = runtime::get_caller();
 
 	runtime::call_contract::<()>(
 		nft_contract_hash,
-		ENDPOINT_NFT_MINT,
+		&NFTContractEndpoints::Mint.to_string(),
 		runtime_args! {
-            ARG_NFT_TOKEN_OWNER => runtime::get_caller(),
-            ARG_NFT_METADATA => token_id,
-        }
+			NFTContractArgs::DomainName.to_string() => domain.clone(),
+			NFTContractArgs::Owner.to_string() => Key::Account(caller),
+			NFTContractArgs::ExpirationDate.to_string() => end_time,
+		}
 	);
 
 	// Save to database
-	let end_time = calculate_domain_name_end_date(duration);
-	let caller = runtime::get_caller();
-
-	let db_contract_hash = match
-		get_contract_hash_from_authority_contract(
-			authorities_hash.unwrap(),
-			ContractKind::Database,
-			Some(model.extension)
-		)
-	{
-		Ok(hash) =>
-			match hash {
-				Some(res) => res,
-				None => {
-					return response_error(
-						MainContractErrors::DatabaseFulfilledOrNotConfigured
-					);
-				}
-			}
-		Err(e) => {
-			return response_error(e);
-		}
-	};
-
 	let saving_domain_name = DomainName {
 		end_time,
 		name: domain.clone(),
-		token_id: token_id.to_string(),
-		owner: caller,
+		token_id: get_token_id(&domain),
+		owner: Key::Account(caller),
 		resolver: resolver_address,
 	};
 
 	runtime::call_contract::<()>(
 		db_contract_hash,
-		ENDPOINT_DATABASE_SAVE_DOMAIN_NAME,
+		&DatabaseEndpoints::SaveDomainName.to_string(),
 		runtime_args! {
-            ARG_DATABASE_DOMAIN_NAME => saving_domain_name.clone()
-        }
-	);
-	// Save to name_contract_hash_map
-	NameContractHashDb::instance().set_contract_hash_for_domain_name(
-		&domain,
-		db_contract_hash
+			DatabaseArgs::DomainName.to_string() => saving_domain_name.clone(),
+		}
 	);
+	increment_shard(ContractKind::Database, db_contract_hash, &model.extension);
+	increment_shard(ContractKind::NFTCore, nft_core_contract_hash, &model.extension);
 	Ok(saving_domain_name)
 }
@@ -0,0 +1,93 @@
+use alloc::string::{ String, ToString };
+use casper_contract::contract_api::runtime;
+use casper_types::{ runtime_args, RuntimeArgs };
+use common_lib::{
+	constants::common_keys::{
+		DatabaseArgs,
+		DatabaseEndpoints,
+		MainArgs,
+		NFTContractArgs,
+		NFTContractEndpoints,
+	},
+	enums::{
+		contracts_enum::ContractKind,
+		domain_name_actual_state::DomainNameActualState,
+	},
+	errors::MainContractErrors,
+	models::DomainName,
+	utils::{
+		domain_name::{
+			get_domain_name_extension,
+			get_end_time_actual_state,
+			is_domain_name_valid,
+		},
+		pause::ensure_not_paused,
+		routes::{ get_route, remove_route },
+	},
+};
+
+use crate::{
+	types::TResult,
+	utils::{ decrement_shard, get_config_for_name, get_nft_contract_hash },
+};
+
+/// Releases a name past the grace period, anyone can release it
+/// The token of the previous holder is burnt and the name is removed from its database shard with the subdomains,
+/// both shards count one name less and the registry route is removed, the next registration mints the token again
+pub fn release_domain() -> TResult<()> {
+	let domain: String = runtime::get_named_arg(&MainArgs::Domain.to_string());
+
+	if !is_domain_name_valid(&domain) {
+		return Err(MainContractErrors::InvalidName);
+	}
+
+	// Releases of the extension might be paused
+	let extension = get_domain_name_extension(&domain).ok_or(
+		MainContractErrors::InvalidExtension
+	)?;
+	ensure_not_paused(ContractKind::Main, Some(extension.clone()));
+
+	let route = get_route(&domain).ok_or(MainContractErrors::DomainNotExists)?;
+	let store_domain: Option<DomainName> = runtime::call_contract(
+		route.db_contract_hash,
+		&DatabaseEndpoints::GetDomain.to_string(),
+		runtime_args! {
+			DatabaseArgs::DomainName.to_string() => domain.clone(),
+		}
+	);
+	let store_domain = store_domain.ok_or(MainContractErrors::DomainNotExists)?;
+
+	let config = get_config_for_name(&domain);
+	match get_end_time_actual_state(Some(store_domain.end_time), config.grace_period) {
+		DomainNameActualState::Busy => {
+			return Err(MainContractErrors::DomainNameIsNotExpired);
+		}
+		DomainNameActualState::GracePeriod => {
+			return Err(MainContractErrors::DomainNameIsInGracePeriod);
+		}
+		DomainNameActualState::Available => {}
+	}
+
+	runtime::call_contract::<()>(
+		get_nft_contract_hash(),
+		&NFTContractEndpoints::Burn.to_string(),
+		runtime_args! {
+			NFTContractArgs::DomainName.to_string() => domain.clone(),
+			NFTContractArgs::TokenId.to_string() => store_domain.token_id,
+		}
+	);
+
+	runtime::call_contract::<()>(
+		route.db_contract_hash,
+		&DatabaseEndpoints::RemoveDomainName.to_string(),
+		runtime_args! {
+			DatabaseArgs::DomainName.to_string() => domain.clone(),
+		}
+	);
+	decrement_shard(ContractKind::Database, route.db_contract_hash, &extension);
+	decrement_shard(ContractKind::NFTCore, route.nft_contract_hash, &extension);
+
+	// Routed last, the burn resolves the nft-core shard through the route
+	remove_route(&domain);
+	Ok(())
+}
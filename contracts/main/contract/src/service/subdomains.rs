@@ -0,0 +1,173 @@
+use alloc::{ string::{ String, ToString }, vec::Vec };
+use casper_contract::contract_api::runtime;
+use casper_types::{ account::AccountHash, runtime_args, ContractHash, RuntimeArgs };
+use common_lib::{
+	constants::common_keys::{ DatabaseArgs, DatabaseEndpoints, MainArgs },
+	enums::contracts_enum::ContractKind,
+	errors::MainContractErrors,
+	models::{ DomainName, SubdomainName },
+	utils::{
+		domain_name::{
+			get_domain_name_extension,
+			is_domain_name_valid,
+			is_sub_domain_name_valid,
+		},
+		pause::ensure_not_paused,
+		routes::get_route,
+	},
+};
+
+use crate::{
+	types::TResult,
+	utils::{ get_config_for_name, is_owner_or_approved },
+};
+
+/// Subdomains live next to their domain in its database shard
+/// The owner of the domain or an operator approved in nft-core can register one, up to the subdomain limit of the extension
+pub fn register_sub_domain() -> TResult<()> {
+	let subdomain_name: String = runtime::get_named_arg(
+		&MainArgs::Subdomain.to_string()
+	);
+	let resolver: AccountHash = runtime::get_named_arg(
+		&MainArgs::ResolverAddress.to_string()
+	);
+	let (db_contract_hash, domain) = get_managed_domain(&subdomain_name)?;
+
+	let subdomains = get_subdomain_list(db_contract_hash, &domain.name);
+	if subdomains.contains(&subdomain_name) {
+		return Err(MainContractErrors::DomainAlreadyExists);
+	}
+	let max_subdomain_count = get_config_for_name(&domain.name).max_subdomain_count;
+	if subdomains.len() >= max_subdomain_count.into() {
+		return Err(MainContractErrors::SubdomainMaxCountExceeded);
+	}
+
+	runtime::call_contract::<()>(
+		db_contract_hash,
+		&DatabaseEndpoints::SaveSubdomainName.to_string(),
+		runtime_args! {
+			DatabaseArgs::DomainName.to_string() => domain.name,
+			DatabaseArgs::SubdomainName.to_string() => SubdomainName {
+				name: subdomain_name,
+				resolver,
+			},
+		}
+	);
+	Ok(())
+}
+
+pub fn remove_subdomain() -> TResult<()> {
+	let subdomain_name: String = runtime::get_named_arg(
+		&MainArgs::Subdomain.to_string()
+	);
+	let (db_contract_hash, domain) = get_managed_domain(&subdomain_name)?;
+	if !get_subdomain_list(db_contract_hash, &domain.name).contains(&subdomain_name) {
+		return Err(MainContractErrors::SubdomainNotExists);
+	}
+
+	runtime::call_contract::<()>(
+		db_contract_hash,
+		&DatabaseEndpoints::RemoveSubdomainName.to_string(),
+		runtime_args! {
+			DatabaseArgs::DomainName.to_string() => domain.name,
+			DatabaseArgs::SubdomainName.to_string() => subdomain_name,
+		}
+	);
+	Ok(())
+}
+
+pub fn set_resolver_address_for_subdomain() -> TResult<()> {
+	let subdomain_name: String = runtime::get_named_arg(
+		&MainArgs::Subdomain.to_string()
+	);
+	let resolver: AccountHash = runtime::get_named_arg(
+		&MainArgs::ResolverAddress.to_string()
+	);
+	let (db_contract_hash, domain) = get_managed_domain(&subdomain_name)?;
+	if !get_subdomain_list(db_contract_hash, &domain.name).contains(&subdomain_name) {
+		return Err(MainContractErrors::SubdomainNotExists);
+	}
+
+	runtime::call_contract::<()>(
+		db_contract_hash,
+		&DatabaseEndpoints::SetSubdomainResolver.to_string(),
+		runtime_args! {
+			DatabaseArgs::SubdomainName.to_string() => subdomain_name,
+			DatabaseArgs::Resolver.to_string() => resolver,
+		}
+	);
+	Ok(())
+}
+
+/// Subdomains of a routed domain, unknown domains have none
+pub fn get_subdomains_for_domain() -> TResult<Option<Vec<SubdomainName>>> {
+	let domain_name: String = runtime::get_named_arg(
+		&MainArgs::Domain.to_string()
+	);
+	if !is_domain_name_valid(&domain_name) {
+		return Err(MainContractErrors::InvalidName);
+	}
+	let route = match get_route(&domain_name) {
+		Some(route) => route,
+		None => {
+			return Ok(None);
+		}
+	};
+
+	let subdomains = get_subdomain_list(route.db_contract_hash, &domain_name)
+		.into_iter()
+		.filter_map(|subdomain_name| {
+			runtime::call_contract::<Option<SubdomainName>>(
+				route.db_contract_hash,
+				&DatabaseEndpoints::GetSubdomain.to_string(),
+				runtime_args! {
+					DatabaseArgs::SubdomainName.to_string() => subdomain_name,
+				}
+			)
+		})
+		.collect::<Vec<SubdomainName>>();
+	Ok(Some(subdomains))
+}
+
+/// Database shard and record of the parent domain, the caller has to own it or be approved for it in nft-core
+fn get_managed_domain(
+	subdomain_name: &str
+) -> TResult<(ContractHash, DomainName)> {
+	let (is_valid, domain_name) = is_sub_domain_name_valid(subdomain_name);
+	if !is_valid {
+		return Err(MainContractErrors::InvalidSubdomain);
+	}
+	let domain_name = domain_name.ok_or(MainContractErrors::SubdomainParseError)?;
+
+	// Subdomains of the extension might be paused
+	ensure_not_paused(
+		ContractKind::Main,
+		get_domain_name_extension(&domain_name)
+	);
+
+	let route = get_route(&domain_name).ok_or(
+		MainContractErrors::DomainNotExists
+	)?;
+	let domain: Option<DomainName> = runtime::call_contract(
+		route.db_contract_hash,
+		&DatabaseEndpoints::GetDomain.to_string(),
+		runtime_args! {
+			DatabaseArgs::DomainName.to_string() => domain_name,
+		}
+	);
+	let domain = domain.ok_or(MainContractErrors::DomainNotExists)?;
+	if !is_owner_or_approved(&domain) {
+		return Err(MainContractErrors::InvalidOwner);
+	}
+	Ok((route.db_contract_hash, domain))
+}
+
+fn get_subdomain_list(db_contract_hash: ContractHash, domain_name: &str) -> Vec<String> {
+	runtime::call_contract(
+		db_contract_hash,
+		&DatabaseEndpoints::GetSubdomainList.to_string(),
+		runtime_args! {
+			DatabaseArgs::DomainName.to_string() => domain_name.to_string(),
+		}
+	)
+}
@@ -1,11 +1,38 @@
-use alloc::{ string::{ String, ToString }, vec::{ self, Vec } };
-use casper_contract::contract_api::runtime;
-use casper_types::runtime_args;
+use alloc::{ string::{ String, ToString }, vec::Vec };
+use casper_contract::{
+	contract_api::{ runtime, system },
+	unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{ runtime_args, ContractHash, Key, RuntimeArgs, URef, U512 };
 use common_lib::{
-	constants::common_keys::AdministrationEndpoints,
-	utils::contract::get_administration_contract_hash,
+	constants::common_keys::{
+		AdministrationArgs,
+		AdministrationEndpoints,
+		MainArgs,
+		NFTCoreContractEndpoints,
+		PriceOracleArgs,
+		ENDPOINT_PO_GET_PRICE,
+	},
+	enums::{ contracts_enum::ContractKind, revenue_stream::RevenueStream },
+	errors::{ CommonError, MainContractErrors },
+	models::{ confiugration::ExtensionConfig, DomainName },
+	utils::{
+		contract::get_administration_contract_hash,
+		domain_name::get_domain_name_extension,
+		extension_config::get_extension_config,
+		payment::{
+			get_payment_token,
+			get_price_oracle_contract_hash,
+			transfer_from,
+		},
+		routes::get_route,
+		treasury::deposit,
+		upgrade::get_optional_named_arg,
+	},
 };
 
+use crate::types::TResult;
+
 pub fn get_allowed_extensions() -> Vec<String> {
 	let contract_hash = get_administration_contract_hash();
 
@@ -15,3 +42,179 @@ pub fn get_allowed_extensions() -> Vec<String> {
 		runtime_args! {}
 	)
 }
+
+/// Configuration of the extension the name belongs to
+pub fn get_config_for_name(name: &str) -> ExtensionConfig {
+	let extension = get_domain_name_extension(name).unwrap_or_revert_with(
+		MainContractErrors::InvalidExtension
+	);
+	get_extension_config(&extension)
+}
+
+/// Shard of the extension new names go to, the administration directory skips the filled ones
+pub fn get_shard(kind: ContractKind, extension: &str) -> TResult<ContractHash> {
+	let (key, _): (Key, Option<u32>) = runtime::call_contract(
+		get_administration_contract_hash(),
+		&AdministrationEndpoints::GetContract.to_string(),
+		runtime_args! {
+			AdministrationArgs::ContractKind.to_string() => kind,
+			AdministrationArgs::Extension.to_string() => Some(extension.to_string()),
+		}
+	);
+	key
+		.into_hash()
+		.map(ContractHash::new)
+		.ok_or(MainContractErrors::DatabaseFulfilledOrNotConfigured)
+}
+
+/// Counts the name stored in the shard, the directory marks the shard filled by the count
+pub fn increment_shard(kind: ContractKind, contract_hash: ContractHash, extension: &str) {
+	change_shard_count(
+		AdministrationEndpoints::IncrementContract,
+		kind,
+		contract_hash,
+		extension
+	)
+}
+
+/// Uncounts the name removed from the shard, so the shard takes new names again
+pub fn decrement_shard(kind: ContractKind, contract_hash: ContractHash, extension: &str) {
+	change_shard_count(
+		AdministrationEndpoints::DecrementContract,
+		kind,
+		contract_hash,
+		extension
+	)
+}
+
+fn change_shard_count(
+	endpoint: AdministrationEndpoints,
+	kind: ContractKind,
+	contract_hash: ContractHash,
+	extension: &str
+) {
+	runtime::call_contract::<()>(
+		get_administration_contract_hash(),
+		&endpoint.to_string(),
+		runtime_args! {
+			AdministrationArgs::ContractKind.to_string() => kind,
+			AdministrationArgs::Key.to_string() => Key::from(contract_hash),
+			AdministrationArgs::Extension.to_string() => Some(extension.to_string()),
+		}
+	)
+}
+
+/// NFT contract wraps every nft-core shard, it is registered without an extension
+pub fn get_nft_contract_hash() -> ContractHash {
+	let (key, _): (Key, Option<u32>) = runtime::call_contract(
+		get_administration_contract_hash(),
+		&AdministrationEndpoints::GetContract.to_string(),
+		runtime_args! {
+			AdministrationArgs::ContractKind.to_string() => ContractKind::NFT,
+			AdministrationArgs::Extension.to_string() => Option::<String>::None,
+		}
+	);
+	key
+		.into_hash()
+		.map(ContractHash::new)
+		.unwrap_or_revert_with(CommonError::UnexpectedKeyVariant)
+}
+
+/// Owner of the name, the approved spender of its token or an operator for all tokens of the owner
+/// Ownership and approvals live in the nft-core shard of the name, the owner kept in the database
+/// is not updated when the token moves through nft-core or the NFT contract
+pub fn is_owner_or_approved(domain: &DomainName) -> bool {
+	let caller = Key::Account(runtime::get_caller());
+	let nft_core_contract_hash = match get_route(&domain.name) {
+		Some(route) => route.nft_contract_hash,
+		None => {
+			return false;
+		}
+	};
+
+	let owner: Key = runtime::call_contract(
+		nft_core_contract_hash,
+		&NFTCoreContractEndpoints::OwnerOf.to_string(),
+		runtime_args! {
+			"token_hash" => domain.token_id.clone(),
+		}
+	);
+	if owner == caller {
+		return true;
+	}
+
+	let approved: Option<Key> = runtime::call_contract(
+		nft_core_contract_hash,
+		&NFTCoreContractEndpoints::GetApproved.to_string(),
+		runtime_args! {
+			"token_hash" => domain.token_id.clone(),
+		}
+	);
+	if approved == Some(caller) {
+		return true;
+	}
+
+	runtime::call_contract::<bool>(
+		nft_core_contract_hash,
+		&NFTCoreContractEndpoints::IsApprovedForAll.to_string(),
+		runtime_args! {
+			"token_owner" => owner,
+			"operator" => caller,
+		}
+	)
+}
+
+/// Yearly price of a name of the extension with the given label length
+pub fn get_price(extension: &str, chars_count: usize) -> U512 {
+	runtime::call_contract(
+		get_price_oracle_contract_hash(),
+		ENDPOINT_PO_GET_PRICE,
+		runtime_args! {
+			PriceOracleArgs::Extension.to_string() => extension.to_string(),
+			PriceOracleArgs::CharsCount.to_string() => chars_count as u64,
+		}
+	)
+}
+
+/// Takes the price in motes either from the customer purse or in the CEP-18 token passed by `arg_payment_token`
+/// The token amount is the price converted by the rate the price oracle holds, it goes to the treasury of the token
+/// through `transfer_from`, so the caller approves the package of the main contract as a spender beforehand
+/// Payments in motes are deposited to the treasury under the revenue stream
+pub fn collect_payment(price: U512, stream: RevenueStream) -> TResult<()> {
+	let amount: U512 = runtime::get_named_arg(
+		&MainArgs::RegisterAmount.to_string()
+	);
+
+	if
+		let Some(token) = get_optional_named_arg::<ContractHash>(
+			&MainArgs::PaymentToken.to_string()
+		)
+	{
+		let payment_token = get_payment_token(token);
+		// Overflows revert with the price oracle error
+		if payment_token.convert(price).unwrap_or_revert() != amount {
+			return Err(MainContractErrors::PriceDiscrepancy);
+		}
+		transfer_from(
+			token,
+			Key::Account(runtime::get_caller()),
+			payment_token.treasury,
+			amount
+		);
+		return Ok(());
+	}
+
+	let customer_purse = get_optional_named_arg::<URef>(
+		&MainArgs::CustomerPurse.to_string()
+	).ok_or(MainContractErrors::PaymentIsNotProvided)?;
+	if price != amount {
+		return Err(MainContractErrors::PriceDiscrepancy);
+	}
+	let balance = system::get_purse_balance(customer_purse).unwrap_or_revert();
+	if balance < amount {
+		return Err(MainContractErrors::InsufficientCustomerBalance);
+	}
+
+	deposit(stream, customer_purse, amount);
+	Ok(())
+}
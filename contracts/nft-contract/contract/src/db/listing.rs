@@ -3,6 +3,7 @@ use casper_types::U512;
 use common_lib::db::dictionary::Dictionary;
 use common_lib::db::traits::Storable;
 use common_lib::constants::common_keys::NFTContractKeys;
+use common_lib::models::auction::Auction;
 
 pub trait Listing {
 	fn listing_initialize() -> ();
@@ -38,3 +39,39 @@ impl Listing for Dictionary {
 		self.get::<U512>(&token_id)
 	}
 }
+
+/// English auctions by token id, a token is either listed at a fixed price or auctioned
+pub trait Auctions {
+	fn auctions_initialize() -> ();
+	fn auctions_instance() -> Self;
+	fn set_auction(&mut self, token_id: &str, auction: Auction) -> ();
+	fn remove_auction(&mut self, token_id: &str) -> ();
+	fn get_auction(&self, token_id: &str) -> Option<Auction>;
+	fn is_in_auction(&self, token_id: &str) -> bool;
+}
+
+impl Auctions for Dictionary {
+	fn auctions_initialize() -> () {
+		Dictionary::init(&NFTContractKeys::Auctions.to_string())
+	}
+
+	fn auctions_instance() -> Self {
+		Dictionary::instance(&NFTContractKeys::Auctions.to_string())
+	}
+
+	fn set_auction(&mut self, token_id: &str, auction: Auction) -> () {
+		self.set(token_id, auction)
+	}
+
+	fn remove_auction(&mut self, token_id: &str) -> () {
+		self.remove::<Auction>(token_id)
+	}
+
+	fn get_auction(&self, token_id: &str) -> Option<Auction> {
+		self.get::<Auction>(token_id)
+	}
+
+	fn is_in_auction(&self, token_id: &str) -> bool {
+		self.get::<Auction>(token_id).is_some()
+	}
+}
@@ -1,2 +1,5 @@
 pub mod core_contract_hash;
 pub mod listing;
+pub mod royalty_policies;
+pub mod token_metadata;
+pub mod token_royalties;
@@ -0,0 +1,31 @@
+use alloc::string::ToString;
+use common_lib::db::dictionary::Dictionary;
+use common_lib::db::traits::Storable;
+use common_lib::constants::common_keys::NFTContractKeys;
+use common_lib::models::royalty::RoyaltyPolicy;
+
+/// Protocol royalty policies by extension
+pub trait RoyaltyPolicies {
+	fn royalty_policies_initialize();
+	fn royalty_policies_instance() -> Self;
+	fn set_policy(&mut self, extension: &str, policy: RoyaltyPolicy);
+	fn get_policy(&self, extension: &str) -> Option<RoyaltyPolicy>;
+}
+
+impl RoyaltyPolicies for Dictionary {
+	fn royalty_policies_initialize() {
+		Dictionary::init(&NFTContractKeys::RoyaltyPolicies.to_string())
+	}
+
+	fn royalty_policies_instance() -> Self {
+		Dictionary::instance(&NFTContractKeys::RoyaltyPolicies.to_string())
+	}
+
+	fn set_policy(&mut self, extension: &str, policy: RoyaltyPolicy) {
+		self.set(extension, policy)
+	}
+
+	fn get_policy(&self, extension: &str) -> Option<RoyaltyPolicy> {
+		self.get::<RoyaltyPolicy>(extension)
+	}
+}
@@ -0,0 +1,31 @@
+use alloc::string::ToString;
+use common_lib::db::dictionary::Dictionary;
+use common_lib::db::traits::Storable;
+use common_lib::constants::common_keys::NFTContractKeys;
+use common_lib::models::nft::Metadata;
+
+/// Metadata of the minted tokens by token id, renewals rebuild the nft-core metadata from it
+pub trait TokenMetadata {
+	fn token_metadata_initialize() -> ();
+	fn token_metadata_instance() -> Self;
+	fn set_metadata(&mut self, token_id: &str, metadata: Metadata) -> ();
+	fn get_metadata(&self, token_id: &str) -> Option<Metadata>;
+}
+
+impl TokenMetadata for Dictionary {
+	fn token_metadata_initialize() -> () {
+		Dictionary::init(&NFTContractKeys::TokenMetadata.to_string())
+	}
+
+	fn token_metadata_instance() -> Self {
+		Dictionary::instance(&NFTContractKeys::TokenMetadata.to_string())
+	}
+
+	fn set_metadata(&mut self, token_id: &str, metadata: Metadata) -> () {
+		self.set(token_id, metadata)
+	}
+
+	fn get_metadata(&self, token_id: &str) -> Option<Metadata> {
+		self.get::<Metadata>(token_id)
+	}
+}
@@ -0,0 +1,31 @@
+use alloc::string::ToString;
+use common_lib::db::dictionary::Dictionary;
+use common_lib::db::traits::Storable;
+use common_lib::constants::common_keys::NFTContractKeys;
+use common_lib::models::royalty::TokenRoyalty;
+
+/// Creator royalties by token id, recorded when the token is minted to an account
+pub trait TokenRoyalties {
+	fn token_royalties_initialize();
+	fn token_royalties_instance() -> Self;
+	fn set_royalty(&mut self, token_id: &str, royalty: TokenRoyalty);
+	fn get_royalty(&self, token_id: &str) -> Option<TokenRoyalty>;
+}
+
+impl TokenRoyalties for Dictionary {
+	fn token_royalties_initialize() {
+		Dictionary::init(&NFTContractKeys::TokenRoyalties.to_string())
+	}
+
+	fn token_royalties_instance() -> Self {
+		Dictionary::instance(&NFTContractKeys::TokenRoyalties.to_string())
+	}
+
+	fn set_royalty(&mut self, token_id: &str, royalty: TokenRoyalty) {
+		self.set(token_id, royalty)
+	}
+
+	fn get_royalty(&self, token_id: &str) -> Option<TokenRoyalty> {
+		self.get::<TokenRoyalty>(token_id)
+	}
+}
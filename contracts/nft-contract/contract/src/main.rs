@@ -11,63 +11,69 @@ extern crate alloc;
 mod db;
 mod service;
 mod types;
+mod utils;
 
-use alloc::string::{ String, ToString };
-use alloc::vec;
-
-use casper_types::{
-	CLType,
-	CLTyped,
-	ContractHash,
-	EntryPointAccess,
-	EntryPointType,
-	EntryPoints,
-	Key,
-	Parameter,
-};
-use common_lib::enums::controller_roles::ControllerRoles;
-use common_lib::utils::contract::{ create_entrypoint, setup_contract_info };
+use alloc::{ string::ToString, vec };
 
-use casper_types::contracts::NamedKeys;
-use common_lib::constants::common_keys::{
-	NFTContractEndpoints,
-	NFTContractArgs,
-	CommonEndpoints,
-	CommonArgs,
-};
+use casper_contract::contract_api::{ runtime, storage };
+use casper_types::{ contracts::NamedKeys, ContractHash };
+use common_lib::constants::common_keys::{ CommonArgs, CommonKeys };
+use common_lib::enums::controller_roles::ControllerRoles;
+use common_lib::enums::contracts_enum::ContractKind;
+use common_lib::enums::roles::Role;
+use common_lib::schema;
+use common_lib::utils::contract::setup_contract_info;
+use common_lib::utils::pause::ensure_not_paused;
 
 use common_lib::utils::response::controller;
 
+use crate::service::auctions as auction_service;
 use crate::service::nft_operations as nft_service;
+use crate::service::owned_tokens as owned_tokens_service;
+use crate::service::royalties as royalty_service;
 
 #[allow(unused_imports)]
 use common_lib::controllers::authorities;
 
 #[no_mangle]
-pub extern "C" fn mint() {
-	controller(nft_service::mint, vec![ControllerRoles::OnlyAuthorizedContracts])
+pub extern "C" fn init() {
+	controller(crate::service::init::init, vec![ControllerRoles::OnlyMaintainer])
 }
 
 #[no_mangle]
-pub extern "C" fn transfer() {
+pub extern "C" fn mint() {
+	ensure_not_paused(ContractKind::NFT, None);
 	controller(
-		nft_service::transfer,
-		vec![ControllerRoles::OnlyAuthorizedContracts]
+		nft_service::mint,
+		vec![ControllerRoles::OnlyContract(ContractKind::Main)]
 	)
 }
 
+/// The service lets the owner of the token or an approved operator through
+#[no_mangle]
+pub extern "C" fn transfer() {
+	ensure_not_paused(ContractKind::NFT, None);
+	controller(nft_service::transfer, vec![])
+}
+
 #[no_mangle]
 pub extern "C" fn burn() {
-	controller(nft_service::burn, vec![ControllerRoles::OnlyAuthorizedContracts])
+	ensure_not_paused(ContractKind::NFT, None);
+	controller(
+		nft_service::burn,
+		vec![ControllerRoles::OnlyContract(ContractKind::Main)]
+	)
 }
 
 #[no_mangle]
 pub extern "C" fn list() {
+	ensure_not_paused(ContractKind::NFT, None);
 	controller(nft_service::list, vec![ControllerRoles::OnlyAuthorizedContracts])
 }
 
 #[no_mangle]
 pub extern "C" fn un_list() {
+	ensure_not_paused(ContractKind::NFT, None);
 	controller(
 		nft_service::un_list,
 		vec![ControllerRoles::OnlyAuthorizedContracts]
@@ -76,157 +82,120 @@ pub extern "C" fn un_list() {
 
 #[no_mangle]
 pub extern "C" fn buy() {
+	ensure_not_paused(ContractKind::NFT, None);
 	controller(nft_service::buy, vec![ControllerRoles::OnlyAuthorizedContracts])
 }
 
-/**
- * 1. mint
- * 2. transfer
- * 3. burn
- * 4. list
- * 5. un_list
- * 6. buy
- */
 #[no_mangle]
-pub extern "C" fn call() {
-	let mut entrypoints = EntryPoints::new();
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&NFTContractEndpoints::Mint.to_string(),
-			vec![
-				Parameter::new(
-					&NFTContractArgs::NftCoreContractHash.to_string(),
-					ContractHash::cl_type()
-				),
-				Parameter::new(&NFTContractArgs::Owner.to_string(), Key::cl_type()),
-				Parameter::new(
-					&NFTContractArgs::Metadata.to_string(),
-					String::cl_type()
-				)
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
+pub extern "C" fn set_token_expiration() {
+	ensure_not_paused(ContractKind::NFT, None);
+	controller(
+		nft_service::set_token_expiration,
+		vec![ControllerRoles::OnlyAuthorizedContracts]
+	)
+}
 
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&NFTContractEndpoints::Burn.to_string(),
-			vec![
-				Parameter::new(
-					&NFTContractArgs::NftCoreContractHash.to_string(),
-					ContractHash::cl_type()
-				),
-				Parameter::new(&NFTContractArgs::TokenId.to_string(), String::cl_type())
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
+#[no_mangle]
+pub extern "C" fn set_royalty_policy() {
+	controller(
+		royalty_service::set_royalty_policy,
+		vec![
+			ControllerRoles::OnlyMaintainer,
+			ControllerRoles::OnlyRole(Role::MarketplaceOperator)
+		]
+	)
+}
 
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&NFTContractEndpoints::Burn.to_string(),
-			vec![
-				Parameter::new(
-					&NFTContractArgs::NftCoreContractHash.to_string(),
-					ContractHash::cl_type()
-				),
-				Parameter::new(
-					&NFTContractArgs::TokenId.to_string(),
-					String::cl_type()
-				),
-				Parameter::new(&NFTContractArgs::SourceKey.to_string(), Key::cl_type()),
-				Parameter::new(
-					&NFTContractArgs::DestinationKey.to_string(),
-					Key::cl_type()
-				)
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
+/// The service checks that the caller is the creator of the token
+#[no_mangle]
+pub extern "C" fn set_token_royalty() {
+	ensure_not_paused(ContractKind::NFT, None);
+	controller(royalty_service::set_token_royalty, vec![])
+}
 
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&NFTContractEndpoints::List.to_string(),
-			vec![
-				Parameter::new(&NFTContractArgs::TokenId.to_string(), String::cl_type())
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
+#[no_mangle]
+pub extern "C" fn royalty_info() {
+	controller(royalty_service::royalty_info, vec![])
+}
 
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&NFTContractEndpoints::UnList.to_string(),
-			vec![
-				Parameter::new(&NFTContractArgs::TokenId.to_string(), String::cl_type())
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
+#[no_mangle]
+pub extern "C" fn get_owned_tokens() {
+	controller(owned_tokens_service::get_owned_tokens, vec![])
+}
 
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&NFTContractEndpoints::Buy.to_string(),
-			vec![
-				Parameter::new(&NFTContractArgs::TokenId.to_string(), String::cl_type())
-			],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
+/// The service checks the owner or an approved operator
+#[no_mangle]
+pub extern "C" fn start_auction() {
+	ensure_not_paused(ContractKind::NFT, None);
+	controller(auction_service::start_auction, vec![])
+}
 
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&CommonEndpoints::SetAuthorities.to_string(),
-			vec![Parameter::new(&CommonArgs::Authorities.to_string(), CLType::Any)],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
+/// The service checks the seller or an approved operator
+#[no_mangle]
+pub extern "C" fn cancel_auction() {
+	ensure_not_paused(ContractKind::NFT, None);
+	controller(auction_service::cancel_auction, vec![])
+}
 
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&CommonEndpoints::AddAuthority.to_string(),
-			vec![Parameter::new(&CommonArgs::Authority.to_string(), CLType::Key)],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
+#[no_mangle]
+pub extern "C" fn bid() {
+	ensure_not_paused(ContractKind::NFT, None);
+	controller(auction_service::bid, vec![])
+}
 
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&CommonEndpoints::RemoveAuthority.to_string(),
-			vec![Parameter::new(&CommonArgs::Authority.to_string(), CLType::Key)],
-			CLType::Unit,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
+#[no_mangle]
+pub extern "C" fn settle_auction() {
+	ensure_not_paused(ContractKind::NFT, None);
+	controller(auction_service::settle_auction, vec![])
+}
 
-	entrypoints.add_entry_point(
-		create_entrypoint(
-			&CommonEndpoints::GetAuthorities.to_string(),
-			vec![],
-			CLType::Any,
-			EntryPointAccess::Public,
-			EntryPointType::Contract
-		)
-	);
+#[no_mangle]
+pub extern "C" fn get_auction() {
+	controller(auction_service::get_auction, vec![])
+}
+
+#[no_mangle]
+pub extern "C" fn migrate() {
+	controller(
+		crate::service::migrate::migrate,
+		vec![ControllerRoles::OnlyMaintainer]
+	)
+}
 
-	let named_keys = NamedKeys::new();
+/**
+ * 1. init
+ * 2. mint
+ * 3. transfer
+ * 4. burn
+ * 5. list
+ * 6. un_list
+ * 7. buy
+ * 8. set_token_expiration
+ * 9. set_royalty_policy
+ * 10. set_token_royalty
+ * 11. royalty_info
+ * 12. get_owned_tokens
+ * 13. start_auction
+ * 14. cancel_auction
+ * 15. bid
+ * 16. settle_auction
+ * 17. get_auction
+ * 18. migrate
+ */
+#[no_mangle]
+pub extern "C" fn call() {
+	let entrypoints = schema::nft::schema().to_entry_points();
+
+	let mut named_keys = NamedKeys::new();
+
+	// Tokens are routed to the nft-core shards of the administration directory
+	let administration_contract_hash: ContractHash = runtime::get_named_arg(
+		&CommonArgs::AdministrationContract.to_string()
+	);
+	named_keys.insert(
+		CommonKeys::AdministrationContract.to_string(),
+		storage::new_uref(administration_contract_hash).into()
+	);
 
 	setup_contract_info(entrypoints, named_keys)
 }
@@ -0,0 +1,267 @@
+use alloc::string::{ String, ToString };
+use casper_contract::{
+	contract_api::{
+		runtime,
+		system::{
+			get_purse_balance,
+			transfer_from_purse_to_account,
+			transfer_from_purse_to_purse,
+		},
+	},
+	unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{ Key, URef, U512 };
+use common_lib::{
+	constants::common_keys::{
+		NFTContractArgs,
+		NFTContractKeys,
+		MAX_AUCTION_DURATION,
+		MIN_AUCTION_DURATION,
+	},
+	db::dictionary::Dictionary,
+	errors::NFTErrors,
+	events::nft::{ AuctionSettled, BidPlaced },
+	models::auction::Auction,
+	utils::domain_name::get_token_id,
+};
+
+use crate::db::{
+	listing::{ Auctions, Listing },
+	token_metadata::TokenMetadata,
+};
+use crate::service::nft_operations::{
+	ensure_caller_can_transfer,
+	ensure_transfer_allowed,
+	get_nft_core_contract_hash,
+	get_token_owner,
+	register_owner,
+	transfer_token,
+};
+use crate::service::royalties::settle_sale;
+use crate::types::{ NResult, SalePayment };
+use crate::utils::ensure_token_not_paused;
+
+/// Starts an English auction of the name, the owner or its operators can start it
+/// The seller keeps the token until the settlement, it approves this contract as an operator so nft-core lets it move the token
+pub fn start_auction() -> NResult<()> {
+	let domain_name = runtime::get_named_arg::<String>(
+		&NFTContractArgs::DomainName.to_string()
+	);
+	let reserve_price = runtime::get_named_arg::<U512>(
+		&NFTContractArgs::ReservePrice.to_string()
+	);
+	let min_bid_increment = runtime::get_named_arg::<U512>(
+		&NFTContractArgs::MinBidIncrement.to_string()
+	);
+	let duration = runtime::get_named_arg::<u64>(
+		&NFTContractArgs::Duration.to_string()
+	);
+	if !(MIN_AUCTION_DURATION..=MAX_AUCTION_DURATION).contains(&duration) {
+		return Err(NFTErrors::InvalidAuctionDuration);
+	}
+
+	let token_id = get_token_id(&domain_name);
+	ensure_token_not_paused(&token_id);
+	let nft_core_contract_hash = get_nft_core_contract_hash()?;
+	let owner = get_token_owner(nft_core_contract_hash, &token_id);
+	ensure_caller_can_transfer(nft_core_contract_hash, &token_id, owner)?;
+	ensure_transfer_allowed(&token_id)?;
+	let seller = owner.into_account().ok_or(NFTErrors::SellerIsNotAccount)?;
+
+	if Dictionary::listing_instance().is_listed(token_id.clone()) {
+		return Err(NFTErrors::TokenIsListed);
+	}
+	let mut instance = Dictionary::auctions_instance();
+	if instance.is_in_auction(&token_id) {
+		return Err(NFTErrors::TokenIsInAuction);
+	}
+
+	let now: u64 = runtime::get_blocktime().into();
+	let end_time = now + duration;
+	// The name has to outlive the auction, an expired name can't be transferred to the winner
+	if
+		let Some(metadata) =
+			Dictionary::token_metadata_instance().get_metadata(&token_id)
+	{
+		if metadata.expiration_date <= end_time {
+			return Err(NFTErrors::InvalidAuctionDuration);
+		}
+	}
+
+	instance.set_auction(
+		&token_id,
+		Auction::new(seller, reserve_price, min_bid_increment, end_time)
+	);
+	Ok(())
+}
+
+/// The seller or the ones who can transfer its token (approved spender, operators) can cancel, only before the first bid
+pub fn cancel_auction() -> NResult<()> {
+	let domain_name = runtime::get_named_arg::<String>(
+		&NFTContractArgs::DomainName.to_string()
+	);
+	let token_id = get_token_id(&domain_name);
+
+	let mut instance = Dictionary::auctions_instance();
+	let auction = instance
+		.get_auction(&token_id)
+		.ok_or(NFTErrors::AuctionNotFound)?;
+	if auction.seller != runtime::get_caller() {
+		ensure_caller_can_transfer(
+			get_nft_core_contract_hash()?,
+			&token_id,
+			Key::Account(auction.seller)
+		).map_err(|_| NFTErrors::CallerIsNotSeller)?;
+	}
+	if auction.highest_bidder.is_some() {
+		return Err(NFTErrors::AuctionHasBids);
+	}
+
+	instance.remove_auction(&token_id);
+	Ok(())
+}
+
+/// Escrows the bid in the auction purse and refunds the outbid bidder, the seller can't bid on its own auction
+pub fn bid() -> NResult<()> {
+	let domain_name = runtime::get_named_arg::<String>(
+		&NFTContractArgs::DomainName.to_string()
+	);
+	let token_id = get_token_id(&domain_name);
+	ensure_token_not_paused(&token_id);
+	let amount = runtime::get_named_arg::<U512>(
+		&NFTContractArgs::Amount.to_string()
+	);
+	let customer_purse = runtime::get_named_arg::<URef>(
+		&NFTContractArgs::CustomerPurse.to_string()
+	);
+
+	let mut instance = Dictionary::auctions_instance();
+	let mut auction = instance
+		.get_auction(&token_id)
+		.ok_or(NFTErrors::AuctionNotFound)?;
+	let now: u64 = runtime::get_blocktime().into();
+	if auction.is_ended(now) {
+		return Err(NFTErrors::AuctionIsOver);
+	}
+	let bidder = runtime::get_caller();
+	if bidder == auction.seller {
+		return Err(NFTErrors::SellerCannotBid);
+	}
+	if amount < auction.min_next_bid() {
+		return Err(NFTErrors::BidTooLow);
+	}
+	let balance = get_purse_balance(customer_purse).unwrap_or_revert();
+	if balance < amount {
+		return Err(NFTErrors::InsufficientBuyerBalance);
+	}
+
+	let auction_purse = get_auction_purse();
+	transfer_from_purse_to_purse(
+		customer_purse,
+		auction_purse,
+		amount,
+		None
+	).unwrap_or_revert();
+	refund_highest_bid(&auction, auction_purse);
+
+	auction.highest_bidder = Some(bidder);
+	auction.highest_bid = amount;
+	auction.extend_end_time(now);
+
+	casper_event_standard::emit(BidPlaced {
+		token_id: token_id.clone(),
+		bidder: Key::Account(bidder),
+		amount,
+		end_time: auction.end_time,
+	});
+	instance.set_auction(&token_id, auction);
+	Ok(())
+}
+
+/// Anyone can settle an ended auction, the winner gets the token and the seller the proceeds after the royalties
+/// The bid is refunded if the seller doesn't own the token anymore or the transfer is denied
+pub fn settle_auction() -> NResult<()> {
+	let domain_name = runtime::get_named_arg::<String>(
+		&NFTContractArgs::DomainName.to_string()
+	);
+	let token_id = get_token_id(&domain_name);
+
+	let mut instance = Dictionary::auctions_instance();
+	let auction = instance
+		.get_auction(&token_id)
+		.ok_or(NFTErrors::AuctionNotFound)?;
+	if !auction.is_ended(runtime::get_blocktime().into()) {
+		return Err(NFTErrors::AuctionIsNotOver);
+	}
+	let nft_core_contract_hash = get_nft_core_contract_hash()?;
+	instance.remove_auction(&token_id);
+
+	let auction_purse = get_auction_purse();
+	let seller = Key::Account(auction.seller);
+	let winner = match auction.highest_bidder {
+		Some(bidder) if
+			get_token_owner(nft_core_contract_hash, &token_id) == seller &&
+			ensure_transfer_allowed(&token_id).is_ok()
+		=> {
+			let winner = Key::Account(bidder);
+			settle_sale(
+				&token_id,
+				auction.highest_bid,
+				seller,
+				SalePayment::Purse(auction_purse)
+			)?;
+			register_owner(nft_core_contract_hash, winner);
+			transfer_token(nft_core_contract_hash, &token_id, seller, winner);
+			Some(winner)
+		}
+		_ => {
+			refund_highest_bid(&auction, auction_purse);
+			None
+		}
+	};
+
+	casper_event_standard::emit(AuctionSettled {
+		token_id,
+		winner,
+		price: match winner {
+			Some(_) => auction.highest_bid,
+			None => U512::zero(),
+		},
+	});
+	Ok(())
+}
+
+pub fn get_auction() -> NResult<Option<Auction>> {
+	let domain_name = runtime::get_named_arg::<String>(
+		&NFTContractArgs::DomainName.to_string()
+	);
+	Ok(Dictionary::auctions_instance().get_auction(&get_token_id(&domain_name)))
+}
+
+/// Burnt tokens can't be sold, the highest bid goes back to the bidder
+pub(crate) fn drop_auction(token_id: &str) {
+	let mut instance = Dictionary::auctions_instance();
+	if let Some(auction) = instance.get_auction(token_id) {
+		refund_highest_bid(&auction, get_auction_purse());
+		instance.remove_auction(token_id);
+	}
+}
+
+/// Helpers
+fn refund_highest_bid(auction: &Auction, auction_purse: URef) {
+	if let Some(bidder) = auction.highest_bidder {
+		transfer_from_purse_to_account(
+			auction_purse,
+			bidder,
+			auction.highest_bid,
+			None
+		).unwrap_or_revert();
+	}
+}
+
+fn get_auction_purse() -> URef {
+	runtime
+		::get_key(&NFTContractKeys::AuctionPurse.to_string())
+		.and_then(|key| key.into_uref())
+		.unwrap_or_revert()
+}
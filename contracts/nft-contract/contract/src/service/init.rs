@@ -0,0 +1,29 @@
+use alloc::string::ToString;
+use casper_contract::contract_api::{ runtime, system };
+use common_lib::constants::common_keys::NFTContractKeys;
+use common_lib::db::dictionary::Dictionary;
+use common_lib::schema;
+
+use crate::db::{
+	listing::{ Auctions, Listing },
+	royalty_policies::RoyaltyPolicies,
+	token_metadata::TokenMetadata,
+	token_royalties::TokenRoyalties,
+};
+use crate::types::NResult;
+
+/// Runs in the contract context, creates dictionaries, the auction purse and event schemas
+pub fn init() -> NResult<()> {
+	Dictionary::listing_initialize();
+	Dictionary::token_metadata_initialize();
+	Dictionary::royalty_policies_initialize();
+	Dictionary::token_royalties_initialize();
+	Dictionary::auctions_initialize();
+	runtime::put_key(
+		&NFTContractKeys::AuctionPurse.to_string(),
+		system::create_purse().into()
+	);
+
+	casper_event_standard::init(schema::nft::schema().events);
+	Ok(())
+}
@@ -0,0 +1,59 @@
+use alloc::string::ToString;
+use casper_contract::{
+	contract_api::{ runtime, storage, system },
+	unwrap_or_revert::UnwrapOrRevert,
+};
+use common_lib::constants::common_keys::NFTContractKeys;
+use common_lib::db::dictionary::Dictionary;
+use common_lib::schema;
+use common_lib::utils::upgrade::run_migrations;
+
+use crate::db::{
+	listing::{ Auctions, Listing },
+	royalty_policies::RoyaltyPolicies,
+	token_metadata::TokenMetadata,
+	token_royalties::TokenRoyalties,
+};
+use crate::types::NResult;
+
+/// Schema version of listings, auctions, token metadata and royalties stored by the contract
+pub const SCHEMA_VERSION: u32 = 4;
+
+/// Runs right after a new contract version is added to the package,
+/// each step moves the stored state from `version` to `version + 1`
+/// This service is closed, only maintainer is passed
+/// Returns the schema version of the stored state
+pub fn migrate() -> NResult<u32> {
+	Ok(
+		run_migrations(SCHEMA_VERSION, |version| {
+			if version == 1 {
+				// Dictionaries are created by `init` since version 2
+				if !runtime::has_key(&NFTContractKeys::Listing.to_string()) {
+					Dictionary::listing_initialize();
+				}
+				if !runtime::has_key(&NFTContractKeys::TokenMetadata.to_string()) {
+					Dictionary::token_metadata_initialize();
+				}
+			}
+			if version == 2 {
+				// Royalties, tokens minted before have no creator
+				Dictionary::royalty_policies_initialize();
+				Dictionary::token_royalties_initialize();
+				casper_event_standard::init(schema::nft::schema().events);
+			}
+			if version == 3 {
+				// Auctions, the event schemas are initialized already and only get the auction events
+				Dictionary::auctions_initialize();
+				runtime::put_key(
+					&NFTContractKeys::AuctionPurse.to_string(),
+					system::create_purse().into()
+				);
+				let schemas = runtime
+					::get_key(casper_event_standard::EVENTS_SCHEMA)
+					.and_then(|key| key.into_uref())
+					.unwrap_or_revert();
+				storage::write(schemas, schema::nft::schema().events);
+			}
+		})
+	)
+}
@@ -1 +1,6 @@
+pub mod auctions;
+pub mod init;
+pub mod migrate;
 pub mod nft_operations;
+pub mod owned_tokens;
+pub mod royalties;
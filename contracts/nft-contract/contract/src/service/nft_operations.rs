@@ -1,43 +1,116 @@
 use alloc::string::{ ToString, String };
-use casper_contract::contract_api::runtime;
-use casper_types::{ Key, ContractHash, runtime_args, U512, RuntimeArgs };
+use casper_contract::{
+	contract_api::runtime,
+	unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{ Key, ContractHash, runtime_args, U512, URef, RuntimeArgs };
 use common_lib::{
-	constants::common_keys::{ NFTContractArgs, NFTCoreContractEndpoints },
+	constants::common_keys::{
+		AdministrationArgs,
+		AdministrationEndpoints,
+		NFTContractArgs,
+		NFTCoreContractEndpoints,
+		TransferFilterArgs,
+		TransferFilterEndpoints,
+	},
 	db::dictionary::Dictionary,
-	errors::NFTErrors,
+	enums::{ contracts_enum::ContractKind, transfer_filter::TransferDenial },
+	errors::{ CommonError, NFTErrors },
+	models::nft::Metadata,
+	utils::{
+		contract::get_administration_contract_hash,
+		domain_name::{ get_domain_name_extension, get_token_id },
+		payment::get_payment_token,
+		routes::get_route,
+		upgrade::get_optional_named_arg,
+	},
+};
+use crate::db::{
+	listing::{ Auctions, Listing },
+	token_metadata::TokenMetadata,
 };
-use crate::db::listing::Listing;
-use crate::types::NResult;
+use crate::service::auctions::drop_auction;
+use crate::service::royalties::{ record_creator, settle_sale };
+use crate::types::{
+	MintReceipt,
+	NResult,
+	RegisterOwnerReceipt,
+	SalePayment,
+	TransferReceipt,
+};
+use crate::utils::{ ensure_token_not_paused, get_nft_core_contract_hashes };
 
 pub fn mint() -> NResult<()> {
 	let owner = runtime::get_named_arg::<Key>(
 		&NFTContractArgs::Owner.to_string()
 	);
-	let metadata = runtime::get_named_arg::<String>(
-		&NFTContractArgs::Metadata.to_string()
+	let expiration_date = runtime::get_named_arg::<u64>(
+		&NFTContractArgs::ExpirationDate.to_string()
 	);
-	let nft_core_contract_hash = runtime::get_named_arg::<ContractHash>(
-		&NFTContractArgs::NftCoreContractHash.to_string()
+	let domain_name = runtime::get_named_arg::<String>(
+		&NFTContractArgs::DomainName.to_string()
 	);
+	let nft_core_contract_hash = get_nft_core_contract_hash()?;
 
-	Ok(
-		runtime::call_contract::<()>(
-			nft_core_contract_hash,
-			&NFTCoreContractEndpoints::Mint.to_string(),
-			runtime_args! {
-                    "token_owner" => owner,
-                    "token_meta_data" => metadata,
-                }
-		)
-	)
+	let token_id = get_token_id(&domain_name);
+	let metadata = Metadata::new(
+		&domain_name,
+		runtime::get_blocktime().into(),
+		expiration_date
+	);
+	Dictionary::token_metadata_instance().set_metadata(
+		&token_id,
+		metadata.clone()
+	);
+	record_creator(&token_id, owner);
+	register_owner(nft_core_contract_hash, owner);
+
+	runtime::call_contract::<MintReceipt>(
+		nft_core_contract_hash,
+		&NFTCoreContractEndpoints::Mint.to_string(),
+		runtime_args! {
+                "token_owner" => owner,
+                "token_meta_data" => metadata.to_json(),
+                "token_hash" => token_id,
+            }
+	);
+	Ok(())
 }
 
-pub fn transfer() -> NResult<()> {
-	let nft_core_contract_hash = runtime::get_named_arg::<ContractHash>(
-		&NFTContractArgs::NftCoreContractHash.to_string()
+/// Renewals move the expiration date of the token metadata
+pub fn set_token_expiration() -> NResult<()> {
+	let expiration_date = runtime::get_named_arg::<u64>(
+		&NFTContractArgs::ExpirationDate.to_string()
 	);
-	let token_id = runtime::get_named_arg::<String>(
-		&NFTContractArgs::TokenId.to_string()
+	let domain_name = runtime::get_named_arg::<String>(
+		&NFTContractArgs::DomainName.to_string()
+	);
+	let nft_core_contract_hash = get_nft_core_contract_hash()?;
+
+	let token_id = get_token_id(&domain_name);
+	let mut instance = Dictionary::token_metadata_instance();
+	let mut metadata = instance
+		.get_metadata(&token_id)
+		.ok_or(NFTErrors::TokenMetadataNotFound)?;
+	metadata.expiration_date = expiration_date;
+	instance.set_metadata(&token_id, metadata.clone());
+
+	runtime::call_contract::<()>(
+		nft_core_contract_hash,
+		&NFTCoreContractEndpoints::SetTokenMetadata.to_string(),
+		runtime_args! {
+			"token_hash" => token_id,
+			"token_meta_data" => metadata.to_json(),
+		}
+	);
+	Ok(())
+}
+
+/// The token is the one of the name, the name also picks the nft-core shard
+pub fn transfer() -> NResult<()> {
+	let nft_core_contract_hash = get_nft_core_contract_hash()?;
+	let token_id = get_token_id(
+		&runtime::get_named_arg::<String>(&NFTContractArgs::DomainName.to_string())
 	);
 	let source_key = runtime::get_named_arg::<Key>(
 		&NFTContractArgs::SourceKey.to_string()
@@ -45,45 +118,62 @@ pub fn transfer() -> NResult<()> {
 	let destination_key = runtime::get_named_arg::<Key>(
 		&NFTContractArgs::DestinationKey.to_string()
 	);
-
-	Ok(
-		runtime::call_contract::<()>(
-			nft_core_contract_hash,
-			&NFTCoreContractEndpoints::Transfer.to_string(),
-			runtime_args! {
-                "token_hash" => token_id,
-                "source_key" => source_key,
-                "target_key" => destination_key,
-            }
-		)
-	)
+	ensure_caller_can_transfer(nft_core_contract_hash, &token_id, source_key)?;
+	ensure_transfer_allowed(&token_id)?;
+	register_owner(nft_core_contract_hash, destination_key);
+	transfer_token(
+		nft_core_contract_hash,
+		&token_id,
+		source_key,
+		destination_key
+	);
+	Ok(())
 }
 
+/// Burns the token of a released name, nft-core lets the wrapper burn with operator burn mode
+/// The listing and the auction of the previous holder are dropped, the token is minted again on reregistration
 pub fn burn() -> NResult<()> {
-	let nft_core_contract_hash = runtime::get_named_arg::<ContractHash>(
-		&NFTContractArgs::NftCoreContractHash.to_string()
-	);
+	let nft_core_contract_hash = get_nft_core_contract_hash()?;
 	let token_id = runtime::get_named_arg::<String>(
 		&NFTContractArgs::TokenId.to_string()
 	);
-	Ok(
-		runtime::call_contract::<()>(
-			nft_core_contract_hash,
-			&NFTCoreContractEndpoints::Burn.to_string(),
-			runtime_args! {
-                "token_id" => token_id,
-            }
-		)
-	)
+
+	let mut instance = Dictionary::listing_instance();
+	if instance.is_listed(token_id.clone()) {
+		instance.un_list(token_id.clone());
+	}
+	drop_auction(&token_id);
+
+	runtime::call_contract::<()>(
+		nft_core_contract_hash,
+		&NFTCoreContractEndpoints::Burn.to_string(),
+		runtime_args! {
+			"token_hash" => token_id,
+		}
+	);
+	Ok(())
 }
 
+/// Listings are made on behalf of the seller, it has to own the token of the name in nft-core
 pub fn list() -> NResult<()> {
-	let token_id = runtime::get_named_arg::<String>(
-		&NFTContractArgs::TokenId.to_string()
+	let token_id = get_token_id(
+		&runtime::get_named_arg::<String>(&NFTContractArgs::DomainName.to_string())
+	);
+	let source_key = runtime::get_named_arg::<Key>(
+		&NFTContractArgs::SourceKey.to_string()
 	);
 	let token_price = runtime::get_named_arg::<U512>(
 		&NFTContractArgs::TokenPrice.to_string()
 	);
+	ensure_token_not_paused(&token_id);
+
+	if get_token_owner(get_nft_core_contract_hash()?, &token_id) != source_key {
+		return Err(NFTErrors::SourceIsNotTokenOwner);
+	}
+
+	if Dictionary::auctions_instance().is_in_auction(&token_id) {
+		return Err(NFTErrors::TokenIsInAuction);
+	}
 
 	let mut instance = Dictionary::listing_instance();
 	if !instance.is_listed(token_id.clone()) {
@@ -113,26 +203,185 @@ pub fn buy() -> NResult<()> {
 	let source_key = runtime::get_named_arg::<Key>(
 		&NFTContractArgs::SourceKey.to_string()
 	);
-	let instance = Dictionary::listing_instance();
+	let amount = runtime::get_named_arg::<U512>(
+		&NFTContractArgs::Amount.to_string()
+	);
+	ensure_token_not_paused(&token_id);
+	let mut instance = Dictionary::listing_instance();
 	let caller_key: Key = runtime::get_caller().into();
-	if !instance.is_listed(token_id.clone()) {
-		return Err(NFTErrors::NFTIsNotListed);
+	let price = instance
+		.get_price(token_id.clone())
+		.ok_or(NFTErrors::NFTIsNotListed)?;
+
+	// Listings are priced in motes, a CEP-18 amount is the price converted by the rate of the token
+	let payment = match
+		get_optional_named_arg::<ContractHash>(
+			&NFTContractArgs::PaymentToken.to_string()
+		)
+	{
+		Some(token) => {
+			if amount != get_payment_token(token).convert(price).unwrap_or_revert() {
+				return Err(NFTErrors::PriceDiscrepancy);
+			}
+			SalePayment::Token { token, buyer: caller_key }
+		}
+		None => {
+			let customer_purse = get_optional_named_arg::<URef>(
+				&NFTContractArgs::CustomerPurse.to_string()
+			).ok_or(NFTErrors::PaymentIsNotProvided)?;
+			if amount != price {
+				return Err(NFTErrors::PriceDiscrepancy);
+			}
+			SalePayment::Purse(customer_purse)
+		}
+	};
+
+	// The sale pays the source key, it has to be the current owner and not only the one passed by the caller
+	let nft_core_contract_hash = get_nft_core_contract_hash()?;
+	if get_token_owner(nft_core_contract_hash, &token_id) != source_key {
+		return Err(NFTErrors::SourceIsNotTokenOwner);
 	}
+	ensure_transfer_allowed(&token_id)?;
+	settle_sale(&token_id, price, source_key, payment)?;
+	instance.un_list(token_id.clone());
+	register_owner(nft_core_contract_hash, caller_key);
+	transfer_token(nft_core_contract_hash, &token_id, source_key, caller_key);
+
+	Ok(())
+}
 
-	let nft_core_contract_hash = runtime::get_named_arg::<ContractHash>(
-		&NFTContractArgs::NftCoreContractHash.to_string()
+/// Helpers
+/// Core contract of the name is resolved through the registry, callers can not point the wrapper at another contract
+/// The route has to be one of the nft-core shards the administration directory holds for the extension
+pub(crate) fn get_nft_core_contract_hash() -> NResult<ContractHash> {
+	let domain_name = runtime::get_named_arg::<String>(
+		&NFTContractArgs::DomainName.to_string()
 	);
-	// TODO: payment (2.5% - commission, 97.5% - to the owner)
+	let nft_core_contract_hash = get_route(&domain_name)
+		.map(|route| route.nft_contract_hash)
+		.ok_or(NFTErrors::NFTCoreHashIsNotSet)?;
+	let extension = get_domain_name_extension(&domain_name).ok_or(
+		NFTErrors::UnknownNFTCoreContract
+	)?;
+	if !get_nft_core_contract_hashes(Some(extension)).contains(&nft_core_contract_hash) {
+		return Err(NFTErrors::UnknownNFTCoreContract);
+	}
+	Ok(nft_core_contract_hash)
+}
 
-	runtime::call_contract::<()>(
+/// Same rule nft-core applies to its own callers: the owner, the approved spender of the token or an operator of the owner.
+/// nft-core still checks the wrapper, owners approve it as an operator before transfers and sales
+pub(crate) fn ensure_caller_can_transfer(
+	nft_core_contract_hash: ContractHash,
+	token_id: &str,
+	source_key: Key
+) -> NResult<()> {
+	let owner = get_token_owner(nft_core_contract_hash, token_id);
+	if owner != source_key {
+		return Err(NFTErrors::SourceIsNotTokenOwner);
+	}
+
+	let caller: Key = runtime::get_caller().into();
+	if caller == owner {
+		return Ok(());
+	}
+
+	let approved = runtime::call_contract::<Option<Key>>(
+		nft_core_contract_hash,
+		&NFTCoreContractEndpoints::GetApproved.to_string(),
+		runtime_args! {
+			"token_hash" => token_id.to_string(),
+		}
+	);
+	if approved == Some(caller) {
+		return Ok(());
+	}
+
+	let is_operator = runtime::call_contract::<bool>(
+		nft_core_contract_hash,
+		&NFTCoreContractEndpoints::IsApprovedForAll.to_string(),
+		runtime_args! {
+			"token_owner" => owner,
+			"operator" => caller,
+		}
+	);
+	if is_operator {
+		Ok(())
+	} else {
+		Err(NFTErrors::CallerIsNotOwnerOrApproved)
+	}
+}
+
+/// Current owner of the token in nft-core
+pub(crate) fn get_token_owner(
+	nft_core_contract_hash: ContractHash,
+	token_id: &str
+) -> Key {
+	runtime::call_contract::<Key>(
+		nft_core_contract_hash,
+		&NFTCoreContractEndpoints::OwnerOf.to_string(),
+		runtime_args! {
+			"token_hash" => token_id.to_string(),
+		}
+	)
+}
+
+/// nft-core keeps the page record of every owner in the Complete reverse lookup mode,
+/// an owner has to be registered before it receives a token
+pub(crate) fn register_owner(nft_core_contract_hash: ContractHash, owner: Key) {
+	runtime::call_contract::<RegisterOwnerReceipt>(
+		nft_core_contract_hash,
+		&NFTCoreContractEndpoints::RegisterOwner.to_string(),
+		runtime_args! {
+			"token_owner" => owner,
+		}
+	);
+}
+
+/// nft-core asks the same filter and reverts with its own generic error, the reason is surfaced here first
+pub(crate) fn ensure_transfer_allowed(token_id: &str) -> NResult<()> {
+	let denial: Option<TransferDenial> = runtime::call_contract(
+		get_transfer_filter_contract_hash(),
+		&TransferFilterEndpoints::GetTransferDenial.to_string(),
+		runtime_args! {
+			TransferFilterArgs::TokenId.to_string() => token_id.to_string(),
+		}
+	);
+	match denial {
+		Some(denial) => Err(denial.into()),
+		None => Ok(()),
+	}
+}
+
+/// nft-core only moves the token if the wrapper is the owner, the approved spender or an operator of the owner
+pub(crate) fn transfer_token(
+	nft_core_contract_hash: ContractHash,
+	token_id: &str,
+	source_key: Key,
+	target_key: Key
+) {
+	runtime::call_contract::<TransferReceipt>(
 		nft_core_contract_hash,
 		&NFTCoreContractEndpoints::Transfer.to_string(),
 		runtime_args! {
-            "token_hash" => token_id,
-            "source_key" => source_key,
-            "target_key" => caller_key,
-        }
+			"token_hash" => token_id.to_string(),
+			"source_key" => source_key,
+			"target_key" => target_key,
+		}
 	);
+}
 
-	Ok(())
+fn get_transfer_filter_contract_hash() -> ContractHash {
+	let (key, _): (Key, Option<u32>) = runtime::call_contract(
+		get_administration_contract_hash(),
+		&AdministrationEndpoints::GetContract.to_string(),
+		runtime_args! {
+			AdministrationArgs::ContractKind.to_string() => ContractKind::TransferFilter,
+			AdministrationArgs::Extension.to_string() => Option::<String>::None,
+		}
+	);
+	key
+		.into_hash()
+		.map(ContractHash::new)
+		.unwrap_or_else(|| runtime::revert(CommonError::UnexpectedKeyVariant))
 }
@@ -0,0 +1,66 @@
+use alloc::{ string::{ String, ToString }, vec::Vec };
+use casper_contract::contract_api::runtime;
+use casper_types::{ runtime_args, Key, RuntimeArgs };
+use common_lib::{
+	constants::common_keys::{ NFTContractArgs, NFTCoreContractEndpoints, MAX_PAGE_SIZE },
+	db::dictionary::Dictionary,
+};
+
+use crate::db::token_metadata::TokenMetadata;
+use crate::types::NResult;
+use crate::utils::get_nft_core_contract_hashes;
+
+/// Token ids and domain names owned by the key, MAX_PAGE_SIZE per page
+/// Every nft-core shard is asked through its Complete reverse lookup, shards are skipped by the
+/// balance of the owner and each shard returns only the tokens of the page,
+/// tokens minted before the token metadata have no domain name
+pub fn get_owned_tokens() -> NResult<Vec<(String, Option<String>)>> {
+	let owner = runtime::get_named_arg::<Key>(
+		&NFTContractArgs::Owner.to_string()
+	);
+	let page = runtime::get_named_arg::<u64>(&NFTContractArgs::Page.to_string());
+
+	let mut offset = page * (MAX_PAGE_SIZE as u64);
+	let mut token_ids: Vec<String> = Vec::new();
+	for nft_core_contract_hash in get_nft_core_contract_hashes(None) {
+		if token_ids.len() >= MAX_PAGE_SIZE {
+			break;
+		}
+		let balance = runtime::call_contract::<u64>(
+			nft_core_contract_hash,
+			&NFTCoreContractEndpoints::BalanceOf.to_string(),
+			runtime_args! {
+				"token_owner" => owner,
+			}
+		);
+		if balance <= offset {
+			offset -= balance;
+			continue;
+		}
+		token_ids.extend(
+			runtime::call_contract::<Vec<String>>(
+				nft_core_contract_hash,
+				&NFTCoreContractEndpoints::GetOwnedTokens.to_string(),
+				runtime_args! {
+					"token_owner" => owner,
+					"offset" => offset,
+					"limit" => (MAX_PAGE_SIZE - token_ids.len()) as u64,
+				}
+			)
+		);
+		offset = 0;
+	}
+
+	let metadata = Dictionary::token_metadata_instance();
+	let owned_tokens = token_ids
+		.into_iter()
+		.map(|token_id| {
+			let domain_name = metadata
+				.get_metadata(&token_id)
+				.map(|metadata| metadata.name);
+			(token_id, domain_name)
+		})
+		.collect();
+
+	Ok(owned_tokens)
+}
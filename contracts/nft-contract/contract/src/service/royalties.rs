@@ -0,0 +1,256 @@
+use alloc::{ string::{ String, ToString }, vec::Vec };
+use casper_contract::{
+	contract_api::{ runtime, system::{ get_purse_balance, transfer_from_purse_to_account } },
+	unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{ Key, U512 };
+use common_lib::{
+	constants::common_keys::{
+		NFTContractArgs,
+		MAX_CREATOR_ROYALTY_SHARE,
+		MAX_PROTOCOL_ROYALTY_SHARE,
+	},
+	db::dictionary::Dictionary,
+	enums::revenue_stream::RevenueStream,
+	errors::{ CommonError, NFTErrors },
+	events::nft::RoyaltyPaid,
+	models::royalty::{ RoyaltyPolicy, TokenRoyalty },
+	utils::{
+		payment::{ get_payment_token, transfer_from },
+		treasury::{ deposit, get_treasury_contract_hash },
+	},
+};
+
+use crate::db::{
+	royalty_policies::RoyaltyPolicies,
+	token_metadata::TokenMetadata,
+	token_royalties::TokenRoyalties,
+};
+use crate::types::{ NResult, SalePayment };
+
+/// Sets the protocol share of the secondary sales of the extension, the treasury the sale is paid in takes it
+/// Zero share switches the protocol royalty of the extension off
+pub fn set_royalty_policy() -> NResult<()> {
+	let extension = runtime::get_named_arg::<String>(
+		&NFTContractArgs::Extension.to_string()
+	);
+	let share = runtime::get_named_arg::<u32>(
+		&NFTContractArgs::RoyaltyShare.to_string()
+	);
+	if share > MAX_PROTOCOL_ROYALTY_SHARE {
+		return Err(NFTErrors::RoyaltyShareTooHigh);
+	}
+
+	Dictionary::royalty_policies_instance().set_policy(
+		&extension,
+		RoyaltyPolicy { share }
+	);
+	Ok(())
+}
+
+/// Sets the creator share of the secondary sales of the token, only the creator can set it
+pub fn set_token_royalty() -> NResult<()> {
+	let token_id = runtime::get_named_arg::<String>(
+		&NFTContractArgs::TokenId.to_string()
+	);
+	let share = runtime::get_named_arg::<u32>(
+		&NFTContractArgs::RoyaltyShare.to_string()
+	);
+	if share > MAX_CREATOR_ROYALTY_SHARE {
+		return Err(NFTErrors::RoyaltyShareTooHigh);
+	}
+
+	let mut instance = Dictionary::token_royalties_instance();
+	let mut royalty = instance
+		.get_royalty(&token_id)
+		.ok_or(NFTErrors::CallerIsNotTokenCreator)?;
+	if royalty.creator != runtime::get_caller() {
+		return Err(NFTErrors::CallerIsNotTokenCreator);
+	}
+	royalty.share = share;
+	instance.set_royalty(&token_id, royalty);
+	Ok(())
+}
+
+/// Receivers and amounts of the royalties of a CSPR sale for the sale price,
+/// marketplaces pay them the same way `buy` does, the protocol share to the treasury
+/// Sales settled outside of this contract (offers accepted on a marketplace, plain transfers) are not enforced,
+/// royalties are only paid there if the marketplace pays what this endpoint returns
+pub fn royalty_info() -> NResult<Vec<(Key, U512)>> {
+	let token_id = runtime::get_named_arg::<String>(
+		&NFTContractArgs::TokenId.to_string()
+	);
+	let sale_price = runtime::get_named_arg::<U512>(
+		&NFTContractArgs::SalePrice.to_string()
+	);
+
+	Ok(
+		get_royalty_payouts(&token_id, sale_price, Key::from(get_treasury_contract_hash()))?
+			.iter()
+			.map(|payout| (payout.receiver(), payout.amount()))
+			.collect()
+	)
+}
+
+/// Pays the royalties of the sale and the rest of the price to the seller
+/// Every sale of the contract (`buy` of a listing and `settle_auction`) settles here, so the royalties can't be skipped,
+/// the contract has no offers, see `royalty_info` for the sales settled elsewhere
+/// The protocol share of CSPR sales is deposited to the treasury as a marketplace commission,
+/// sales in a CEP-18 token are converted by the rate of the token, the protocol share goes to the treasury of the token
+pub(crate) fn settle_sale(
+	token_id: &str,
+	sale_price: U512,
+	seller: Key,
+	payment: SalePayment
+) -> NResult<()> {
+	let seller = seller.into_account().ok_or(NFTErrors::SellerIsNotAccount)?;
+
+	match payment {
+		SalePayment::Purse(customer_purse) => {
+			let balance = get_purse_balance(customer_purse).unwrap_or_revert();
+			if balance < sale_price {
+				return Err(NFTErrors::InsufficientBuyerBalance);
+			}
+			let treasury = Key::from(get_treasury_contract_hash());
+			let proceeds = pay_royalties(
+				token_id,
+				get_royalty_payouts(token_id, sale_price, treasury)?,
+				sale_price,
+				|payout| {
+					match payout {
+						RoyaltyPayout::Protocol(_, amount) => {
+							deposit(
+								RevenueStream::MarketplaceCommission,
+								customer_purse,
+								*amount
+							);
+						}
+						RoyaltyPayout::Creator(receiver, amount) => {
+							let receiver = receiver
+								.into_account()
+								.unwrap_or_revert_with(CommonError::UnexpectedKeyVariant);
+							transfer_from_purse_to_account(
+								customer_purse,
+								receiver,
+								*amount,
+								None
+							).unwrap_or_revert();
+						}
+					}
+				}
+			);
+			if !proceeds.is_zero() {
+				transfer_from_purse_to_account(
+					customer_purse,
+					seller,
+					proceeds,
+					None
+				).unwrap_or_revert();
+			}
+		}
+		SalePayment::Token { token, buyer } => {
+			let payment_token = get_payment_token(token);
+			let sale_price = payment_token.convert(sale_price).unwrap_or_revert();
+			let proceeds = pay_royalties(
+				token_id,
+				get_royalty_payouts(token_id, sale_price, payment_token.treasury)?,
+				sale_price,
+				|payout| {
+					transfer_from(token, buyer, payout.receiver(), payout.amount());
+				}
+			);
+			if !proceeds.is_zero() {
+				transfer_from(token, buyer, Key::Account(seller), proceeds);
+			}
+		}
+	}
+	Ok(())
+}
+
+/// Protocol share and creator royalty of a sale
+enum RoyaltyPayout {
+	Protocol(Key, U512),
+	Creator(Key, U512),
+}
+
+impl RoyaltyPayout {
+	fn receiver(&self) -> Key {
+		match self {
+			Self::Protocol(receiver, _) | Self::Creator(receiver, _) => *receiver,
+		}
+	}
+
+	fn amount(&self) -> U512 {
+		match self {
+			Self::Protocol(_, amount) | Self::Creator(_, amount) => *amount,
+		}
+	}
+}
+
+/// Returns the rest of the price for the seller
+fn pay_royalties<F: Fn(&RoyaltyPayout)>(
+	token_id: &str,
+	payouts: Vec<RoyaltyPayout>,
+	sale_price: U512,
+	pay: F
+) -> U512 {
+	let mut proceeds = sale_price;
+	for payout in payouts {
+		pay(&payout);
+		proceeds -= payout.amount();
+
+		casper_event_standard::emit(RoyaltyPaid {
+			token_id: token_id.to_string(),
+			receiver: payout.receiver(),
+			amount: payout.amount(),
+			sale_price,
+		});
+	}
+	proceeds
+}
+
+/// Protocol share comes first, zero amounts are skipped
+/// Tokens minted before the royalties have no metadata and pay nothing
+/// The treasury (of the name service or of a payment token) takes the protocol share
+fn get_royalty_payouts(
+	token_id: &str,
+	sale_price: U512,
+	treasury: Key
+) -> NResult<Vec<RoyaltyPayout>> {
+	let mut payouts = Vec::new();
+
+	let extension = Dictionary::token_metadata_instance()
+		.get_metadata(token_id)
+		.map(|metadata| metadata.extension);
+	if
+		let Some(policy) = extension.and_then(|extension|
+			Dictionary::royalty_policies_instance().get_policy(&extension)
+		)
+	{
+		payouts.push(RoyaltyPayout::Protocol(treasury, policy.amount(sale_price)?));
+	}
+	if
+		let Some(royalty) =
+			Dictionary::token_royalties_instance().get_royalty(token_id)
+	{
+		payouts.push(
+			RoyaltyPayout::Creator(
+				Key::Account(royalty.creator),
+				royalty.amount(sale_price)?
+			)
+		);
+	}
+
+	payouts.retain(|payout| !payout.amount().is_zero());
+	Ok(payouts)
+}
+
+/// Creator is the account the token is minted to, tokens minted to contracts have no creator royalty
+pub fn record_creator(token_id: &str, owner: Key) {
+	if let Some(creator) = owner.into_account() {
+		Dictionary::token_royalties_instance().set_royalty(
+			token_id,
+			TokenRoyalty { creator, share: 0 }
+		);
+	}
+}
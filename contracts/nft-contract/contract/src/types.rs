@@ -1,3 +1,20 @@
+use alloc::string::String;
+use casper_types::{ ContractHash, Key, URef };
 use common_lib::errors::NFTErrors;
 
 pub(crate) type NResult<T> = Result<T, NFTErrors>;
+
+/// Receipts nft-core returns in the Complete reverse lookup mode
+pub(crate) type MintReceipt = (String, Key, String);
+pub(crate) type TransferReceipt = (String, Key);
+pub(crate) type RegisterOwnerReceipt = (String, URef);
+
+/// How the buyer pays a sale, auction bids are escrowed in CSPR
+pub(crate) enum SalePayment {
+	Purse(URef),
+	/// Accepted CEP-18 token, the buyer approves the contract package as a spender of the converted price
+	Token {
+		token: ContractHash,
+		buyer: Key,
+	},
+}
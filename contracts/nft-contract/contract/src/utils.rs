@@ -0,0 +1,44 @@
+use alloc::{ string::{ String, ToString }, vec::Vec };
+use casper_contract::{
+	contract_api::runtime,
+	unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{ runtime_args, ContractHash, Key, RuntimeArgs };
+use common_lib::{
+	constants::common_keys::{ AdministrationArgs, AdministrationEndpoints },
+	db::dictionary::Dictionary,
+	enums::contracts_enum::ContractKind,
+	errors::CommonError,
+	utils::{ contract::get_administration_contract_hash, pause::ensure_not_paused },
+};
+
+use crate::db::token_metadata::TokenMetadata;
+
+/// nft-core shards the administration directory holds for the extension, every shard without it
+pub fn get_nft_core_contract_hashes(extension: Option<String>) -> Vec<ContractHash> {
+	let keys = runtime::call_contract::<Vec<Key>>(
+		get_administration_contract_hash(),
+		&AdministrationEndpoints::GetCompoundContracts.to_string(),
+		runtime_args! {
+			AdministrationArgs::ContractKind.to_string() => ContractKind::NFTCore,
+			AdministrationArgs::Extension.to_string() => extension,
+		}
+	);
+	keys
+		.into_iter()
+		.map(|key|
+			key
+				.into_hash()
+				.map(ContractHash::new)
+				.unwrap_or_revert_with(CommonError::UnexpectedKeyVariant)
+		)
+		.collect()
+}
+
+/// Sales of the name stop once its extension is paused,
+/// tokens minted before the metadata was kept are checked by the contract kind only
+pub fn ensure_token_not_paused(token_id: &str) {
+	if let Some(metadata) = Dictionary::token_metadata_instance().get_metadata(token_id) {
+		ensure_not_paused(ContractKind::NFT, Some(metadata.extension));
+	}
+}
@@ -0,0 +1,59 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    ext_ffi,
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{api_error, bytesrepr::FromBytes, CLTyped, ContractHash, Key, RuntimeArgs};
+
+const ENTRY_POINT_GET_OWNED_TOKENS: &str = "get_owned_tokens";
+const ARG_NFT_CONTRACT_HASH: &str = "nft_contract_hash";
+const ARG_TOKEN_OWNER: &str = "token_owner";
+const ARG_KEY_NAME: &str = "key_name";
+const ARG_OFFSET: &str = "offset";
+const ARG_LIMIT: &str = "limit";
+
+// Paging arguments are forwarded only when the deploy provides them
+fn get_optional_named_arg<T: CLTyped + FromBytes>(name: &str) -> Option<T> {
+    let mut arg_size: usize = 0;
+    let ret = unsafe {
+        ext_ffi::casper_get_named_arg_size(name.as_bytes().as_ptr(), name.len(), &mut arg_size)
+    };
+    api_error::result_from(ret)
+        .ok()
+        .map(|_| runtime::get_named_arg(name))
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let nft_contract_hash: ContractHash = runtime::get_named_arg::<Key>(ARG_NFT_CONTRACT_HASH)
+        .into_hash()
+        .map(ContractHash::new)
+        .unwrap();
+    let key_name: String = runtime::get_named_arg(ARG_KEY_NAME);
+    let token_owner: Key = runtime::get_named_arg(ARG_TOKEN_OWNER);
+
+    let mut args = RuntimeArgs::new();
+    args.insert(ARG_TOKEN_OWNER, token_owner).unwrap_or_revert();
+    if let Some(offset) = get_optional_named_arg::<u64>(ARG_OFFSET) {
+        args.insert(ARG_OFFSET, offset).unwrap_or_revert();
+    }
+    if let Some(limit) = get_optional_named_arg::<u64>(ARG_LIMIT) {
+        args.insert(ARG_LIMIT, limit).unwrap_or_revert();
+    }
+
+    let owned_tokens = runtime::call_contract::<Vec<String>>(
+        nft_contract_hash,
+        ENTRY_POINT_GET_OWNED_TOKENS,
+        args,
+    );
+    runtime::put_key(&key_name, storage::new_uref(owned_tokens).into());
+}
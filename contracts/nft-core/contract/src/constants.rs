@@ -20,11 +20,13 @@ pub const ARG_HASH_KEY_NAME_1_0_0: &str = "hash_key_name";
 pub const ARG_HOLDER_MODE: &str = "holder_mode";
 pub const ARG_IDENTIFIER_MODE: &str = "identifier_mode";
 pub const ARG_JSON_SCHEMA: &str = "json_schema";
+pub const ARG_LIMIT: &str = "limit";
 pub const ARG_METADATA_MUTABILITY: &str = "metadata_mutability";
 pub const ARG_MINTING_MODE: &str = "minting_mode";
 pub const ARG_NAMED_KEY_CONVENTION: &str = "named_key_convention";
 pub const ARG_NFT_KIND: &str = "nft_kind";
 pub const ARG_NFT_METADATA_KIND: &str = "nft_metadata_kind";
+pub const ARG_OFFSET: &str = "offset";
 pub const ARG_NFT_PACKAGE_KEY: &str = "cep78_package_key";
 pub const ARG_OPTIONAL_METADATA: &str = "optional_metadata";
 pub const ARG_OPERATOR: &str = "operator";
@@ -42,12 +44,14 @@ pub const ARG_TOKEN_META_DATA: &str = "token_meta_data";
 pub const ARG_TOKEN_OWNER: &str = "token_owner";
 pub const ARG_TOTAL_TOKEN_SUPPLY: &str = "total_token_supply";
 pub const ARG_TRANSFER_FILTER_CONTRACT: &str = "transfer_filter_contract";
+pub const ARG_ACL_MANAGER: &str = "acl_manager";
 pub const ARG_WHITELIST_MODE: &str = "whitelist_mode";
 
 pub const ENTRY_POINT_APPROVE: &str = "approve";
 pub const ENTRY_POINT_BALANCE_OF: &str = "balance_of";
 pub const ENTRY_POINT_BURN: &str = "burn";
 pub const ENTRY_POINT_GET_APPROVED: &str = "get_approved";
+pub const ENTRY_POINT_GET_OWNED_TOKENS: &str = "get_owned_tokens";
 pub const ENTRY_POINT_INIT: &str = "init";
 pub const ENTRY_POINT_IS_APPROVED_FOR_ALL: &str = "is_approved_for_all";
 pub const ENTRY_POINT_METADATA: &str = "metadata";
@@ -59,6 +63,7 @@ pub const ENTRY_POINT_REGISTER_OWNER: &str = "register_owner";
 pub const ENTRY_POINT_SET_APPROVALL_FOR_ALL: &str = "set_approval_for_all";
 pub const ENTRY_POINT_SET_TOKEN_METADATA: &str = "set_token_metadata";
 pub const ENTRY_POINT_SET_VARIABLES: &str = "set_variables";
+pub const ENTRY_POINT_SET_ACL_WHITELIST: &str = "set_acl_whitelist";
 pub const ENTRY_POINT_TRANSFER: &str = "transfer";
 pub const ENTRY_POINT_UPDATED_RECEIPTS: &str = "updated_receipts";
 
@@ -103,6 +108,7 @@ pub const PAGE_LIMIT: &str = "page_limit";
 pub const PAGE_TABLE: &str = "page_table";
 pub const RECEIPT_NAME: &str = "receipt_name";
 pub const RECIPIENT: &str = "recipient";
+pub const REMINTED_TOKENS: &str = "reminted_tokens";
 pub const REPORTING_MODE: &str = "reporting_mode";
 pub const RLO_MFLAG: &str = "rlo_mflag";
 pub const SENDER: &str = "sender";
@@ -114,6 +120,7 @@ pub const TOKEN_OWNERS: &str = "token_owners";
 pub const TOTAL_TOKEN_SUPPLY: &str = "total_token_supply";
 pub const TRANSFER_FILTER_CONTRACT: &str = "transfer_filter_contract";
 pub const TRANSFER_FILTER_CONTRACT_METHOD: &str = "can_transfer";
+pub const ACL_MANAGER: &str = "acl_manager";
 pub const UNMATCHED_HASH_COUNT: &str = "unmatched_hash_count";
 pub const WHITELIST_MODE: &str = "whitelist_mode";
 
@@ -173,6 +173,9 @@ pub enum NFTCoreError {
     MissingOperatorBurnMode = 168,
     InvalidIdentifier = 169,
     DuplicateIdentifier = 170,
+    InvalidACLManager = 171,
+    InvalidOffset = 172,
+    InvalidLimit = 173,
 }
 
 impl From<NFTCoreError> for ApiError {
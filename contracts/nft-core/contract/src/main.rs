@@ -29,34 +29,36 @@ use casper_contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    contracts::NamedKeys, runtime_args, CLType, CLValue, ContractHash, ContractPackageHash,
-    EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, KeyTag, Parameter, RuntimeArgs,
-    Tagged,
+    contracts::NamedKeys, runtime_args, system::CallStackElement, CLType, CLValue, ContractHash,
+    ContractPackageHash, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, KeyTag,
+    Parameter, RuntimeArgs, Tagged,
 };
 use constants::{
-    ACCESS_KEY_NAME_1_0_0, ACL_PACKAGE_MODE, ACL_WHITELIST, ALLOW_MINTING, APPROVED,
-    ARG_ACCESS_KEY_NAME_1_0_0, ARG_ACL_PACKAGE_MODE, ARG_ACL_WHITELIST,
+    ACCESS_KEY_NAME_1_0_0, ACL_MANAGER, ACL_PACKAGE_MODE, ACL_WHITELIST, ALLOW_MINTING, APPROVED,
+    ARG_ACCESS_KEY_NAME_1_0_0, ARG_ACL_MANAGER, ARG_ACL_PACKAGE_MODE, ARG_ACL_WHITELIST,
     ARG_ADDITIONAL_REQUIRED_METADATA, ARG_ALLOW_MINTING, ARG_APPROVE_ALL, ARG_BURN_MODE,
     ARG_COLLECTION_NAME, ARG_COLLECTION_SYMBOL, ARG_CONTRACT_WHITELIST, ARG_EVENTS_MODE,
-    ARG_HASH_KEY_NAME_1_0_0, ARG_HOLDER_MODE, ARG_IDENTIFIER_MODE, ARG_JSON_SCHEMA,
+    ARG_HASH_KEY_NAME_1_0_0, ARG_HOLDER_MODE, ARG_IDENTIFIER_MODE, ARG_JSON_SCHEMA, ARG_LIMIT,
     ARG_METADATA_MUTABILITY, ARG_MINTING_MODE, ARG_NAMED_KEY_CONVENTION, ARG_NFT_KIND,
-    ARG_NFT_METADATA_KIND, ARG_NFT_PACKAGE_KEY, ARG_OPERATOR, ARG_OPERATOR_BURN_MODE,
+    ARG_NFT_METADATA_KIND, ARG_NFT_PACKAGE_KEY, ARG_OFFSET, ARG_OPERATOR, ARG_OPERATOR_BURN_MODE,
     ARG_OPTIONAL_METADATA, ARG_OWNERSHIP_MODE, ARG_OWNER_LOOKUP_MODE, ARG_PACKAGE_OPERATOR_MODE,
     ARG_RECEIPT_NAME, ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_HASH, ARG_TOKEN_ID,
     ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ARG_TOTAL_TOKEN_SUPPLY, ARG_TRANSFER_FILTER_CONTRACT,
     ARG_WHITELIST_MODE, BURNT_TOKENS, BURN_MODE, COLLECTION_NAME, COLLECTION_SYMBOL,
     ENTRY_POINT_APPROVE, ENTRY_POINT_BALANCE_OF, ENTRY_POINT_BURN, ENTRY_POINT_GET_APPROVED,
-    ENTRY_POINT_INIT, ENTRY_POINT_IS_APPROVED_FOR_ALL, ENTRY_POINT_METADATA, ENTRY_POINT_MIGRATE,
-    ENTRY_POINT_MINT, ENTRY_POINT_OWNER_OF, ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_REVOKE,
-    ENTRY_POINT_SET_APPROVALL_FOR_ALL, ENTRY_POINT_SET_TOKEN_METADATA, ENTRY_POINT_SET_VARIABLES,
-    ENTRY_POINT_TRANSFER, ENTRY_POINT_UPDATED_RECEIPTS, EVENTS_MODE, HASH_BY_INDEX,
-    HASH_KEY_NAME_1_0_0, HOLDER_MODE, IDENTIFIER_MODE, INDEX_BY_HASH, INSTALLER, JSON_SCHEMA,
-    MAX_TOTAL_TOKEN_SUPPLY, METADATA_CEP78, METADATA_CUSTOM_VALIDATED, METADATA_MUTABILITY,
-    METADATA_NFT721, METADATA_RAW, MINTING_MODE, NFT_KIND, NFT_METADATA_KIND, NFT_METADATA_KINDS,
-    NUMBER_OF_MINTED_TOKENS, OPERATOR, OPERATORS, OPERATOR_BURN_MODE, OWNED_TOKENS, OWNERSHIP_MODE,
-    PACKAGE_OPERATOR_MODE, PAGE_LIMIT, PAGE_TABLE, PREFIX_ACCESS_KEY_NAME, PREFIX_CEP78,
-    PREFIX_CONTRACT_NAME, PREFIX_CONTRACT_VERSION, PREFIX_HASH_KEY_NAME, PREFIX_PAGE_DICTIONARY,
-    RECEIPT_NAME, REPORTING_MODE, RLO_MFLAG, TOKEN_COUNT, TOKEN_ISSUERS, TOKEN_OWNERS,
+    ENTRY_POINT_GET_OWNED_TOKENS, ENTRY_POINT_INIT, ENTRY_POINT_IS_APPROVED_FOR_ALL,
+    ENTRY_POINT_METADATA, ENTRY_POINT_MIGRATE, ENTRY_POINT_MINT, ENTRY_POINT_OWNER_OF,
+    ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_REVOKE, ENTRY_POINT_SET_APPROVALL_FOR_ALL,
+    ENTRY_POINT_SET_ACL_WHITELIST, ENTRY_POINT_SET_TOKEN_METADATA, ENTRY_POINT_SET_VARIABLES,
+    ENTRY_POINT_TRANSFER,
+    ENTRY_POINT_UPDATED_RECEIPTS, EVENTS_MODE, HASH_BY_INDEX, HASH_KEY_NAME_1_0_0, HOLDER_MODE,
+    IDENTIFIER_MODE, INDEX_BY_HASH, INSTALLER, JSON_SCHEMA, MAX_TOTAL_TOKEN_SUPPLY, METADATA_CEP78,
+    METADATA_CUSTOM_VALIDATED, METADATA_MUTABILITY, METADATA_NFT721, METADATA_RAW, MINTING_MODE,
+    NFT_KIND, NFT_METADATA_KIND, NFT_METADATA_KINDS, NUMBER_OF_MINTED_TOKENS, OPERATOR, OPERATORS,
+    OPERATOR_BURN_MODE, OWNED_TOKENS, OWNERSHIP_MODE, PACKAGE_OPERATOR_MODE, PAGE_LIMIT,
+    PAGE_TABLE, PREFIX_ACCESS_KEY_NAME, PREFIX_CEP78, PREFIX_CONTRACT_NAME,
+    PREFIX_CONTRACT_VERSION, PREFIX_HASH_KEY_NAME, PREFIX_PAGE_DICTIONARY, RECEIPT_NAME,
+    REMINTED_TOKENS, REPORTING_MODE, RLO_MFLAG, TOKEN_COUNT, TOKEN_ISSUERS, TOKEN_OWNERS,
     TOTAL_TOKEN_SUPPLY, TRANSFER_FILTER_CONTRACT, TRANSFER_FILTER_CONTRACT_METHOD,
     UNMATCHED_HASH_COUNT, WHITELIST_MODE,
 };
@@ -347,6 +349,21 @@ pub extern "C" fn init() {
         runtime::revert(NFTCoreError::TransferFilterContractNeedsTransferableMode)
     }
 
+    // The contract allowed to replace the ACL whitelist besides the installer
+    let acl_manager_contract_hash: Option<ContractHash> =
+        utils::get_optional_named_arg_with_user_errors::<Option<Key>>(
+            ARG_ACL_MANAGER,
+            NFTCoreError::InvalidACLManager,
+        )
+        .unwrap_or_default()
+        .map(|acl_manager_key| {
+            ContractHash::from(
+                acl_manager_key
+                    .into_hash()
+                    .unwrap_or_revert_with(NFTCoreError::InvalidACLManager),
+            )
+        });
+
     // Put all created URefs into the contract's context (necessary to retain access rights,
     // for future use).
     //
@@ -434,6 +451,8 @@ pub extern "C" fn init() {
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(BURNT_TOKENS)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(REMINTED_TOKENS)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(TOKEN_COUNT)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(METADATA_CUSTOM_VALIDATED)
@@ -488,6 +507,10 @@ pub extern "C" fn init() {
             storage::new_uref(transfer_filter_contract).into(),
         );
     }
+
+    if let Some(acl_manager) = acl_manager_contract_hash {
+        runtime::put_key(ACL_MANAGER, storage::new_uref(acl_manager).into());
+    }
 }
 
 // set_variables allows the user to set any variable or any combination of variables simultaneously.
@@ -572,30 +595,7 @@ pub extern "C" fn set_variables() {
     }
 
     if !new_acl_whitelist.is_empty() {
-        let whitelist_mode: WhitelistMode = utils::get_stored_value_with_user_errors::<u8>(
-            WHITELIST_MODE,
-            NFTCoreError::MissingWhitelistMode,
-            NFTCoreError::InvalidWhitelistMode,
-        )
-        .try_into()
-        .unwrap_or_revert();
-        match whitelist_mode {
-            WhitelistMode::Unlocked => {
-                // Clear acl whitelist
-                runtime::remove_key(ACL_WHITELIST);
-                storage::new_dictionary(ACL_WHITELIST)
-                    .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
-                // Update acl whitelist
-                for key in new_acl_whitelist.iter() {
-                    utils::upsert_dictionary_value_from_key(
-                        ACL_WHITELIST,
-                        &utils::encode_dictionary_item_key(*key),
-                        true,
-                    );
-                }
-            }
-            WhitelistMode::Locked => runtime::revert(NFTCoreError::InvalidWhitelistMode),
-        }
+        replace_acl_whitelist(&new_acl_whitelist);
     }
 
     let events_mode: EventsMode = utils::get_stored_value_with_user_errors::<u8>(
@@ -614,6 +614,59 @@ pub extern "C" fn set_variables() {
     }
 }
 
+// Replaces the ACL whitelist, callable by the installer or by the ACL manager contract
+// passed on installation, e.g. the contract which brings a new shard into service.
+#[no_mangle]
+pub extern "C" fn set_acl_whitelist() {
+    let installer = utils::get_account_hash(
+        INSTALLER,
+        NFTCoreError::MissingInstaller,
+        NFTCoreError::InvalidInstaller,
+    );
+    // Holder mode restricts the token owners, not the caller of this entrypoint
+    let is_allowed = match *runtime::get_call_stack()
+        .iter()
+        .nth_back(1)
+        .unwrap_or_revert()
+    {
+        CallStackElement::Session { account_hash } => account_hash == installer,
+        CallStackElement::StoredSession { contract_hash, .. }
+        | CallStackElement::StoredContract { contract_hash, .. } => {
+            utils::named_uref_exists(ACL_MANAGER)
+                && utils::get_stored_value_with_user_errors::<ContractHash>(
+                    ACL_MANAGER,
+                    NFTCoreError::InvalidACLManager,
+                    NFTCoreError::InvalidACLManager,
+                ) == contract_hash
+        }
+    };
+    if !is_allowed {
+        runtime::revert(NFTCoreError::InvalidAccount);
+    }
+
+    let new_acl_whitelist = utils::get_named_arg_with_user_errors::<Vec<Key>>(
+        ARG_ACL_WHITELIST,
+        NFTCoreError::MissingACLWhiteList,
+        NFTCoreError::InvalidACLWhitelist,
+    )
+    .unwrap_or_revert();
+    replace_acl_whitelist(&new_acl_whitelist);
+
+    let events_mode: EventsMode = utils::get_stored_value_with_user_errors::<u8>(
+        EVENTS_MODE,
+        NFTCoreError::MissingEventsMode,
+        NFTCoreError::InvalidEventsMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    match events_mode {
+        EventsMode::NoEvents => {}
+        EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::VariablesSet),
+        EventsMode::CES => casper_event_standard::emit(VariablesSet::new()),
+    }
+}
+
 // Mints a new token. Minting will fail if allow_minting is set to false.
 #[no_mangle]
 pub extern "C" fn mint() {
@@ -745,6 +798,22 @@ pub extern "C" fn mint() {
         NFTCoreError::InvalidIdentifier,
     )
     .unwrap_or_default();
+
+    let metadata_mutability: MetadataMutability = utils::get_stored_value_with_user_errors::<u8>(
+        METADATA_MUTABILITY,
+        NFTCoreError::MissingMetadataMutability,
+        NFTCoreError::InvalidMetadataMutability,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    // A hash derived from mutable metadata would not follow its updates.
+    if NFTIdentifierMode::Hash == identifier_mode
+        && matches!(metadata_mutability, MetadataMutability::Mutable)
+        && optional_token_hash.is_empty()
+    {
+        runtime::revert(NFTCoreError::InvalidIdentifier)
+    }
     let token_identifier: TokenIdentifier = match identifier_mode {
         NFTIdentifierMode::Ordinal => TokenIdentifier::Index(minted_tokens_count),
         NFTIdentifierMode::Hash => TokenIdentifier::Hash(if optional_token_hash.is_empty() {
@@ -754,6 +823,18 @@ pub extern "C" fn mint() {
         }),
     };
 
+    // A burnt hash is minted again at its index, the names are reregistered under the same hash
+    let is_remint =
+        NFTIdentifierMode::Hash == identifier_mode && utils::is_token_burned(&token_identifier);
+    let previous_owner_key: Option<Key> = if is_remint {
+        utils::get_dictionary_value_from_key(
+            TOKEN_OWNERS,
+            &token_identifier.get_dictionary_item_key(),
+        )
+    } else {
+        None
+    };
+
     for (metadata_kind, required) in metadata_kinds {
         if required == Requirement::Unneeded {
             continue;
@@ -798,7 +879,13 @@ pub extern "C" fn mint() {
     );
     let owned_tokens_item_key = utils::encode_dictionary_item_key(token_owner_key);
 
-    if let NFTIdentifierMode::Hash = identifier_mode {
+    if is_remint {
+        utils::upsert_dictionary_value_from_key(
+            REMINTED_TOKENS,
+            &token_identifier.get_dictionary_item_key(),
+            true,
+        );
+    } else if let NFTIdentifierMode::Hash = identifier_mode {
         // Update the forward and reverse trackers
         utils::insert_hash_id_lookups(minted_tokens_count, token_identifier.clone());
     }
@@ -815,13 +902,15 @@ pub extern "C" fn mint() {
         updated_token_count,
     );
 
-    // Increment number_of_minted_tokens by one
-    let number_of_minted_tokens_uref = utils::get_uref(
-        NUMBER_OF_MINTED_TOKENS,
-        NFTCoreError::MissingTotalTokenSupply,
-        NFTCoreError::InvalidTotalTokenSupply,
-    );
-    storage::write(number_of_minted_tokens_uref, minted_tokens_count + 1u64);
+    // Increment number_of_minted_tokens by one, a reminted token keeps its index
+    if !is_remint {
+        let number_of_minted_tokens_uref = utils::get_uref(
+            NUMBER_OF_MINTED_TOKENS,
+            NFTCoreError::MissingTotalTokenSupply,
+            NFTCoreError::InvalidTotalTokenSupply,
+        );
+        storage::write(number_of_minted_tokens_uref, minted_tokens_count + 1u64);
+    }
 
     // Emit Mint event.
     let events_mode: EventsMode =
@@ -853,11 +942,19 @@ pub extern "C" fn mint() {
             utils::migrate_token_hashes(token_owner_key)
         }
 
-        let (page_table_entry, page_uref) = utils::add_page_entry_and_page_record(
-            minted_tokens_count,
-            &owned_tokens_item_key,
-            true,
-        );
+        // The page record of a reminted token moves from the previous owner like on transfer
+        let (page_table_entry, page_uref) = match previous_owner_key {
+            Some(previous_owner_key) => utils::update_page_entry_and_page_record(
+                utils::get_token_index(&token_identifier),
+                &utils::encode_dictionary_item_key(previous_owner_key),
+                &owned_tokens_item_key,
+            ),
+            None => utils::add_page_entry_and_page_record(
+                minted_tokens_count,
+                &owned_tokens_item_key,
+                true,
+            ),
+        };
 
         let receipt_string = utils::get_receipt_name(page_table_entry);
         let receipt_address = Key::dictionary(page_uref, owned_tokens_item_key.as_bytes());
@@ -936,8 +1033,22 @@ pub extern "C" fn burn() {
         false
     };
 
+    // With operator burn mode the contracts allowed to mint burn the tokens of any owner,
+    // names are released this way when they expire
+    let is_minter = if !is_owner && !is_operator && !is_package_operator {
+        utils::named_uref_exists(OPERATOR_BURN_MODE)
+            && utils::get_stored_value_with_user_errors::<bool>(
+                OPERATOR_BURN_MODE,
+                NFTCoreError::MissingOperatorBurnMode,
+                NFTCoreError::InvalidOperatorBurnMode,
+            )
+            && is_acl_whitelisted_minter(caller, contract_package)
+    } else {
+        false
+    };
+
     // Revert if caller is not token_owner nor operator for the owner
-    if !is_owner && !is_operator && !is_package_operator {
+    if !is_owner && !is_operator && !is_package_operator && !is_minter {
         runtime::revert(NFTCoreError::InvalidTokenOwner)
     };
 
@@ -954,6 +1065,20 @@ pub extern "C" fn burn() {
         &token_identifier.get_dictionary_item_key(),
         (),
     );
+    // A reminted token is burnt again
+    if utils::is_token_reminted(&token_identifier.get_dictionary_item_key()) {
+        utils::upsert_dictionary_value_from_key(
+            REMINTED_TOKENS,
+            &token_identifier.get_dictionary_item_key(),
+            false,
+        );
+    }
+    // The spender approved by the previous owner must not carry over to a reminted token
+    utils::upsert_dictionary_value_from_key(
+        APPROVED,
+        &token_identifier.get_dictionary_item_key(),
+        Option::<Key>::None,
+    );
 
     let owned_tokens_item_key = utils::encode_dictionary_item_key(token_owner);
 
@@ -1799,11 +1924,15 @@ pub extern "C" fn set_token_metadata() {
     );
 
     if let Some(token_owner_key) = token_owner {
-        let caller: Key = match utils::get_verified_caller().unwrap_or_revert() {
-            Caller::Session(account_hash) => account_hash.into(),
-            Caller::StoredCaller(contract_hash, _) => contract_hash.into(),
-        };
-        if caller != token_owner_key {
+        let (caller, contract_package): (Key, Option<Key>) =
+            match utils::get_verified_caller().unwrap_or_revert() {
+                Caller::Session(account_hash) => (account_hash.into(), None),
+                Caller::StoredCaller(contract_hash, contract_package_hash) => {
+                    (contract_hash.into(), Some(contract_package_hash.into()))
+                }
+            };
+        // The minting contract keeps the expiration in the metadata up to date.
+        if caller != token_owner_key && !is_acl_whitelisted_minter(caller, contract_package) {
             runtime::revert(NFTCoreError::InvalidTokenOwner)
         }
     } else {
@@ -2080,6 +2209,11 @@ pub extern "C" fn migrate() {
         storage::new_dictionary(OPERATORS)
             .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     }
+    // Add REMINTED_TOKENS dict
+    if runtime::get_key(REMINTED_TOKENS).is_none() {
+        storage::new_dictionary(REMINTED_TOKENS)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
 
     utils::migrate_contract_whitelist_to_acl_whitelist();
 }
@@ -2132,6 +2266,97 @@ pub extern "C" fn updated_receipts() {
     }
 }
 
+// Returns the identifiers of the tokens owned by the token owner, read from the page
+// dictionaries of the owner. Burnt tokens are skipped. Reverts unless the reporting
+// mode is Complete.
+#[no_mangle]
+pub extern "C" fn get_owned_tokens() {
+    if OwnerReverseLookupMode::Complete != utils::get_reporting_mode() {
+        runtime::revert(NFTCoreError::InvalidReportingMode)
+    }
+
+    let token_owner = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_TOKEN_OWNER,
+        NFTCoreError::MissingTokenOwner,
+        NFTCoreError::InvalidTokenOwner,
+    )
+    .unwrap_or_revert();
+
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    // Callers listing the tokens of several contracts skip and limit them here,
+    // without the arguments every owned token is returned
+    let offset = utils::get_optional_named_arg_with_user_errors::<u64>(
+        ARG_OFFSET,
+        NFTCoreError::InvalidOffset,
+    )
+    .unwrap_or(0);
+    let limit = utils::get_optional_named_arg_with_user_errors::<u64>(
+        ARG_LIMIT,
+        NFTCoreError::InvalidLimit,
+    );
+
+    let token_owner_item_key = utils::encode_dictionary_item_key(token_owner);
+
+    let page_table =
+        utils::get_dictionary_value_from_key::<Vec<bool>>(PAGE_TABLE, &token_owner_item_key)
+            .unwrap_or_default();
+
+    let mut owned_tokens: Vec<String> = vec![];
+    let mut skipped = 0u64;
+
+    'pages: for (page_table_entry, allocated) in page_table.into_iter().enumerate() {
+        if !allocated {
+            continue;
+        }
+        let page = utils::get_dictionary_value_from_key::<Vec<bool>>(
+            &format!("{PREFIX_PAGE_DICTIONARY}_{page_table_entry}"),
+            &token_owner_item_key,
+        )
+        .unwrap_or_default();
+
+        for (page_address, owned) in page.into_iter().enumerate() {
+            if !owned {
+                continue;
+            }
+            let token_index = page_table_entry as u64 * utils::PAGE_SIZE + page_address as u64;
+            let token_identifier = match identifier_mode {
+                NFTIdentifierMode::Ordinal => TokenIdentifier::new_index(token_index),
+                NFTIdentifierMode::Hash => TokenIdentifier::new_hash(
+                    utils::get_dictionary_value_from_key::<String>(
+                        HASH_BY_INDEX,
+                        &token_index.to_string(),
+                    )
+                    .unwrap_or_revert_with(NFTCoreError::InvalidTokenIdentifier),
+                ),
+            };
+            // A burnt token stays on the page of its last owner
+            if utils::is_token_burned(&token_identifier) {
+                continue;
+            }
+            if skipped < offset {
+                skipped += 1;
+                continue;
+            }
+            if limit.map_or(false, |limit| owned_tokens.len() as u64 >= limit) {
+                break 'pages;
+            }
+            owned_tokens.push(token_identifier.get_dictionary_item_key());
+        }
+    }
+
+    let owned_tokens_cl_value =
+        CLValue::from_t(owned_tokens).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+
+    runtime::ret(owned_tokens_cl_value);
+}
+
 #[no_mangle]
 pub extern "C" fn register_owner() {
     if vec![
@@ -2228,6 +2453,7 @@ fn generate_entry_points() -> EntryPoints {
                 ARG_TRANSFER_FILTER_CONTRACT,
                 CLType::Option(Box::new(CLType::Key)),
             ),
+            Parameter::new(ARG_ACL_MANAGER, CLType::Option(Box::new(CLType::Key))),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -2388,6 +2614,20 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
+    // This entrypoint returns the identifiers of the tokens owned by the provided token holder.
+    // Reverts unless the reporting mode is Complete.
+    let get_owned_tokens = EntryPoint::new(
+        ENTRY_POINT_GET_OWNED_TOKENS,
+        vec![
+            Parameter::new(ARG_TOKEN_OWNER, CLType::Key),
+            Parameter::new(ARG_OFFSET, CLType::U64),
+            Parameter::new(ARG_LIMIT, CLType::U64),
+        ],
+        CLType::List(Box::new(CLType::String)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint returns number of owned tokens associated with the provided token holder
     let balance_of = EntryPoint::new(
         ENTRY_POINT_BALANCE_OF,
@@ -2428,6 +2668,19 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
+    // This entrypoint replaces the ACL whitelist. Meant to be called by the installer or
+    // by the ACL manager contract, e.g. when a new shard is brought into service.
+    let set_acl_whitelist = EntryPoint::new(
+        ENTRY_POINT_SET_ACL_WHITELIST,
+        vec![Parameter::new(
+            ARG_ACL_WHITELIST,
+            CLType::List(Box::new(CLType::Key)),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint will allow NFT owners to update their receipts from
     // the previous owned_tokens list model to the current pagination model
     // scheme. Calling the entrypoint will return a list of receipt names
@@ -2459,6 +2712,7 @@ fn generate_entry_points() -> EntryPoints {
 
     entry_points.add_entry_point(init_contract);
     entry_points.add_entry_point(set_variables);
+    entry_points.add_entry_point(set_acl_whitelist);
     entry_points.add_entry_point(mint);
     entry_points.add_entry_point(burn);
     entry_points.add_entry_point(transfer);
@@ -2474,9 +2728,66 @@ fn generate_entry_points() -> EntryPoints {
     entry_points.add_entry_point(migrate);
     entry_points.add_entry_point(updated_receipts);
     entry_points.add_entry_point(register_owner);
+    entry_points.add_entry_point(get_owned_tokens);
     entry_points
 }
 
+fn replace_acl_whitelist(new_acl_whitelist: &[Key]) {
+    let whitelist_mode: WhitelistMode = utils::get_stored_value_with_user_errors::<u8>(
+        WHITELIST_MODE,
+        NFTCoreError::MissingWhitelistMode,
+        NFTCoreError::InvalidWhitelistMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+    match whitelist_mode {
+        WhitelistMode::Unlocked => {
+            // Clear acl whitelist
+            runtime::remove_key(ACL_WHITELIST);
+            storage::new_dictionary(ACL_WHITELIST)
+                .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+            // Update acl whitelist
+            for key in new_acl_whitelist.iter() {
+                utils::upsert_dictionary_value_from_key(
+                    ACL_WHITELIST,
+                    &utils::encode_dictionary_item_key(*key),
+                    true,
+                );
+            }
+        }
+        WhitelistMode::Locked => runtime::revert(NFTCoreError::InvalidWhitelistMode),
+    }
+}
+
+// Contracts allowed to mint in ACL minting mode, the same check as in `mint`.
+fn is_acl_whitelisted_minter(caller: Key, contract_package: Option<Key>) -> bool {
+    let minting_mode: MintingMode = utils::get_stored_value_with_user_errors::<u8>(
+        MINTING_MODE,
+        NFTCoreError::MissingMintingMode,
+        NFTCoreError::InvalidMintingMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+    if MintingMode::Acl != minting_mode || !matches!(caller, Key::Hash(_)) {
+        return false;
+    }
+
+    let acl_package_mode: bool = utils::get_stored_value_with_user_errors::<bool>(
+        ACL_PACKAGE_MODE,
+        NFTCoreError::MissingACLPackageMode,
+        NFTCoreError::InvalidACLPackageMode,
+    );
+    let whitelisted_key = match (acl_package_mode, contract_package) {
+        (true, Some(contract_package)) => contract_package,
+        _ => caller,
+    };
+    utils::get_dictionary_value_from_key::<bool>(
+        ACL_WHITELIST,
+        &utils::encode_dictionary_item_key(whitelisted_key),
+    )
+    .unwrap_or_default()
+}
+
 fn install_contract() {
     // Represents the name of the NFT collection
     // This value cannot be changed after installation.
@@ -2658,7 +2969,9 @@ fn install_contract() {
     )
     .unwrap_or_revert();
 
-    if identifier_mode == 1 && metadata_mutability == 1 {
+    // A hash derived from the metadata goes stale once the metadata is updated,
+    // the contracts whitelisted in the ACL minting mode pass their own `token_hash`.
+    if identifier_mode == 1 && metadata_mutability == 1 && minting_mode != MintingMode::Acl as u8 {
         runtime::revert(NFTCoreError::InvalidMetadataMutability)
     }
 
@@ -2695,6 +3008,12 @@ fn install_contract() {
             NFTCoreError::InvalidTransferFilterContract,
         );
 
+    let acl_manager_contract_key: Option<Key> =
+        utils::get_optional_named_arg_with_user_errors(
+            ARG_ACL_MANAGER,
+            NFTCoreError::InvalidACLManager,
+        );
+
     if ownership_mode == 0 && minting_mode == 0 && reporting_mode == 1 {
         runtime::revert(NFTCoreError::InvalidReportingMode)
     }
@@ -2772,6 +3091,7 @@ fn install_contract() {
         ARG_PACKAGE_OPERATOR_MODE => package_operator_mode,
         ARG_TRANSFER_FILTER_CONTRACT =>
         transfer_filter_contract_contract_key,
+        ARG_ACL_MANAGER => acl_manager_contract_key,
     };
 
     // Call contract to initialize it
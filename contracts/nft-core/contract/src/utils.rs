@@ -26,8 +26,8 @@ use crate::{
         ACL_WHITELIST, ARG_TOKEN_HASH, ARG_TOKEN_ID, BURNT_TOKENS, BURN_MODE, CONTRACT_WHITELIST,
         HASH_BY_INDEX, HOLDER_MODE, INDEX_BY_HASH, MIGRATION_FLAG, MINTING_MODE,
         NUMBER_OF_MINTED_TOKENS, OWNED_TOKENS, OWNERSHIP_MODE, PAGE_LIMIT, PAGE_TABLE,
-        PREFIX_PAGE_DICTIONARY, RECEIPT_NAME, REPORTING_MODE, RLO_MFLAG, TOKEN_OWNERS,
-        TRANSFER_FILTER_CONTRACT, UNMATCHED_HASH_COUNT,
+        PREFIX_PAGE_DICTIONARY, RECEIPT_NAME, REMINTED_TOKENS, REPORTING_MODE, RLO_MFLAG,
+        TOKEN_OWNERS, TRANSFER_FILTER_CONTRACT, UNMATCHED_HASH_COUNT,
     },
     error::NFTCoreError,
     events::events_ces::{
@@ -387,8 +387,16 @@ pub fn get_burn_mode() -> BurnMode {
 }
 
 pub fn is_token_burned(token_identifier: &TokenIdentifier) -> bool {
-    get_dictionary_value_from_key::<()>(BURNT_TOKENS, &token_identifier.get_dictionary_item_key())
-        .is_some()
+    let item_key = token_identifier.get_dictionary_item_key();
+    get_dictionary_value_from_key::<()>(BURNT_TOKENS, &item_key).is_some()
+        && !is_token_reminted(&item_key)
+}
+
+// Dictionary items can't be removed, a burnt hash minted again is tracked separately.
+// Installations without the dictionary have no reminted tokens.
+pub fn is_token_reminted(item_key: &str) -> bool {
+    runtime::get_key(REMINTED_TOKENS).is_some()
+        && get_dictionary_value_from_key::<bool>(REMINTED_TOKENS, item_key).unwrap_or_default()
 }
 
 pub fn get_transfer_filter_contract() -> Option<ContractHash> {
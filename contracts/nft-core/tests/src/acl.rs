@@ -49,6 +49,7 @@ use nft_core_contract::{
 		ARG_TOKEN_META_DATA,
 		ARG_TOKEN_OWNER,
 		ENTRY_POINT_MINT,
+		ENTRY_POINT_SET_ACL_WHITELIST,
 		ENTRY_POINT_SET_VARIABLES,
 		TOKEN_OWNERS,
 	},
@@ -1325,6 +1326,83 @@ fn should_be_able_to_update_whitelist_for_minting() {
 	builder.exec(mint_via_contract_call).expect_success().commit();
 }
 
+#[test]
+fn should_only_let_installer_or_acl_manager_set_acl_whitelist() {
+	let mut builder = InMemoryWasmTestBuilder::default();
+	builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST).commit();
+
+	let minting_contract_install_request = ExecuteRequestBuilder::standard(
+		*DEFAULT_ACCOUNT_ADDR,
+		MINTING_CONTRACT_WASM,
+		runtime_args! {}
+	).build();
+
+	builder.exec(minting_contract_install_request).expect_success().commit();
+
+	let minting_contract_hash = get_minting_contract_hash(&builder);
+	let account_user_1 = support::create_funded_dummy_account(
+		&mut builder,
+		Some(ACCOUNT_USER_1)
+	);
+
+	let install_request = InstallerRequestBuilder::new(
+		*DEFAULT_ACCOUNT_ADDR,
+		NFT_CONTRACT_WASM
+	)
+		.with_total_token_supply(100u64)
+		.with_holder_mode(NFTHolderMode::Contracts)
+		.with_whitelist_mode(WhitelistMode::Unlocked)
+		.with_ownership_mode(OwnershipMode::Minter)
+		.with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+		.with_minting_mode(MintingMode::Acl)
+		.with_acl_whitelist(vec![])
+		.build();
+
+	builder.exec(install_request).expect_success().commit();
+
+	let nft_contract_hash = get_nft_contract_hash(&builder);
+	let nft_contract_key: Key = nft_contract_hash.into();
+
+	// No ACL manager was passed on installation
+	let set_acl_whitelist_request = ExecuteRequestBuilder::contract_call_by_hash(
+		account_user_1,
+		nft_contract_hash,
+		ENTRY_POINT_SET_ACL_WHITELIST,
+		runtime_args! {
+            ARG_ACL_WHITELIST => vec![Key::from(minting_contract_hash)]
+        }
+	).build();
+
+	builder.exec(set_acl_whitelist_request).expect_failure();
+
+	let error = builder.get_error().expect("should have an error");
+	assert_expected_error(
+		error,
+		1,
+		"Only the installer or the ACL manager may set the ACL whitelist"
+	);
+
+	let set_acl_whitelist_request = ExecuteRequestBuilder::contract_call_by_hash(
+		*DEFAULT_ACCOUNT_ADDR,
+		nft_contract_hash,
+		ENTRY_POINT_SET_ACL_WHITELIST,
+		runtime_args! {
+            ARG_ACL_WHITELIST => vec![Key::from(minting_contract_hash)]
+        }
+	).build();
+
+	builder.exec(set_acl_whitelist_request).expect_success().commit();
+
+	let is_updated_acl_whitelist = get_dictionary_value_from_key::<bool>(
+		&builder,
+		&nft_contract_key,
+		ACL_WHITELIST,
+		&minting_contract_hash.to_string()
+	);
+
+	assert!(is_updated_acl_whitelist, "acl whitelist is incorrectly set");
+}
+
 // Upgrade
 
 #[test]
@@ -38,19 +38,23 @@ use casper_engine_test_support::{
 use casper_types::{ runtime_args, Key, RuntimeArgs };
 use nft_core_contract::{
 	constants::{
+		APPROVED,
 		ARG_APPROVE_ALL,
 		ARG_COLLECTION_NAME,
 		ARG_OPERATOR,
+		ARG_SPENDER,
 		ARG_TOKEN_HASH,
 		ARG_TOKEN_ID,
 		ARG_TOKEN_META_DATA,
 		ARG_TOKEN_OWNER,
 		BURNT_TOKENS,
 		BURN_MODE,
+		ENTRY_POINT_APPROVE,
 		ENTRY_POINT_BURN,
 		ENTRY_POINT_MINT,
 		ENTRY_POINT_SET_APPROVALL_FOR_ALL,
 		TOKEN_COUNT,
+		TOKEN_OWNERS,
 	},
 	events::events_ces::Burn,
 	modalities::TokenIdentifier,
@@ -916,3 +920,213 @@ fn should_burn_token_in_hash_identifier_mode() {
 
 	builder.exec(burn_request).expect_success().commit();
 }
+
+fn burn_token_by_acl_minter(operator_burn_mode: bool) -> InMemoryWasmTestBuilder {
+	let mut builder = InMemoryWasmTestBuilder::default();
+	builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST).commit();
+
+	let minting_contract_install_request = ExecuteRequestBuilder::standard(
+		*DEFAULT_ACCOUNT_ADDR,
+		MINTING_CONTRACT_WASM,
+		runtime_args! {}
+	).build();
+
+	builder.exec(minting_contract_install_request).expect_success().commit();
+
+	let minting_contract_hash = get_minting_contract_hash(&builder);
+
+	let install_request = InstallerRequestBuilder::new(
+		*DEFAULT_ACCOUNT_ADDR,
+		NFT_CONTRACT_WASM
+	)
+		.with_total_token_supply(100u64)
+		.with_ownership_mode(OwnershipMode::Transferable)
+		.with_minting_mode(MintingMode::Acl)
+		.with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+		.with_acl_whitelist(vec![Key::from(minting_contract_hash)])
+		.with_operator_burn_mode(operator_burn_mode)
+		.build();
+
+	builder.exec(install_request).expect_success().commit();
+
+	let nft_contract_key: Key = get_nft_contract_hash(&builder).into();
+
+	// The token is held by an account which never approved the minting contract
+	let mint_via_contract_call = ExecuteRequestBuilder::contract_call_by_hash(
+		*DEFAULT_ACCOUNT_ADDR,
+		minting_contract_hash,
+		ENTRY_POINT_MINT,
+		runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_REVERSE_LOOKUP => false,
+        }
+	).build();
+
+	builder.exec(mint_via_contract_call).expect_success().commit();
+
+	let burn_via_contract_call = ExecuteRequestBuilder::contract_call_by_hash(
+		*DEFAULT_ACCOUNT_ADDR,
+		minting_contract_hash,
+		ENTRY_POINT_BURN,
+		runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_ID => 0u64
+        }
+	).build();
+
+	builder.exec(burn_via_contract_call);
+	builder
+}
+
+#[test]
+fn should_let_acl_minter_burn_tokens_with_operator_burn_mode() {
+	let mut builder = burn_token_by_acl_minter(true);
+	builder.expect_success().commit();
+
+	let nft_contract_key: Key = get_nft_contract_hash(&builder).into();
+	let token_balance = get_dictionary_value_from_key::<u64>(
+		&builder,
+		&nft_contract_key,
+		TOKEN_COUNT,
+		&DEFAULT_ACCOUNT_ADDR.to_string()
+	);
+
+	assert_eq!(token_balance, 0u64)
+}
+
+#[test]
+fn should_not_let_acl_minter_burn_tokens_without_operator_burn_mode() {
+	let mut builder = burn_token_by_acl_minter(false);
+	builder.expect_failure();
+
+	let error = builder.get_error().expect("must have error");
+	support::assert_expected_error(
+		error,
+		6u16,
+		"should disallow burning of other owners' tokens by the minter"
+	);
+}
+
+#[test]
+fn should_remint_burnt_token_in_hash_identifier_mode() {
+	let mut builder = InMemoryWasmTestBuilder::default();
+	builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST).commit();
+
+	let install_request = InstallerRequestBuilder::new(
+		*DEFAULT_ACCOUNT_ADDR,
+		NFT_CONTRACT_WASM
+	)
+		.with_total_token_supply(100u64)
+		.with_identifier_mode(NFTIdentifierMode::Hash)
+		.with_ownership_mode(OwnershipMode::Transferable)
+		.with_metadata_mutability(MetadataMutability::Immutable)
+		.with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+		.build();
+
+	builder.exec(install_request).expect_success().commit();
+
+	let nft_contract_hash = get_nft_contract_hash(&builder);
+	let nft_contract_key: Key = nft_contract_hash.into();
+	let account_user_1 = support::create_funded_dummy_account(
+		&mut builder,
+		Some(ACCOUNT_USER_1)
+	);
+	let token_hash: String = base16::encode_lower(
+		&support::create_blake2b_hash(TEST_PRETTY_721_META_DATA)
+	);
+
+	let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+		*DEFAULT_ACCOUNT_ADDR,
+		nft_contract_hash,
+		ENTRY_POINT_MINT,
+		runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        }
+	).build();
+
+	builder.exec(mint_request).expect_success().commit();
+
+	let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+		*DEFAULT_ACCOUNT_ADDR,
+		nft_contract_hash,
+		ENTRY_POINT_APPROVE,
+		runtime_args! {
+            ARG_TOKEN_HASH => token_hash.clone(),
+            ARG_SPENDER => Key::Account(account_user_1),
+        }
+	).build();
+
+	builder.exec(approve_request).expect_success().commit();
+
+	let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+		*DEFAULT_ACCOUNT_ADDR,
+		nft_contract_hash,
+		ENTRY_POINT_BURN,
+		runtime_args! {
+            ARG_TOKEN_HASH => token_hash.clone(),
+        }
+	).build();
+
+	builder.exec(burn_request).expect_success().commit();
+
+	// The same hash is minted again to the next holder
+	let remint_request = ExecuteRequestBuilder::contract_call_by_hash(
+		*DEFAULT_ACCOUNT_ADDR,
+		nft_contract_hash,
+		ENTRY_POINT_MINT,
+		runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(account_user_1),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        }
+	).build();
+
+	builder.exec(remint_request).expect_success().commit();
+
+	let token_owner = get_dictionary_value_from_key::<Key>(
+		&builder,
+		&nft_contract_key,
+		TOKEN_OWNERS,
+		&token_hash
+	);
+	assert_eq!(token_owner, Key::Account(account_user_1));
+
+	// The spender approved by the previous holder is dropped by the burn
+	let approved = get_dictionary_value_from_key::<Option<Key>>(
+		&builder,
+		&nft_contract_key,
+		APPROVED,
+		&token_hash
+	);
+	assert_eq!(approved, None);
+
+	let previous_owner_balance = get_dictionary_value_from_key::<u64>(
+		&builder,
+		&nft_contract_key,
+		TOKEN_COUNT,
+		&DEFAULT_ACCOUNT_ADDR.to_string()
+	);
+	assert_eq!(previous_owner_balance, 0u64);
+
+	let owner_balance = get_dictionary_value_from_key::<u64>(
+		&builder,
+		&nft_contract_key,
+		TOKEN_COUNT,
+		&account_user_1.to_string()
+	);
+	assert_eq!(owner_balance, 1u64);
+
+	// The reminted token is live, its holder can burn it again
+	let re_burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+		account_user_1,
+		nft_contract_hash,
+		ENTRY_POINT_BURN,
+		runtime_args! {
+            ARG_TOKEN_HASH => token_hash,
+        }
+	).build();
+
+	builder.exec(re_burn_request).expect_success().commit();
+}
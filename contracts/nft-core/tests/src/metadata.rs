@@ -151,6 +151,84 @@ fn should_prevent_install_with_hash_identifier_in_mutable_mode() {
 	)
 }
 
+#[test]
+fn should_allow_hash_identifier_in_mutable_mode_for_acl_minters() {
+	let mut builder = InMemoryWasmTestBuilder::default();
+	builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST).commit();
+
+	let minting_contract_install_request = ExecuteRequestBuilder::standard(
+		*DEFAULT_ACCOUNT_ADDR,
+		MINTING_CONTRACT_WASM,
+		runtime_args! {}
+	).build();
+
+	builder.exec(minting_contract_install_request).expect_success().commit();
+
+	let minting_contract_hash = get_minting_contract_hash(&builder);
+
+	let install_request = InstallerRequestBuilder::new(
+		*DEFAULT_ACCOUNT_ADDR,
+		NFT_CONTRACT_WASM
+	)
+		.with_total_token_supply(10u64)
+		.with_nft_metadata_kind(NFTMetadataKind::NFT721)
+		.with_identifier_mode(NFTIdentifierMode::Hash)
+		.with_metadata_mutability(MetadataMutability::Mutable)
+		.with_ownership_mode(OwnershipMode::Transferable)
+		.with_holder_mode(NFTHolderMode::Mixed)
+		.with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+		.with_minting_mode(MintingMode::Acl)
+		.with_acl_whitelist(vec![Key::from(minting_contract_hash)])
+		.build();
+
+	builder.exec(install_request).expect_success().commit();
+
+	let nft_contract_key: Key = get_nft_contract_hash(&builder).into();
+
+	// The hash can't be derived from the metadata
+	let minting_request = ExecuteRequestBuilder::contract_call_by_hash(
+		*DEFAULT_ACCOUNT_ADDR,
+		minting_contract_hash,
+		ENTRY_POINT_MINT,
+		runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_REVERSE_LOOKUP => false
+        }
+	).build();
+
+	builder.exec(minting_request).expect_failure();
+
+	let error = builder.get_error().expect("must fail without token hash");
+
+	assert_expected_error(error, 169, "Should raise InvalidIdentifier(169)");
+
+	let token_hash = String::from("bakhrom.cspr");
+	let minting_request = ExecuteRequestBuilder::contract_call_by_hash(
+		*DEFAULT_ACCOUNT_ADDR,
+		minting_contract_hash,
+		ENTRY_POINT_MINT,
+		runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_REVERSE_LOOKUP => false,
+            ARG_TOKEN_HASH => token_hash.clone()
+        }
+	).build();
+
+	builder.exec(minting_request).expect_success().commit();
+
+	let token_owner: Key = support::get_dictionary_value_from_key(
+		&builder,
+		&nft_contract_key,
+		TOKEN_OWNERS,
+		&token_hash
+	);
+	assert_eq!(token_owner, Key::Account(*DEFAULT_ACCOUNT_ADDR));
+}
+
 #[test]
 fn should_prevent_update_for_invalid_metadata() {
 	let mut builder = InMemoryWasmTestBuilder::default();
@@ -3,7 +3,9 @@ use nft_core_contract::{
 		APPROVED,
 		ARG_APPROVE_ALL,
 		ARG_COLLECTION_NAME,
+		ARG_LIMIT,
 		ARG_MINTING_MODE,
+		ARG_OFFSET,
 		ARG_OPERATOR,
 		ARG_SOURCE_KEY,
 		ARG_SPENDER,
@@ -13,6 +15,7 @@ use nft_core_contract::{
 		ARG_TOKEN_META_DATA,
 		ARG_TOKEN_OWNER,
 		ENTRY_POINT_APPROVE,
+		ENTRY_POINT_BURN,
 		ENTRY_POINT_MINT,
 		ENTRY_POINT_REGISTER_OWNER,
 		ENTRY_POINT_SET_APPROVALL_FOR_ALL,
@@ -58,6 +61,7 @@ use crate::utility::{
 		BALANCE_OF_SESSION_WASM,
 		CONTRACT_NAME,
 		GET_APPROVED_WASM,
+		GET_OWNED_TOKENS_WASM,
 		IS_APPROVED_FOR_ALL_WASM,
 		MALFORMED_META_DATA,
 		MINT_SESSION_WASM,
@@ -1545,6 +1549,99 @@ fn should_maintain_page_table_despite_invoking_register_owner() {
 	assert_eq!(actual_page_table, table_post_register)
 }
 
+#[test]
+fn should_get_owned_tokens_in_complete_reporting_mode() {
+	let mut builder = InMemoryWasmTestBuilder::default();
+	builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST).commit();
+
+	let install_request = InstallerRequestBuilder::new(
+		*DEFAULT_ACCOUNT_ADDR,
+		NFT_CONTRACT_WASM
+	)
+		.with_total_token_supply(1000u64)
+		.with_identifier_mode(NFTIdentifierMode::Ordinal)
+		.with_metadata_mutability(MetadataMutability::Immutable)
+		.with_ownership_mode(OwnershipMode::Transferable)
+		.with_reporting_mode(OwnerReverseLookupMode::Complete)
+		.with_nft_metadata_kind(NFTMetadataKind::Raw)
+		.build();
+
+	builder.exec(install_request).expect_success().commit();
+
+	let nft_contract_hash = get_nft_contract_hash(&builder);
+	let nft_contract_key: Key = nft_contract_hash.into();
+
+	for _ in 0..3 {
+		let mint_session_call = ExecuteRequestBuilder::standard(
+			*DEFAULT_ACCOUNT_ADDR,
+			MINT_SESSION_WASM,
+			runtime_args! {
+                ARG_NFT_CONTRACT_HASH => nft_contract_key,
+                ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                ARG_TOKEN_META_DATA => "",
+                ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+            }
+		).build();
+
+		builder.exec(mint_session_call).expect_success().commit();
+	}
+
+	let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+		*DEFAULT_ACCOUNT_ADDR,
+		nft_contract_hash,
+		ENTRY_POINT_BURN,
+		runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+        }
+	).build();
+
+	builder.exec(burn_request).expect_success().commit();
+
+	// The burnt token stays on the page of the owner, but is not listed
+	let owned_tokens: Vec<String> = call_session_code_with_ret(
+		&mut builder,
+		*DEFAULT_ACCOUNT_ADDR,
+		nft_contract_key,
+		runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        },
+		GET_OWNED_TOKENS_WASM,
+		"owned_tokens"
+	);
+
+	assert_eq!(owned_tokens, vec!["0".to_string(), "2".to_string()]);
+
+	// Burnt tokens are not counted by the offset either
+	let owned_tokens: Vec<String> = call_session_code_with_ret(
+		&mut builder,
+		*DEFAULT_ACCOUNT_ADDR,
+		nft_contract_key,
+		runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_OFFSET => 1u64,
+            ARG_LIMIT => 1u64,
+        },
+		GET_OWNED_TOKENS_WASM,
+		"owned_tokens"
+	);
+
+	assert_eq!(owned_tokens, vec!["2".to_string()]);
+
+	let owned_tokens: Vec<String> = call_session_code_with_ret(
+		&mut builder,
+		*DEFAULT_ACCOUNT_ADDR,
+		nft_contract_key,
+		runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_LIMIT => 1u64,
+        },
+		GET_OWNED_TOKENS_WASM,
+		"owned_tokens"
+	);
+
+	assert_eq!(owned_tokens, vec!["0".to_string()]);
+}
+
 #[test]
 fn should_prevent_mint_to_unregistered_owner() {
 	let mut builder = InMemoryWasmTestBuilder::default();
@@ -5,6 +5,7 @@ pub const CONTRACT_1_2_0_WASM: &str = "1_2_0/contract.wasm";
 pub const CONTRACT_1_3_0_WASM: &str = "1_3_0/contract.wasm";
 pub const CONTRACT_1_4_0_WASM: &str = "1_4_0/contract.wasm";
 pub const GET_APPROVED_WASM: &str = "get_approved_call.wasm";
+pub const GET_OWNED_TOKENS_WASM: &str = "get_owned_tokens_call.wasm";
 pub const IS_APPROVED_FOR_ALL_WASM: &str = "is_approved_for_all_call.wasm";
 pub const MANGLE_NAMED_KEYS: &str = "mangle_named_keys.wasm";
 pub const MINT_1_0_0_WASM: &str = "1_0_0/mint_call.wasm";
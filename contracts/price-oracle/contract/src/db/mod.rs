@@ -1 +1,2 @@
 pub mod price_oracle;
+pub mod payment_tokens;
@@ -0,0 +1,45 @@
+use alloc::string::ToString;
+use casper_types::ContractHash;
+use common_lib::constants::common_keys::PriceOracleKeys;
+use common_lib::db::dictionary::Dictionary;
+use common_lib::db::traits::Storable;
+use common_lib::models::payment_token::PaymentToken;
+
+/// Accepted CEP-18 tokens keyed by their contract hash
+pub trait PaymentTokens {
+	fn payment_tokens_initialize() -> ();
+	fn payment_tokens_instance() -> Self;
+	fn set_payment_token(
+		&self,
+		token: ContractHash,
+		payment_token: PaymentToken
+	) -> ();
+	fn remove_payment_token(&self, token: ContractHash) -> ();
+	fn get_payment_token(&self, token: ContractHash) -> Option<PaymentToken>;
+}
+
+impl PaymentTokens for Dictionary {
+	fn payment_tokens_initialize() -> () {
+		Dictionary::init(&PriceOracleKeys::PaymentTokens.to_string())
+	}
+
+	fn payment_tokens_instance() -> Self {
+		Dictionary::instance(&PriceOracleKeys::PaymentTokens.to_string())
+	}
+
+	fn set_payment_token(
+		&self,
+		token: ContractHash,
+		payment_token: PaymentToken
+	) -> () {
+		self.set(&token.to_string(), payment_token)
+	}
+
+	fn remove_payment_token(&self, token: ContractHash) -> () {
+		self.remove::<PaymentToken>(&token.to_string())
+	}
+
+	fn get_payment_token(&self, token: ContractHash) -> Option<PaymentToken> {
+		self.get(&token.to_string())
+	}
+}
@@ -22,8 +22,8 @@ pub trait PriceOracle {
 	) -> ();
 
 	fn get_price_for(&self, extension: &str) -> Option<Price>;
-	fn set_price_for_simple_operations(&self, price: U512) -> ();
-	fn get_price_simple_operations(&self) -> Option<U512>;
+	fn set_price_for_simple_operations(&self, extension: &str, price: U512) -> ();
+	fn get_price_simple_operations(&self, extension: &str) -> Option<U512>;
 }
 
 impl PriceOracle for Dictionary {
@@ -71,11 +71,16 @@ impl PriceOracle for Dictionary {
 		self.get(extension)
 	}
 
-	fn set_price_for_simple_operations(&self, price: U512) -> () {
-		self.set(&PriceOracleKeys::SimpleOperations.to_string(), price)
+	/// Kept next to the price of the extension under `<extension>:simple_operations`
+	fn set_price_for_simple_operations(&self, extension: &str, price: U512) -> () {
+		self.set(&simple_operations_key(extension), price)
 	}
 
-	fn get_price_simple_operations(&self) -> Option<U512> {
-		self.get(&PriceOracleKeys::SimpleOperations.to_string())
+	fn get_price_simple_operations(&self, extension: &str) -> Option<U512> {
+		self.get(&simple_operations_key(extension))
 	}
 }
+
+fn simple_operations_key(extension: &str) -> String {
+	concat(extension, &PriceOracleKeys::SimpleOperations.to_string(), ":")
+}
@@ -0,0 +1,50 @@
+use alloc::{ string::{ String, ToString }, vec::Vec };
+use casper_contract::contract_api::runtime;
+use casper_types::{ runtime_args, RuntimeArgs };
+use common_lib::{
+	constants::common_keys::AdministrationEndpoints,
+	db::{ dictionary::Dictionary, traits::Storable },
+	models::price::Price,
+	utils::{ contract::get_administration_contract_hash, upgrade::run_migrations },
+};
+
+use crate::{
+	db::{ payment_tokens::PaymentTokens, price_oracle::PriceOracle },
+	types::PResult,
+};
+
+/// Schema version of the stored price tables
+pub const SCHEMA_VERSION: u32 = 3;
+
+/// Runs right after a new contract version is added to the package,
+/// a step moves price tables from `version` to `version + 1`
+/// This service is closed, only maintainer is passed
+/// Returns the schema version of the stored state
+pub fn migrate() -> PResult<u32> {
+	Ok(
+		run_migrations(SCHEMA_VERSION, |version| {
+			if version == 1 {
+				// Accepted CEP-18 payment tokens
+				Dictionary::payment_tokens_initialize();
+			}
+			if version == 2 {
+				rewrite_prices();
+			}
+		})
+	)
+}
+
+/// Prices were stored as `CLType::Any`, they are written back with their structural CLType.
+/// Prices are keyed by the extension, so the extensions are asked from the administration
+/// contract, the upgrading account has to be its maintainer
+fn rewrite_prices() {
+	let extensions: Vec<String> = runtime::call_contract(
+		get_administration_contract_hash(),
+		&AdministrationEndpoints::GetAllowedExtensions.to_string(),
+		runtime_args! {}
+	);
+	let prices = Dictionary::price_oracle_instance();
+	for extension in extensions {
+		prices.rewrite::<Price>(&extension);
+	}
+}
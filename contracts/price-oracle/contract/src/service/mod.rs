@@ -1 +1,3 @@
+pub mod migrate;
 pub mod price_oracle;
+pub mod payment_tokens;
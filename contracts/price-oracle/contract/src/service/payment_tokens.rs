@@ -0,0 +1,48 @@
+use alloc::string::ToString;
+use casper_contract::contract_api::runtime;
+use casper_types::{ ContractHash, Key, U512 };
+use common_lib::{
+	constants::common_keys::PriceOracleArgs,
+	db::dictionary::Dictionary,
+	errors::PriceOracleContractErrors,
+	models::payment_token::PaymentToken,
+};
+
+use crate::{ db::payment_tokens::PaymentTokens, types::PResult };
+
+/// Accepts the CEP-18 token, the rate is in token units per CSPR
+/// Setting an accepted token again replaces its rate and treasury
+pub fn set_payment_token() -> PResult<()> {
+	let token: ContractHash = runtime::get_named_arg(
+		&PriceOracleArgs::PaymentToken.to_string()
+	);
+	let rate: U512 = runtime::get_named_arg(&PriceOracleArgs::Rate.to_string());
+	let treasury: Key = runtime::get_named_arg(
+		&PriceOracleArgs::Treasury.to_string()
+	);
+	if rate.is_zero() {
+		return Err(PriceOracleContractErrors::PaymentTokenRateIsZero);
+	}
+
+	Dictionary::payment_tokens_instance().set_payment_token(token, PaymentToken {
+		rate,
+		treasury,
+	});
+	Ok(())
+}
+
+pub fn remove_payment_token() -> PResult<()> {
+	let token: ContractHash = runtime::get_named_arg(
+		&PriceOracleArgs::PaymentToken.to_string()
+	);
+	Dictionary::payment_tokens_instance().remove_payment_token(token);
+	Ok(())
+}
+
+/// None if the token is not accepted
+pub fn get_payment_token() -> PResult<Option<PaymentToken>> {
+	let token: ContractHash = runtime::get_named_arg(
+		&PriceOracleArgs::PaymentToken.to_string()
+	);
+	Ok(Dictionary::payment_tokens_instance().get_payment_token(token))
+}
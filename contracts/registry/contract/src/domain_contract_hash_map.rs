@@ -1,6 +1,6 @@
 use alloc::string::String;
 use common_lib::{
-    constants::KEY_REGISTRY_DOMAIN_CONTRACT_HASH,
+    constants::common_keys::KEY_REGISTRY_DOMAIN_CONTRACT_HASH,
     db::{dictionary::Dictionary, traits::Storable},
     models::registry_contract_hash_pair::RegistryContractHashPair,
 };
@@ -1,69 +1,25 @@
 #![no_std]
 #![no_main]
 
-// Contract Hash List per Extension
-// Domain <-> Contract Hash
-// Get Actual Contract Hash
+// Domain <-> (Database contract hash, NFT contract hash)
+// Single router of names, main and NFT contracts resolve routes here
 
-mod contract_operators_db;
 mod domain_contract_hash_map;
-mod max_value_db;
 mod operators_db;
-mod pointer_db;
 mod registry_whitelist_db;
 mod service;
+mod token_name_map;
 mod types;
 
 extern crate alloc;
 
-use alloc::{ string::String, collections::BTreeMap };
-use alloc::vec::{ self, Vec };
+use alloc::vec;
 
-use casper_contract::{
-	contract_api::runtime::{ self },
-	unwrap_or_revert::UnwrapOrRevert,
-};
-use casper_types::{ account::AccountHash, ContractHash, Key, KeyTag, Tagged };
-use common_lib::utils::response::controller;
+use casper_types::contracts::NamedKeys;
 use common_lib::{
-	constants::common_keys::{
-		ARG_REGISTRY_ATTR_KEY,
-		ARG_REGISTRY_CONTRACT_HASH,
-		ARG_REGISTRY_CONTRACT_HASH_LIST,
-		ARG_REGISTRY_CONTRACT_HASH_OPERATOR,
-		ARG_REGISTRY_CONTRACT_KIND,
-		ARG_REGISTRY_DATABASE_CONTRACT_HASH,
-		ARG_REGISTRY_DOMAIN_NAME,
-		ARG_REGISTRY_NFT_CONTRACT_HASH,
-		ARG_REGISTRY_OPERATOR,
-		ARG_REGISTRY_OPERATOR_TYPE,
-		KEY_REGISTRY_MAINTAINER,
-	},
-	enums::{
-		caller_verification_type::CallerVerificationType,
-		contracts_enum::ContractKind,
-		controller_roles::ControllerRoles,
-	},
-	errors::{ CommonError, RegistryErrors },
-	models::{
-		registry_contract_hash_list::RegistryContractHashList,
-		registry_contract_hash_pair::RegistryContractHashPair,
-		registry_pointer::CompoundContract,
-	},
-	utils::{
-		registry::get_verified_caller,
-		response::{ response_error, response_success },
-		storage::get_stored_value_from_key,
-	},
-};
-use contract_operators_db::ContractOperatorsDb;
-use max_value_db::MaxValueDb;
-use pointer_db::PointStore;
-use registry_whitelist_db::RegistryWhitelistStore;
-
-use crate::{
-	domain_contract_hash_map::DomainContractHashMap,
-	operators_db::OperatorsDb,
+	enums::controller_roles::ControllerRoles,
+	schema,
+	utils::{ contract::setup_contract_info, response::controller },
 };
 
 /**
@@ -72,21 +28,126 @@ use crate::{
  *  - DatabaseContract
  *  - NFTContract
  *
+ * Routes are written by the maintainer, registry operators and whitelisted contracts
  */
-
+#[no_mangle]
 pub extern "C" fn map_domain_name_to_contract_hash() {
 	controller(
 		service::map_domain_name_to_contract_hash::map_domain_name_to_contract_hash,
-		vec![ControllerRoles::OnlyAuthorizedContracts]
+		vec![]
 	);
 }
 
+/// Drops the route of a released name, the next registration writes it again
+#[no_mangle]
+pub extern "C" fn unmap_domain_name() {
+	controller(service::unmap_domain_name::unmap_domain_name, vec![]);
+}
+
+#[no_mangle]
 pub extern "C" fn get_contract_hash_for_domain_name() {
 	controller(
 		service::get_contract_hash_for_domain_name::get_contract_hash_for_domain_name,
-		vec![ControllerRoles::OnlyAuthorizedContracts]
+		vec![]
+	);
+}
+
+/// Repoints domain names to another database contract, nft contract stays the same
+#[no_mangle]
+pub extern "C" fn remap_domain_names() {
+	controller(service::remap_domain_names::remap_domain_names, vec![]);
+}
+
+#[no_mangle]
+pub extern "C" fn resolve_routes() {
+	controller(service::resolve_routes::resolve_routes, vec![]);
+}
+
+/// Whitelist
+#[no_mangle]
+pub extern "C" fn add_to_whitelist() {
+	controller(
+		service::whitelist::add_to_whitelist,
+		vec![ControllerRoles::OnlyMaintainer]
+	);
+}
+
+#[no_mangle]
+pub extern "C" fn remove_from_whitelist() {
+	controller(
+		service::whitelist::remove_from_whitelist,
+		vec![ControllerRoles::OnlyMaintainer]
+	);
+}
+
+#[no_mangle]
+pub extern "C" fn get_whitelist() {
+	controller(service::whitelist::get_whitelist, vec![]);
+}
+
+/// Operators
+#[no_mangle]
+pub extern "C" fn add_operator() {
+	controller(
+		service::operators::add_operator,
+		vec![ControllerRoles::OnlyMaintainer]
+	);
+}
+
+#[no_mangle]
+pub extern "C" fn remove_operator() {
+	controller(
+		service::operators::remove_operator,
+		vec![ControllerRoles::OnlyMaintainer]
+	);
+}
+
+#[no_mangle]
+pub extern "C" fn get_operators() {
+	controller(service::operators::get_operators, vec![]);
+}
+
+/// Name of the nft-core token, read by the transfer filter
+#[no_mangle]
+pub extern "C" fn get_name_for_token() {
+	controller(service::get_name_for_token::get_name_for_token, vec![]);
+}
+
+#[no_mangle]
+pub extern "C" fn init() {
+	controller(service::init::init, vec![ControllerRoles::OnlyMaintainer]);
+}
+
+#[no_mangle]
+pub extern "C" fn migrate() {
+	controller(
+		service::migrate::migrate,
+		vec![ControllerRoles::OnlyMaintainer]
 	);
 }
 
+/**
+ * Endpoints:
+ * 1. map_domain_name_to_contract_hash
+ * 2. unmap_domain_name
+ * 3. get_contract_hash_for_domain_name
+ * 4. remap_domain_names
+ * 5. resolve_routes
+ * 6. add_to_whitelist
+ * 7. remove_from_whitelist
+ * 8. get_whitelist
+ * 9. add_operator
+ * 10. remove_operator
+ * 11. get_operators
+ * 12. get_name_for_token
+ * 13. init
+ * 14. migrate
+ */
 #[no_mangle]
-pub extern "C" fn call() {}
+pub extern "C" fn call() {
+	let entrypoints = schema::registry::schema().to_entry_points();
+
+	let named_keys = NamedKeys::new();
+
+	setup_contract_info(entrypoints, named_keys)
+}
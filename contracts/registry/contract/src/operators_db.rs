@@ -1,7 +1,7 @@
 use alloc::vec::Vec;
 use casper_types::account::AccountHash;
 use common_lib::{
-    constants::KEY_REGISTRY_OPERATORS,
+    constants::common_keys::KEY_REGISTRY_OPERATORS,
     db::{store::Store, traits::Storable},
     errors::RegistryErrors,
     utils::response::response_error,
@@ -1,7 +1,7 @@
 use alloc::vec::Vec;
 use casper_types::ContractHash;
 use common_lib::{
-    constants::KEY_REGISTRY_WHITELIST_CONTRACT_HASH,
+    constants::common_keys::KEY_REGISTRY_WHITELIST_CONTRACT_HASH,
     db::{store::Store, traits::Storable},
 };
 
@@ -0,0 +1,35 @@
+use casper_types::{ ContractHash, Key };
+use common_lib::{
+	enums::caller_verification_type::CallerVerificationType,
+	errors::RegistryErrors,
+	utils::{ maintainer::is_caller_maintainer, registry::get_verified_caller },
+};
+
+use crate::{
+	operators_db::OperatorsDb,
+	registry_whitelist_db::RegistryWhitelistStore,
+	types::TResult,
+};
+
+/// Routes are written by the maintainer, registry operators (accounts) and whitelisted contracts
+pub(crate) fn ensure_route_writer() -> TResult<()> {
+	if is_caller_maintainer() {
+		return Ok(());
+	}
+	let caller = get_verified_caller(CallerVerificationType::All).map_err(
+		|_| RegistryErrors::InvalidCaller
+	)?;
+	let is_writer = match caller {
+		Key::Account(account) =>
+			OperatorsDb::instance().get_operators().contains(&account),
+		Key::Hash(hash) =>
+			RegistryWhitelistStore::instance()
+				.get_contract_hash_list()
+				.contains(&ContractHash::new(hash)),
+		_ => false,
+	};
+	if !is_writer {
+		return Err(RegistryErrors::InvalidCaller);
+	}
+	Ok(())
+}
@@ -0,0 +1,15 @@
+use alloc::string::{ String, ToString };
+use casper_contract::contract_api::runtime;
+use common_lib::constants::common_keys::RegistryArgs;
+
+use crate::{ token_name_map::TokenNameMap, types::TResult };
+
+/// Name of the nft-core token, names are indexed when their route is written
+/// Parameters:
+/// - token_id - required
+pub fn get_name_for_token() -> TResult<Option<String>> {
+	let token_id: String = runtime::get_named_arg(
+		&RegistryArgs::TokenId.to_string()
+	);
+	Ok(TokenNameMap::instance().get_name_for_token(&token_id))
+}
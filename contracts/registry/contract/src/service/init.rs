@@ -0,0 +1,11 @@
+use crate::{
+	domain_contract_hash_map::DomainContractHashMap,
+	token_name_map::TokenNameMap,
+	types::TResult,
+};
+
+pub fn init() -> TResult<()> {
+	DomainContractHashMap::initialize();
+	TokenNameMap::initialize();
+	Ok(())
+}
@@ -6,9 +6,16 @@ use common_lib::{
 	models::registry_contract_hash_pair::RegistryContractHashPair,
 };
 
-use crate::{ domain_contract_hash_map::DomainContractHashMap, types::TResult };
+use crate::{
+	domain_contract_hash_map::DomainContractHashMap,
+	service::access::ensure_route_writer,
+	token_name_map::TokenNameMap,
+	types::TResult,
+};
 
 pub fn map_domain_name_to_contract_hash() -> TResult<()> {
+	ensure_route_writer()?;
+
 	let domain_name: String = runtime::get_named_arg(
 		&RegistryArgs::DomainName.to_string()
 	);
@@ -28,5 +35,6 @@ pub fn map_domain_name_to_contract_hash() -> TResult<()> {
 		domain_name.clone(),
 		registry_object
 	);
+	TokenNameMap::instance().index_name(&domain_name);
 	Ok(())
 }
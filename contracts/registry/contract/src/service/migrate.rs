@@ -0,0 +1,21 @@
+use common_lib::utils::upgrade::run_migrations;
+
+use crate::{ token_name_map::TokenNameMap, types::TResult };
+
+/// Schema version of the route map, whitelist and operators
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Runs right after a new contract version is added to the package,
+/// every step moves the stored routes from `version` to `version + 1`
+/// This service is closed, only maintainer is passed
+/// 1 -> 2: token index of the names, filled by `remap_domain_names`
+/// Returns the schema version of the stored state
+pub fn migrate() -> TResult<u32> {
+	Ok(
+		run_migrations(SCHEMA_VERSION, |version| {
+			if version == 1 {
+				TokenNameMap::initialize_if_missing();
+			}
+		})
+	)
+}
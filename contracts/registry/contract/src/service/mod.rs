@@ -1,2 +1,11 @@
-pub mod map_domain_name_to_contract_hash;
+pub mod access;
 pub mod get_contract_hash_for_domain_name;
+pub mod get_name_for_token;
+pub mod init;
+pub mod map_domain_name_to_contract_hash;
+pub mod migrate;
+pub mod operators;
+pub mod remap_domain_names;
+pub mod resolve_routes;
+pub mod unmap_domain_name;
+pub mod whitelist;
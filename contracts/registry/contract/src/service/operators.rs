@@ -0,0 +1,39 @@
+use alloc::{ string::ToString, vec::Vec };
+use casper_contract::contract_api::runtime;
+use casper_types::account::AccountHash;
+use common_lib::{ constants::common_keys::RegistryArgs, errors::RegistryErrors };
+
+use crate::{ operators_db::OperatorsDb, types::TResult };
+
+/// Operators are accounts allowed to write routes
+/// Parameters:
+/// - operator - required
+pub fn add_operator() -> TResult<()> {
+	let operator: AccountHash = runtime::get_named_arg(
+		&RegistryArgs::Operator.to_string()
+	);
+	let operators_db = OperatorsDb::instance();
+	if operators_db.get_operators().contains(&operator) {
+		return Err(RegistryErrors::OperatorAlreadyExists);
+	}
+	operators_db.save_operator(operator);
+	Ok(())
+}
+
+/// Parameters:
+/// - operator - required
+pub fn remove_operator() -> TResult<()> {
+	let operator: AccountHash = runtime::get_named_arg(
+		&RegistryArgs::Operator.to_string()
+	);
+	let operators_db = OperatorsDb::instance();
+	if !operators_db.get_operators().contains(&operator) {
+		return Err(RegistryErrors::OperatorDoesntExist);
+	}
+	operators_db.remove_operator(operator);
+	Ok(())
+}
+
+pub fn get_operators() -> TResult<Vec<AccountHash>> {
+	Ok(OperatorsDb::instance().get_operators())
+}
@@ -0,0 +1,41 @@
+use alloc::{ string::{ String, ToString }, vec::Vec };
+use casper_contract::contract_api::runtime;
+use casper_types::ContractHash;
+use common_lib::constants::common_keys::RegistryArgs;
+
+use crate::{
+	domain_contract_hash_map::DomainContractHashMap,
+	service::access::ensure_route_writer,
+	token_name_map::TokenNameMap,
+	types::TResult,
+};
+
+/// Names routed before the token index existed are indexed here as well
+/// Parameters:
+/// - domain_names - required
+/// - database_contract_hash - required
+pub fn remap_domain_names() -> TResult<()> {
+	ensure_route_writer()?;
+
+	let domain_names: Vec<String> = runtime::get_named_arg(
+		&RegistryArgs::DomainNames.to_string()
+	);
+	let database_contract_hash: ContractHash = runtime::get_named_arg(
+		&RegistryArgs::DatabaseContractHash.to_string()
+	);
+
+	let domain_map = DomainContractHashMap::instance();
+	let token_map = TokenNameMap::instance();
+	for domain_name in domain_names {
+		if
+			let Some(mut pair) = domain_map.get_contract_hash_for_domain_name(
+				domain_name.clone()
+			)
+		{
+			pair.db_contract_hash = database_contract_hash;
+			token_map.index_name(&domain_name);
+			domain_map.map_domain_name_to_contract_hash(domain_name, pair);
+		}
+	}
+	Ok(())
+}
@@ -0,0 +1,29 @@
+use alloc::{ string::{ String, ToString }, vec::Vec };
+use casper_contract::contract_api::runtime;
+use common_lib::{
+	constants::common_keys::{ RegistryArgs, MAX_RESOLVE_ROUTES_SIZE },
+	errors::RegistryErrors,
+	models::registry_contract_hash_pair::RegistryContractHashPair,
+};
+
+use crate::{ domain_contract_hash_map::DomainContractHashMap, types::TResult };
+
+/// Bulk query for indexers, routes are returned in the order of the names
+/// Parameters:
+/// - domain_names - required
+pub fn resolve_routes() -> TResult<Vec<Option<RegistryContractHashPair>>> {
+	let domain_names: Vec<String> = runtime::get_named_arg(
+		&RegistryArgs::DomainNames.to_string()
+	);
+	if domain_names.len() > MAX_RESOLVE_ROUTES_SIZE {
+		return Err(RegistryErrors::TooManyRoutesRequested);
+	}
+
+	let domain_map = DomainContractHashMap::instance();
+	Ok(
+		domain_names
+			.into_iter()
+			.map(|name| domain_map.get_contract_hash_for_domain_name(name))
+			.collect()
+	)
+}
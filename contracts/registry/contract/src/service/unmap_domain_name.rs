@@ -0,0 +1,29 @@
+use alloc::string::{ String, ToString };
+use casper_contract::contract_api::runtime;
+use common_lib::{ constants::common_keys::RegistryArgs, errors::RegistryErrors };
+
+use crate::{
+	domain_contract_hash_map::DomainContractHashMap,
+	service::access::ensure_route_writer,
+	token_name_map::TokenNameMap,
+	types::TResult,
+};
+
+/// Route and the token index of the name are removed together
+/// Parameters:
+/// - domain_name - required
+pub fn unmap_domain_name() -> TResult<()> {
+	ensure_route_writer()?;
+
+	let domain_name: String = runtime::get_named_arg(
+		&RegistryArgs::DomainName.to_string()
+	);
+
+	let domain_map = DomainContractHashMap::instance();
+	if domain_map.get_contract_hash_for_domain_name(domain_name.clone()).is_none() {
+		return Err(RegistryErrors::RegistryObjectNotFound);
+	}
+	domain_map.remove_domain_name_map(domain_name.clone());
+	TokenNameMap::instance().remove_name(&domain_name);
+	Ok(())
+}
@@ -0,0 +1,31 @@
+use alloc::{ string::ToString, vec::Vec };
+use casper_contract::contract_api::runtime;
+use casper_types::ContractHash;
+use common_lib::constants::common_keys::RegistryArgs;
+
+use crate::{ registry_whitelist_db::RegistryWhitelistStore, types::TResult };
+
+/// Whitelisted contracts are allowed to write routes (main and administration contracts)
+/// Parameters:
+/// - contract_hash - required
+pub fn add_to_whitelist() -> TResult<()> {
+	let contract_hash: ContractHash = runtime::get_named_arg(
+		&RegistryArgs::ContractHash.to_string()
+	);
+	RegistryWhitelistStore::instance().add_contract_hash(contract_hash);
+	Ok(())
+}
+
+/// Parameters:
+/// - contract_hash - required
+pub fn remove_from_whitelist() -> TResult<()> {
+	let contract_hash: ContractHash = runtime::get_named_arg(
+		&RegistryArgs::ContractHash.to_string()
+	);
+	RegistryWhitelistStore::instance().remove_contract_hash(contract_hash);
+	Ok(())
+}
+
+pub fn get_whitelist() -> TResult<Vec<ContractHash>> {
+	Ok(RegistryWhitelistStore::instance().get_contract_hash_list())
+}
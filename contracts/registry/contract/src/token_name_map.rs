@@ -0,0 +1,42 @@
+use alloc::string::String;
+use casper_contract::contract_api::runtime;
+use common_lib::{
+    constants::common_keys::KEY_REGISTRY_TOKEN_NAME,
+    db::{dictionary::Dictionary, traits::Storable},
+    utils::domain_name::get_token_id,
+};
+
+/// Token id <-> name, nft-core passes only the token to the transfer filter
+pub(crate) struct TokenNameMap {
+    dictionary: Dictionary,
+}
+
+impl TokenNameMap {
+    pub fn instance() -> Self {
+        Self {
+            dictionary: Dictionary::instance(KEY_REGISTRY_TOKEN_NAME),
+        }
+    }
+    pub fn initialize() {
+        Dictionary::init(KEY_REGISTRY_TOKEN_NAME)
+    }
+
+    /// Map was added with schema version 2, contracts installed before keep it missing until migrated
+    pub fn initialize_if_missing() {
+        if !runtime::has_key(KEY_REGISTRY_TOKEN_NAME) {
+            Self::initialize()
+        }
+    }
+
+    pub fn index_name(&self, name: &str) {
+        self.dictionary.set(&get_token_id(name), String::from(name))
+    }
+
+    pub fn remove_name(&self, name: &str) {
+        self.dictionary.remove::<String>(&get_token_id(name))
+    }
+
+    pub fn get_name_for_token(&self, token_id: &str) -> Option<String> {
+        self.dictionary.get(token_id)
+    }
+}
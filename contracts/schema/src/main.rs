@@ -0,0 +1,111 @@
+//! Writes `documentation/schema/<contract>.json` from the entry point declarations
+//! of `common_lib::schema`, the SDKs and the front-end are generated from these files.
+//!
+//! Usage: `cargo run -p cspr-names-schema [output_dir]`
+
+use std::{ env, fs, path::PathBuf };
+
+use casper_types::CLType;
+use common_lib::schema::{
+	self,
+	ArgSchema,
+	ContractSchema,
+	EntryPointSchema,
+};
+use serde_json::{ json, Value };
+
+fn main() {
+	let output_dir = env
+		::args()
+		.nth(1)
+		.map(PathBuf::from)
+		.unwrap_or_else(|| {
+			PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../documentation/schema")
+		});
+	fs::create_dir_all(&output_dir).expect("Error while creating the output dir");
+
+	let schemas = vec![
+		schema::administration::schema(),
+		schema::database::schema(),
+		schema::main::schema(),
+		schema::nft::schema(),
+		schema::price_oracle::schema(),
+		schema::registry::schema(),
+		schema::transfer_filter::schema(),
+		schema::treasury::schema()
+	];
+
+	for contract_schema in schemas {
+		let path = output_dir.join(format!("{}.json", contract_schema.contract));
+		let content = serde_json
+			::to_string_pretty(&contract_json(contract_schema))
+			.expect("Error while serializing the schema");
+		fs::write(&path, content + "\n").expect("Error while writing the schema");
+		println!("{}", path.display());
+	}
+}
+
+fn contract_json(contract_schema: ContractSchema) -> Value {
+	let entry_points = contract_schema.entry_points
+		.iter()
+		.map(entry_point_json)
+		.collect::<Vec<Value>>();
+
+	let events = contract_schema.events.0
+		.into_iter()
+		.map(|(name, event_schema)| {
+			let fields = event_schema
+				.to_vec()
+				.into_iter()
+				.map(|(field, cl_type)| {
+					json!({ "name": field, "cl_type": cl_type_json(&cl_type.0) })
+				})
+				.collect::<Vec<Value>>();
+			json!({ "name": name, "fields": fields })
+		})
+		.collect::<Vec<Value>>();
+
+	let errors = contract_schema.errors
+		.iter()
+		.flat_map(|table| table.iter())
+		.map(|entry| {
+			json!({
+				"code": entry.code,
+				"contract": entry.contract,
+				"variant": entry.variant,
+				"message": entry.message,
+			})
+		})
+		.collect::<Vec<Value>>();
+
+	json!({
+		"contract": contract_schema.contract,
+		"entry_points": entry_points,
+		"events": events,
+		"errors": errors,
+	})
+}
+
+fn entry_point_json(entry_point: &EntryPointSchema) -> Value {
+	json!({
+		"name": entry_point.name,
+		"args": entry_point.args.iter().map(arg_json).collect::<Vec<Value>>(),
+		"ret": cl_type_json(&entry_point.ret),
+		"access": entry_point.access
+			.iter()
+			.map(|access| access.to_string())
+			.collect::<Vec<String>>(),
+	})
+}
+
+fn arg_json(arg: &ArgSchema) -> Value {
+	json!({
+		"name": arg.name,
+		"cl_type": cl_type_json(&arg.cl_type),
+		"optional": arg.optional,
+	})
+}
+
+fn cl_type_json(cl_type: &CLType) -> Value {
+	serde_json::to_value(cl_type).expect("Error while serializing the CLType")
+}
@@ -1,15 +1,31 @@
-use casper_types::{ runtime_args, RuntimeArgs, Key, account::AccountHash };
+use std::path::PathBuf;
+
+use casper_types::{
+	runtime_args,
+	RuntimeArgs,
+	Key,
+	account::AccountHash,
+	ContractHash,
+	ContractPackageHash,
+};
 use common_lib::{
 	constants::common_keys::{
 		AdministrationEndpoints,
 		AdministrationArgs,
 		AdministractionStoreKeys,
+		CommonArgs,
 		CommonKeys,
 		CommonEndpoints,
+		KEY_CONTRACT_PACKAGE_NAME,
 	},
-	enums::contracts_enum::ContractKind,
+	enums::{ contracts_enum::ContractKind, roles::Role },
 	utils::helpers::to_domain_list_limit_key,
-	models::registry_pointer::CompoundContract,
+	models::{
+		confiugration::ExtensionConfig,
+		registry_pointer::CompoundContract,
+		proposal::{ Proposal, ProposalAction },
+		topology::TopologyIssue,
+	},
 };
 
 use crate::utils::{
@@ -100,6 +116,29 @@ impl UnitTestContext {
 		)
 	}
 
+	pub fn add_contract_authority(
+		&mut self,
+		signer: &AccountHash,
+		contract_hash: ContractHash,
+		authority: Key,
+		success: bool
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: AdministrationEndpoints::AddContractAuthority.to_string(),
+			}),
+			runtime_args! {
+				AdministrationArgs::ContractHash.to_string() => contract_hash,
+				AdministrationArgs::ContractAuthority.to_string() => authority,
+			},
+			success,
+			None
+		)
+	}
+
 	pub fn get_authorities(&self) -> Vec<Key> {
 		query::<Vec<Key>>(
 			&self.builder,
@@ -112,15 +151,19 @@ impl UnitTestContext {
 /// Limits
 impl UnitTestContext {
 	pub fn get_min_chars_count(&self, extension: String) -> u8 {
-		let key = format!(
-			"{}:{}",
-			extension,
-			AdministractionStoreKeys::CharsCount.to_string()
-		);
-		query(&self.builder, self.contract_hash.into(), &[key])
+		self.get_extension_config(extension).min_chars_count
 	}
 
 	pub fn set_min_chars_count(&mut self, extension: String, count: u8) {
+		self.set_min_chars_count_with_result(extension, count, true)
+	}
+
+	pub fn set_min_chars_count_with_result(
+		&mut self,
+		extension: String,
+		count: u8,
+		success: bool
+	) {
 		let args =
 			runtime_args! {
 			"chars_count" => count,
@@ -135,7 +178,7 @@ impl UnitTestContext {
 				entry_point: AdministrationEndpoints::SetCharsMinCount.to_string(),
 			}),
 			args,
-			true,
+			success,
 			None
 		)
 	}
@@ -146,6 +189,15 @@ impl UnitTestContext {
 	}
 
 	pub fn set_listing_limit(&mut self, kind: ContractKind, count: u32) {
+		self.set_listing_limit_with_result(kind, count, true)
+	}
+
+	pub fn set_listing_limit_with_result(
+		&mut self,
+		kind: ContractKind,
+		count: u32,
+		success: bool
+	) {
 		deploy(
 			&mut self.builder,
 			&self.maintainer,
@@ -157,7 +209,7 @@ impl UnitTestContext {
 				"chars_count" => count,
 				"contract_kind" => kind,
 			},
-			true,
+			success,
 			None
 		)
 	}
@@ -282,3 +334,584 @@ impl UnitTestContext {
 		)
 	}
 }
+
+/// Timelock
+impl UnitTestContext {
+	pub fn init(&mut self) {
+		deploy(
+			&mut self.builder,
+			&self.maintainer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: AdministrationEndpoints::Init.to_string(),
+			}),
+			runtime_args! {},
+			true,
+			None
+		)
+	}
+
+	pub fn set_min_delay(&mut self, delay: u64, success: bool) {
+		deploy(
+			&mut self.builder,
+			&self.maintainer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: AdministrationEndpoints::SetMinDelay.to_string(),
+			}),
+			runtime_args! {
+				"min_delay" => delay,
+			},
+			success,
+			None
+		)
+	}
+
+	pub fn get_min_delay(&self) -> u64 {
+		query(
+			&self.builder,
+			self.contract_hash.into(),
+			&[AdministractionStoreKeys::MinDelay.to_string()]
+		)
+	}
+
+	pub fn queue_proposal(
+		&mut self,
+		signer: &AccountHash,
+		action: ProposalAction,
+		success: bool,
+		block_time: u64
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: AdministrationEndpoints::QueueProposal.to_string(),
+			}),
+			runtime_args! {
+				"proposal_action" => action,
+			},
+			success,
+			Some(block_time)
+		)
+	}
+
+	pub fn cancel_proposal(&mut self, id: u64, success: bool) {
+		let maintainer = self.maintainer;
+		self.cancel_proposal_by(&maintainer, id, success)
+	}
+
+	pub fn cancel_proposal_by(&mut self, signer: &AccountHash, id: u64, success: bool) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: AdministrationEndpoints::CancelProposal.to_string(),
+			}),
+			runtime_args! {
+				"proposal_id" => id,
+			},
+			success,
+			None
+		)
+	}
+
+	pub fn execute_proposal(
+		&mut self,
+		signer: &AccountHash,
+		id: u64,
+		success: bool,
+		block_time: u64
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: AdministrationEndpoints::ExecuteProposal.to_string(),
+			}),
+			runtime_args! {
+				"proposal_id" => id,
+			},
+			success,
+			Some(block_time)
+		)
+	}
+
+	pub fn get_proposal(&self, id: u64) -> Proposal {
+		let uref = query_uref(
+			&self.builder,
+			self.contract_hash.into(),
+			&[],
+			&AdministractionStoreKeys::Proposals.to_string()
+		);
+		// Dictionary items are kept as options
+		query_dictionary::<Option<Proposal>>(
+			&self.builder,
+			uref,
+			&id.to_string()
+		).expect("proposal must be queued")
+	}
+}
+
+/// Multisig
+impl UnitTestContext {
+	pub fn set_signers(&mut self, signers: Vec<Key>, threshold: u8, success: bool) {
+		deploy(
+			&mut self.builder,
+			&self.maintainer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: AdministrationEndpoints::SetSigners.to_string(),
+			}),
+			runtime_args! {
+				"signers" => signers,
+				"threshold" => threshold,
+			},
+			success,
+			None
+		)
+	}
+
+	pub fn approve_proposal(
+		&mut self,
+		signer: &AccountHash,
+		id: u64,
+		success: bool,
+		block_time: u64
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: AdministrationEndpoints::ApproveProposal.to_string(),
+			}),
+			runtime_args! {
+				"proposal_id" => id,
+			},
+			success,
+			Some(block_time)
+		)
+	}
+
+	pub fn revoke_approval(&mut self, signer: &AccountHash, id: u64, success: bool) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: AdministrationEndpoints::RevokeApproval.to_string(),
+			}),
+			runtime_args! {
+				"proposal_id" => id,
+			},
+			success,
+			None
+		)
+	}
+
+	pub fn get_threshold(&self) -> u8 {
+		query(
+			&self.builder,
+			self.contract_hash.into(),
+			&[AdministractionStoreKeys::Threshold.to_string()]
+		)
+	}
+}
+
+/// Roles
+impl UnitTestContext {
+	pub fn grant_role(&mut self, role: Role, account: AccountHash) {
+		let maintainer = self.maintainer;
+		self.grant_role_by(&maintainer, role, account, true)
+	}
+
+	pub fn grant_role_by(
+		&mut self,
+		signer: &AccountHash,
+		role: Role,
+		account: AccountHash,
+		success: bool
+	) {
+		self.grant_role_to_key(signer, role, Key::Account(account), success)
+	}
+
+	/// Contracts hold roles by their hash key
+	pub fn grant_role_to_key(
+		&mut self,
+		signer: &AccountHash,
+		role: Role,
+		account: Key,
+		success: bool
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: AdministrationEndpoints::GrantRole.to_string(),
+			}),
+			runtime_args! {
+				"role" => role,
+				"account" => account,
+			},
+			success,
+			None
+		)
+	}
+
+	pub fn renounce_role(&mut self, signer: &AccountHash, role: Role) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: AdministrationEndpoints::RenounceRole.to_string(),
+			}),
+			runtime_args! {
+				"role" => role,
+			},
+			true,
+			None
+		)
+	}
+
+	pub fn get_role_members(&self, role: Role) -> Vec<Key> {
+		query(
+			&self.builder,
+			self.contract_hash.into(),
+			&[format!("{}:{}", CommonKeys::Roles, role)]
+		)
+	}
+}
+
+/// Pause
+impl UnitTestContext {
+	pub fn pause(
+		&mut self,
+		signer: &AccountHash,
+		contract_kind: Option<ContractKind>,
+		extension: Option<String>,
+		success: bool
+	) {
+		self.set_paused(
+			signer,
+			AdministrationEndpoints::Pause,
+			contract_kind,
+			extension,
+			success
+		)
+	}
+
+	pub fn unpause(
+		&mut self,
+		signer: &AccountHash,
+		contract_kind: Option<ContractKind>,
+		extension: Option<String>,
+		success: bool
+	) {
+		self.set_paused(
+			signer,
+			AdministrationEndpoints::Unpause,
+			contract_kind,
+			extension,
+			success
+		)
+	}
+
+	/// scope - "global", contract kind or "ext:<extension>"
+	pub fn is_scope_paused(&self, scope: &str) -> bool {
+		query(
+			&self.builder,
+			self.contract_hash.into(),
+			&[format!("{}:{}", CommonKeys::Paused, scope)]
+		)
+	}
+
+	fn set_paused(
+		&mut self,
+		signer: &AccountHash,
+		endpoint: AdministrationEndpoints,
+		contract_kind: Option<ContractKind>,
+		extension: Option<String>,
+		success: bool
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: endpoint.to_string(),
+			}),
+			runtime_args! {
+				"contract_kind" => contract_kind,
+				"extension" => extension,
+			},
+			success,
+			None
+		)
+	}
+}
+
+/// Extension Config
+impl UnitTestContext {
+	pub fn get_extension_config(&self, extension: String) -> ExtensionConfig {
+		query(
+			&self.builder,
+			self.contract_hash.into(),
+			&[
+				format!(
+					"{}:{}",
+					AdministractionStoreKeys::ExtensionConfig,
+					extension
+				),
+			]
+		)
+	}
+
+	pub fn set_extension_config_field(
+		&mut self,
+		signer: &AccountHash,
+		endpoint: AdministrationEndpoints,
+		args: RuntimeArgs,
+		success: bool
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: endpoint.to_string(),
+			}),
+			args,
+			success,
+			None
+		)
+	}
+
+	pub fn add_to_allowlist(&mut self, extension: String, account: AccountHash) {
+		let maintainer = self.maintainer;
+		self.set_extension_config_field(
+			&maintainer,
+			AdministrationEndpoints::AddToAllowlist,
+			runtime_args! {
+				"extension" => Some(extension),
+				"account" => Key::Account(account),
+			},
+			true
+		)
+	}
+
+	pub fn get_registration_allowlist(&self, extension: String) -> Vec<Key> {
+		query(
+			&self.builder,
+			self.contract_hash.into(),
+			&[
+				format!(
+					"{}:{}",
+					AdministractionStoreKeys::RegistrationAllowlist,
+					extension
+				),
+			]
+		)
+	}
+}
+
+/// Provisioning
+impl UnitTestContext {
+	pub fn add_standby_contract(
+		&mut self,
+		kind: ContractKind,
+		key: Key,
+		extension: String
+	) {
+		deploy(
+			&mut self.builder,
+			&self.maintainer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: AdministrationEndpoints::AddStandbyContract.to_string(),
+			}),
+			runtime_args! {
+				"contract_kind" => kind,
+				"extension" => Some(extension),
+				"key" => key,
+			},
+			true,
+			None
+		)
+	}
+
+	pub fn provision_contract(
+		&mut self,
+		signer: &AccountHash,
+		kind: ContractKind,
+		extension: String,
+		success: bool
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: AdministrationEndpoints::ProvisionContract.to_string(),
+			}),
+			runtime_args! {
+				"contract_kind" => kind,
+				"extension" => Some(extension),
+			},
+			success,
+			None
+		)
+	}
+
+	pub fn get_compound_contracts(
+		&self,
+		kind: ContractKind,
+		extension: String
+	) -> Vec<CompoundContract> {
+		query(
+			&self.builder,
+			self.contract_hash.into(),
+			&[format!("{}:{}", kind, extension)]
+		)
+	}
+
+	pub fn get_standby_contracts(
+		&self,
+		kind: ContractKind,
+		extension: String
+	) -> Vec<Key> {
+		query(
+			&self.builder,
+			self.contract_hash.into(),
+			&[
+				format!(
+					"{}:{}:{}",
+					AdministractionStoreKeys::Standby,
+					kind,
+					extension
+				),
+			]
+		)
+	}
+
+	/// Domain migration
+	pub fn migrate_domains(
+		&mut self,
+		signer: &AccountHash,
+		from: Key,
+		to: Key,
+		extension: String,
+		domain_names: Vec<String>,
+		success: bool
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: AdministrationEndpoints::MigrateDomains.to_string(),
+			}),
+			runtime_args! {
+				AdministrationArgs::From.to_string() => from,
+				AdministrationArgs::To.to_string() => to,
+				AdministrationArgs::Extension.to_string() => Some(extension),
+				AdministrationArgs::DomainNames.to_string() => domain_names,
+			},
+			success,
+			None
+		)
+	}
+}
+
+/// Contract upgrades
+impl UnitTestContext {
+	pub fn contract_upgraded(
+		&mut self,
+		signer: &AccountHash,
+		previous_key: Key,
+		key: Key,
+		success: bool
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: AdministrationEndpoints::ContractUpgraded.to_string(),
+			}),
+			runtime_args! {
+				AdministrationArgs::PreviousKey.to_string() => previous_key,
+				AdministrationArgs::Key.to_string() => key,
+			},
+			success,
+			None
+		)
+	}
+
+	/// Adds the contract version built from the wasm to the package installed by the maintainer
+	pub fn upgrade_contract(&mut self, contract_path: &str) {
+		let maintainer = self.maintainer;
+		let package_hash = self.builder
+			.get_expected_account(maintainer)
+			.named_keys()
+			.get(KEY_CONTRACT_PACKAGE_NAME)
+			.and_then(|key| key.into_hash())
+			.map(ContractPackageHash::new)
+			.expect("must have contract package hash");
+		deploy(
+			&mut self.builder,
+			&maintainer,
+			&crate::utils::DeploySource::Code(PathBuf::from(contract_path)),
+			runtime_args! {
+				CommonArgs::PackageHash.to_string() => package_hash,
+			},
+			true,
+			None
+		);
+		self.contract_hash = query(
+			&self.builder,
+			Key::Account(maintainer),
+			&[CommonKeys::ContractHash.to_string()]
+		);
+	}
+
+	pub fn get_schema_version(&self) -> u32 {
+		query(
+			&self.builder,
+			self.contract_hash.into(),
+			&[CommonKeys::SchemaVersion.to_string()]
+		)
+	}
+}
+
+/// Topology
+impl UnitTestContext {
+	pub fn verify_topology(&mut self, signer: &AccountHash, success: bool) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: AdministrationEndpoints::VerifyTopology.to_string(),
+			}),
+			runtime_args! {},
+			success,
+			None
+		)
+	}
+
+	/// Issues found by `verify_topology`, empty when the topology is consistent
+	pub fn get_topology_issues(&mut self) -> Vec<TopologyIssue> {
+		let administration = self.contract_hash;
+		self.call_and_read(
+			administration,
+			&AdministrationEndpoints::VerifyTopology.to_string(),
+			runtime_args! {}
+		)
+	}
+}
@@ -0,0 +1,145 @@
+use std::path::PathBuf;
+
+use casper_types::{
+	bytesrepr::ToBytes,
+	crypto::blake2b,
+	runtime_args,
+	account::AccountHash,
+	ContractHash,
+	Key,
+	RuntimeArgs,
+	U256,
+};
+use common_lib::constants::common_keys::{ Cep18Args, Cep18Endpoints };
+
+use crate::utils::{ UnitTestContext, DeploySource, deploy, query, query_uref, query_dictionary };
+
+const CEP18_CONTRACT_NAME: &str = "cep18_test_contract.wasm";
+const KEY_CEP18_CONTRACT_HASH: &str = "cep18_test_contract_hash";
+/// Free mint of the test token, not a CEP-18 entry point
+const ENDPOINT_CEP18_MINT: &str = "mint";
+const KEY_CEP18_BALANCES: &str = "balances";
+const KEY_CEP18_ALLOWANCES: &str = "allowances";
+
+/// Dictionary item key the test token uses for the address
+fn cep18_dictionary_key(bytes: &[u8]) -> String {
+	hex::encode(blake2b(bytes))
+}
+
+/// Balances and allowances of the test CEP-18 token
+impl UnitTestContext {
+	/// Token next to the contract of the context, contracts are its spenders by their package hash
+	pub fn install_cep18(&mut self) -> ContractHash {
+		let maintainer = self.maintainer;
+		deploy(
+			&mut self.builder,
+			&maintainer,
+			&DeploySource::Code(PathBuf::from(CEP18_CONTRACT_NAME)),
+			runtime_args! {},
+			true,
+			None
+		);
+		query(&self.builder, Key::Account(maintainer), &[KEY_CEP18_CONTRACT_HASH.to_string()])
+	}
+
+	pub fn mint_cep18(&mut self, token: ContractHash, owner: Key, amount: U256) {
+		let maintainer = self.maintainer;
+		deploy(
+			&mut self.builder,
+			&maintainer,
+			&(DeploySource::ByContractHash {
+				hash: token,
+				entry_point: ENDPOINT_CEP18_MINT.to_string(),
+			}),
+			runtime_args! {
+				Cep18Args::Owner.to_string() => owner,
+				Cep18Args::Amount.to_string() => amount,
+			},
+			true,
+			None
+		)
+	}
+
+	pub fn approve_cep18(
+		&mut self,
+		token: ContractHash,
+		signer: &AccountHash,
+		spender: Key,
+		amount: U256
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(DeploySource::ByContractHash {
+				hash: token,
+				entry_point: Cep18Endpoints::Approve.to_string(),
+			}),
+			runtime_args! {
+				Cep18Args::Spender.to_string() => spender,
+				Cep18Args::Amount.to_string() => amount,
+			},
+			true,
+			None
+		)
+	}
+
+	pub fn transfer_from_cep18(
+		&mut self,
+		token: ContractHash,
+		signer: &AccountHash,
+		owner: Key,
+		recipient: Key,
+		amount: U256,
+		success: bool
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(DeploySource::ByContractHash {
+				hash: token,
+				entry_point: Cep18Endpoints::TransferFrom.to_string(),
+			}),
+			runtime_args! {
+				Cep18Args::Owner.to_string() => owner,
+				Cep18Args::Recipient.to_string() => recipient,
+				Cep18Args::Amount.to_string() => amount,
+			},
+			success,
+			None
+		)
+	}
+
+	pub fn get_cep18_balance(&self, token: ContractHash, owner: Key) -> U256 {
+		let dictionary = query_uref(
+			&self.builder,
+			token.into(),
+			&[],
+			KEY_CEP18_BALANCES
+		);
+		query_dictionary(
+			&self.builder,
+			dictionary,
+			&cep18_dictionary_key(&owner.to_bytes().unwrap())
+		)
+	}
+
+	pub fn get_cep18_allowance(&self, token: ContractHash, owner: Key, spender: Key) -> U256 {
+		let dictionary = query_uref(
+			&self.builder,
+			token.into(),
+			&[],
+			KEY_CEP18_ALLOWANCES
+		);
+		let mut bytes = owner.to_bytes().unwrap();
+		bytes.append(&mut spender.to_bytes().unwrap());
+		query_dictionary(&self.builder, dictionary, &cep18_dictionary_key(&bytes))
+	}
+
+	/// Spender key the test token knows the contract by
+	pub fn get_package_key(&self, contract_hash: ContractHash) -> Key {
+		let contract = self.builder
+			.get_contract(contract_hash)
+			.expect("must have the contract");
+		Key::from(contract.contract_package_hash())
+	}
+}
@@ -0,0 +1,218 @@
+use casper_types::{ account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs };
+use common_lib::{
+	constants::common_keys::{
+		CommonArgs,
+		DatabaseArgs,
+		DatabaseEndpoints,
+		RegistryArgs,
+		RegistryEndpoints,
+		KEY_REGISTRY_DOMAIN_CONTRACT_HASH,
+		YEAR_IN_MILLIS,
+	},
+	enums::{ contracts_enum::ContractKind, transfer_lock::TransferLock },
+	models::{
+		domain_name::DomainName,
+		registry_contract_hash_pair::RegistryContractHashPair,
+		subdomain_name::SubdomainName,
+		transfer_restrictions::TransferRestrictions,
+	},
+};
+
+use crate::{
+	context::nft::{ get_token_id, NftSystem },
+	utils::{ UnitTestContext, query_dictionary, query_uref },
+};
+
+const DATABASE_CONTRACT_NAME: &str = "database-contract.wasm";
+
+/// Database shards of the nft system, its main contract stores the names
+impl UnitTestContext {
+	/// Installs and initializes a database shard of the extension and adds it to the directory,
+	/// the administration contract is whitelisted in the registry to remap the migrated names
+	pub fn install_database_shard(
+		&mut self,
+		system: &NftSystem,
+		extension: &str
+	) -> ContractHash {
+		let administration = self.contract_hash;
+		let shard = self.install_contract(
+			DATABASE_CONTRACT_NAME,
+			runtime_args! {
+				CommonArgs::AdministrationContract.to_string() => administration,
+			}
+		);
+		self.call_by_maintainer(
+			shard,
+			&DatabaseEndpoints::Init.to_string(),
+			runtime_args! {}
+		);
+		self.add_contract(
+			ContractKind::Database,
+			shard.into(),
+			Some(extension.to_string())
+		);
+		self.call_by_maintainer(
+			system.registry,
+			&RegistryEndpoints::AddToWhitelist.to_string(),
+			runtime_args! {
+				RegistryArgs::ContractHash.to_string() => administration,
+			}
+		);
+		self.fund_maintainer();
+		shard
+	}
+
+	pub fn save_domain_name(
+		&mut self,
+		system: &NftSystem,
+		shard: ContractHash,
+		domain_name: &str,
+		owner: AccountHash
+	) {
+		let domain = DomainName {
+			end_time: YEAR_IN_MILLIS,
+			name: domain_name.to_string(),
+			token_id: get_token_id(domain_name),
+			owner: Key::Account(owner),
+			resolver: owner,
+		};
+		self.call_as_main(
+			system,
+			shard,
+			&DatabaseEndpoints::SaveDomainName.to_string(),
+			runtime_args! {
+				DatabaseArgs::DomainName.to_string() => domain,
+			},
+			true
+		)
+	}
+
+	/// Mints the token of the name and stores the name in the shard it is routed to
+	pub fn mint_stored_name(
+		&mut self,
+		system: &NftSystem,
+		shard: ContractHash,
+		name: &str,
+		owner: AccountHash
+	) {
+		self.mint_name(system, name, owner, YEAR_IN_MILLIS);
+		self.map_database_route(system, shard, name);
+		self.save_domain_name(system, shard, name, owner);
+	}
+
+	/// Routes the name to the shard and the nft-core contract of the system,
+	/// the registry indexes the token of the name for the transfer filter
+	pub fn map_database_route(
+		&mut self,
+		system: &NftSystem,
+		shard: ContractHash,
+		name: &str
+	) {
+		self.call_by_maintainer(
+			system.registry,
+			&RegistryEndpoints::MapDomainNameToContractHash.to_string(),
+			runtime_args! {
+				RegistryArgs::DomainName.to_string() => name.to_string(),
+				RegistryArgs::DatabaseContractHash.to_string() => shard,
+				RegistryArgs::NftContractHash.to_string() => system.nft_core,
+			}
+		)
+	}
+
+	pub fn save_subdomain_name(
+		&mut self,
+		system: &NftSystem,
+		shard: ContractHash,
+		domain_name: &str,
+		subdomain_name: &str,
+		resolver: AccountHash
+	) {
+		let subdomain = SubdomainName {
+			name: subdomain_name.to_string(),
+			resolver,
+		};
+		self.call_as_main(
+			system,
+			shard,
+			&DatabaseEndpoints::SaveSubdomainName.to_string(),
+			runtime_args! {
+				DatabaseArgs::DomainName.to_string() => domain_name.to_string(),
+				DatabaseArgs::SubdomainName.to_string() => subdomain,
+			},
+			true
+		)
+	}
+
+	pub fn set_transfer_lock(
+		&mut self,
+		system: &NftSystem,
+		shard: ContractHash,
+		name: &str,
+		lock: Option<TransferLock>,
+		success: bool
+	) {
+		self.call_as_main(
+			system,
+			shard,
+			&DatabaseEndpoints::SetTransferLock.to_string(),
+			runtime_args! {
+				DatabaseArgs::Name.to_string() => name.to_string(),
+				DatabaseArgs::TransferLock.to_string() => lock,
+			},
+			success
+		)
+	}
+
+	pub fn burn_transfer_permission(
+		&mut self,
+		system: &NftSystem,
+		shard: ContractHash,
+		name: &str,
+		success: bool
+	) {
+		self.call_as_main(
+			system,
+			shard,
+			&DatabaseEndpoints::BurnTransferPermission.to_string(),
+			runtime_args! {
+				DatabaseArgs::Name.to_string() => name.to_string(),
+			},
+			success
+		)
+	}
+
+	pub fn get_transfer_restrictions(
+		&mut self,
+		shard: ContractHash,
+		name: &str
+	) -> TransferRestrictions {
+		self.call_and_read(
+			shard,
+			&DatabaseEndpoints::GetTransferRestrictions.to_string(),
+			runtime_args! {
+				DatabaseArgs::Name.to_string() => name.to_string(),
+			}
+		)
+	}
+
+	/// Database shard the registry routes the name to
+	pub fn get_database_route(
+		&self,
+		system: &NftSystem,
+		domain_name: &str
+	) -> ContractHash {
+		let uref = query_uref(
+			&self.builder,
+			system.registry.into(),
+			&[],
+			KEY_REGISTRY_DOMAIN_CONTRACT_HASH
+		);
+		query_dictionary::<Option<RegistryContractHashPair>>(
+			&self.builder,
+			uref,
+			domain_name
+		)
+			.expect("route must be mapped")
+			.db_contract_hash
+	}
+}
@@ -0,0 +1,324 @@
+use std::path::PathBuf;
+
+use casper_types::{
+	account::AccountHash,
+	runtime_args,
+	ContractHash,
+	Key,
+	RuntimeArgs,
+	URef,
+	U512,
+};
+use common_lib::{
+	constants::common_keys::{
+		CommonArgs,
+		DatabaseArgs,
+		DatabaseEndpoints,
+		RegistryArgs,
+		RegistryEndpoints,
+		ARG_MAIN_PRICE_ORACLE_CONTRACT_HASH,
+		KEY_MAIN_CONTRACT_HASH,
+	},
+	enums::{
+		contracts_enum::ContractKind,
+		price_oracle_contract::PriceType,
+		roles::Role,
+	},
+	models::{
+		proposal::ProposalAction,
+		registry_contract_hash_pair::RegistryContractHashPair,
+		DomainName,
+		SubdomainName,
+	},
+};
+use cspr_names_client::{ CallTarget, MainClient };
+
+use crate::{
+	context::nft::NftSystem,
+	utils::{ UnitTestContext, DeploySource, deploy, deploy_call, deploy_payment, query },
+};
+
+const MAIN_CONTRACT_NAME: &str = "main-contract.wasm";
+
+/// Contracts the main contract registers the names through
+pub struct MainSystem {
+	/// Its `main` stays the forwarding contract, the directory points to the real main contract
+	pub nft: NftSystem,
+	pub main: ContractHash,
+	pub database: ContractHash,
+	pub price_oracle: ContractHash,
+	pub treasury: ContractHash,
+	/// Yearly price of every name of the extension
+	pub price: U512,
+}
+
+/// Install
+impl UnitTestContext {
+	/// Installs the main contract next to the nft system, a database shard, the price oracle and
+	/// the treasury of the extension, the administration contract has to be initialized.
+	/// The extension and its fixed price are set by the first two proposals of the administration contract
+	pub fn install_main_system(&mut self, extension: &str, price: U512) -> MainSystem {
+		let maintainer = self.maintainer;
+		let administration = self.contract_hash;
+
+		let nft = self.install_nft_system(extension);
+		let database = self.install_database_shard(&nft, extension);
+		let treasury = self.install_treasury();
+		let price_oracle = self.install_price_oracle();
+		let actions = vec![
+			ProposalAction::SetAllowedExtensions {
+				extensions: vec![extension.to_string()],
+			},
+			ProposalAction::SetPrice {
+				extension: extension.to_string(),
+				price_type: PriceType::Fixed,
+				price,
+				price_mid: vec![],
+				chars_count_mid: vec![],
+				price_more: U512::zero(),
+			},
+		];
+		for (id, action) in actions.into_iter().enumerate() {
+			self.queue_proposal(&maintainer, action, true, 0);
+			self.execute_proposal(&maintainer, id as u64, true, 0);
+		}
+
+		deploy(
+			&mut self.builder,
+			&maintainer,
+			&DeploySource::Code(PathBuf::from(MAIN_CONTRACT_NAME)),
+			runtime_args! {
+				CommonArgs::AdministrationContract.to_string() => administration,
+				ARG_MAIN_PRICE_ORACLE_CONTRACT_HASH => price_oracle,
+			},
+			true,
+			None
+		);
+		let main: ContractHash = query(
+			&self.builder,
+			Key::Account(maintainer),
+			&[KEY_MAIN_CONTRACT_HASH.to_string()]
+		);
+		self.add_contract(ContractKind::Main, main.into(), None);
+		for contract in [main, database, price_oracle, treasury] {
+			self.grant_role_to_key(&maintainer, Role::SystemContract, contract.into(), true);
+		}
+		self.call_by_maintainer(
+			nft.registry,
+			&RegistryEndpoints::AddToWhitelist.to_string(),
+			runtime_args! {
+				RegistryArgs::ContractHash.to_string() => main,
+			}
+		);
+
+		self.fund_maintainer();
+
+		MainSystem {
+			nft,
+			main,
+			database,
+			price_oracle,
+			treasury,
+			price,
+		}
+	}
+}
+
+/// Calls of the main contract
+impl UnitTestContext {
+	/// Pays the price of the duration from the main purse of the signer, see `deploy_payment`
+	pub fn register_domain(
+		&mut self,
+		system: &MainSystem,
+		signer: &AccountHash,
+		domain_name: &str,
+		duration: u8,
+		success: bool,
+		block_time: Option<u64>
+	) {
+		let amount = U512::from(duration) * system.price;
+		let call = MainClient::new(CallTarget::ContractHash(system.main)).register_domain(
+			domain_name.to_string(),
+			duration,
+			*signer,
+			amount,
+			self.get_main_purse(signer)
+		);
+		deploy_payment(&mut self.builder, signer, &call, amount, success, block_time)
+	}
+
+	pub fn extend_domain(
+		&mut self,
+		system: &MainSystem,
+		signer: &AccountHash,
+		domain_name: &str,
+		duration: u8,
+		success: bool,
+		block_time: Option<u64>
+	) {
+		let amount = U512::from(duration) * system.price;
+		let call = MainClient::new(CallTarget::ContractHash(system.main)).extend(
+			domain_name.to_string(),
+			duration,
+			amount,
+			self.get_main_purse(signer)
+		);
+		deploy_payment(&mut self.builder, signer, &call, amount, success, block_time)
+	}
+
+	/// Pays the price of the duration converted by the rate of the token, the signer approves
+	/// the main contract package as a spender beforehand
+	pub fn register_domain_with_token(
+		&mut self,
+		system: &MainSystem,
+		signer: &AccountHash,
+		domain_name: &str,
+		amount: U512,
+		token: ContractHash,
+		success: bool
+	) {
+		let call = MainClient::new(
+			CallTarget::ContractHash(system.main)
+		).register_domain_with_token(domain_name.to_string(), 1, *signer, amount, token);
+		deploy_call(&mut self.builder, signer, &call, success, None)
+	}
+
+	pub fn extend_domain_with_token(
+		&mut self,
+		system: &MainSystem,
+		signer: &AccountHash,
+		domain_name: &str,
+		amount: U512,
+		token: ContractHash,
+		success: bool
+	) {
+		let call = MainClient::new(CallTarget::ContractHash(system.main)).extend_with_token(
+			domain_name.to_string(),
+			1,
+			amount,
+			token
+		);
+		deploy_call(&mut self.builder, signer, &call, success, None)
+	}
+
+	/// Resolvers of names the signer doesn't own or isn't approved for are left as they are
+	pub fn set_resolver(
+		&mut self,
+		system: &MainSystem,
+		signer: &AccountHash,
+		domain_name: &str,
+		resolver: AccountHash
+	) {
+		let call = MainClient::new(
+			CallTarget::ContractHash(system.main)
+		).set_resolver_address_for_domain(domain_name.to_string(), resolver);
+		deploy_call(&mut self.builder, signer, &call, true, None)
+	}
+
+	/// Owners of the parent domain and operators approved in nft-core manage its subdomains
+	pub fn register_sub_domain(
+		&mut self,
+		system: &MainSystem,
+		signer: &AccountHash,
+		subdomain_name: &str,
+		resolver: AccountHash,
+		success: bool
+	) {
+		let call = MainClient::new(CallTarget::ContractHash(system.main)).register_sub_domain(
+			subdomain_name.to_string(),
+			resolver
+		);
+		deploy_call(&mut self.builder, signer, &call, success, None)
+	}
+
+	pub fn set_subdomain_resolver(
+		&mut self,
+		system: &MainSystem,
+		signer: &AccountHash,
+		subdomain_name: &str,
+		resolver: AccountHash,
+		success: bool
+	) {
+		let call = MainClient::new(
+			CallTarget::ContractHash(system.main)
+		).set_resolver_address_for_subdomain(subdomain_name.to_string(), resolver);
+		deploy_call(&mut self.builder, signer, &call, success, None)
+	}
+
+	pub fn remove_subdomain(
+		&mut self,
+		system: &MainSystem,
+		signer: &AccountHash,
+		subdomain_name: &str,
+		success: bool
+	) {
+		let call = MainClient::new(CallTarget::ContractHash(system.main)).remove_subdomain(
+			subdomain_name.to_string()
+		);
+		deploy_call(&mut self.builder, signer, &call, success, None)
+	}
+
+	/// Anyone can release a name past the grace period
+	pub fn release_domain(
+		&mut self,
+		system: &MainSystem,
+		signer: &AccountHash,
+		domain_name: &str,
+		success: bool,
+		block_time: Option<u64>
+	) {
+		let call = MainClient::new(CallTarget::ContractHash(system.main)).release_domain(
+			domain_name.to_string()
+		);
+		deploy_call(&mut self.builder, signer, &call, success, block_time)
+	}
+
+	/// Name as stored in the database shard of the system
+	pub fn get_domain(&mut self, system: &MainSystem, domain_name: &str) -> Option<DomainName> {
+		self.call_and_read(
+			system.database,
+			&DatabaseEndpoints::GetDomain.to_string(),
+			runtime_args! {
+				DatabaseArgs::DomainName.to_string() => domain_name.to_string(),
+			}
+		)
+	}
+
+	/// Route of the name in the registry of the system
+	pub fn get_main_route(
+		&mut self,
+		system: &MainSystem,
+		domain_name: &str
+	) -> Option<RegistryContractHashPair> {
+		self.call_and_read(
+			system.nft.registry,
+			&RegistryEndpoints::GetContractHashForDomainName.to_string(),
+			runtime_args! {
+				RegistryArgs::DomainName.to_string() => domain_name.to_string(),
+			}
+		)
+	}
+
+	/// Subdomain as stored in the database shard of the system
+	pub fn get_subdomain(
+		&mut self,
+		system: &MainSystem,
+		subdomain_name: &str
+	) -> Option<SubdomainName> {
+		self.call_and_read(
+			system.database,
+			&DatabaseEndpoints::GetSubdomain.to_string(),
+			runtime_args! {
+				DatabaseArgs::SubdomainName.to_string() => subdomain_name.to_string(),
+			}
+		)
+	}
+
+	pub fn get_main_purse(&self, account: &AccountHash) -> URef {
+		self.builder.get_expected_account(*account).main_purse()
+	}
+
+	pub fn get_account_balance(&self, account: &AccountHash) -> U512 {
+		self.builder.get_purse_balance(self.get_main_purse(account))
+	}
+}
@@ -1 +1,9 @@
 mod administration;
+mod cep18;
+mod database;
+pub(crate) mod main;
+pub(crate) mod nft;
+mod price_oracle;
+mod registry;
+mod transfer_filter;
+mod treasury;
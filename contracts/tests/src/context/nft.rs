@@ -0,0 +1,577 @@
+use std::path::PathBuf;
+
+use casper_types::{
+	bytesrepr::{ Bytes, ToBytes },
+	crypto::blake2b,
+	runtime_args,
+	account::AccountHash,
+	ContractHash,
+	Key,
+	RuntimeArgs,
+	U512,
+};
+use common_lib::{
+	constants::common_keys::{
+		get_custom_metadata_schema,
+		CommonArgs,
+		NFTContractKeys,
+		NFTContractEndpoints,
+		NFTCoreContractEndpoints,
+		RegistryArgs,
+		RegistryEndpoints,
+	},
+	enums::{ contracts_enum::ContractKind, roles::Role },
+	models::auction::Auction,
+};
+use cspr_names_client::{ CallTarget, ContractCall, NftClient };
+
+use crate::utils::{
+	UnitTestContext,
+	DeploySource,
+	ENDPOINT_CALLER_FORWARD,
+	deploy,
+	deploy_call,
+	deploy_payment,
+	query_uref,
+};
+
+const REGISTRY_CONTRACT_NAME: &str = "registry-contract.wasm";
+const TRANSFER_FILTER_CONTRACT_NAME: &str = "transfer-filter-contract.wasm";
+const NFT_CONTRACT_NAME: &str = "nft-contract.wasm";
+const NFT_CORE_CONTRACT_NAME: &str = "nft-core-contract.wasm";
+const NFT_CORE_COLLECTION_NAME: &str = "cspr-names";
+const NFT_CORE_ACL_WHITELIST: &str = "acl_whitelist";
+const NFT_CORE_SET_APPROVAL_FOR_ALL: &str = "set_approval_for_all";
+const NFT_CORE_METADATA: &str = "metadata";
+/// Routes of the names point to a database shard, the nft contract doesn't call it
+const DATABASE_CONTRACT_HASH: [u8; 32] = [71; 32];
+
+/// Contracts the nft contract is wired with, the administration contract is the one of the context
+pub struct NftSystem {
+	pub registry: ContractHash,
+	pub transfer_filter: ContractHash,
+	pub nft: ContractHash,
+	pub nft_core: ContractHash,
+	/// Registered as the main contract, forwards the calls only main is allowed to make
+	pub main: ContractHash,
+}
+
+/// Token id nft-core addresses the token of the name by, see `get_token_id`
+pub fn get_token_id(domain_name: &str) -> String {
+	base16::encode_lower(&blake2b(domain_name))
+}
+
+/// Install
+impl UnitTestContext {
+	/// Installs the contracts with the arguments of `admin-cli/config.example.toml`,
+	/// the administration contract has to be initialized
+	pub fn install_nft_system(&mut self, extension: &str) -> NftSystem {
+		let maintainer = self.maintainer;
+		let administration = self.contract_hash;
+
+		let registry = self.install_contract(REGISTRY_CONTRACT_NAME, runtime_args! {});
+		self.call_by_maintainer(
+			registry,
+			&RegistryEndpoints::Init.to_string(),
+			runtime_args! {}
+		);
+		self.add_contract(ContractKind::Registry, registry.into(), None);
+
+		let transfer_filter = self.install_contract(
+			TRANSFER_FILTER_CONTRACT_NAME,
+			runtime_args! {
+				CommonArgs::AdministrationContract.to_string() => administration,
+			}
+		);
+		self.add_contract(ContractKind::TransferFilter, transfer_filter.into(), None);
+
+		let nft = self.install_contract(
+			NFT_CONTRACT_NAME,
+			runtime_args! {
+				CommonArgs::AdministrationContract.to_string() => administration,
+			}
+		);
+		self.call_by_maintainer(
+			nft,
+			&NFTContractEndpoints::Init.to_string(),
+			runtime_args! {}
+		);
+		self.add_contract(ContractKind::NFT, nft.into(), None);
+
+		let main = self.install_caller_contract();
+		self.add_contract(ContractKind::Main, main.into(), None);
+
+		for contract in [main, nft, transfer_filter] {
+			self.grant_role_to_key(&maintainer, Role::SystemContract, contract.into(), true);
+		}
+
+		let nft_core = self.install_nft_core(
+			NFT_CORE_COLLECTION_NAME,
+			vec![Key::from(nft)],
+			transfer_filter
+		);
+		self.add_contract(
+			ContractKind::NFTCore,
+			nft_core.into(),
+			Some(extension.to_string())
+		);
+
+		NftSystem {
+			registry,
+			transfer_filter,
+			nft,
+			nft_core,
+			main,
+		}
+	}
+
+	/// Standby shard, its ACL whitelist is empty until the administration contract promotes it
+	pub fn install_nft_core_shard(
+		&mut self,
+		system: &NftSystem,
+		collection_name: &str
+	) -> ContractHash {
+		self.install_nft_core(collection_name, vec![], system.transfer_filter)
+	}
+
+	fn install_nft_core(
+		&mut self,
+		collection_name: &str,
+		acl_whitelist: Vec<Key>,
+		transfer_filter: ContractHash
+	) -> ContractHash {
+		let maintainer = self.maintainer;
+		let administration = self.contract_hash;
+		let json_schema = serde_json
+			::to_string(&get_custom_metadata_schema())
+			.expect("must convert to json schema");
+		deploy(
+			&mut self.builder,
+			&maintainer,
+			&DeploySource::Code(PathBuf::from(NFT_CORE_CONTRACT_NAME)),
+			runtime_args! {
+				"collection_name" => collection_name.to_string(),
+				"collection_symbol" => "CSPR".to_string(),
+				"total_token_supply" => 1_000_000u64,
+				"ownership_mode" => 2u8,
+				"nft_kind" => 2u8,
+				"nft_metadata_kind" => 3u8,
+				"identifier_mode" => 1u8,
+				"owner_reverse_lookup_mode" => 1u8,
+				"metadata_mutability" => 1u8,
+				"json_schema" => json_schema,
+				"minting_mode" => 2u8,
+				"acl_whitelist" => acl_whitelist,
+				"burn_mode" => 0u8,
+				"operator_burn_mode" => true,
+				"transfer_filter_contract" => Key::from(transfer_filter),
+				"acl_manager" => Key::from(administration),
+			},
+			true,
+			None
+		);
+		// nft-core keeps the hash key itself under the account, not a uref
+		self.builder
+			.get_expected_account(maintainer)
+			.named_keys()
+			.get(&format!("cep78_contract_hash_{}", collection_name))
+			.and_then(|key| key.into_hash())
+			.map(ContractHash::new)
+			.expect("must have nft-core contract hash")
+	}
+
+	pub(crate) fn call_by_maintainer(
+		&mut self,
+		contract_hash: ContractHash,
+		entry_point: &str,
+		args: RuntimeArgs
+	) {
+		let maintainer = self.maintainer;
+		deploy(
+			&mut self.builder,
+			&maintainer,
+			&DeploySource::ByContractHash {
+				hash: contract_hash,
+				entry_point: entry_point.to_string(),
+			},
+			args,
+			true,
+			None
+		)
+	}
+}
+
+/// Calls of the nft contract
+impl UnitTestContext {
+	/// Routes the name to the nft-core contract of the system
+	pub fn map_nft_route(&mut self, system: &NftSystem, domain_name: &str) {
+		self.call_by_maintainer(
+			system.registry,
+			&RegistryEndpoints::MapDomainNameToContractHash.to_string(),
+			runtime_args! {
+				RegistryArgs::DomainName.to_string() => domain_name.to_string(),
+				RegistryArgs::DatabaseContractHash.to_string() => ContractHash::new(DATABASE_CONTRACT_HASH),
+				RegistryArgs::NftContractHash.to_string() => system.nft_core,
+			}
+		)
+	}
+
+	/// Makes the call of the nft contract through the contract registered as main
+	pub fn call_nft_as_main(
+		&mut self,
+		system: &NftSystem,
+		call: ContractCall,
+		success: bool
+	) {
+		self.call_as_main(system, system.nft, &call.entry_point, call.args, success)
+	}
+
+	/// Forwards the call through the contract registered as main
+	pub fn call_as_main(
+		&mut self,
+		system: &NftSystem,
+		contract_hash: ContractHash,
+		entry_point: &str,
+		args: RuntimeArgs,
+		success: bool
+	) {
+		let maintainer = self.maintainer;
+		let args = args.to_bytes().expect("must serialize args");
+		deploy(
+			&mut self.builder,
+			&maintainer,
+			&DeploySource::ByContractHash {
+				hash: system.main,
+				entry_point: ENDPOINT_CALLER_FORWARD.to_string(),
+			},
+			runtime_args! {
+				"contract_hash" => contract_hash,
+				"entry_point" => entry_point.to_string(),
+				"args" => Bytes::from(args),
+			},
+			success,
+			None
+		)
+	}
+
+	pub fn mint_name(
+		&mut self,
+		system: &NftSystem,
+		domain_name: &str,
+		owner: AccountHash,
+		expiration_date: u64
+	) {
+		self.map_nft_route(system, domain_name);
+		let call = NftClient::new(CallTarget::ContractHash(system.nft)).mint(
+			domain_name.to_string(),
+			Key::Account(owner),
+			expiration_date
+		);
+		self.call_nft_as_main(system, call, true)
+	}
+
+	/// Renewals of main rebuild the nft-core metadata with the new expiration date
+	pub fn set_token_expiration(
+		&mut self,
+		system: &NftSystem,
+		domain_name: &str,
+		expiration_date: u64,
+		success: bool
+	) {
+		let call = NftClient::new(CallTarget::ContractHash(system.nft)).set_token_expiration(
+			domain_name.to_string(),
+			expiration_date
+		);
+		self.call_nft_as_main(system, call, success)
+	}
+
+	/// Custom validated metadata nft-core holds for the token, as json
+	pub fn get_token_metadata(&mut self, system: &NftSystem, domain_name: &str) -> String {
+		self.call_and_read(
+			system.nft_core,
+			NFT_CORE_METADATA,
+			runtime_args! {
+				"token_hash" => get_token_id(domain_name),
+			}
+		)
+	}
+
+	/// The owner transfers through the nft contract, see `approve_nft_contract`
+	pub fn transfer_name(
+		&mut self,
+		system: &NftSystem,
+		domain_name: &str,
+		owner: AccountHash,
+		recipient: AccountHash,
+		success: bool
+	) {
+		let call = NftClient::new(CallTarget::ContractHash(system.nft)).transfer(
+			domain_name.to_string(),
+			Key::Account(owner),
+			Key::Account(recipient)
+		);
+		deploy_call(&mut self.builder, &owner, &call, success, None)
+	}
+
+	/// Listings are made by the system contracts, e.g. main on behalf of the owner
+	pub fn list_name(
+		&mut self,
+		system: &NftSystem,
+		domain_name: &str,
+		seller: AccountHash,
+		price: U512,
+		success: bool
+	) {
+		let call = NftClient::new(CallTarget::ContractHash(system.nft)).list(
+			domain_name.to_string(),
+			Key::Account(seller),
+			price
+		);
+		self.call_nft_as_main(system, call, success)
+	}
+
+	pub fn start_auction_by(
+		&mut self,
+		system: &NftSystem,
+		signer: &AccountHash,
+		domain_name: &str,
+		duration: u64,
+		success: bool
+	) {
+		let call = NftClient::new(CallTarget::ContractHash(system.nft)).start_auction(
+			domain_name.to_string(),
+			U512::from(1_000u64),
+			U512::from(100u64),
+			duration
+		);
+		deploy_call(&mut self.builder, signer, &call, success, None)
+	}
+
+	/// Pays the bid from the main purse of the bidder, see `deploy_payment`
+	pub fn bid(
+		&mut self,
+		system: &NftSystem,
+		bidder: &AccountHash,
+		domain_name: &str,
+		amount: U512,
+		success: bool,
+		block_time: Option<u64>
+	) {
+		let call = NftClient::new(CallTarget::ContractHash(system.nft)).bid(
+			domain_name.to_string(),
+			amount,
+			self.get_main_purse(bidder)
+		);
+		deploy_payment(&mut self.builder, bidder, &call, amount, success, block_time)
+	}
+
+	pub fn cancel_auction(
+		&mut self,
+		system: &NftSystem,
+		signer: &AccountHash,
+		domain_name: &str,
+		success: bool
+	) {
+		let call = NftClient::new(CallTarget::ContractHash(system.nft)).cancel_auction(
+			domain_name.to_string()
+		);
+		deploy_call(&mut self.builder, signer, &call, success, None)
+	}
+
+	/// Anyone can settle an ended auction, the maintainer signs
+	pub fn settle_auction(
+		&mut self,
+		system: &NftSystem,
+		domain_name: &str,
+		success: bool,
+		block_time: Option<u64>
+	) {
+		let maintainer = self.maintainer;
+		let call = NftClient::new(CallTarget::ContractHash(system.nft)).settle_auction(
+			domain_name.to_string()
+		);
+		deploy_call(&mut self.builder, &maintainer, &call, success, block_time)
+	}
+
+	pub fn get_auction(&mut self, system: &NftSystem, domain_name: &str) -> Option<Auction> {
+		let call = NftClient::new(CallTarget::ContractHash(system.nft)).get_auction(
+			domain_name.to_string()
+		);
+		self.call_and_read(system.nft, &call.entry_point, call.args)
+	}
+
+	pub fn get_listing_price(
+		&self,
+		system: &NftSystem,
+		domain_name: &str
+	) -> Option<U512> {
+		let uref = query_uref(
+			&self.builder,
+			system.nft.into(),
+			&[],
+			&NFTContractKeys::Listing.to_string()
+		);
+		// Dictionary items are kept as options, the item of a never listed token is missing
+		self.builder
+			.query_dictionary_item(None, uref, &get_token_id(domain_name))
+			.ok()
+			.and_then(|value| value.as_cl_value().cloned())
+			.and_then(|value| value.into_t::<Option<U512>>().ok())
+			.flatten()
+	}
+
+	pub fn set_royalty_policy(
+		&mut self,
+		extension: &str,
+		system: &NftSystem,
+		share: u32,
+		success: bool
+	) {
+		let maintainer = self.maintainer;
+		let call = NftClient::new(CallTarget::ContractHash(system.nft)).set_royalty_policy(
+			extension.to_string(),
+			share
+		);
+		deploy_call(&mut self.builder, &maintainer, &call, success, None)
+	}
+
+	/// Only the account the token was minted to is the creator
+	pub fn set_token_royalty(
+		&mut self,
+		system: &NftSystem,
+		signer: &AccountHash,
+		domain_name: &str,
+		share: u32,
+		success: bool
+	) {
+		let call = NftClient::new(CallTarget::ContractHash(system.nft)).set_token_royalty(
+			get_token_id(domain_name),
+			share
+		);
+		deploy_call(&mut self.builder, signer, &call, success, None)
+	}
+
+	pub fn royalty_info(
+		&mut self,
+		system: &NftSystem,
+		domain_name: &str,
+		sale_price: U512
+	) -> Vec<(Key, U512)> {
+		let call = NftClient::new(CallTarget::ContractHash(system.nft)).royalty_info(
+			get_token_id(domain_name),
+			sale_price
+		);
+		self.call_and_read(system.nft, &call.entry_point, call.args)
+	}
+
+	/// Buys are made by the system contracts, the maintainer passes the check and pays from its main purse
+	pub fn buy_name(
+		&mut self,
+		system: &NftSystem,
+		domain_name: &str,
+		seller: AccountHash,
+		price: U512,
+		success: bool
+	) {
+		let maintainer = self.maintainer;
+		let call = NftClient::new(CallTarget::ContractHash(system.nft)).buy(
+			domain_name.to_string(),
+			get_token_id(domain_name),
+			Key::Account(seller),
+			price,
+			self.get_main_purse(&maintainer)
+		);
+		deploy_payment(&mut self.builder, &maintainer, &call, price, success, None)
+	}
+
+	/// Same buy paid in the CEP-18 token, the maintainer approves the nft contract package beforehand
+	pub fn buy_name_with_token(
+		&mut self,
+		system: &NftSystem,
+		domain_name: &str,
+		seller: AccountHash,
+		amount: U512,
+		token: ContractHash,
+		success: bool
+	) {
+		let maintainer = self.maintainer;
+		let call = NftClient::new(CallTarget::ContractHash(system.nft)).buy_with_token(
+			domain_name.to_string(),
+			get_token_id(domain_name),
+			Key::Account(seller),
+			amount,
+			token
+		);
+		deploy_call(&mut self.builder, &maintainer, &call, success, None)
+	}
+
+	/// Token ids and domain names of the owner across the nft-core shards, `MAX_PAGE_SIZE` per page
+	pub fn get_owned_tokens(
+		&mut self,
+		system: &NftSystem,
+		owner: AccountHash,
+		page: u64
+	) -> Vec<(String, Option<String>)> {
+		let call = NftClient::new(CallTarget::ContractHash(system.nft)).get_owned_tokens(
+			Key::Account(owner),
+			page
+		);
+		self.call_and_read(system.nft, &call.entry_point, call.args)
+	}
+
+	pub fn get_token_owner(&mut self, system: &NftSystem, domain_name: &str) -> Key {
+		self.call_and_read(
+			system.nft_core,
+			&NFTCoreContractEndpoints::OwnerOf.to_string(),
+			runtime_args! {
+				"token_hash" => get_token_id(domain_name),
+			}
+		)
+	}
+
+	/// Transfers and sales of the nft contract move the token only if it is an operator of the owner
+	pub fn approve_nft_contract(&mut self, system: &NftSystem, owner: &AccountHash) {
+		deploy(
+			&mut self.builder,
+			owner,
+			&DeploySource::ByContractHash {
+				hash: system.nft_core,
+				entry_point: NFT_CORE_SET_APPROVAL_FOR_ALL.to_string(),
+			},
+			runtime_args! {
+				"approve_all" => true,
+				"operator" => Key::from(system.nft),
+			},
+			true,
+			None
+		)
+	}
+
+	/// Operators approved for all tokens of the owner manage the names through the main contract
+	pub fn approve_operator(&mut self, system: &NftSystem, owner: &AccountHash, operator: Key) {
+		deploy(
+			&mut self.builder,
+			owner,
+			&DeploySource::ByContractHash {
+				hash: system.nft_core,
+				entry_point: NFT_CORE_SET_APPROVAL_FOR_ALL.to_string(),
+			},
+			runtime_args! {
+				"approve_all" => true,
+				"operator" => operator,
+			},
+			true,
+			None
+		)
+	}
+
+	pub fn is_acl_whitelisted(&self, nft_core: ContractHash, contract: ContractHash) -> bool {
+		let uref = query_uref(&self.builder, nft_core.into(), &[], NFT_CORE_ACL_WHITELIST);
+		self.builder
+			.query_dictionary_item(None, uref, &contract.to_string())
+			.ok()
+			.and_then(|value| value.as_cl_value().cloned())
+			.and_then(|value| value.into_t::<bool>().ok())
+			.unwrap_or_default()
+	}
+}
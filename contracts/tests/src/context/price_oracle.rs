@@ -0,0 +1,119 @@
+use casper_types::{
+	bytesrepr::FromBytes,
+	runtime_args,
+	CLTyped,
+	RuntimeArgs,
+	Key,
+	account::AccountHash,
+	ContractHash,
+	U512,
+};
+use common_lib::{
+	constants::common_keys::{
+		CommonArgs,
+		PriceOracleArgs,
+		PriceOracleKeys,
+		ENDPOINT_PO_INIT,
+		ENDPOINT_PO_SET_PRICE,
+	},
+	enums::{ contracts_enum::ContractKind, price_oracle_contract::PriceType },
+	models::price::Price,
+};
+use cspr_names_client::{ CallTarget, PriceOracleClient };
+
+use crate::utils::{ UnitTestContext, deploy, deploy_call, query_uref };
+
+const PRICE_ORACLE_CONTRACT_NAME: &str = "price-oracle-contract.wasm";
+
+/// Price oracle next to the administration contract of the context
+impl UnitTestContext {
+	pub fn install_price_oracle(&mut self) -> ContractHash {
+		let price_oracle = self.install_contract(
+			PRICE_ORACLE_CONTRACT_NAME,
+			runtime_args! {
+				CommonArgs::AdministrationContract.to_string() => self.contract_hash,
+			}
+		);
+		self.call_by_maintainer(price_oracle, ENDPOINT_PO_INIT, runtime_args! {});
+		self.add_contract(ContractKind::PriceOracle, price_oracle.into(), None);
+		price_oracle
+	}
+
+	pub fn set_fixed_price(
+		&mut self,
+		signer: &AccountHash,
+		price_oracle: ContractHash,
+		extension: String,
+		price: U512,
+		success: bool
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: price_oracle,
+				entry_point: ENDPOINT_PO_SET_PRICE.to_string(),
+			}),
+			runtime_args! {
+				PriceOracleArgs::Extension.to_string() => extension,
+				PriceOracleArgs::PriceType.to_string() => PriceType::Fixed,
+				PriceOracleArgs::Price.to_string() => price,
+			},
+			success,
+			None
+		)
+	}
+
+	pub fn get_price(&self, price_oracle: ContractHash, extension: &str) -> Option<Price> {
+		self.get_price_oracle_item(price_oracle, extension)
+	}
+
+	pub fn get_price_simple_operations(
+		&self,
+		price_oracle: ContractHash,
+		extension: &str
+	) -> Option<U512> {
+		self.get_price_oracle_item(
+			price_oracle,
+			&format!("{}:{}", extension, PriceOracleKeys::SimpleOperations)
+		)
+	}
+
+	/// Items of an extension without a price are missing from the dictionary
+	fn get_price_oracle_item<T: FromBytes + CLTyped>(
+		&self,
+		price_oracle: ContractHash,
+		key: &str
+	) -> Option<T> {
+		let uref = query_uref(
+			&self.builder,
+			Key::from(price_oracle),
+			&[],
+			&PriceOracleKeys::Main.to_string()
+		);
+		self.builder
+			.query_dictionary_item(None, uref, key)
+			.ok()
+			.and_then(|value| value.as_cl_value().cloned())
+			.and_then(|value| value.into_t::<Option<T>>().ok())
+			.flatten()
+	}
+
+	/// Rate is in token units per CSPR, the proceeds in the token go to its treasury
+	pub fn set_payment_token(
+		&mut self,
+		signer: &AccountHash,
+		price_oracle: ContractHash,
+		token: ContractHash,
+		rate: U512,
+		treasury: Key,
+		success: bool
+	) {
+		let call = PriceOracleClient::new(CallTarget::ContractHash(price_oracle)).set_payment_token(
+			token,
+			rate,
+			treasury
+		);
+		deploy_call(&mut self.builder, signer, &call, success, None)
+	}
+}
@@ -0,0 +1,230 @@
+use casper_types::{
+	runtime_args,
+	RuntimeArgs,
+	account::AccountHash,
+	ContractHash,
+};
+use common_lib::{
+	constants::common_keys::{
+		RegistryArgs,
+		RegistryEndpoints,
+		KEY_REGISTRY_DOMAIN_CONTRACT_HASH,
+		KEY_REGISTRY_OPERATORS,
+		KEY_REGISTRY_WHITELIST_CONTRACT_HASH,
+	},
+	models::registry_contract_hash_pair::RegistryContractHashPair,
+};
+
+use crate::utils::{ UnitTestContext, deploy, query_uref, query_dictionary, query };
+
+/// Init
+impl UnitTestContext {
+	pub fn init_registry(&mut self) {
+		let maintainer = self.maintainer;
+		deploy(
+			&mut self.builder,
+			&maintainer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: RegistryEndpoints::Init.to_string(),
+			}),
+			runtime_args! {},
+			true,
+			None
+		)
+	}
+}
+
+/// Whitelist and operators
+impl UnitTestContext {
+	pub fn add_to_whitelist(
+		&mut self,
+		signer: &AccountHash,
+		contract_hash: ContractHash,
+		success: bool
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: RegistryEndpoints::AddToWhitelist.to_string(),
+			}),
+			runtime_args! {
+				RegistryArgs::ContractHash.to_string() => contract_hash,
+			},
+			success,
+			None
+		)
+	}
+
+	pub fn get_whitelist(&self) -> Vec<ContractHash> {
+		query(
+			&self.builder,
+			self.contract_hash.into(),
+			&[KEY_REGISTRY_WHITELIST_CONTRACT_HASH.to_string()]
+		)
+	}
+
+	pub fn add_registry_operator(
+		&mut self,
+		signer: &AccountHash,
+		operator: AccountHash,
+		success: bool
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: RegistryEndpoints::AddOperator.to_string(),
+			}),
+			runtime_args! {
+				RegistryArgs::Operator.to_string() => operator,
+			},
+			success,
+			None
+		)
+	}
+
+	pub fn remove_registry_operator(
+		&mut self,
+		signer: &AccountHash,
+		operator: AccountHash,
+		success: bool
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: RegistryEndpoints::RemoveOperator.to_string(),
+			}),
+			runtime_args! {
+				RegistryArgs::Operator.to_string() => operator,
+			},
+			success,
+			None
+		)
+	}
+
+	pub fn get_registry_operators(&self) -> Vec<AccountHash> {
+		query(
+			&self.builder,
+			self.contract_hash.into(),
+			&[KEY_REGISTRY_OPERATORS.to_string()]
+		)
+	}
+}
+
+/// Routes
+impl UnitTestContext {
+	pub fn map_route(
+		&mut self,
+		signer: &AccountHash,
+		domain_name: &str,
+		route: RegistryContractHashPair,
+		success: bool
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: RegistryEndpoints::MapDomainNameToContractHash.to_string(),
+			}),
+			runtime_args! {
+				RegistryArgs::DomainName.to_string() => domain_name.to_string(),
+				RegistryArgs::DatabaseContractHash.to_string() => route.db_contract_hash,
+				RegistryArgs::NftContractHash.to_string() => route.nft_contract_hash,
+			},
+			success,
+			None
+		)
+	}
+
+	pub fn unmap_route(
+		&mut self,
+		signer: &AccountHash,
+		domain_name: &str,
+		success: bool
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: RegistryEndpoints::UnmapDomainName.to_string(),
+			}),
+			runtime_args! {
+				RegistryArgs::DomainName.to_string() => domain_name.to_string(),
+			},
+			success,
+			None
+		)
+	}
+
+	pub fn remap_routes(
+		&mut self,
+		signer: &AccountHash,
+		domain_names: Vec<String>,
+		db_contract_hash: ContractHash,
+		success: bool
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: RegistryEndpoints::RemapDomainNames.to_string(),
+			}),
+			runtime_args! {
+				RegistryArgs::DomainNames.to_string() => domain_names,
+				RegistryArgs::DatabaseContractHash.to_string() => db_contract_hash,
+			},
+			success,
+			None
+		)
+	}
+
+	pub fn resolve_routes(
+		&mut self,
+		signer: &AccountHash,
+		domain_names: Vec<String>,
+		success: bool
+	) {
+		deploy(
+			&mut self.builder,
+			signer,
+			&(crate::utils::DeploySource::ByContractHash {
+				hash: self.contract_hash,
+				entry_point: RegistryEndpoints::ResolveRoutes.to_string(),
+			}),
+			runtime_args! {
+				RegistryArgs::DomainNames.to_string() => domain_names,
+			},
+			success,
+			None
+		)
+	}
+
+	pub fn get_route(&self, domain_name: &str) -> RegistryContractHashPair {
+		self.find_route(domain_name).expect("route must be mapped")
+	}
+
+	/// Unmapped names keep an empty dictionary item
+	pub fn find_route(&self, domain_name: &str) -> Option<RegistryContractHashPair> {
+		let uref = query_uref(
+			&self.builder,
+			self.contract_hash.into(),
+			&[],
+			KEY_REGISTRY_DOMAIN_CONTRACT_HASH
+		);
+		// Dictionary items are kept as options
+		query_dictionary::<Option<RegistryContractHashPair>>(
+			&self.builder,
+			uref,
+			domain_name
+		)
+	}
+}
@@ -0,0 +1,44 @@
+use casper_types::{ runtime_args, Key, RuntimeArgs };
+use common_lib::{
+	constants::common_keys::{ TransferFilterArgs, TransferFilterEndpoints },
+	enums::transfer_filter::TransferDenial,
+};
+
+use crate::{
+	context::nft::{ get_token_id, NftSystem },
+	utils::UnitTestContext,
+};
+
+/// Checks of the transfer filter the nft-core contract of the system calls
+impl UnitTestContext {
+	/// Denial of the token of the name at the block time
+	pub fn get_transfer_denial(
+		&mut self,
+		system: &NftSystem,
+		name: &str,
+		block_time: Option<u64>
+	) -> Option<TransferDenial> {
+		self.call_and_read_at(
+			system.transfer_filter,
+			&TransferFilterEndpoints::GetTransferDenial.to_string(),
+			runtime_args! {
+				TransferFilterArgs::TokenId.to_string() => get_token_id(name),
+			},
+			block_time
+		)
+	}
+
+	/// Result nft-core receives, see `TransferFilterResult`
+	pub fn can_transfer(&mut self, system: &NftSystem, name: &str) -> u8 {
+		let maintainer = Key::Account(self.maintainer);
+		self.call_and_read(
+			system.transfer_filter,
+			&TransferFilterEndpoints::CanTransfer.to_string(),
+			runtime_args! {
+				TransferFilterArgs::SourceKey.to_string() => maintainer,
+				TransferFilterArgs::TargetKey.to_string() => maintainer,
+				TransferFilterArgs::TokenId.to_string() => get_token_id(name),
+			}
+		)
+	}
+}
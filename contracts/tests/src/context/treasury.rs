@@ -0,0 +1,159 @@
+use casper_types::{
+	account::AccountHash,
+	bytesrepr::{ Bytes, ToBytes },
+	runtime_args,
+	CLType,
+	ContractHash,
+	RuntimeArgs,
+	U512,
+};
+use common_lib::{
+	constants::common_keys::{ CommonArgs, TreasuryArgs, TreasuryEndpoints, TreasuryKeys },
+	enums::{ contracts_enum::ContractKind, revenue_stream::RevenueStream },
+	models::treasury::Beneficiary,
+};
+use cspr_names_client::{ CallTarget, ContractCall, TreasuryClient };
+
+use crate::utils::{
+	UnitTestContext,
+	ENDPOINT_CALLER_FORWARD_PAYMENT,
+	deploy_call,
+	deploy_payment,
+	query_uref,
+};
+
+const TREASURY_CONTRACT_NAME: &str = "treasury-contract.wasm";
+
+/// Treasury of the fees, main and nft deposit into it
+impl UnitTestContext {
+	pub fn install_treasury(&mut self) -> ContractHash {
+		let administration = self.contract_hash;
+		let treasury = self.install_contract(
+			TREASURY_CONTRACT_NAME,
+			runtime_args! {
+				CommonArgs::AdministrationContract.to_string() => administration,
+			}
+		);
+		self.call_by_maintainer(
+			treasury,
+			&TreasuryEndpoints::Init.to_string(),
+			runtime_args! {}
+		);
+		self.add_contract(ContractKind::Treasury, treasury.into(), None);
+		treasury
+	}
+
+	/// Deposits from the main purse of the maintainer through the caller contract,
+	/// the treasury only takes deposits of the contracts registered as main or nft
+	pub fn deposit(
+		&mut self,
+		treasury: ContractHash,
+		caller: ContractHash,
+		stream: RevenueStream,
+		amount: U512,
+		success: bool
+	) {
+		self.deposit_with_purse_balance(treasury, caller, stream, amount, amount, success)
+	}
+
+	/// The purse passed to the treasury holds `purse_balance`, the deposit takes `amount` of it
+	pub fn deposit_with_purse_balance(
+		&mut self,
+		treasury: ContractHash,
+		caller: ContractHash,
+		stream: RevenueStream,
+		amount: U512,
+		purse_balance: U512,
+		success: bool
+	) {
+		let maintainer = self.maintainer;
+		let purse = self.get_main_purse(&maintainer);
+		let deposit = TreasuryClient::new(CallTarget::ContractHash(treasury)).deposit(
+			stream,
+			amount,
+			purse
+		);
+		let mut args = RuntimeArgs::new();
+		for named_arg in deposit.args.named_args() {
+			if named_arg.cl_value().cl_type() != &CLType::URef {
+				args.insert_cl_value(named_arg.name(), named_arg.cl_value().clone());
+			}
+		}
+		// The purse is only a placeholder, `deploy_payment` passes the funded purse under its name
+		let call = ContractCall {
+			target: CallTarget::ContractHash(caller),
+			entry_point: ENDPOINT_CALLER_FORWARD_PAYMENT.to_string(),
+			args: runtime_args! {
+				"contract_hash" => treasury,
+				"entry_point" => deposit.entry_point,
+				"args" => Bytes::from(args.to_bytes().expect("must serialize args")),
+				"purse_arg" => TreasuryArgs::Purse.to_string(),
+				"purse" => purse,
+			},
+		};
+		deploy_payment(&mut self.builder, &maintainer, &call, purse_balance, success, None)
+	}
+
+	/// Maintainer passes the TREASURY_MANAGER role check
+	pub fn set_beneficiaries(
+		&mut self,
+		treasury: ContractHash,
+		stream: RevenueStream,
+		beneficiaries: Vec<Beneficiary>,
+		success: bool
+	) {
+		let maintainer = self.maintainer;
+		let call = TreasuryClient::new(CallTarget::ContractHash(treasury)).set_beneficiaries(
+			stream,
+			beneficiaries
+		);
+		deploy_call(&mut self.builder, &maintainer, &call, success, None)
+	}
+
+	pub fn get_revenue(&mut self, treasury: ContractHash, stream: RevenueStream) -> U512 {
+		let call = TreasuryClient::new(CallTarget::ContractHash(treasury)).get_revenue(stream);
+		self.call_and_read(treasury, &call.entry_point, call.args)
+	}
+
+	pub fn get_undistributed(&mut self, treasury: ContractHash, stream: RevenueStream) -> U512 {
+		let call = TreasuryClient::new(CallTarget::ContractHash(treasury)).get_undistributed(
+			stream
+		);
+		self.call_and_read(treasury, &call.entry_point, call.args)
+	}
+
+	pub fn get_treasury_balance(
+		&mut self,
+		treasury: ContractHash,
+		stream: RevenueStream,
+		beneficiary: AccountHash
+	) -> U512 {
+		let call = TreasuryClient::new(CallTarget::ContractHash(treasury)).get_balance(
+			stream,
+			beneficiary
+		);
+		self.call_and_read(treasury, &call.entry_point, call.args)
+	}
+
+	/// Deposits not withdrawn yet
+	pub fn get_treasury_purse_balance(&self, treasury: ContractHash) -> U512 {
+		let purse = query_uref(
+			&self.builder,
+			treasury.into(),
+			&[],
+			&TreasuryKeys::Purse.to_string()
+		);
+		self.builder.get_purse_balance(purse)
+	}
+
+	pub fn withdraw(
+		&mut self,
+		treasury: ContractHash,
+		signer: &AccountHash,
+		stream: RevenueStream,
+		success: bool
+	) {
+		let call = TreasuryClient::new(CallTarget::ContractHash(treasury)).withdraw(stream);
+		deploy_call(&mut self.builder, signer, &call, success, None)
+	}
+}
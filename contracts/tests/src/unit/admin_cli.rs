@@ -0,0 +1,455 @@
+use std::{ fs, path::PathBuf, process };
+
+use casper_execution_engine::core::engine_state::executable_deploy_item::ExecutableDeployItem;
+use casper_hashing::Digest;
+use casper_types::{
+	bytesrepr::ToBytes,
+	crypto,
+	runtime_args,
+	CLValue,
+	ContractHash,
+	Key,
+	RuntimeArgs,
+	SecretKey,
+	Signature,
+	Timestamp,
+	U512,
+};
+use common_lib::{
+	enums::{
+		price_oracle_contract::PriceType,
+		registration_mode::RegistrationMode,
+		roles::Role,
+	},
+	models::{
+		confiugration::ExtensionConfig as StoredExtensionConfig,
+		price::{ Price, PriceItem },
+		registry_pointer::CompoundContract,
+	},
+};
+use cspr_names_admin::{
+	config::{
+		parse_contract_hash,
+		parse_registration_mode,
+		parse_role,
+		ArgValue,
+		Config,
+		NetworkConfig,
+	},
+	deploy::{ contract_session, format_timestamp, Deploy, Signer },
+	error::AdminError,
+	plan::Deployment,
+	rpc::{ dechunk, to_cl_value },
+	settings::{ settings, Setting },
+};
+use cspr_names_client::{ CallTarget, ContractCall };
+use serde_json::json;
+
+const DEPLOY_TIMESTAMP: u64 = 1_700_000_000_123;
+/// Hash of the deploy of `get_call` signed by `get_signer` at `DEPLOY_TIMESTAMP`
+const DEPLOY_HASH: &str =
+	"91272f4e1308c0fb623cf45ecee525e19e2286e7746c24ba1d160778386c50ea";
+
+/// 1. Test known deploy
+/// 2. Test timestamp format
+/// 3. Test config parsing
+/// 4. Test rejected configs
+/// 5. Test diff of the settings
+/// 6. Test node responses
+
+#[test]
+fn should_test_known_deploy() {
+	let network = get_network();
+	let signer = get_signer();
+	let session = contract_session(&get_call());
+	let deploy = Deploy::with_timestamp(
+		session.clone(),
+		U512::from(5_000_000_000u64),
+		&network,
+		&signer,
+		DEPLOY_TIMESTAMP
+	).unwrap();
+	let json = deploy.to_json();
+
+	// Body hash covers the serialized payment and session
+	let payment = ExecutableDeployItem::ModuleBytes {
+		module_bytes: Vec::new().into(),
+		args: runtime_args! { "amount" => U512::from(5_000_000_000u64) },
+	};
+	let mut body = payment.to_bytes().unwrap();
+	body.append(&mut session.to_bytes().unwrap());
+	let body_hash = Digest::hash(body);
+	assert_eq!(json["header"]["body_hash"], json!(body_hash));
+
+	// Deploy hash covers the serialized header
+	let mut header = signer.public_key.to_bytes().unwrap();
+	header.append(&mut DEPLOY_TIMESTAMP.to_bytes().unwrap());
+	header.append(&mut network.ttl().to_bytes().unwrap());
+	header.append(&mut network.gas_price().to_bytes().unwrap());
+	header.append(&mut body_hash.to_bytes().unwrap());
+	header.append(&mut Vec::<Digest>::new().to_bytes().unwrap());
+	header.append(&mut network.chain_name.to_bytes().unwrap());
+	assert_eq!(deploy.hash, Digest::hash(header));
+	assert_eq!(deploy.hash_hex(), DEPLOY_HASH);
+	assert_eq!(json["hash"], json!(DEPLOY_HASH));
+
+	assert_eq!(json["header"]["account"], json!(signer.public_key));
+	assert_eq!(json["header"]["timestamp"], json!("2023-11-14T22:13:20.123Z"));
+	assert_eq!(json["header"]["ttl"], json!("1800000ms"));
+	assert_eq!(json["header"]["gas_price"], json!(1));
+	assert_eq!(json["header"]["chain_name"], json!("casper-test"));
+	assert_eq!(json["header"]["dependencies"], json!([]));
+	assert_eq!(json["session"], json!(session));
+
+	// Approval signs the deploy hash
+	let signature: Signature = serde_json
+		::from_value(json["approvals"][0]["signature"].clone())
+		.unwrap();
+	assert_eq!(json["approvals"][0]["signer"], json!(signer.public_key));
+	assert!(crypto::verify(deploy.hash, &signature, &signer.public_key).is_ok());
+
+	let later = Deploy::with_timestamp(
+		session,
+		U512::from(5_000_000_000u64),
+		&network,
+		&signer,
+		DEPLOY_TIMESTAMP + 1
+	).unwrap();
+	assert_ne!(later.hash, deploy.hash);
+}
+
+#[test]
+fn should_test_timestamp_format() {
+	assert_eq!(format_timestamp(0), "1970-01-01T00:00:00.000Z");
+	assert_eq!(format_timestamp(951_782_400_000), "2000-02-29T00:00:00.000Z");
+	assert_eq!(format_timestamp(4_107_542_399_999), "2100-02-28T23:59:59.999Z");
+	assert_eq!(format_timestamp(4_107_542_400_000), "2100-03-01T00:00:00.000Z");
+
+	// Every day up to the year 2400 and the last millisecond of each of them
+	let day = 86_400_000u64;
+	for index in 0..157_000u64 {
+		for millis in [index * day, index * day + day - 1, index * day + 45_296_789] {
+			assert_eq!(
+				format_timestamp(millis),
+				Timestamp::from(millis).to_string(),
+				"{}",
+				millis
+			);
+		}
+	}
+}
+
+#[test]
+fn should_test_config_parsing() {
+	let dir = get_config_dir("parsing");
+	fs::write(dir.join("config.toml"), get_config_content()).unwrap();
+	let config = Config::load(&dir.join("config.toml")).unwrap();
+
+	// Relative paths are relative to the config file
+	assert_eq!(config.network.secret_key, dir.join("keys/secret_key.pem"));
+	assert_eq!(
+		config.contracts.transfer_filter.wasm,
+		Some(dir.join("wasm/transfer-filter-contract.wasm"))
+	);
+	assert_eq!(
+		config.contracts.treasury.wasm,
+		Some(dir.join("wasm/treasury-contract.wasm"))
+	);
+	assert_eq!(
+		config.extensions[0].nft_core.wasm,
+		Some(dir.join("wasm/nft-core-contract.wasm"))
+	);
+
+	assert_eq!(config.network.payment_amount().unwrap(), U512::from(5_000_000_000u64));
+	assert_eq!(config.network.ttl(), 30 * 60 * 1000);
+	assert_eq!(config.network.gas_price(), 1);
+	assert_eq!(
+		config.contracts.administration.install_payment().unwrap(),
+		U512::from(300_000_000_000u64)
+	);
+
+	let args = &config.extensions[0].nft_core.args;
+	assert!(matches!(args["collection_name"], ArgValue::Text(ref name) if name == "cspr-names"));
+	assert!(matches!(args["total_token_supply"], ArgValue::Integer(1000)));
+	assert!(matches!(args["allow_minting"], ArgValue::Bool(true)));
+	assert!(
+		matches!(args["nft_kind"], ArgValue::Typed { ref cl_type, ref value } if cl_type == "u8" && value == "2")
+	);
+
+	let price = config.extensions[0].price.as_ref().unwrap();
+	assert!(price.is_dynamic());
+	assert_eq!(price.mid[1].chars_count, 4);
+
+	// JSON configs are read as JSON
+	let json_config = json!({
+		"network": {
+			"node_address": "http://127.0.0.1:11101",
+			"chain_name": "casper-test",
+			"secret_key": "secret_key.pem",
+			"ttl": 60000
+		},
+		"contracts": {
+			"administration": { "hash": get_hash_string(1) },
+			"registry": { "hash": get_hash_string(2) },
+			"price_oracle": { "hash": get_hash_string(3) },
+			"main": { "hash": get_hash_string(4) },
+			"nft": { "hash": get_hash_string(5) },
+			"transfer_filter": { "hash": get_hash_string(6) },
+			"treasury": { "hash": get_hash_string(7) }
+		}
+	});
+	fs::write(dir.join("config.json"), json_config.to_string()).unwrap();
+	let config = Config::load(&dir.join("config.json")).unwrap();
+	assert_eq!(config.network.ttl(), 60000);
+	assert!(config.extensions.is_empty());
+
+	assert!(Config::load(&get_example_dir().join("config.example.toml")).is_ok());
+
+	assert!(parse_role("PRICE_SETTER").unwrap() == Role::PriceSetter);
+	assert!(parse_registration_mode("allowlisted").unwrap() == RegistrationMode::Allowlisted);
+	assert_eq!(
+		parse_contract_hash(&get_hash_string(1)).unwrap(),
+		parse_contract_hash(&format!("hash-{}", "01".repeat(32))).unwrap()
+	);
+
+	fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn should_test_rejected_configs() {
+	let dir = get_config_dir("rejected");
+
+	// Unknown fields are refused
+	let content = get_config_content().replace("chain_name", "chain");
+	fs::write(dir.join("config.toml"), content).unwrap();
+	assert!(matches!(Config::load(&dir.join("config.toml")), Err(AdminError::Config(_))));
+
+	let content = get_config_content().replace("5000000000", "5 CSPR");
+	fs::write(dir.join("config.toml"), content).unwrap();
+	let config = Config::load(&dir.join("config.toml")).unwrap();
+	assert!(matches!(config.network.payment_amount(), Err(AdminError::Config(_))));
+
+	assert!(matches!(parse_role("OWNER"), Err(AdminError::Config(_))));
+	assert!(matches!(parse_registration_mode("closed"), Err(AdminError::Config(_))));
+	assert!(matches!(parse_contract_hash("account-hash-00"), Err(AdminError::Config(_))));
+
+	// Settings need the hashes of the contracts they call
+	let config = Config::load(&get_example_dir().join("config.example.toml")).unwrap();
+	let deployment = Deployment::from_config(&config).unwrap();
+	assert!(
+		matches!(settings(&config, &deployment), Err(AdminError::MissingHash(name)) if name == "administration")
+	);
+
+	fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn should_test_settings_diff() {
+	let dir = get_config_dir("diff");
+	let content = get_config_content()
+		.replace("wasm = \"wasm/nft-core-contract.wasm\"", &format!("hash = \"{}\"", get_hash_string(9)))
+		.replace("wasm = \"wasm/database-contract.wasm\"", &format!("hash = \"{}\"", get_hash_string(8)));
+	fs::write(dir.join("config.toml"), content).unwrap();
+	let config = Config::load(&dir.join("config.toml")).unwrap();
+	let deployment = Deployment::from_config(&config).unwrap();
+	let settings = settings(&config, &deployment).unwrap();
+
+	// Directory of a compound contract lists every shard
+	let database = Key::from(ContractHash::new([8u8; 32]));
+	let shards = vec![
+		CompoundContract { key: Key::from(ContractHash::new([10u8; 32])), count: Some(1) },
+		CompoundContract { key: database, count: Some(0) }
+	];
+	assert_no_diff(get_setting(&settings, "directory: database:cspr"), CLValue::from_t(shards).unwrap());
+	assert_diff(get_setting(&settings, "directory: database:cspr"), None);
+
+	assert_no_diff(
+		get_setting(&settings, "registry whitelist: @main"),
+		CLValue::from_t(vec![ContractHash::new([4u8; 32])]).unwrap()
+	);
+	assert_no_diff(
+		get_setting(&settings, "role PRICE_SETTER: @main"),
+		CLValue::from_t(vec![Key::from(ContractHash::new([4u8; 32]))]).unwrap()
+	);
+	assert_diff(
+		get_setting(&settings, "role PRICE_SETTER: @main"),
+		Some(CLValue::from_t(Vec::<Key>::new()).unwrap())
+	);
+	assert_no_diff(
+		get_setting(&settings, "allowed extensions"),
+		CLValue::from_t(vec!["cspr".to_string()]).unwrap()
+	);
+
+	// Dictionaries store the items as options
+	let extension_config = StoredExtensionConfig {
+		min_chars_count: 3,
+		max_chars_count: 64,
+		..Default::default()
+	};
+	assert_no_diff(
+		get_setting(&settings, "cspr: chars_min_count"),
+		CLValue::from_t(Some(extension_config.clone())).unwrap()
+	);
+	assert_no_diff(
+		get_setting(&settings, "cspr: max_chars_count"),
+		CLValue::from_t(extension_config).unwrap()
+	);
+
+	let price = Price {
+		price_type: PriceType::Dynamic,
+		price: U512::from(10_000_000_000u64),
+		price_by_count: vec![
+			PriceItem { char_count: 3, price: U512::from(500_000_000_000u64) },
+			PriceItem { char_count: 4, price: U512::from(100_000_000_000u64) }
+		],
+		price_more: U512::from(10_000_000_000u64),
+	};
+	let setting = get_setting(&settings, "cspr: price");
+	assert_eq!(
+		setting.expected,
+		"dynamic 10000000000, [3: 500000000000, 4: 100000000000], more 10000000000"
+	);
+	assert_no_diff(setting, CLValue::from_t(Some(price)).unwrap());
+	assert_eq!((setting.render)(None).unwrap(), "<not set>");
+
+	fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn should_test_node_responses() {
+	let stored_value = json!({ "CLValue": CLValue::from_t(120u64).unwrap() });
+	assert!(to_cl_value(&stored_value).unwrap() == CLValue::from_t(120u64).unwrap());
+	assert!(
+		matches!(to_cl_value(&json!({ "Account": {} })), Err(AdminError::Rpc(_)))
+	);
+
+	assert_eq!(dechunk("5\r\nhello\r\n6\r\n world\r\n0\r\n\r\n"), "hello world");
+	assert_eq!(dechunk("a\r\n0123456789\r\n0\r\n\r\n"), "0123456789");
+	// Truncated chunks are dropped
+	assert_eq!(dechunk("5\r\nhello\r\n10\r\nworld"), "hello");
+}
+
+/// Helpers
+fn get_network() -> NetworkConfig {
+	NetworkConfig {
+		node_address: "http://127.0.0.1:11101".to_string(),
+		chain_name: "casper-test".to_string(),
+		secret_key: PathBuf::new(),
+		payment_amount: None,
+		ttl: None,
+		gas_price: None,
+		deploy_timeout: None,
+	}
+}
+
+fn get_signer() -> Signer {
+	Signer::new(SecretKey::ed25519_from_bytes([1u8; 32]).unwrap())
+}
+
+fn get_call() -> ContractCall {
+	ContractCall::new(
+		CallTarget::ContractHash(ContractHash::new([1u8; 32])),
+		"init".to_string(),
+		RuntimeArgs::new()
+	)
+}
+
+fn get_hash_string(byte: u8) -> String {
+	ContractHash::new([byte; 32]).to_formatted_string()
+}
+
+fn get_config_dir(name: &str) -> PathBuf {
+	let dir = std::env::temp_dir().join(format!("cspr-names-admin-{}-{}", name, process::id()));
+	fs::create_dir_all(&dir).unwrap();
+	dir
+}
+
+fn get_example_dir() -> PathBuf {
+	PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../admin-cli")
+}
+
+fn get_config_content() -> String {
+	format!(
+		r#"
+[network]
+node_address = "http://127.0.0.1:11101"
+chain_name = "casper-test"
+secret_key = "keys/secret_key.pem"
+payment_amount = "5000000000"
+
+[contracts.administration]
+hash = "{administration}"
+
+[contracts.registry]
+hash = "{registry}"
+whitelist = ["@main"]
+
+[contracts.price_oracle]
+hash = "{price_oracle}"
+
+[contracts.main]
+hash = "{main}"
+
+[contracts.nft]
+hash = "{nft}"
+
+[contracts.transfer_filter]
+wasm = "wasm/transfer-filter-contract.wasm"
+hash = "{transfer_filter}"
+
+[contracts.treasury]
+wasm = "wasm/treasury-contract.wasm"
+hash = "{treasury}"
+
+[roles]
+PRICE_SETTER = ["@main"]
+
+[[extensions]]
+name = "cspr"
+chars_min_count = 3
+max_chars_count = 64
+
+[extensions.price]
+price = "10000000000"
+mid = [
+	{{ chars_count = 3, price = "500000000000" }},
+	{{ chars_count = 4, price = "100000000000" }},
+]
+more = "10000000000"
+
+[extensions.database]
+wasm = "wasm/database-contract.wasm"
+
+[extensions.nft_core]
+wasm = "wasm/nft-core-contract.wasm"
+
+[extensions.nft_core.args]
+collection_name = "cspr-names"
+total_token_supply = 1000
+allow_minting = true
+nft_kind = {{ type = "u8", value = "2" }}
+"#,
+		administration = get_hash_string(1),
+		registry = get_hash_string(2),
+		price_oracle = get_hash_string(3),
+		main = get_hash_string(4),
+		nft = get_hash_string(5),
+		transfer_filter = get_hash_string(6),
+		treasury = get_hash_string(7)
+	)
+}
+
+fn get_setting<'a>(settings: &'a [Setting], label: &str) -> &'a Setting {
+	settings
+		.iter()
+		.find(|setting| setting.label == label)
+		.unwrap_or_else(|| panic!("No setting {}", label))
+}
+
+fn assert_no_diff(setting: &Setting, stored: CLValue) {
+	assert_eq!((setting.render)(Some(stored)).unwrap(), setting.expected, "{}", setting.label);
+}
+
+fn assert_diff(setting: &Setting, stored: Option<CLValue>) {
+	assert_ne!((setting.render)(stored).unwrap(), setting.expected, "{}", setting.label);
+}
@@ -5,6 +5,7 @@ use casper_types::{
 	account::AccountHash,
 	ContractHash,
 	PublicKey,
+	U512,
 };
 use common_lib::{
 	constants::common_keys::{
@@ -12,18 +13,45 @@ use common_lib::{
 		AdministrationArgs,
 		AdministractionStoreKeys,
 		CommonKeys,
+		RegistryArgs,
+		RegistryEndpoints,
+		MAX_MIGRATION_BATCH_SIZE,
 	},
-	enums::contracts_enum::ContractKind,
+	enums::{
+		contracts_enum::ContractKind,
+		price_oracle_contract::PriceType,
+		proposal_status::ProposalStatus,
+		registration_mode::RegistrationMode,
+		roles::Role,
+		topology_issue_kind::TopologyIssueKind,
+	},
+	models::proposal::ProposalAction,
 };
 
 use crate::utils::UnitTestContext;
 
 const CONTRACT_NAME: &str = "administration-contract.wasm";
+/// Built before schema versions were introduced, it keeps the chars min count under its own key
+const CONTRACT_1_0_0_NAME: &str = "1_0_0/administration-contract.wasm";
 
 /// 1. Test allowed extensions - done
 /// 2. Test authority
 /// 3. Test limits
 /// 4. Test contracts list
+/// 5. Test timelocked proposals
+/// 6. Test multisig approvals
+/// 7. Test roles
+/// 8. Test pause
+/// 9. Test extension config
+/// 10. Test shard provisioning
+/// 11. Test domain migration validation
+/// 12. Test contract upgrade notification
+/// 13. Test topology verification
+/// 14. Test timelocked price changes
+/// 15. Test multisig cancellation and role grants
+/// 16. Test system contract role wiring
+/// 17. Test domain migration of a name stored in both shards
+/// 18. Test upgrade migrations
 
 #[test]
 fn should_test_allowed_extensions() {
@@ -58,10 +86,9 @@ fn should_test_not_allowed_extensions_with_authority() {
 
 	ctx.fund_account(0);
 	ctx.fund_account(1);
+	ctx.init();
 
-	ctx.add_user_to_authority_with_maintainer(
-		ctx.accounts.get(0).unwrap().clone()
-	);
+	ctx.grant_role(Role::ExtensionManager, ctx.accounts.get(0).unwrap().clone());
 
 	ctx.fund_account(1);
 
@@ -119,10 +146,17 @@ fn should_test_limits() {
 	let extension = String::from("cspr");
 	ctx.set_min_chars_count(extension.clone(), 10);
 
-	let min_chars_count = ctx.get_min_chars_count(extension);
+	let min_chars_count = ctx.get_min_chars_count(extension.clone());
 
 	assert_eq!(min_chars_count, 10);
 
+	// Min chars count is validated as a part of the extension config
+	let max_chars_count = ctx.get_extension_config(extension.clone()).max_chars_count;
+	ctx.set_min_chars_count_with_result(extension.clone(), 0, false);
+	ctx.set_min_chars_count_with_result(extension.clone(), max_chars_count + 1, false);
+	ctx.set_min_chars_count(extension.clone(), max_chars_count);
+	assert_eq!(ctx.get_min_chars_count(extension), max_chars_count);
+
 	ctx.set_listing_limit(ContractKind::NFTCore, 120);
 	let nft_limit = ctx.get_listing_limit(ContractKind::NFTCore);
 	assert_eq!(nft_limit, 120);
@@ -219,3 +253,876 @@ fn should_test_contract_increment_and_decrement() {
 	assert_eq!(db_contract_result.0, db_contract_hash.into());
 	assert_eq!(db_contract_result.1.unwrap(), 0u32);
 }
+
+#[test]
+fn should_test_timelocked_proposal() {
+	let mut ctx = UnitTestContext::instance(
+		1,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.init();
+
+	let delay = 60_000u64;
+	ctx.set_min_delay(delay, true);
+	assert_eq!(ctx.get_min_delay(), delay);
+
+	// Direct changes are locked once the timelock is active
+	ctx.set_min_delay(1, false);
+
+	let maintainer = ctx.maintainer;
+	let queued_at = 1_000u64;
+	ctx.queue_proposal(
+		&maintainer,
+		ProposalAction::SetAllowedExtensions {
+			extensions: vec![String::from("cspr")],
+		},
+		true,
+		queued_at
+	);
+
+	let proposal = ctx.get_proposal(0);
+	assert_eq!(proposal.eta, queued_at + delay);
+	assert!(proposal.status == ProposalStatus::Queued);
+
+	// Anyone can execute, but only after the delay
+	let executor = *ctx.accounts.get(0).unwrap();
+	ctx.execute_proposal(&executor, 0, false, queued_at + delay - 1);
+	ctx.execute_proposal(&executor, 0, true, queued_at + delay);
+
+	assert!(ctx.get_proposal(0).status == ProposalStatus::Executed);
+	assert_eq!(ctx.get_allowed_extensions(), vec![String::from("cspr")]);
+
+	ctx.execute_proposal(&executor, 0, false, queued_at + delay);
+}
+
+#[test]
+fn should_test_cancelled_proposal() {
+	let mut ctx = UnitTestContext::instance(
+		1,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.init();
+	ctx.set_min_delay(60_000, true);
+
+	let maintainer = ctx.maintainer;
+	let stranger = *ctx.accounts.get(0).unwrap();
+	let action = ProposalAction::SetListingLimit {
+		kind: ContractKind::Database,
+		limit: 10,
+	};
+
+	ctx.queue_proposal(&stranger, action.clone(), false, 1_000);
+	ctx.queue_proposal(&maintainer, action, true, 1_000);
+	ctx.cancel_proposal(0, true);
+
+	assert!(ctx.get_proposal(0).status == ProposalStatus::Cancelled);
+	ctx.execute_proposal(&stranger, 0, false, 100_000);
+	ctx.cancel_proposal(0, false);
+}
+
+#[test]
+fn should_test_multisig_proposal() {
+	let mut ctx = UnitTestContext::instance(
+		3,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.fund_account(1);
+	ctx.fund_account(2);
+	ctx.init();
+
+	let signers: Vec<Key> = ctx.accounts
+		.iter()
+		.map(|account| Key::Account(*account))
+		.collect();
+	ctx.set_signers(signers.clone(), 4, false);
+	ctx.set_signers(signers.clone(), 2, true);
+	assert_eq!(ctx.get_threshold(), 2);
+
+	// Single authority can not change anything directly anymore
+	ctx.set_listing_limit_with_result(ContractKind::Database, 10, false);
+
+	let first = *ctx.accounts.get(0).unwrap();
+	let second = *ctx.accounts.get(1).unwrap();
+	let maintainer = ctx.maintainer;
+
+	ctx.queue_proposal(
+		&maintainer,
+		ProposalAction::SetListingLimit { kind: ContractKind::Database, limit: 10 },
+		false,
+		1_000
+	);
+	ctx.queue_proposal(
+		&first,
+		ProposalAction::SetListingLimit { kind: ContractKind::Database, limit: 10 },
+		true,
+		1_000
+	);
+	assert!(ctx.get_proposal(0).status == ProposalStatus::Pending);
+
+	ctx.approve_proposal(&first, 0, false, 1_000);
+	ctx.approve_proposal(&second, 0, true, 1_000);
+	ctx.revoke_approval(&second, 0, false);
+
+	let proposal = ctx.get_proposal(0);
+	assert!(proposal.status == ProposalStatus::Queued);
+	assert_eq!(proposal.approvals.len(), 2);
+
+	ctx.execute_proposal(&maintainer, 0, true, 1_000);
+	assert_eq!(ctx.get_listing_limit(ContractKind::Database), 10);
+
+	// Signer set rotation goes through the same threshold
+	ctx.queue_proposal(
+		&first,
+		ProposalAction::SetSigners { signers: signers[..2].to_vec(), threshold: 2 },
+		true,
+		2_000
+	);
+	ctx.approve_proposal(&second, 1, true, 2_000);
+	ctx.execute_proposal(&maintainer, 1, true, 2_000);
+
+	let third = *ctx.accounts.get(2).unwrap();
+	ctx.queue_proposal(
+		&third,
+		ProposalAction::SetMinDelay { delay: 1 },
+		false,
+		3_000
+	);
+}
+
+#[test]
+fn should_test_roles() {
+	let mut ctx = UnitTestContext::instance(
+		2,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.fund_account(1);
+	ctx.init();
+
+	let admin = *ctx.accounts.get(0).unwrap();
+	let manager = *ctx.accounts.get(1).unwrap();
+
+	ctx.grant_role(Role::Admin, admin);
+	ctx.grant_role_by(&manager, Role::ShardManager, manager, false);
+	ctx.grant_role_by(&admin, Role::ShardManager, manager, true);
+	assert_eq!(
+		ctx.get_role_members(Role::ShardManager),
+		vec![Key::Account(manager)]
+	);
+
+	// Roles are not interchangeable
+	ctx.set_allowed_extensions(
+		&manager,
+		runtime_args! {
+			"allowed_extensions" => vec![String::from("cspr")],
+		},
+		false
+	);
+
+	ctx.renounce_role(&manager, Role::ShardManager);
+	assert!(ctx.get_role_members(Role::ShardManager).is_empty());
+}
+
+#[test]
+fn should_test_pause() {
+	let mut ctx = UnitTestContext::instance(
+		2,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.fund_account(1);
+	ctx.init();
+
+	let guardian = *ctx.accounts.get(0).unwrap();
+	let stranger = *ctx.accounts.get(1).unwrap();
+
+	ctx.grant_role(Role::Guardian, guardian);
+	ctx.pause(&stranger, None, None, false);
+
+	// Global
+	ctx.pause(&guardian, None, None, true);
+	assert!(ctx.is_scope_paused("global"));
+	ctx.unpause(&guardian, None, None, true);
+	assert!(!ctx.is_scope_paused("global"));
+
+	// Contract kind
+	ctx.pause(&guardian, Some(ContractKind::NFT), None, true);
+	assert!(ctx.is_scope_paused(&ContractKind::NFT.to_string()));
+
+	// Extension
+	ctx.pause(&guardian, None, Some(String::from("cspr")), true);
+	assert!(ctx.is_scope_paused("ext:cspr"));
+	ctx.unpause(&stranger, None, Some(String::from("cspr")), false);
+	ctx.unpause(&guardian, None, Some(String::from("cspr")), true);
+	assert!(!ctx.is_scope_paused("ext:cspr"));
+
+	// Scope is ambiguous
+	ctx.pause(
+		&guardian,
+		Some(ContractKind::Database),
+		Some(String::from("cspr")),
+		false
+	);
+}
+
+#[test]
+fn should_test_extension_config() {
+	let mut ctx = UnitTestContext::instance(
+		2,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.fund_account(1);
+	ctx.init();
+
+	let maintainer = ctx.maintainer;
+	let manager = *ctx.accounts.get(0).unwrap();
+	let stranger = *ctx.accounts.get(1).unwrap();
+	let extension = String::from("cspr");
+
+	ctx.grant_role(Role::ExtensionManager, manager);
+	ctx.set_min_chars_count(extension.clone(), 2);
+
+	ctx.set_extension_config_field(
+		&manager,
+		AdministrationEndpoints::SetMaxSubscriptionYears,
+		runtime_args! {
+			"extension" => Some(extension.clone()),
+			"max_subscription_years" => 5u8,
+		},
+		true
+	);
+	ctx.set_extension_config_field(
+		&manager,
+		AdministrationEndpoints::SetGracePeriod,
+		runtime_args! {
+			"extension" => Some(extension.clone()),
+			"grace_period" => 1000u64,
+		},
+		true
+	);
+	ctx.set_extension_config_field(
+		&manager,
+		AdministrationEndpoints::SetSubdomainLimit,
+		runtime_args! {
+			"extension" => Some(extension.clone()),
+			"subdomain_limit" => 10u8,
+		},
+		true
+	);
+	ctx.set_extension_config_field(
+		&manager,
+		AdministrationEndpoints::SetRegistrationMode,
+		runtime_args! {
+			"extension" => Some(extension.clone()),
+			"registration_mode" => RegistrationMode::Allowlisted,
+		},
+		true
+	);
+	ctx.set_extension_config_field(
+		&manager,
+		AdministrationEndpoints::SetFeeRecipient,
+		runtime_args! {
+			"extension" => Some(extension.clone()),
+			"fee_recipient" => Some(manager),
+		},
+		true
+	);
+
+	// Only extension managers can change the config
+	ctx.set_extension_config_field(
+		&stranger,
+		AdministrationEndpoints::SetGracePeriod,
+		runtime_args! {
+			"extension" => Some(extension.clone()),
+			"grace_period" => 0u64,
+		},
+		false
+	);
+
+	// Max chars count can not be less than min chars count
+	ctx.set_extension_config_field(
+		&maintainer,
+		AdministrationEndpoints::SetMaxCharsCount,
+		runtime_args! {
+			"extension" => Some(extension.clone()),
+			"max_chars_count" => 1u8,
+		},
+		false
+	);
+
+	let config = ctx.get_extension_config(extension.clone());
+	assert_eq!(config.min_chars_count, 2);
+	assert_eq!(config.max_subscription_years, 5);
+	assert_eq!(config.grace_period, 1000);
+	assert_eq!(config.max_subdomain_count, 10);
+	assert!(config.registration_mode == RegistrationMode::Allowlisted);
+	assert_eq!(config.fee_recipient, Some(manager));
+
+	ctx.add_to_allowlist(extension.clone(), stranger);
+	assert_eq!(
+		ctx.get_registration_allowlist(extension),
+		vec![Key::Account(stranger)]
+	);
+}
+
+#[test]
+fn should_test_shard_provisioning() {
+	let mut ctx = UnitTestContext::instance(
+		1,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.init();
+
+	let keeper = *ctx.accounts.get(0).unwrap();
+	let extension = String::from("cspr");
+	let active_hash: Key = ContractHash::from([11; 32]).into();
+	let standby_hash: Key = ContractHash::from([12; 32]).into();
+
+	ctx.set_listing_limit(ContractKind::Database, 1);
+	ctx.add_contract(ContractKind::Database, active_hash, Some(extension.clone()));
+	ctx.add_standby_contract(ContractKind::Database, standby_hash, extension.clone());
+
+	// Active shard still has free space
+	ctx.provision_contract(&keeper, ContractKind::Database, extension.clone(), false);
+
+	ctx.increment_contract(
+		ContractKind::Database,
+		active_hash,
+		Some(extension.clone())
+	);
+	ctx.provision_contract(&keeper, ContractKind::Database, extension.clone(), true);
+
+	let contracts = ctx.get_compound_contracts(
+		ContractKind::Database,
+		extension.clone()
+	);
+	assert_eq!(contracts.len(), 2);
+	assert_eq!(contracts.last().unwrap().key, standby_hash);
+	assert!(
+		ctx.get_standby_contracts(ContractKind::Database, extension.clone()).is_empty()
+	);
+
+	// Pool is empty
+	ctx.increment_contract(
+		ContractKind::Database,
+		standby_hash,
+		Some(extension.clone())
+	);
+	ctx.provision_contract(&keeper, ContractKind::Database, extension, false);
+}
+
+#[test]
+fn should_test_domain_migration_validation() {
+	let mut ctx = UnitTestContext::instance(
+		1,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.init();
+
+	let maintainer = ctx.maintainer;
+	let stranger = *ctx.accounts.get(0).unwrap();
+	let extension = String::from("cspr");
+	let from: Key = ContractHash::from([21; 32]).into();
+	let to: Key = ContractHash::from([22; 32]).into();
+	let unknown: Key = ContractHash::from([23; 32]).into();
+	let names = vec![String::from("bakhrom.cspr")];
+
+	ctx.add_contract(ContractKind::Database, from, Some(extension.clone()));
+	ctx.add_contract(ContractKind::Database, to, Some(extension.clone()));
+
+	// Only maintainer and SHARD_MANAGER role
+	ctx.migrate_domains(
+		&stranger,
+		from,
+		to,
+		extension.clone(),
+		names.clone(),
+		false
+	);
+
+	// Same shard
+	ctx.migrate_domains(
+		&maintainer,
+		from,
+		from,
+		extension.clone(),
+		names.clone(),
+		false
+	);
+
+	// Target is not a shard of the extension
+	ctx.migrate_domains(
+		&maintainer,
+		from,
+		unknown,
+		extension.clone(),
+		names.clone(),
+		false
+	);
+
+	// Name of another extension
+	ctx.migrate_domains(
+		&maintainer,
+		from,
+		to,
+		extension.clone(),
+		vec![String::from("bakhrom.csprx")],
+		false
+	);
+
+	// Batch is too large
+	let batch = (0..=MAX_MIGRATION_BATCH_SIZE)
+		.map(|index| format!("name{}.cspr", index))
+		.collect::<Vec<String>>();
+	ctx.migrate_domains(&maintainer, from, to, extension, batch, false);
+}
+
+#[test]
+fn should_test_contract_upgrade_notification() {
+	let mut ctx = UnitTestContext::instance(
+		1,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.init();
+	ctx.set_allowed_extensions_with_maintainer(
+		runtime_args! {
+			"allowed_extensions" => vec![String::from("cspr")]
+		}
+	);
+
+	let maintainer = ctx.maintainer;
+	let stranger = *ctx.accounts.get(0).unwrap();
+	let extension = String::from("cspr");
+	let registry: Key = ContractHash::from([31; 32]).into();
+	let database: Key = ContractHash::from([32; 32]).into();
+	let standby: Key = ContractHash::from([33; 32]).into();
+	let unknown: Key = ContractHash::from([34; 32]).into();
+
+	ctx.add_contract(ContractKind::Registry, registry, None);
+	ctx.add_contract(ContractKind::Database, database, Some(extension.clone()));
+	ctx.add_standby_contract(ContractKind::Database, standby, extension.clone());
+	ctx.increment_contract(
+		ContractKind::Database,
+		database,
+		Some(extension.clone())
+	);
+
+	let upgraded_registry: Key = ContractHash::from([41; 32]).into();
+	let upgraded_database: Key = ContractHash::from([42; 32]).into();
+	let upgraded_standby: Key = ContractHash::from([43; 32]).into();
+
+	// Only maintainer and SHARD_MANAGER role
+	ctx.contract_upgraded(&stranger, registry, upgraded_registry, false);
+
+	// Previous version is not in the directory
+	ctx.contract_upgraded(&maintainer, unknown, upgraded_registry, false);
+
+	ctx.contract_upgraded(&maintainer, registry, upgraded_registry, true);
+	let (registry_key, _) = ctx.get_contract(ContractKind::Registry, false, None);
+	assert_eq!(registry_key, upgraded_registry);
+
+	// Compound contract keeps the count of stored items
+	ctx.contract_upgraded(&maintainer, database, upgraded_database, true);
+	let contracts = ctx.get_compound_contracts(
+		ContractKind::Database,
+		extension.clone()
+	);
+	assert_eq!(contracts.len(), 1);
+	assert_eq!(contracts[0].key, upgraded_database);
+	assert_eq!(contracts[0].count, Some(1));
+
+	ctx.contract_upgraded(&maintainer, standby, upgraded_standby, true);
+	assert_eq!(
+		ctx.get_standby_contracts(ContractKind::Database, extension),
+		vec![upgraded_standby]
+	);
+}
+
+#[test]
+fn should_test_topology_verification() {
+	let mut ctx = UnitTestContext::instance(
+		1,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.init();
+	let extension = String::from("cspr");
+	ctx.set_allowed_extensions_with_maintainer(
+		runtime_args! {
+			"allowed_extensions" => vec![extension.clone()]
+		}
+	);
+
+	// Empty directory is reported, not reverted, and anyone can check it
+	let stranger = *ctx.accounts.get(0).unwrap();
+	ctx.verify_topology(&stranger, true);
+	let issues = ctx.get_topology_issues();
+	assert_eq!(issues.len(), 8);
+	assert!(issues.iter().all(|issue| issue.kind == TopologyIssueKind::MissingContract));
+	for kind in [ContractKind::Database, ContractKind::NFTCore] {
+		assert!(
+			issues
+				.iter()
+				.any(
+					|issue|
+						issue.contract_kind == kind &&
+						issue.extension == Some(extension.clone())
+				)
+		);
+	}
+
+	// Treasury is wired without the role the other contracts call it by
+	let maintainer = ctx.maintainer;
+	let system = ctx.install_nft_system(&extension);
+	for writer in [system.main, ctx.contract_hash] {
+		ctx.call_by_maintainer(
+			system.registry,
+			&RegistryEndpoints::AddToWhitelist.to_string(),
+			runtime_args! {
+				RegistryArgs::ContractHash.to_string() => writer,
+			}
+		);
+	}
+	let price_oracle: Key = ContractHash::from([51; 32]).into();
+	let treasury: Key = ContractHash::from([52; 32]).into();
+	ctx.add_contract(ContractKind::PriceOracle, price_oracle, None);
+	ctx.add_contract(ContractKind::Treasury, treasury, None);
+	ctx.grant_role_to_key(&maintainer, Role::SystemContract, price_oracle, true);
+
+	let issues = ctx.get_topology_issues();
+	assert_eq!(issues.len(), 2);
+	assert!(
+		issues
+			.iter()
+			.any(
+				|issue|
+					issue.kind == TopologyIssueKind::MissingContract &&
+					issue.contract_kind == ContractKind::Database &&
+					issue.extension == Some(extension.clone())
+			)
+	);
+	assert!(
+		issues
+			.iter()
+			.any(
+				|issue|
+					issue.kind == TopologyIssueKind::MissingAuthority &&
+					issue.contract_kind == ContractKind::Treasury &&
+					issue.key == Some(treasury)
+			)
+	);
+
+	// Every contract is in the directory, holds the role and writes to the registry
+	ctx.grant_role_to_key(&maintainer, Role::SystemContract, treasury, true);
+	let shard = ctx.install_database_shard(&system, &extension);
+	ctx.grant_role_to_key(&maintainer, Role::SystemContract, shard.into(), true);
+	assert!(ctx.get_topology_issues().is_empty());
+
+	// Stored count of the shard no longer matches its totals
+	ctx.increment_contract(ContractKind::Database, shard.into(), Some(extension.clone()));
+	let issues = ctx.get_topology_issues();
+	assert_eq!(issues.len(), 1);
+	let issue = issues.first().unwrap();
+	assert_eq!(issue.kind, TopologyIssueKind::CountMismatch);
+	assert!(issue.contract_kind == ContractKind::Database);
+	assert_eq!(issue.extension, Some(extension));
+	assert_eq!(issue.key, Some(shard.into()));
+	assert_eq!(issue.stored_count, Some(1));
+	assert_eq!(issue.actual_count, Some(0));
+}
+
+#[test]
+fn should_test_timelocked_price_change() {
+	let mut ctx = UnitTestContext::instance(
+		2,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.fund_account(1);
+	ctx.init();
+	let price_oracle = ctx.install_price_oracle();
+
+	let maintainer = ctx.maintainer;
+	let price_setter = *ctx.accounts.get(0).unwrap();
+	let stranger = *ctx.accounts.get(1).unwrap();
+	let extension = String::from("cspr");
+	ctx.grant_role(Role::PriceSetter, price_setter);
+
+	let delay = 60_000u64;
+	ctx.set_min_delay(delay, true);
+
+	// Price oracle accepts price changes only from the administration contract
+	ctx.set_fixed_price(
+		&price_setter,
+		price_oracle,
+		extension.clone(),
+		U512::from(100),
+		false
+	);
+	ctx.set_fixed_price(
+		&maintainer,
+		price_oracle,
+		extension.clone(),
+		U512::from(100),
+		false
+	);
+
+	let action = ProposalAction::SetPrice {
+		extension: extension.clone(),
+		price_type: PriceType::Fixed,
+		price: U512::from(100),
+		price_mid: vec![],
+		chars_count_mid: vec![],
+		price_more: U512::zero(),
+	};
+	ctx.queue_proposal(&stranger, action.clone(), false, 1_000);
+	ctx.queue_proposal(&price_setter, action, true, 1_000);
+
+	// PRICE_SETTER role does not open the other proposals
+	ctx.queue_proposal(
+		&price_setter,
+		ProposalAction::SetMinDelay { delay: 1 },
+		false,
+		1_000
+	);
+
+	ctx.execute_proposal(&stranger, 0, false, 1_000 + delay - 1);
+	assert!(ctx.get_price(price_oracle, &extension).is_none());
+
+	ctx.execute_proposal(&stranger, 0, true, 1_000 + delay);
+	assert!(ctx.get_proposal(0).status == ProposalStatus::Executed);
+	assert_eq!(
+		ctx.get_price(price_oracle, &extension).unwrap().price,
+		U512::from(100)
+	);
+
+	// Simple operations are priced per extension as well
+	let action = ProposalAction::SetPriceSimpleOperations {
+		extension: extension.clone(),
+		price: U512::from(10),
+	};
+	ctx.queue_proposal(&price_setter, action, true, 2_000);
+	ctx.execute_proposal(&stranger, 1, true, 2_000 + delay);
+	assert_eq!(
+		ctx.get_price_simple_operations(price_oracle, &extension),
+		Some(U512::from(10))
+	);
+	assert!(ctx.get_price_simple_operations(price_oracle, "other").is_none());
+}
+
+#[test]
+fn should_test_multisig_cancellation_and_role_grants() {
+	let mut ctx = UnitTestContext::instance(
+		3,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.fund_account(1);
+	ctx.fund_account(2);
+	ctx.init();
+
+	let first = *ctx.accounts.get(0).unwrap();
+	let second = *ctx.accounts.get(1).unwrap();
+	let third = *ctx.accounts.get(2).unwrap();
+	let signers: Vec<Key> = ctx.accounts
+		.iter()
+		.map(|account| Key::Account(*account))
+		.collect();
+	ctx.set_signers(signers, 2, true);
+
+	// Roles and authorities are not changed by a single key anymore
+	let maintainer = ctx.maintainer;
+	ctx.grant_role_by(&maintainer, Role::Admin, first, false);
+	ctx.add_contract_authority(
+		&maintainer,
+		ContractHash::from([61; 32]),
+		Key::Account(first),
+		false
+	);
+
+	let grant = ProposalAction::GrantRole {
+		role: Role::Admin,
+		account: Key::Account(third),
+	};
+	ctx.queue_proposal(&first, grant.clone(), true, 1_000);
+	ctx.approve_proposal(&second, 0, true, 1_000);
+	assert!(ctx.get_proposal(0).status == ProposalStatus::Queued);
+
+	// A single signer other than the proposer only approves the cancellation
+	ctx.cancel_proposal_by(&second, 0, true);
+	assert!(ctx.get_proposal(0).status == ProposalStatus::Queued);
+	ctx.cancel_proposal_by(&second, 0, false);
+	ctx.cancel_proposal_by(&third, 0, true);
+	assert!(ctx.get_proposal(0).status == ProposalStatus::Cancelled);
+
+	// Proposer withdraws its own proposal at once
+	ctx.queue_proposal(&first, grant.clone(), true, 2_000);
+	ctx.cancel_proposal_by(&first, 1, true);
+	assert!(ctx.get_proposal(1).status == ProposalStatus::Cancelled);
+
+	ctx.queue_proposal(&first, grant, true, 3_000);
+	ctx.approve_proposal(&second, 2, true, 3_000);
+	ctx.execute_proposal(&second, 2, true, 3_000);
+	assert_eq!(ctx.get_role_members(Role::Admin), vec![Key::Account(third)]);
+}
+
+#[test]
+fn should_test_system_contract_role_wiring() {
+	let mut ctx = UnitTestContext::instance(
+		1,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.init();
+
+	let maintainer = ctx.maintainer;
+	let extension = String::from("cspr");
+	let main: Key = ContractHash::from([61; 32]).into();
+	let upgraded_main: Key = ContractHash::from([62; 32]).into();
+	let active_shard: Key = ContractHash::from([63; 32]).into();
+	let standby_shard: Key = ContractHash::from([64; 32]).into();
+
+	ctx.add_contract(ContractKind::Main, main, None);
+	ctx.grant_role_to_key(&maintainer, Role::SystemContract, main, true);
+	assert_eq!(ctx.get_role_members(Role::SystemContract), vec![main]);
+
+	// Upgraded contract takes the role of the previous version
+	ctx.contract_upgraded(&maintainer, main, upgraded_main, true);
+	assert_eq!(ctx.get_role_members(Role::SystemContract), vec![upgraded_main]);
+
+	// Promoted shard gets the role of its filled sibling
+	ctx.set_listing_limit(ContractKind::Database, 1);
+	ctx.add_contract(ContractKind::Database, active_shard, Some(extension.clone()));
+	ctx.grant_role_to_key(&maintainer, Role::SystemContract, active_shard, true);
+	ctx.add_standby_contract(ContractKind::Database, standby_shard, extension.clone());
+	ctx.increment_contract(
+		ContractKind::Database,
+		active_shard,
+		Some(extension.clone())
+	);
+	ctx.provision_contract(&maintainer, ContractKind::Database, extension, true);
+	assert_eq!(
+		ctx.get_role_members(Role::SystemContract),
+		vec![upgraded_main, active_shard, standby_shard]
+	);
+}
+
+#[test]
+fn should_test_domain_migration_of_name_in_both_shards() {
+	let mut ctx = UnitTestContext::instance(
+		1,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.init();
+
+	let maintainer = ctx.maintainer;
+	let owner = *ctx.accounts.get(0).unwrap();
+	let extension = String::from("cspr");
+	let system = ctx.install_nft_system(&extension);
+	let from = ctx.install_database_shard(&system, &extension);
+	let to = ctx.install_database_shard(&system, &extension);
+
+	// "bakhrom.cspr" is stored in both shards, "alice.cspr" only in the source
+	for name in ["bakhrom.cspr", "alice.cspr"] {
+		ctx.map_nft_route(&system, name);
+		ctx.save_domain_name(&system, from, name, owner);
+		ctx.increment_contract(
+			ContractKind::Database,
+			from.into(),
+			Some(extension.clone())
+		);
+	}
+	ctx.save_domain_name(&system, to, "bakhrom.cspr", owner);
+	ctx.increment_contract(ContractKind::Database, to.into(), Some(extension.clone()));
+
+	let get_count = |ctx: &UnitTestContext, shard: ContractHash| {
+		ctx.get_compound_contracts(ContractKind::Database, String::from("cspr"))
+			.iter()
+			.find(|item| item.key == Key::from(shard))
+			.and_then(|item| item.count)
+			.unwrap_or_default()
+	};
+	ctx.migrate_domains(
+		&maintainer,
+		from.into(),
+		to.into(),
+		extension,
+		vec![String::from("bakhrom.cspr"), String::from("alice.cspr")],
+		true
+	);
+
+	// Both names left the source, the target already held one of them
+	assert_eq!(get_count(&ctx, from), 0);
+	assert_eq!(get_count(&ctx, to), 2);
+	assert_eq!(ctx.get_database_route(&system, "bakhrom.cspr"), to);
+	assert_eq!(ctx.get_database_route(&system, "alice.cspr"), to);
+}
+
+#[test]
+fn should_test_upgrade_migrations() {
+	let mut ctx = UnitTestContext::instance(
+		0,
+		CONTRACT_1_0_0_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	let extension = String::from("cspr");
+	ctx.set_allowed_extensions_with_maintainer(
+		runtime_args! {
+			"allowed_extensions" => vec![extension.clone()]
+		}
+	);
+	ctx.set_min_chars_count(extension.clone(), 5);
+	ctx.set_listing_limit(ContractKind::Database, 120);
+	let previous_hash = ctx.contract_hash;
+
+	ctx.upgrade_contract(CONTRACT_NAME);
+	assert_ne!(ctx.contract_hash, previous_hash);
+
+	// Every migration step ran
+	assert_eq!(ctx.get_schema_version(), 5);
+	assert_eq!(ctx.get_min_chars_count(extension.clone()), 5);
+	let legacy_key = format!("{}:{}", extension, AdministractionStoreKeys::CharsCount);
+	assert!(
+		ctx.builder
+			.query(None, ctx.contract_hash.into(), &[legacy_key])
+			.is_err()
+	);
+
+	// Stored state of the previous version stays in place
+	assert_eq!(ctx.get_allowed_extensions(), vec![extension.clone()]);
+	assert_eq!(ctx.get_listing_limit(ContractKind::Database), 120);
+
+	// Dictionaries missing in the previous version are created by the migration
+	let maintainer = ctx.maintainer;
+	ctx.queue_proposal(
+		&maintainer,
+		ProposalAction::SetAllowedExtensions {
+			extensions: vec![extension],
+		},
+		true,
+		1_000
+	);
+	assert!(ctx.get_proposal(0).status == ProposalStatus::Queued);
+}
@@ -0,0 +1,41 @@
+use casper_types::{ Key, U256 };
+
+use crate::utils::UnitTestContext;
+
+const CONTRACT_NAME: &str = "cep18_test_contract.wasm";
+const CONTRACT_HASH_KEY: &str = "cep18_test_contract_hash";
+
+/// 1. Test the allowance flow the name service pays through
+
+#[test]
+fn should_test_transfer_from_within_allowance() {
+	let mut ctx = UnitTestContext::instance(2, CONTRACT_NAME, CONTRACT_HASH_KEY);
+	ctx.fund_account(0);
+	ctx.fund_account(1);
+
+	let maintainer = ctx.maintainer;
+	let spender = *ctx.accounts.get(0).unwrap();
+	let stranger = *ctx.accounts.get(1).unwrap();
+	let owner = Key::Account(maintainer);
+	let treasury = Key::Account(stranger);
+	let token = ctx.contract_hash;
+
+	ctx.mint_cep18(token, owner, U256::from(1_000));
+	ctx.approve_cep18(token, &maintainer, Key::Account(spender), U256::from(300));
+	assert_eq!(
+		ctx.get_cep18_allowance(token, owner, Key::Account(spender)),
+		U256::from(300)
+	);
+
+	// Only the approved spender moves the tokens and only up to the allowance
+	ctx.transfer_from_cep18(token, &stranger, owner, treasury, U256::from(100), false);
+	ctx.transfer_from_cep18(token, &spender, owner, treasury, U256::from(200), true);
+	ctx.transfer_from_cep18(token, &spender, owner, treasury, U256::from(200), false);
+
+	assert_eq!(ctx.get_cep18_balance(token, owner), U256::from(800));
+	assert_eq!(ctx.get_cep18_balance(token, treasury), U256::from(200));
+	assert_eq!(
+		ctx.get_cep18_allowance(token, owner, Key::Account(spender)),
+		U256::from(100)
+	);
+}
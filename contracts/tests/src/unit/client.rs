@@ -0,0 +1,497 @@
+use casper_types::{
+	account::AccountHash,
+	bytesrepr::ToBytes,
+	AccessRights,
+	CLType,
+	CLTyped,
+	CLValue,
+	ContractHash,
+	Key,
+	RuntimeArgs,
+	URef,
+	U512,
+};
+use common_lib::{
+	constants::common_keys::{
+		CommonKeys,
+		PriceOracleArgs,
+		RegistryArgs,
+		RegistryEndpoints,
+		KEY_REGISTRY_DOMAIN_CONTRACT_HASH,
+		KEY_REGISTRY_TOKEN_NAME,
+		ENDPOINT_PO_GET_PRICE,
+	},
+	enums::{
+		contracts_enum::ContractKind,
+		registration_mode::RegistrationMode,
+		revenue_stream::RevenueStream,
+		roles::Role,
+		transfer_lock::TransferLock,
+	},
+	models::{
+		proposal::ProposalAction,
+		registry_contract_hash_pair::RegistryContractHashPair,
+		treasury::Beneficiary,
+		DomainName,
+		SubdomainName,
+	},
+	schema::{ self, ContractSchema },
+};
+use cspr_names_client::{
+	decode::{ decode_bytes, decode_cl_value, decode_hex },
+	dictionary,
+	AdministrationClient,
+	CallTarget,
+	ClientError,
+	ContractCall,
+	DatabaseClient,
+	MainClient,
+	NftClient,
+	PriceOracleClient,
+	RegistryClient,
+	TransferFilterClient,
+	TreasuryClient,
+};
+
+use crate::utils::{ deploy_call, query_dictionary_cl_value, query_uref, UnitTestContext };
+
+const CONTRACT_NAME: &str = "registry-contract.wasm";
+
+/// 1. Test calls against the schema
+/// 2. Test rejected calls
+/// 3. Test decoders
+/// 4. Test registry calls
+
+#[test]
+fn should_test_calls_against_schema() {
+	for (contract_schema, calls) in get_calls() {
+		for call in calls {
+			assert_eq!(
+				call.check(&contract_schema),
+				Ok(()),
+				"{}::{}",
+				contract_schema.contract,
+				call.entry_point
+			);
+		}
+	}
+}
+
+#[test]
+fn should_test_rejected_calls() {
+	let price_oracle = PriceOracleClient::new(get_target());
+	let contract_schema = schema::price_oracle::schema();
+
+	let mut call = price_oracle.get_price("cspr".to_string(), 3);
+	call.entry_point = "get_prices".to_string();
+	assert_eq!(
+		call.check(&contract_schema),
+		Err(ClientError::UnknownEntryPoint("get_prices".to_string()))
+	);
+
+	let mut call = price_oracle.get_price("cspr".to_string(), 3);
+	call.args.insert("price_type", 0u8).unwrap();
+	assert_eq!(
+		call.check(&contract_schema),
+		Err(ClientError::UnexpectedArg("price_type".to_string()))
+	);
+
+	// `get_price` takes the chars count as `u64`
+	let mut args = RuntimeArgs::new();
+	args.insert(PriceOracleArgs::Extension.to_string(), "cspr".to_string()).unwrap();
+	args.insert(PriceOracleArgs::CharsCount.to_string(), 3u8).unwrap();
+	let call = ContractCall::new(get_target(), ENDPOINT_PO_GET_PRICE.to_string(), args);
+	assert_eq!(
+		call.check(&contract_schema),
+		Err(ClientError::ArgTypeMismatch {
+			name: PriceOracleArgs::CharsCount.to_string(),
+			expected: CLType::U64,
+			found: CLType::U8,
+		})
+	);
+
+	let mut args = RuntimeArgs::new();
+	args.insert(PriceOracleArgs::Extension.to_string(), "cspr".to_string()).unwrap();
+	let call = ContractCall::new(get_target(), ENDPOINT_PO_GET_PRICE.to_string(), args);
+	assert_eq!(
+		call.check(&contract_schema),
+		Err(ClientError::MissingArg(PriceOracleArgs::CharsCount.to_string()))
+	);
+
+	// Optional arguments of the dynamic price are left out of the fixed one
+	let call = price_oracle.set_fixed_price("cspr".to_string(), U512::from(100));
+	assert_eq!(call.check(&contract_schema), Ok(()));
+}
+
+#[test]
+fn should_test_decoders() {
+	let route = get_route();
+
+	let decoded: RegistryContractHashPair = decode_cl_value(
+		CLValue::from_t(route.clone()).unwrap()
+	).unwrap();
+	assert_eq!(decoded.db_contract_hash, route.db_contract_hash);
+	assert_eq!(decoded.nft_contract_hash, route.nft_contract_hash);
+
+	assert_eq!(
+		decode_cl_value::<u64>(CLValue::from_t(3u8).unwrap()),
+		Err(ClientError::TypeMismatch {
+			expected: CLType::U64,
+			found: CLType::U8,
+		})
+	);
+
+	let mut bytes = U512::from(100).to_bytes().unwrap();
+	assert_eq!(decode_bytes::<U512>(&bytes), Ok(U512::from(100)));
+	let hex = hex::encode(&bytes);
+	assert_eq!(decode_hex::<U512>(&hex), Ok(U512::from(100)));
+	bytes.push(0);
+	assert_eq!(decode_bytes::<U512>(&bytes), Err(ClientError::LeftoverBytes(1)));
+
+	// `Dictionary` stores the items as `Option<T>`
+	let listing = dictionary::listing("token");
+	assert_eq!(
+		listing.decode(CLValue::from_t(Some(U512::from(100))).unwrap()),
+		Ok(Some(U512::from(100)))
+	);
+	assert_eq!(listing.decode(CLValue::from_t(None::<U512>).unwrap()), Ok(None));
+	assert_eq!(
+		listing.decode(CLValue::from_t(U512::from(100)).unwrap()),
+		Ok(Some(U512::from(100)))
+	);
+	assert_eq!(
+		listing.decode(CLValue::from_t(String::from("100")).unwrap()),
+		Err(ClientError::TypeMismatch {
+			expected: Option::<U512>::cl_type(),
+			found: CLType::String,
+		})
+	);
+}
+
+#[test]
+fn should_test_registry_calls() {
+	let mut ctx = UnitTestContext::instance(
+		1,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+
+	let maintainer = ctx.maintainer;
+	let operator = *ctx.accounts.get(0).unwrap();
+	let route = get_route();
+	let registry = RegistryClient::new(CallTarget::ContractHash(ctx.contract_hash));
+
+	let calls = [
+		registry.init(),
+		registry.add_operator(operator),
+		registry.map_domain_name_to_contract_hash(
+			"bakhrom.cspr".to_string(),
+			route.db_contract_hash,
+			route.nft_contract_hash
+		),
+	];
+	for call in calls.iter() {
+		assert_eq!(call.check(&schema::registry::schema()), Ok(()));
+	}
+	deploy_call(&mut ctx.builder, &maintainer, &calls[0], true, None);
+	deploy_call(&mut ctx.builder, &operator, &calls[1], false, None);
+	deploy_call(&mut ctx.builder, &maintainer, &calls[1], true, None);
+	deploy_call(&mut ctx.builder, &operator, &calls[2], true, None);
+
+	let item = dictionary::route("bakhrom.cspr");
+	assert_eq!(item.dictionary_name, KEY_REGISTRY_DOMAIN_CONTRACT_HASH);
+	let uref = query_uref(
+		&ctx.builder,
+		ctx.contract_hash.into(),
+		&[],
+		&item.dictionary_name
+	);
+	let stored = item
+		.decode(query_dictionary_cl_value(&ctx.builder, uref, &item.item_key))
+		.unwrap()
+		.unwrap();
+	assert_eq!(stored.db_contract_hash, route.db_contract_hash);
+	assert_eq!(stored.nft_contract_hash, route.nft_contract_hash);
+	assert_eq!(
+		calls[2].args.get(&RegistryArgs::DomainName.to_string()),
+		Some(&CLValue::from_t(item.item_key.clone()).unwrap())
+	);
+
+	// Routed names are indexed by their nft-core token
+	let item = dictionary::token_name(&dictionary::token_id("bakhrom.cspr"));
+	assert_eq!(item.dictionary_name, KEY_REGISTRY_TOKEN_NAME);
+	let uref = query_uref(
+		&ctx.builder,
+		ctx.contract_hash.into(),
+		&[],
+		&item.dictionary_name
+	);
+	let stored = item
+		.decode(query_dictionary_cl_value(&ctx.builder, uref, &item.item_key))
+		.unwrap();
+	assert_eq!(stored, Some("bakhrom.cspr".to_string()));
+	assert_eq!(calls[0].entry_point, RegistryEndpoints::Init.to_string());
+}
+
+fn get_target() -> CallTarget {
+	CallTarget::ContractHash(ContractHash::from([21; 32]))
+}
+
+fn get_route() -> RegistryContractHashPair {
+	RegistryContractHashPair {
+		db_contract_hash: ContractHash::from([41; 32]),
+		nft_contract_hash: ContractHash::from([42; 32]),
+	}
+}
+
+fn get_calls() -> Vec<(ContractSchema, Vec<ContractCall>)> {
+	let administration = AdministrationClient::new(get_target());
+	let database = DatabaseClient::new(get_target());
+	let main = MainClient::new(get_target());
+	let nft = NftClient::new(get_target());
+	let price_oracle = PriceOracleClient::new(get_target());
+	let registry = RegistryClient::new(get_target());
+	let transfer_filter = TransferFilterClient::new(get_target());
+	let treasury = TreasuryClient::new(get_target());
+
+	let hash = ContractHash::from([31; 32]);
+	let account = AccountHash::new([32; 32]);
+	let key = Key::Account(account);
+	let purse = URef::new([33; 32], AccessRights::READ_ADD_WRITE);
+	let price = U512::from(100);
+	let name = "bakhrom.cspr".to_string();
+	let extension = Some("cspr".to_string());
+	let domain = DomainName {
+		end_time: 0,
+		name: name.clone(),
+		token_id: name.clone(),
+		owner: key,
+		resolver: account,
+	};
+	let subdomain = SubdomainName {
+		name: "sub.bakhrom.cspr".to_string(),
+		resolver: account,
+	};
+
+	vec![
+		(
+			schema::administration::schema(),
+			vec![
+				administration.set_contract_authority_list(hash, vec![key]),
+				administration.add_contract_authority(hash, key),
+				administration.get_contract_authority_list(hash),
+				administration.remove_contract_authority(hash, key),
+				administration.get_contract(ContractKind::Database, extension.clone()),
+				administration.add_contract(
+					ContractKind::Database,
+					key,
+					extension.clone()
+				),
+				administration.increment_contract(
+					ContractKind::Database,
+					key,
+					extension.clone()
+				),
+				administration.decrement_contract(
+					ContractKind::Database,
+					key,
+					extension.clone()
+				),
+				administration.set_allowed_extensions(vec![name.clone()]),
+				administration.get_allowed_extensions(),
+				administration.add_extension(name.clone()),
+				administration.remove_extension(name.clone()),
+				administration.get_chars_min_count(extension.clone()),
+				administration.set_chars_min_count(1, extension.clone()),
+				administration.get_extension_config(extension.clone()),
+				administration.get_max_chars_count(extension.clone()),
+				administration.set_max_chars_count(extension.clone(), 1),
+				administration.get_max_subscription_years(extension.clone()),
+				administration.set_max_subscription_years(extension.clone(), 1),
+				administration.get_grace_period(extension.clone()),
+				administration.set_grace_period(extension.clone(), 1),
+				administration.get_subdomain_limit(extension.clone()),
+				administration.set_subdomain_limit(extension.clone(), 1),
+				administration.get_registration_mode(extension.clone()),
+				administration.set_registration_mode(
+					extension.clone(),
+					RegistrationMode::Allowlisted
+				),
+				administration.get_fee_recipient(extension.clone()),
+				administration.set_fee_recipient(extension.clone(), Some(account)),
+				administration.add_to_allowlist(extension.clone(), key),
+				administration.remove_from_allowlist(extension.clone(), key),
+				administration.is_allowlisted(extension.clone(), key),
+				administration.add_standby_contract(ContractKind::Database, extension.clone(), key),
+				administration.get_standby_contracts(ContractKind::Database, extension.clone()),
+				administration.provision_contract(ContractKind::Database, extension.clone()),
+				administration.migrate_domains(key, key, extension.clone(), vec![name.clone()]),
+				administration.contract_upgraded(key, key),
+				administration.verify_topology(),
+				administration.get_compound_contracts(ContractKind::NFTCore, extension.clone()),
+				administration.get_listing_limit(ContractKind::Database),
+				administration.set_listing_limit(ContractKind::Database, 1),
+				administration.init(),
+				administration.queue_proposal(ProposalAction::AddExtension {
+					extension: name.clone(),
+				}),
+				administration.cancel_proposal(1),
+				administration.execute_proposal(1),
+				administration.get_proposal(1),
+				administration.get_min_delay(),
+				administration.set_min_delay(1),
+				administration.approve_proposal(1),
+				administration.revoke_approval(1),
+				administration.get_signers(),
+				administration.set_signers(vec![key], 1),
+				administration.grant_role(Role::PriceSetter, key),
+				administration.revoke_role(Role::PriceSetter, key),
+				administration.renounce_role(Role::PriceSetter),
+				administration.has_role(Role::PriceSetter, key),
+				administration.get_role_members(Role::PriceSetter),
+				administration.pause(Some(ContractKind::NFT), extension.clone()),
+				administration.unpause(Some(ContractKind::NFT), extension.clone()),
+				administration.is_paused(ContractKind::Database, extension.clone()),
+				administration.set_authorities(vec![key]),
+				administration.add_authority(key),
+				administration.remove_authority(key),
+				administration.get_authorities(),
+				administration.migrate(),
+			]
+		),
+		(
+			schema::database::schema(),
+			vec![
+				database.save_domain_name(domain.clone()),
+				database.save_subdomain_name(name.clone(), subdomain.clone()),
+				database.remove_domain_name(name.clone()),
+				database.remove_subdomain_name(name.clone(), name.clone()),
+				database.set_domain_ownership(name.clone(), key),
+				database.set_domain_expiration(name.clone(), 1),
+				database.set_domain_resolver(name.clone(), account),
+				database.set_subdomain_resolver(name.clone(), account),
+				database.get_domain_list_for_owner(account),
+				database.get_domain_list(1),
+				database.get_subdomain_list(name.clone()),
+				database.get_totals(),
+				database.get_domain(name.clone()),
+				database.get_subdomain(name.clone()),
+				database.export_domains(vec![name.clone()]),
+				database.import_domains(vec![]),
+				database.set_transfer_lock(name.clone(), Some(TransferLock::Escrow)),
+				database.set_transfer_lock(name.clone(), None),
+				database.burn_transfer_permission(name.clone()),
+				database.get_transfer_restrictions(name.clone()),
+				database.init(),
+				database.migrate(),
+			]
+		),
+		(
+			schema::main::schema(),
+			vec![
+				main.set_authorities_contract_hash(hash),
+				main.init(),
+				main.migrate(),
+				main.register_domain(name.clone(), 1, account, price, purse),
+				main.register_domain_with_token(name.clone(), 1, account, price, hash),
+				main.resolve_domain(name.clone()),
+				main.set_resolver_address_for_domain(name.clone(), account),
+				main.register_sub_domain(name.clone(), account),
+				main.remove_subdomain(name.clone()),
+				main.set_resolver_address_for_subdomain(name.clone(), account),
+				main.get_subdomains_for_domain(name.clone()),
+				main.get_domain_list(1),
+				main.extend(name.clone(), 1, price, purse),
+				main.extend_with_token(name.clone(), 1, price, hash),
+			]
+		),
+		(
+			schema::nft::schema(),
+			vec![
+				nft.init(),
+				nft.mint(name.clone(), key, 1),
+				nft.burn(name.clone(), name.clone()),
+				nft.transfer(name.clone(), key, key),
+				nft.list(name.clone(), key, price),
+				nft.un_list(name.clone()),
+				nft.buy(name.clone(), name.clone(), key, price, purse),
+				nft.buy_with_token(name.clone(), name.clone(), key, price, hash),
+				nft.set_token_expiration(name.clone(), 1),
+				nft.set_royalty_policy(name.clone(), 250),
+				nft.set_token_royalty(name.clone(), 500),
+				nft.royalty_info(name.clone(), price),
+				nft.get_owned_tokens(key, 0),
+				nft.start_auction(name.clone(), price, price, 1),
+				nft.cancel_auction(name.clone()),
+				nft.bid(name.clone(), price, purse),
+				nft.settle_auction(name.clone()),
+				nft.get_auction(name.clone()),
+				nft.set_authorities(vec![key]),
+				nft.add_authority(key),
+				nft.remove_authority(key),
+				nft.get_authorities(),
+				nft.migrate(),
+			]
+		),
+		(
+			schema::price_oracle::schema(),
+			vec![
+				price_oracle.init(),
+				price_oracle.get_price(name.clone(), 1),
+				price_oracle.get_price_simple_operations(name.clone()),
+				price_oracle.set_price_simple_operations(name.clone(), price),
+				price_oracle.set_payment_token(hash, price, key),
+				price_oracle.remove_payment_token(hash),
+				price_oracle.get_payment_token(hash),
+				price_oracle.migrate(),
+				price_oracle.set_fixed_price(name.clone(), price),
+				price_oracle.set_dynamic_price(name.clone(), price, vec![price], vec![3], price),
+			]
+		),
+		(
+			schema::registry::schema(),
+			vec![
+				registry.map_domain_name_to_contract_hash(name.clone(), hash, hash),
+				registry.unmap_domain_name(name.clone()),
+				registry.get_contract_hash_for_domain_name(name.clone()),
+				registry.remap_domain_names(vec![name.clone()], hash),
+				registry.resolve_routes(vec![name.clone()]),
+				registry.add_to_whitelist(hash),
+				registry.remove_from_whitelist(hash),
+				registry.get_whitelist(),
+				registry.add_operator(account),
+				registry.remove_operator(account),
+				registry.get_operators(),
+				registry.get_name_for_token(name.clone()),
+				registry.init(),
+				registry.migrate(),
+			]
+		),
+		(
+			schema::transfer_filter::schema(),
+			vec![
+				transfer_filter.can_transfer(key, key, name.clone()),
+				transfer_filter.get_transfer_denial(name.clone()),
+				transfer_filter.migrate(),
+			]
+		),
+		(
+			schema::treasury::schema(),
+			vec![
+				treasury.init(),
+				treasury.deposit(RevenueStream::Registration, price, purse),
+				treasury.set_beneficiaries(
+					RevenueStream::Renewal,
+					vec![Beneficiary { account, share: 10_000 }]
+				),
+				treasury.get_beneficiaries(RevenueStream::MarketplaceCommission),
+				treasury.get_revenue(RevenueStream::Renewal),
+				treasury.get_undistributed(RevenueStream::Registration),
+				treasury.get_balance(RevenueStream::Registration, account),
+				treasury.withdraw(RevenueStream::Registration),
+				treasury.migrate(),
+			]
+		),
+	]
+}
@@ -0,0 +1,99 @@
+use std::collections::BTreeSet;
+
+use casper_types::ApiError;
+use common_lib::{
+	error_codes::{
+		decode_api_error,
+		decode_error_code,
+		error_table,
+		DecodedError,
+		ERROR_RANGES,
+	},
+	enums::transfer_filter::TransferDenial,
+	errors::{ MainContractErrors, NFTErrors, PriceOracleContractErrors },
+};
+
+/// 1. Test error ranges
+/// 2. Test error decoding
+/// 3. Test transfer denial errors
+
+#[test]
+fn should_test_error_ranges() {
+	for (index, range) in ERROR_RANGES.iter().enumerate() {
+		assert!(range.start <= range.end);
+		for other in ERROR_RANGES.iter().skip(index + 1) {
+			assert!(
+				range.end < other.start || other.end < range.start,
+				"{} overlaps {}",
+				range.contract,
+				other.contract
+			);
+		}
+	}
+
+	let mut codes = BTreeSet::new();
+	for entry in error_table() {
+		assert!(codes.insert(entry.code), "{} is declared twice", entry.code);
+		let range = ERROR_RANGES.iter()
+			.find(|range| range.contract == entry.contract)
+			.unwrap();
+		assert!(range.contains(entry.code));
+	}
+}
+
+#[test]
+fn should_test_error_decoding() {
+	// Codes which used to be shared by the main and price oracle contracts
+	let main_error = decode_api_error(MainContractErrors::CannotRemoveMaintainer.into());
+	let price_error = decode_api_error(
+		PriceOracleContractErrors::PriceTypeMismatch.into()
+	);
+	match (main_error, price_error) {
+		(DecodedError::Known(main_entry), DecodedError::Known(price_entry)) => {
+			assert_eq!(main_entry.contract, "main");
+			assert_eq!(main_entry.variant, "CannotRemoveMaintainer");
+			assert_eq!(price_entry.contract, "price-oracle");
+			assert_eq!(price_entry.variant, "PriceTypeMismatch");
+		}
+		_ => panic!("errors should be known"),
+	}
+
+	// Numeric form of execution results
+	let user_error: u32 = ApiError::from(MainContractErrors::DomainAlreadyExists).into();
+	assert_eq!(
+		decode_error_code(user_error),
+		decode_api_error(MainContractErrors::DomainAlreadyExists.into())
+	);
+
+	assert_eq!(
+		decode_api_error(ApiError::User(40)),
+		DecodedError::Reserved { contract: "nft-core", code: 40 }
+	);
+	assert_eq!(decode_api_error(ApiError::User(9000)), DecodedError::Unknown(9000));
+	assert_eq!(
+		decode_api_error(ApiError::MissingKey),
+		DecodedError::System(ApiError::MissingKey)
+	);
+}
+
+#[test]
+fn should_test_transfer_denial_errors() {
+	let denials = [
+		TransferDenial::NameNotFound,
+		TransferDenial::GracePeriod,
+		TransferDenial::Expired,
+		TransferDenial::EscrowLock,
+		TransferDenial::DisputeLock,
+		TransferDenial::TransferPermissionBurned,
+	];
+	let mut codes = BTreeSet::new();
+	for denial in denials {
+		let error = NFTErrors::from(denial);
+		assert!(NFTErrors::RANGE.contains(error as u16));
+		assert!(codes.insert(error as u16), "{:?} shares its error", denial);
+	}
+	assert_eq!(
+		NFTErrors::from(TransferDenial::EscrowLock),
+		NFTErrors::TransferDeniedEscrowLock
+	);
+}
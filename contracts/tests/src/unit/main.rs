@@ -0,0 +1,259 @@
+use casper_types::{ Key, U256, U512 };
+use common_lib::{
+	constants::common_keys::{
+		CommonKeys,
+		DEFAULT_GRACE_PERIOD,
+		MOTES_PER_CSPR,
+		ROYALTY_BASIS_POINTS,
+		YEAR_IN_MILLIS,
+	},
+	enums::{ contracts_enum::ContractKind, revenue_stream::RevenueStream },
+	models::treasury::Beneficiary,
+};
+
+use crate::{ context::main::MainSystem, utils::UnitTestContext };
+
+const CONTRACT_NAME: &str = "administration-contract.wasm";
+
+/// 1. Test renewals in and after the grace period
+/// 2. Test release of expired names, their routes and the count of names in the shards
+/// 3. Test resolver of a transferred name
+/// 4. Test registration and renewal paid in a CEP-18 token
+/// 5. Test subdomains managed by an approved operator
+
+#[test]
+fn should_test_renewals_after_expiration() {
+	let mut ctx = UnitTestContext::instance(
+		1,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.init();
+
+	let owner = *ctx.accounts.get(0).unwrap();
+	let system = ctx.install_main_system("cspr", U512::from(1_000_000_000u64));
+	pay_fees_to_maintainer(&mut ctx, &system);
+
+	ctx.register_domain(&system, &owner, "bakhrom.cspr", 1, true, Some(0));
+	assert_eq!(ctx.get_domain(&system, "bakhrom.cspr").unwrap().end_time, YEAR_IN_MILLIS);
+
+	// Names in the grace period are still renewed by their owner
+	ctx.extend_domain(&system, &owner, "bakhrom.cspr", 1, true, Some(YEAR_IN_MILLIS + 1));
+	assert_eq!(
+		ctx.get_domain(&system, "bakhrom.cspr").unwrap().end_time,
+		2 * YEAR_IN_MILLIS
+	);
+
+	// Expired names can only be registered again
+	let expired_at = 2 * YEAR_IN_MILLIS + DEFAULT_GRACE_PERIOD;
+	ctx.extend_domain(&system, &owner, "bakhrom.cspr", 1, false, Some(expired_at));
+	assert_eq!(
+		ctx.get_domain(&system, "bakhrom.cspr").unwrap().end_time,
+		2 * YEAR_IN_MILLIS
+	);
+	ctx.register_domain(&system, &owner, "bakhrom.cspr", 1, true, Some(expired_at));
+	assert_eq!(
+		ctx.get_domain(&system, "bakhrom.cspr").unwrap().end_time,
+		expired_at + YEAR_IN_MILLIS
+	);
+}
+
+#[test]
+fn should_test_release_of_expired_names() {
+	let mut ctx = UnitTestContext::instance(
+		2,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.fund_account(1);
+	ctx.init();
+
+	let owner = *ctx.accounts.get(0).unwrap();
+	let next_owner = *ctx.accounts.get(1).unwrap();
+	let system = ctx.install_main_system("cspr", U512::from(1_000_000_000u64));
+	pay_fees_to_maintainer(&mut ctx, &system);
+
+	ctx.register_domain(&system, &owner, "bakhrom.cspr", 1, true, Some(0));
+	assert_eq!(get_shard_counts(&ctx, &system), (Some(1), Some(1)));
+
+	// Active names and names in the grace period stay with the owner
+	ctx.release_domain(&system, &next_owner, "bakhrom.cspr", false, Some(YEAR_IN_MILLIS));
+	ctx.release_domain(&system, &next_owner, "bakhrom.cspr", false, Some(YEAR_IN_MILLIS + 1));
+
+	// Anyone releases the expired name, its token is burnt and the record is removed
+	let expired_at = YEAR_IN_MILLIS + DEFAULT_GRACE_PERIOD;
+	ctx.release_domain(&system, &next_owner, "bakhrom.cspr", true, Some(expired_at));
+	assert!(ctx.get_domain(&system, "bakhrom.cspr").is_none());
+	assert_eq!(get_shard_counts(&ctx, &system), (Some(0), Some(0)));
+	assert!(ctx.get_main_route(&system, "bakhrom.cspr").is_none());
+	ctx.release_domain(&system, &next_owner, "bakhrom.cspr", false, Some(expired_at));
+
+	// The same token is minted to the next owner
+	ctx.register_domain(&system, &next_owner, "bakhrom.cspr", 1, true, Some(expired_at));
+	assert_eq!(
+		ctx.get_domain(&system, "bakhrom.cspr").unwrap().owner,
+		Key::Account(next_owner)
+	);
+	assert_eq!(
+		ctx.get_token_owner(&system.nft, "bakhrom.cspr"),
+		Key::Account(next_owner)
+	);
+	assert_eq!(get_shard_counts(&ctx, &system), (Some(1), Some(1)));
+	assert!(ctx.get_main_route(&system, "bakhrom.cspr").is_some());
+}
+
+#[test]
+fn should_test_resolver_of_transferred_name() {
+	let mut ctx = UnitTestContext::instance(
+		2,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.fund_account(1);
+	ctx.init();
+
+	let owner = *ctx.accounts.get(0).unwrap();
+	let buyer = *ctx.accounts.get(1).unwrap();
+	let system = ctx.install_main_system("cspr", U512::from(1_000_000_000u64));
+	pay_fees_to_maintainer(&mut ctx, &system);
+
+	ctx.register_domain(&system, &owner, "bakhrom.cspr", 1, true, None);
+	ctx.approve_nft_contract(&system.nft, &owner);
+	ctx.transfer_name(&system.nft, "bakhrom.cspr", owner, buyer, true);
+
+	// The database still holds the previous owner, nft-core decides
+	let maintainer = ctx.maintainer;
+	ctx.set_resolver(&system, &owner, "bakhrom.cspr", maintainer);
+	assert_eq!(ctx.get_domain(&system, "bakhrom.cspr").unwrap().resolver, owner);
+	ctx.set_resolver(&system, &buyer, "bakhrom.cspr", buyer);
+	assert_eq!(ctx.get_domain(&system, "bakhrom.cspr").unwrap().resolver, buyer);
+}
+
+#[test]
+fn should_test_registration_paid_in_cep18() {
+	let mut ctx = UnitTestContext::instance(
+		2,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.init();
+
+	let maintainer = ctx.maintainer;
+	let owner = *ctx.accounts.get(0).unwrap();
+	let token_treasury = Key::Account(*ctx.accounts.get(1).unwrap());
+	let system = ctx.install_main_system("cspr", U512::from(MOTES_PER_CSPR) * 100);
+	let token = ctx.install_cep18();
+	let spender = ctx.get_package_key(system.main);
+
+	// 2 token units per CSPR, the yearly price is 200 units
+	let amount = U512::from(200);
+	ctx.set_payment_token(
+		&maintainer,
+		system.price_oracle,
+		token,
+		U512::from(2),
+		token_treasury,
+		true
+	);
+	ctx.mint_cep18(token, Key::Account(owner), U256::from(400));
+
+	// The allowance of the main contract is short of the price
+	ctx.approve_cep18(token, &owner, spender, U256::from(199));
+	ctx.register_domain_with_token(&system, &owner, "bakhrom.cspr", amount, token, false);
+	assert!(ctx.get_domain(&system, "bakhrom.cspr").is_none());
+
+	// Amounts other than the converted price are refused
+	ctx.approve_cep18(token, &owner, spender, U256::from(400));
+	ctx.register_domain_with_token(&system, &owner, "bakhrom.cspr", amount / 2, token, false);
+
+	ctx.register_domain_with_token(&system, &owner, "bakhrom.cspr", amount, token, true);
+	assert_eq!(
+		ctx.get_domain(&system, "bakhrom.cspr").unwrap().owner,
+		Key::Account(owner)
+	);
+	assert_eq!(ctx.get_cep18_balance(token, token_treasury), U256::from(200));
+
+	ctx.extend_domain_with_token(&system, &owner, "bakhrom.cspr", amount, token, true);
+	assert_eq!(
+		ctx.get_domain(&system, "bakhrom.cspr").unwrap().end_time,
+		2 * YEAR_IN_MILLIS
+	);
+	assert_eq!(ctx.get_cep18_balance(token, token_treasury), U256::from(400));
+	assert_eq!(ctx.get_cep18_balance(token, Key::Account(owner)), U256::zero());
+	assert_eq!(ctx.get_cep18_allowance(token, Key::Account(owner), spender), U256::zero());
+
+	// Nothing is left to pay the next renewal with
+	ctx.extend_domain_with_token(&system, &owner, "bakhrom.cspr", amount, token, false);
+}
+
+#[test]
+fn should_test_subdomains_of_approved_operators() {
+	let mut ctx = UnitTestContext::instance(
+		2,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.fund_account(1);
+	ctx.init();
+
+	let owner = *ctx.accounts.get(0).unwrap();
+	let custodian = *ctx.accounts.get(1).unwrap();
+	let system = ctx.install_main_system("cspr", U512::from(1_000_000_000u64));
+	pay_fees_to_maintainer(&mut ctx, &system);
+
+	ctx.register_domain(&system, &owner, "bakhrom.cspr", 1, true, None);
+
+	// Subdomains of unknown domains and of names the signer isn't approved for are refused
+	ctx.register_sub_domain(&system, &owner, "sub.missing.cspr", owner, false);
+	ctx.register_sub_domain(&system, &custodian, "sub.bakhrom.cspr", custodian, false);
+	assert!(ctx.get_subdomain(&system, "sub.bakhrom.cspr").is_none());
+
+	// Operator of the owner manages them in the database shard of the domain
+	ctx.approve_operator(&system.nft, &owner, Key::Account(custodian));
+	ctx.register_sub_domain(&system, &custodian, "sub.bakhrom.cspr", owner, true);
+	ctx.register_sub_domain(&system, &custodian, "sub.bakhrom.cspr", owner, false);
+	assert_eq!(ctx.get_subdomain(&system, "sub.bakhrom.cspr").unwrap().resolver, owner);
+
+	ctx.set_subdomain_resolver(&system, &custodian, "sub.bakhrom.cspr", custodian, true);
+	ctx.set_subdomain_resolver(&system, &custodian, "other.bakhrom.cspr", custodian, false);
+	assert_eq!(ctx.get_subdomain(&system, "sub.bakhrom.cspr").unwrap().resolver, custodian);
+
+	ctx.remove_subdomain(&system, &custodian, "sub.bakhrom.cspr", true);
+	ctx.remove_subdomain(&system, &owner, "sub.bakhrom.cspr", false);
+	assert!(ctx.get_subdomain(&system, "sub.bakhrom.cspr").is_none());
+}
+
+/// Helpers
+fn pay_fees_to_maintainer(ctx: &mut UnitTestContext, system: &MainSystem) {
+	let maintainer = ctx.maintainer;
+	for stream in [RevenueStream::Registration, RevenueStream::Renewal] {
+		ctx.set_beneficiaries(
+			system.treasury,
+			stream,
+			vec![Beneficiary { account: maintainer, share: ROYALTY_BASIS_POINTS }],
+			true
+		);
+	}
+}
+
+/// Names counted in the database and nft-core shards of the extension
+fn get_shard_counts(ctx: &UnitTestContext, system: &MainSystem) -> (Option<u32>, Option<u32>) {
+	let (database, database_count) = ctx.get_contract(
+		ContractKind::Database,
+		true,
+		Some("cspr".to_string())
+	);
+	let (nft_core, nft_core_count) = ctx.get_contract(
+		ContractKind::NFTCore,
+		true,
+		Some("cspr".to_string())
+	);
+	assert_eq!(database, Key::from(system.database));
+	assert_eq!(nft_core, Key::from(system.nft.nft_core));
+	(database_count, nft_core_count)
+}
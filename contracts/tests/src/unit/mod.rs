@@ -1,2 +1,24 @@
 #[cfg(test)]
+mod admin_cli;
+#[cfg(test)]
 mod administration;
+#[cfg(test)]
+mod cep18;
+#[cfg(test)]
+mod client;
+#[cfg(test)]
+mod errors;
+#[cfg(test)]
+mod main;
+#[cfg(test)]
+mod models;
+#[cfg(test)]
+mod nft;
+#[cfg(test)]
+mod registry;
+#[cfg(test)]
+mod schema;
+#[cfg(test)]
+mod transfer_filter;
+#[cfg(test)]
+mod treasury;
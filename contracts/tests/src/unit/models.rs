@@ -0,0 +1,381 @@
+use casper_types::{
+	account::AccountHash,
+	bytesrepr::{ FromBytes, ToBytes },
+	CLType,
+	CLTyped,
+	CLValue,
+	ContractHash,
+	Key,
+	U512,
+};
+use common_lib::{
+	enums::{
+		contracts_enum::ContractKind,
+		price_oracle_contract::PriceType,
+		proposal_status::ProposalStatus,
+		registration_mode::RegistrationMode,
+		topology_issue_kind::TopologyIssueKind,
+		transfer_filter::TransferDenial,
+		transfer_lock::TransferLock,
+	},
+	constants::common_keys::MOTES_PER_CSPR,
+	errors::{ MainContractErrors, NFTErrors, PriceOracleContractErrors },
+	models::{
+		confiugration::{ Configuration, ExtensionConfig },
+		contract_hash_item::ContractHashItem,
+		domain_migration::DomainMigrationEntry,
+		payment_token::PaymentToken,
+		price::{ Price, PriceItem },
+		proposal::{ Proposal, ProposalAction },
+		royalty::get_royalty_amount,
+		registry_contract_hash_list::RegistryContractHashList,
+		registry_contract_hash_pair::RegistryContractHashPair,
+		registry_pointer::CompoundContract,
+		topology::TopologyIssue,
+		transfer_restrictions::TransferRestrictions,
+		DomainName,
+		LocalMetadata,
+		SubdomainName,
+	},
+};
+
+/// 1. Test domain models round trip
+/// 2. Test configuration models round trip
+/// 3. Test contract directory models round trip
+/// 4. Test price models round trip
+/// 5. Test CLType layout
+/// 6. Test name length bounds
+/// 7. Test proposal models round trip
+/// 8. Test payment token conversion
+/// 9. Test royalty amounts
+
+#[test]
+fn should_test_domain_models_round_trip() {
+	let domain = get_domain_name();
+	let result = assert_round_trip(&domain);
+	assert_eq!(result.end_time, domain.end_time);
+	assert_eq!(result.name, domain.name);
+	assert_eq!(result.token_id, domain.token_id);
+	assert_eq!(result.owner, domain.owner);
+	assert_eq!(result.resolver, domain.resolver);
+
+	let subdomain = get_subdomain_name();
+	let result = assert_round_trip(&subdomain);
+	assert_eq!(result.name, subdomain.name);
+	assert_eq!(result.resolver, subdomain.resolver);
+
+	let metadata = LocalMetadata { total_count: 42, page: 3 };
+	let result = assert_round_trip(&metadata);
+	assert_eq!(result.total_count, 42);
+	assert_eq!(result.page, 3);
+
+	let entry = DomainMigrationEntry {
+		domain: get_domain_name(),
+		subdomains: vec![get_subdomain_name(), get_subdomain_name()],
+	};
+	let result = assert_round_trip(&entry);
+	assert_eq!(result.domain.name, entry.domain.name);
+	assert_eq!(result.subdomains.len(), 2);
+
+	let restrictions = TransferRestrictions {
+		lock: Some(TransferLock::Dispute),
+		transfer_permission_burned: true,
+	};
+	let result = assert_round_trip(&restrictions);
+	assert!(result.lock == Some(TransferLock::Dispute));
+	assert!(result.transfer_permission_burned);
+
+	let result = assert_round_trip(&TransferRestrictions::default());
+	assert!(result.lock.is_none());
+	assert!(!result.transfer_permission_burned);
+
+	let result = assert_round_trip(&Some(TransferDenial::TransferPermissionBurned));
+	assert_eq!(result, Some(TransferDenial::TransferPermissionBurned));
+}
+
+#[test]
+fn should_test_configuration_models_round_trip() {
+	// Every field has its own value, so reading fields in a wrong order fails
+	let configuration = Configuration {
+		min_allowed_chars_count: 3,
+		max_enitity_count_per_contract: 1000,
+		allowed_extensions: vec!["cspr".to_string(), "test".to_string()],
+		domains_per_page: 20,
+		max_subscription_years: 5,
+	};
+	let result = assert_round_trip(&configuration);
+	assert_eq!(result.min_allowed_chars_count, 3);
+	assert_eq!(result.max_enitity_count_per_contract, 1000);
+	assert_eq!(result.allowed_extensions, configuration.allowed_extensions);
+	assert_eq!(result.domains_per_page, 20);
+	assert_eq!(result.max_subscription_years, 5);
+
+	let extension_config = ExtensionConfig {
+		min_chars_count: 2,
+		max_chars_count: 64,
+		max_subscription_years: 10,
+		grace_period: 86_400_000,
+		max_subdomain_count: 7,
+		registration_mode: RegistrationMode::Allowlisted,
+		fee_recipient: Some(AccountHash::new([9u8; 32])),
+	};
+	let result = assert_round_trip(&extension_config);
+	assert_eq!(result.min_chars_count, 2);
+	assert_eq!(result.max_chars_count, 64);
+	assert_eq!(result.max_subscription_years, 10);
+	assert_eq!(result.grace_period, 86_400_000);
+	assert_eq!(result.max_subdomain_count, 7);
+	assert!(result.registration_mode == RegistrationMode::Allowlisted);
+	assert_eq!(result.fee_recipient, extension_config.fee_recipient);
+
+	let result = assert_round_trip(&ExtensionConfig::default());
+	assert_eq!(result.fee_recipient, None);
+}
+
+#[test]
+fn should_test_contract_directory_models_round_trip() {
+	let item = ContractHashItem {
+		contract_hash: ContractHash::new([1u8; 32]),
+		count: 513,
+	};
+	let result = assert_round_trip(&item);
+	assert_eq!(result.contract_hash, item.contract_hash);
+	assert_eq!(result.count, 513);
+
+	let list = RegistryContractHashList {
+		contract_type: ContractKind::Database,
+		contract_hash_list: vec![ContractHash::new([2u8; 32]), ContractHash::new([3u8; 32])],
+		attr_key: Some("cspr".to_string()),
+	};
+	let result = assert_round_trip(&list);
+	assert!(result.contract_type == ContractKind::Database);
+	assert_eq!(result.contract_hash_list, list.contract_hash_list);
+	assert_eq!(result.attr_key, list.attr_key);
+
+	let pair = RegistryContractHashPair {
+		db_contract_hash: ContractHash::new([4u8; 32]),
+		nft_contract_hash: ContractHash::new([5u8; 32]),
+	};
+	let result = assert_round_trip(&pair);
+	assert_eq!(result.db_contract_hash, pair.db_contract_hash);
+	assert_eq!(result.nft_contract_hash, pair.nft_contract_hash);
+
+	let compound = CompoundContract {
+		key: Key::Hash([6u8; 32]),
+		count: Some(12),
+	};
+	let result = assert_round_trip(&compound);
+	assert_eq!(result.key, compound.key);
+	assert_eq!(result.count, Some(12));
+
+	let mut issue = TopologyIssue::new(
+		TopologyIssueKind::CountMismatch,
+		ContractKind::Database,
+		Some("cspr".to_string()),
+		Some(Key::Hash([7u8; 32]))
+	);
+	issue.related_key = Some(Key::Hash([8u8; 32]));
+	issue.stored_count = Some(10);
+	issue.actual_count = Some(11);
+	let result = assert_round_trip(&issue);
+	assert_eq!(result.kind, TopologyIssueKind::CountMismatch);
+	assert!(result.contract_kind == ContractKind::Database);
+	assert_eq!(result.extension, issue.extension);
+	assert_eq!(result.key, issue.key);
+	assert_eq!(result.related_key, issue.related_key);
+	assert_eq!(result.stored_count, Some(10));
+	assert_eq!(result.actual_count, Some(11));
+}
+
+#[test]
+fn should_test_price_models_round_trip() {
+	let item = PriceItem { char_count: 3, price: U512::from(1_000u64) };
+	let result = assert_round_trip(&item);
+	assert_eq!(result.char_count, 3);
+	assert_eq!(result.price, item.price);
+
+	let price = Price {
+		price_type: PriceType::Dynamic,
+		price: U512::from(500u64),
+		price_by_count: vec![item.clone(), PriceItem { char_count: 4, price: U512::from(700u64) }],
+		price_more: U512::from(100u64),
+	};
+	let result = assert_round_trip(&price);
+	assert!(matches!(result.price_type, PriceType::Dynamic));
+	assert_eq!(result.price, price.price);
+	assert_eq!(result.price_by_count.len(), 2);
+	assert_eq!(result.price_by_count[1].char_count, 4);
+	assert_eq!(result.price_more, price.price_more);
+
+	assert_round_trip(&Price::default());
+}
+
+#[test]
+fn should_test_cl_type_layout() {
+	assert_eq!(
+		LocalMetadata::cl_type(),
+		CLType::Tuple2([Box::new(CLType::U64), Box::new(CLType::U32)])
+	);
+	// u16 is described by its bytes
+	assert_eq!(
+		ContractHashItem::cl_type(),
+		CLType::Tuple2([Box::new(CLType::ByteArray(32)), Box::new(CLType::ByteArray(2))])
+	);
+	// More than three fields are nested into the last tuple element
+	assert_eq!(
+		DomainName::cl_type(),
+		CLType::Tuple3([
+			Box::new(CLType::U64),
+			Box::new(CLType::String),
+			Box::new(
+				CLType::Tuple3([
+					Box::new(CLType::String),
+					Box::new(CLType::Key),
+					Box::new(CLType::ByteArray(32)),
+				])
+			),
+		])
+	);
+	assert_eq!(
+		DomainMigrationEntry::cl_type(),
+		CLType::Tuple2([
+			Box::new(DomainName::cl_type()),
+			Box::new(CLType::List(Box::new(SubdomainName::cl_type()))),
+		])
+	);
+}
+
+#[test]
+fn should_test_name_length_bounds() {
+	let config = ExtensionConfig {
+		min_chars_count: 3,
+		max_chars_count: 5,
+		..ExtensionConfig::default()
+	};
+
+	// Shorter names are reserved for the maintainer
+	assert!(config.validate_name_length(2, false) == Err(MainContractErrors::InvalidCreator));
+	assert!(config.validate_name_length(2, true).is_ok());
+	assert!(config.validate_name_length(3, false).is_ok());
+	assert!(config.validate_name_length(5, false).is_ok());
+	assert!(config.validate_name_length(6, true) == Err(MainContractErrors::InvalidName));
+}
+
+#[test]
+fn should_test_proposal_models_round_trip() {
+	let proposal = Proposal {
+		id: 3,
+		action: ProposalAction::SetExtensionConfig {
+			extension: "cspr".to_string(),
+			config: ExtensionConfig::default(),
+		},
+		proposer: Key::Account(AccountHash::new([1u8; 32])),
+		eta: 1_000,
+		status: ProposalStatus::Queued,
+		approvals: vec![Key::Account(AccountHash::new([2u8; 32]))],
+	};
+	let result = assert_round_trip(&proposal);
+	assert_eq!(result.id, 3);
+	assert_eq!(result.action.to_string(), "set_extension_config");
+	assert!(result.status == ProposalStatus::Queued);
+	assert_eq!(result.approvals, proposal.approvals);
+
+	// Fields of the action are kept as bytes after its tag
+	assert_eq!(
+		ProposalAction::cl_type(),
+		CLType::Tuple2([Box::new(CLType::U8), Box::new(CLType::List(Box::new(CLType::U8)))])
+	);
+
+	// Stored before the bytes of the fields got their length prefix
+	let action = ProposalAction::SetListingLimit {
+		kind: ContractKind::Database,
+		limit: 120,
+	};
+	let bytes = action.to_bytes().unwrap();
+	let mut legacy_bytes = vec![bytes[0]];
+	legacy_bytes.extend(&bytes[5..]);
+	let (result, remainder) = ProposalAction::from_legacy_bytes(&legacy_bytes).unwrap();
+	assert!(remainder.is_empty());
+	assert_eq!(result.to_bytes().unwrap(), bytes);
+	assert!(ProposalAction::from_bytes(&legacy_bytes).is_err());
+
+	// Simple operations carry the extension the price oracle reads
+	let action = ProposalAction::SetPriceSimpleOperations {
+		extension: "cspr".to_string(),
+		price: U512::from(10),
+	};
+	let bytes = action.to_bytes().unwrap();
+	assert_eq!(bytes.len(), action.serialized_length());
+	let (result, remainder) = ProposalAction::from_bytes(&bytes).unwrap();
+	assert!(remainder.is_empty());
+	assert_eq!(result.to_bytes().unwrap(), bytes);
+
+	// Truncated statuses are rejected instead of panicking
+	assert!(ProposalStatus::from_bytes(&[]).is_err());
+}
+
+#[test]
+fn should_test_payment_token_conversion() {
+	let payment_token = PaymentToken {
+		rate: U512::from(2_000_000u64),
+		treasury: Key::Account(AccountHash::new([1u8; 32])),
+	};
+	assert_eq!(
+		payment_token.convert(U512::from(3 * MOTES_PER_CSPR)).unwrap(),
+		U512::from(6_000_000u64)
+	);
+	// Rounds down
+	assert_eq!(payment_token.convert(U512::from(1u64)).unwrap(), U512::zero());
+
+	// Prices too large for the rate fail with the price oracle error
+	assert_eq!(
+		payment_token.convert(U512::MAX).err(),
+		Some(PriceOracleContractErrors::PaymentTokenAmountOverflow)
+	);
+}
+
+#[test]
+fn should_test_royalty_amounts() {
+	// Shares are in basis points, the amount rounds down
+	assert_eq!(get_royalty_amount(U512::from(1_999u64), 500).unwrap(), U512::from(99u64));
+	assert_eq!(get_royalty_amount(U512::from(1_999u64), 0).unwrap(), U512::zero());
+
+	// Sale prices too large for the share fail instead of wrapping
+	assert_eq!(
+		get_royalty_amount(U512::MAX, 500).err(),
+		Some(NFTErrors::RoyaltyAmountOverflow)
+	);
+}
+
+/// Helpers
+/// Checks the bytes and the CLValue round trip, returns the decoded value
+fn assert_round_trip<T: ToBytes + FromBytes + CLTyped + Clone>(value: &T) -> T {
+	let bytes = value.to_bytes().unwrap();
+	assert_eq!(bytes.len(), value.serialized_length());
+
+	let (result, remainder) = T::from_bytes(&bytes).unwrap();
+	assert!(remainder.is_empty());
+	assert_eq!(result.to_bytes().unwrap(), bytes);
+
+	assert!(!matches!(T::cl_type(), CLType::Any));
+	let cl_value = CLValue::from_t(value.clone()).unwrap();
+	assert_eq!(cl_value.inner_bytes(), &bytes);
+	cl_value.into_t::<T>().unwrap()
+}
+
+fn get_domain_name() -> DomainName {
+	DomainName {
+		end_time: 1_700_000_000_000,
+		name: "alice.cspr".to_string(),
+		token_id: "token-1".to_string(),
+		owner: Key::Account(AccountHash::new([1u8; 32])),
+		resolver: AccountHash::new([2u8; 32]),
+	}
+}
+
+fn get_subdomain_name() -> SubdomainName {
+	SubdomainName {
+		name: "pay.alice.cspr".to_string(),
+		resolver: AccountHash::new([3u8; 32]),
+	}
+}
@@ -0,0 +1,474 @@
+use std::collections::BTreeMap;
+
+use casper_types::{ Key, U256, U512 };
+use common_lib::{
+	constants::common_keys::{
+		get_custom_metadata_schema,
+		CommonKeys,
+		AUCTION_EXTENSION_WINDOW,
+		MAX_CREATOR_ROYALTY_SHARE,
+		MAX_PAGE_SIZE,
+		MAX_PROTOCOL_ROYALTY_SHARE,
+		MIN_AUCTION_DURATION,
+		MOTES_PER_CSPR,
+		ROYALTY_BASIS_POINTS,
+		YEAR_IN_MILLIS,
+	},
+	enums::{ contracts_enum::ContractKind, revenue_stream::RevenueStream, roles::Role },
+	models::treasury::Beneficiary,
+};
+
+use crate::{ context::nft::{ get_token_id, NftSystem }, utils::UnitTestContext };
+
+const CONTRACT_NAME: &str = "administration-contract.wasm";
+
+/// 1. Test paused extension
+/// 2. Test nft-core shard provisioning
+/// 3. Test royalties of a sale
+/// 4. Test owned tokens of every shard
+/// 5. Test sale paid in a CEP-18 token
+/// 6. Test token metadata
+/// 7. Test royalty shares
+/// 8. Test auctions
+
+#[test]
+fn should_test_paused_extension() {
+	let mut ctx = UnitTestContext::instance(
+		2,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.fund_account(1);
+	ctx.init();
+
+	let guardian = *ctx.accounts.get(0).unwrap();
+	let owner = *ctx.accounts.get(1).unwrap();
+	let extension = String::from("cspr");
+	let price = U512::from(10_000_000_000u64);
+
+	let system = ctx.install_nft_system(&extension);
+	ctx.mint_name(&system, "bakhrom.cspr", owner, YEAR_IN_MILLIS);
+	ctx.grant_role(Role::Guardian, guardian);
+
+	// Sales of the paused extension are stopped
+	ctx.pause(&guardian, None, Some(extension.clone()), true);
+	ctx.list_name(&system, "bakhrom.cspr", owner, price, false);
+	ctx.start_auction_by(
+		&system,
+		&owner,
+		"bakhrom.cspr",
+		MIN_AUCTION_DURATION,
+		false
+	);
+	assert_eq!(ctx.get_listing_price(&system, "bakhrom.cspr"), None);
+
+	ctx.unpause(&guardian, None, Some(extension), true);
+	ctx.list_name(&system, "bakhrom.cspr", owner, price, true);
+	assert_eq!(ctx.get_listing_price(&system, "bakhrom.cspr"), Some(price));
+}
+
+#[test]
+fn should_test_nft_core_shard_provisioning() {
+	let mut ctx = UnitTestContext::instance(
+		1,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.init();
+
+	let owner = *ctx.accounts.get(0).unwrap();
+	let extension = String::from("cspr");
+
+	let system = ctx.install_nft_system(&extension);
+	let shard = ctx.install_nft_core_shard(&system, "cspr-names-2");
+	assert!(!ctx.is_acl_whitelisted(shard, system.nft));
+
+	ctx.set_listing_limit(ContractKind::NFTCore, 1);
+	ctx.increment_contract(
+		ContractKind::NFTCore,
+		system.nft_core.into(),
+		Some(extension.clone())
+	);
+	ctx.add_standby_contract(ContractKind::NFTCore, shard.into(), extension.clone());
+	ctx.provision_contract(&owner, ContractKind::NFTCore, extension, true);
+	assert!(ctx.is_acl_whitelisted(shard, system.nft));
+
+	// Names routed to the promoted shard are minted by the nft contract
+	let shard_system = NftSystem {
+		nft_core: shard,
+		..system
+	};
+	ctx.mint_name(&shard_system, "bakhrom.cspr", owner, YEAR_IN_MILLIS);
+}
+
+#[test]
+fn should_test_royalties_of_a_sale() {
+	let mut ctx = UnitTestContext::instance(
+		2,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.fund_account(1);
+	ctx.init();
+
+	let maintainer = ctx.maintainer;
+	let creator = *ctx.accounts.get(0).unwrap();
+	let seller = *ctx.accounts.get(1).unwrap();
+	let extension = String::from("cspr");
+	let price = U512::from(100_000_000_000u64);
+
+	let system = ctx.install_nft_system(&extension);
+	let shard = ctx.install_database_shard(&system, &extension);
+	let treasury = ctx.install_treasury();
+	ctx.set_beneficiaries(
+		treasury,
+		RevenueStream::MarketplaceCommission,
+		vec![Beneficiary { account: maintainer, share: ROYALTY_BASIS_POINTS }],
+		true
+	);
+	ctx.mint_stored_name(&system, shard, "bakhrom.cspr", creator);
+
+	// 5% for the protocol, 10% for the creator, only the creator sets its share
+	ctx.set_royalty_policy(&extension, &system, 500, true);
+	ctx.set_token_royalty(&system, &seller, "bakhrom.cspr", 1_000, false);
+	ctx.set_token_royalty(&system, &creator, "bakhrom.cspr", 1_000, true);
+
+	ctx.approve_nft_contract(&system, &creator);
+	ctx.transfer_name(&system, "bakhrom.cspr", creator, seller, true);
+	ctx.approve_nft_contract(&system, &seller);
+	// The creator does not own the token anymore
+	ctx.list_name(&system, "bakhrom.cspr", creator, price, false);
+	ctx.list_name(&system, "bakhrom.cspr", seller, price, true);
+
+	// Marketplaces are told the same receivers `buy` pays, the protocol share goes to the treasury
+	let protocol_amount = price / 20;
+	let creator_amount = price / 10;
+	assert_eq!(
+		ctx.royalty_info(&system, "bakhrom.cspr", price),
+		vec![
+			(Key::from(treasury), protocol_amount),
+			(Key::Account(creator), creator_amount)
+		]
+	);
+
+	ctx.fund_maintainer();
+	let creator_balance = ctx.get_account_balance(&creator);
+	let seller_balance = ctx.get_account_balance(&seller);
+	ctx.buy_name(&system, "bakhrom.cspr", seller, price, true);
+
+	assert_eq!(
+		ctx.get_revenue(treasury, RevenueStream::MarketplaceCommission),
+		protocol_amount
+	);
+	assert_eq!(
+		ctx.get_treasury_balance(treasury, RevenueStream::MarketplaceCommission, maintainer),
+		protocol_amount
+	);
+	assert_eq!(ctx.get_account_balance(&creator), creator_balance + creator_amount);
+	assert_eq!(
+		ctx.get_account_balance(&seller),
+		seller_balance + price - protocol_amount - creator_amount
+	);
+	assert_eq!(ctx.get_token_owner(&system, "bakhrom.cspr"), Key::Account(maintainer));
+	assert_eq!(ctx.get_listing_price(&system, "bakhrom.cspr"), None);
+}
+
+#[test]
+fn should_test_owned_tokens_of_every_shard() {
+	let mut ctx = UnitTestContext::instance(
+		1,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.init();
+
+	let owner = *ctx.accounts.get(0).unwrap();
+	let extension = String::from("cspr");
+
+	let system = ctx.install_nft_system(&extension);
+	let shard = ctx.install_nft_core_shard(&system, "cspr-names-2");
+	ctx.set_listing_limit(ContractKind::NFTCore, 1);
+	ctx.increment_contract(
+		ContractKind::NFTCore,
+		system.nft_core.into(),
+		Some(extension.clone())
+	);
+	ctx.add_standby_contract(ContractKind::NFTCore, shard.into(), extension.clone());
+	ctx.provision_contract(&owner, ContractKind::NFTCore, extension, true);
+	let shard_system = NftSystem {
+		nft_core: shard,
+		..system
+	};
+
+	// 3 names in the first shard, 9 in the promoted one
+	let names: Vec<String> = (0..12).map(|index| format!("name{}.cspr", index)).collect();
+	for (index, name) in names.iter().enumerate() {
+		if index % 4 == 0 {
+			ctx.fund_maintainer();
+		}
+		let system = if index < 3 { &system } else { &shard_system };
+		ctx.mint_name(system, name, owner, YEAR_IN_MILLIS);
+	}
+	ctx.fund_maintainer();
+
+	// Pages go through the shards in the order of the directory
+	let expected: Vec<(String, Option<String>)> = names
+		.iter()
+		.map(|name| (get_token_id(name), Some(name.clone())))
+		.collect();
+	assert_eq!(ctx.get_owned_tokens(&system, owner, 0), expected[..MAX_PAGE_SIZE].to_vec());
+	assert_eq!(ctx.get_owned_tokens(&system, owner, 1), expected[MAX_PAGE_SIZE..].to_vec());
+	assert!(ctx.get_owned_tokens(&system, owner, 2).is_empty());
+}
+
+#[test]
+fn should_test_sale_paid_in_cep18() {
+	let mut ctx = UnitTestContext::instance(
+		3,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.fund_account(1);
+	ctx.init();
+
+	let maintainer = ctx.maintainer;
+	let creator = *ctx.accounts.get(0).unwrap();
+	let seller = *ctx.accounts.get(1).unwrap();
+	let token_treasury = Key::Account(*ctx.accounts.get(2).unwrap());
+	let extension = String::from("cspr");
+	let price = U512::from(MOTES_PER_CSPR) * 100 + MOTES_PER_CSPR / 3;
+
+	let system = ctx.install_nft_system(&extension);
+	let shard = ctx.install_database_shard(&system, &extension);
+	let price_oracle = ctx.install_price_oracle();
+	let token = ctx.install_cep18();
+	let spender = ctx.get_package_key(system.nft);
+	ctx.mint_stored_name(&system, shard, "bakhrom.cspr", creator);
+
+	// 2 token units per CSPR, fractions of a unit are rounded down, the listing is paid with 200 units
+	let amount = U512::from(200);
+	ctx.set_payment_token(&maintainer, price_oracle, token, U512::from(2), token_treasury, true);
+	ctx.mint_cep18(token, Key::Account(maintainer), U256::from(200));
+
+	// 5% for the protocol, 10% for the creator
+	ctx.set_royalty_policy(&extension, &system, 500, true);
+	ctx.set_token_royalty(&system, &creator, "bakhrom.cspr", 1_000, true);
+	ctx.approve_nft_contract(&system, &creator);
+	ctx.transfer_name(&system, "bakhrom.cspr", creator, seller, true);
+	ctx.approve_nft_contract(&system, &seller);
+	ctx.list_name(&system, "bakhrom.cspr", seller, price, true);
+	ctx.fund_maintainer();
+
+	// The allowance of the nft contract is short of the converted price
+	ctx.approve_cep18(token, &maintainer, spender, U256::from(199));
+	ctx.buy_name_with_token(&system, "bakhrom.cspr", seller, amount, token, false);
+	assert_eq!(ctx.get_token_owner(&system, "bakhrom.cspr"), Key::Account(seller));
+	assert_eq!(ctx.get_cep18_balance(token, Key::Account(maintainer)), U256::from(200));
+
+	// Amounts other than the converted price are refused
+	ctx.approve_cep18(token, &maintainer, spender, U256::from(200));
+	ctx.buy_name_with_token(&system, "bakhrom.cspr", seller, price, token, false);
+
+	// The protocol share goes to the treasury of the token
+	ctx.buy_name_with_token(&system, "bakhrom.cspr", seller, amount, token, true);
+	assert_eq!(ctx.get_cep18_balance(token, token_treasury), U256::from(10));
+	assert_eq!(ctx.get_cep18_balance(token, Key::Account(creator)), U256::from(20));
+	assert_eq!(ctx.get_cep18_balance(token, Key::Account(seller)), U256::from(170));
+	assert_eq!(ctx.get_cep18_balance(token, Key::Account(maintainer)), U256::zero());
+	assert_eq!(ctx.get_token_owner(&system, "bakhrom.cspr"), Key::Account(maintainer));
+	assert_eq!(ctx.get_listing_price(&system, "bakhrom.cspr"), None);
+}
+
+#[test]
+fn should_test_token_metadata() {
+	let mut ctx = UnitTestContext::instance(
+		1,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.init();
+
+	let owner = *ctx.accounts.get(0).unwrap();
+	let system = ctx.install_nft_system("cspr");
+	ctx.mint_name(&system, "alice99.cspr", owner, YEAR_IN_MILLIS);
+
+	// nft-core holds the custom validated metadata as a map of strings
+	let metadata = ctx.get_token_metadata(&system, "alice99.cspr");
+	let attributes: BTreeMap<String, String> = serde_json_wasm::from_str(&metadata).unwrap();
+	assert_eq!(attributes["name"], "alice99.cspr");
+	assert_eq!(attributes["label_length"], "7");
+	assert_eq!(attributes["character_set"], "alphanumeric");
+	assert_eq!(attributes["expiration_date"], YEAR_IN_MILLIS.to_string());
+	assert!(attributes["image"].starts_with("data:image/svg+xml;base64,"));
+
+	// Every property of the installed schema is required and present
+	let schema = serde_json_wasm::to_string(&get_custom_metadata_schema()).unwrap();
+	let schema: BTreeMap<String, BTreeMap<String, MetadataProperty>> =
+		serde_json_wasm::from_str(&schema).unwrap();
+	assert_eq!(
+		schema["properties"].keys().collect::<Vec<_>>(),
+		attributes.keys().collect::<Vec<_>>()
+	);
+	assert!(schema["properties"].values().all(|property| property.required));
+
+	// Renewals update the expiration date, the image depends on the name only
+	ctx.set_token_expiration(&system, "alice99.cspr", YEAR_IN_MILLIS * 2, true);
+	let renewed: BTreeMap<String, String> = serde_json_wasm
+		::from_str(&ctx.get_token_metadata(&system, "alice99.cspr"))
+		.unwrap();
+	assert_eq!(renewed["expiration_date"], (YEAR_IN_MILLIS * 2).to_string());
+	assert_eq!(renewed["registration_date"], attributes["registration_date"]);
+	assert_eq!(renewed["image"], attributes["image"]);
+
+	// Only minted names have metadata
+	ctx.set_token_expiration(&system, "bakhrom.cspr", YEAR_IN_MILLIS * 2, false);
+
+	// Labels are described by the characters they are made of
+	for (name, character_set) in [
+		("12345.cspr", "digits"),
+		("alice.cspr", "letters"),
+		("alice-99.cspr", "mixed"),
+	] {
+		ctx.mint_name(&system, name, owner, YEAR_IN_MILLIS);
+		let attributes: BTreeMap<String, String> = serde_json_wasm
+			::from_str(&ctx.get_token_metadata(&system, name))
+			.unwrap();
+		assert_eq!(attributes["character_set"], character_set);
+	}
+}
+
+#[test]
+fn should_test_royalty_shares() {
+	let mut ctx = UnitTestContext::instance(
+		1,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.init();
+
+	let maintainer = ctx.maintainer;
+	let creator = *ctx.accounts.get(0).unwrap();
+	let extension = String::from("cspr");
+	let system = ctx.install_nft_system(&extension);
+	let treasury = ctx.install_treasury();
+	ctx.mint_name(&system, "bakhrom.cspr", creator, YEAR_IN_MILLIS);
+
+	// Shares are capped, so they never take the whole price
+	ctx.set_royalty_policy(&extension, &system, MAX_PROTOCOL_ROYALTY_SHARE + 1, false);
+	ctx.set_token_royalty(&system, &creator, "bakhrom.cspr", MAX_CREATOR_ROYALTY_SHARE + 1, false);
+	ctx.set_royalty_policy(&extension, &system, MAX_PROTOCOL_ROYALTY_SHARE, true);
+	ctx.set_token_royalty(&system, &creator, "bakhrom.cspr", MAX_CREATOR_ROYALTY_SHARE, true);
+	let sale_price = U512::from(ROYALTY_BASIS_POINTS);
+	let royalties = ctx
+		.royalty_info(&system, "bakhrom.cspr", sale_price)
+		.iter()
+		.fold(U512::zero(), |total, (_, amount)| total + *amount);
+	assert!(royalties < sale_price);
+
+	// Amounts are rounded down, zero amounts are left out and the remainder goes to the seller
+	ctx.set_royalty_policy(&extension, &system, 250, true);
+	ctx.set_token_royalty(&system, &creator, "bakhrom.cspr", 1_000, true);
+	assert_eq!(
+		ctx.royalty_info(&system, "bakhrom.cspr", U512::from(39)),
+		vec![(Key::Account(creator), U512::from(3))]
+	);
+	assert_eq!(
+		ctx.royalty_info(&system, "bakhrom.cspr", U512::from(41)),
+		vec![(Key::from(treasury), U512::one()), (Key::Account(creator), U512::from(4))]
+	);
+
+	// Zero share switches the protocol royalty of the extension off
+	ctx.set_royalty_policy(&extension, &system, 0, true);
+	assert_eq!(
+		ctx.royalty_info(&system, "bakhrom.cspr", U512::from(1_000)),
+		vec![(Key::Account(creator), U512::from(100))]
+	);
+}
+
+#[test]
+fn should_test_auctions() {
+	let mut ctx = UnitTestContext::instance(
+		3,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.fund_account(1);
+	ctx.fund_account(2);
+	ctx.init();
+
+	let maintainer = ctx.maintainer;
+	let seller = *ctx.accounts.get(0).unwrap();
+	let first = *ctx.accounts.get(1).unwrap();
+	let second = *ctx.accounts.get(2).unwrap();
+	let extension = String::from("cspr");
+	let system = ctx.install_nft_system(&extension);
+	let shard = ctx.install_database_shard(&system, &extension);
+	let treasury = ctx.install_treasury();
+	ctx.mint_stored_name(&system, shard, "bakhrom.cspr", seller);
+	ctx.approve_nft_contract(&system, &seller);
+	ctx.set_royalty_policy(&extension, &system, 500, true);
+
+	// Reserve price of 1000 and increments of 100, see `start_auction_by`
+	ctx.start_auction_by(&system, &seller, "bakhrom.cspr", MIN_AUCTION_DURATION - 1, false);
+	ctx.start_auction_by(&system, &seller, "bakhrom.cspr", MIN_AUCTION_DURATION, true);
+	ctx.list_name(&system, "bakhrom.cspr", seller, U512::from(1_000), false);
+	ctx.bid(&system, &seller, "bakhrom.cspr", U512::from(1_000), false, None);
+
+	// The first bid reaches the reserve price, the next ones outbid by the increment
+	ctx.bid(&system, &first, "bakhrom.cspr", U512::from(999), false, None);
+	ctx.bid(&system, &first, "bakhrom.cspr", U512::from(1_000), true, None);
+	ctx.cancel_auction(&system, &seller, "bakhrom.cspr", false);
+	ctx.bid(&system, &second, "bakhrom.cspr", U512::from(1_099), false, None);
+
+	// The outbid bidder is refunded, bids in the last window extend the auction
+	let first_balance = ctx.get_account_balance(&first);
+	let end_time = MIN_AUCTION_DURATION - 1 + AUCTION_EXTENSION_WINDOW;
+	ctx.bid(
+		&system,
+		&second,
+		"bakhrom.cspr",
+		U512::from(1_100),
+		true,
+		Some(MIN_AUCTION_DURATION - 1)
+	);
+	assert_eq!(ctx.get_account_balance(&first), first_balance + U512::from(1_000));
+	let auction = ctx.get_auction(&system, "bakhrom.cspr").unwrap();
+	assert_eq!(auction.highest_bidder, Some(second));
+	assert_eq!(auction.highest_bid, U512::from(1_100));
+	assert_eq!(auction.end_time, end_time);
+
+	ctx.settle_auction(&system, "bakhrom.cspr", false, Some(MIN_AUCTION_DURATION));
+	ctx.bid(&system, &first, "bakhrom.cspr", U512::from(1_200), false, Some(end_time));
+
+	// The winner gets the token, the treasury the protocol share and the seller the rest of the highest bid
+	let seller_balance = ctx.get_account_balance(&seller);
+	ctx.settle_auction(&system, "bakhrom.cspr", true, Some(end_time));
+	assert_eq!(ctx.get_token_owner(&system, "bakhrom.cspr"), Key::Account(second));
+	assert_eq!(
+		ctx.get_revenue(treasury, RevenueStream::MarketplaceCommission),
+		U512::from(55)
+	);
+	assert_eq!(ctx.get_account_balance(&seller), seller_balance + U512::from(1_045));
+	assert!(ctx.get_auction(&system, "bakhrom.cspr").is_none());
+
+	// Operators of the seller cancel an auction without bids as well
+	ctx.approve_nft_contract(&system, &second);
+	ctx.start_auction_by(&system, &second, "bakhrom.cspr", MIN_AUCTION_DURATION, true);
+	ctx.cancel_auction(&system, &seller, "bakhrom.cspr", false);
+	ctx.approve_operator(&system, &second, Key::Account(first));
+	ctx.cancel_auction(&system, &first, "bakhrom.cspr", true);
+	assert!(ctx.get_auction(&system, "bakhrom.cspr").is_none());
+}
+
+/// Helpers
+#[derive(serde::Deserialize)]
+struct MetadataProperty {
+	required: bool,
+}
@@ -0,0 +1,107 @@
+use casper_types::ContractHash;
+use common_lib::{
+	constants::common_keys::{ CommonKeys, MAX_RESOLVE_ROUTES_SIZE },
+	models::registry_contract_hash_pair::RegistryContractHashPair,
+};
+
+use crate::utils::UnitTestContext;
+
+const CONTRACT_NAME: &str = "registry-contract.wasm";
+
+/// 1. Test whitelist and operators
+/// 2. Test routes
+
+#[test]
+fn should_test_whitelist_and_operators() {
+	let mut ctx = UnitTestContext::instance(
+		2,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.fund_account(1);
+	ctx.init_registry();
+
+	let maintainer = ctx.maintainer;
+	let operator = *ctx.accounts.get(0).unwrap();
+	let stranger = *ctx.accounts.get(1).unwrap();
+	let main_hash = ContractHash::from([31; 32]);
+
+	// Only maintainer manages the whitelist and operators
+	ctx.add_to_whitelist(&stranger, main_hash, false);
+	ctx.add_to_whitelist(&maintainer, main_hash, true);
+	assert_eq!(ctx.get_whitelist(), vec![main_hash]);
+
+	ctx.add_registry_operator(&stranger, operator, false);
+	ctx.add_registry_operator(&maintainer, operator, true);
+	ctx.add_registry_operator(&maintainer, operator, false);
+	assert_eq!(ctx.get_registry_operators(), vec![operator]);
+
+	ctx.remove_registry_operator(&maintainer, operator, true);
+	ctx.remove_registry_operator(&maintainer, operator, false);
+	assert!(ctx.get_registry_operators().is_empty());
+}
+
+#[test]
+fn should_test_routes() {
+	let mut ctx = UnitTestContext::instance(
+		2,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.fund_account(1);
+	ctx.init_registry();
+
+	let maintainer = ctx.maintainer;
+	let operator = *ctx.accounts.get(0).unwrap();
+	let stranger = *ctx.accounts.get(1).unwrap();
+	let db_hash = ContractHash::from([41; 32]);
+	let nft_hash = ContractHash::from([42; 32]);
+	let new_db_hash = ContractHash::from([43; 32]);
+	let route = RegistryContractHashPair {
+		db_contract_hash: db_hash,
+		nft_contract_hash: nft_hash,
+	};
+
+	// Only maintainer, operators and whitelisted contracts write routes
+	ctx.map_route(&stranger, "bakhrom.cspr", route.clone(), false);
+	ctx.add_registry_operator(&maintainer, operator, true);
+	ctx.map_route(&operator, "bakhrom.cspr", route, true);
+
+	let stored = ctx.get_route("bakhrom.cspr");
+	assert_eq!(stored.db_contract_hash, db_hash);
+	assert_eq!(stored.nft_contract_hash, nft_hash);
+
+	// Remapping keeps the NFT contract
+	ctx.remap_routes(
+		&stranger,
+		vec![String::from("bakhrom.cspr")],
+		new_db_hash,
+		false
+	);
+	ctx.remap_routes(
+		&operator,
+		vec![String::from("bakhrom.cspr"), String::from("missing.cspr")],
+		new_db_hash,
+		true
+	);
+	let stored = ctx.get_route("bakhrom.cspr");
+	assert_eq!(stored.db_contract_hash, new_db_hash);
+	assert_eq!(stored.nft_contract_hash, nft_hash);
+
+	// Unmapping drops the route, unknown names are refused
+	ctx.unmap_route(&stranger, "bakhrom.cspr", false);
+	ctx.unmap_route(&operator, "missing.cspr", false);
+	ctx.unmap_route(&operator, "bakhrom.cspr", true);
+	assert!(ctx.find_route("bakhrom.cspr").is_none());
+	ctx.map_route(&operator, "bakhrom.cspr", stored, true);
+	assert_eq!(ctx.get_route("bakhrom.cspr").db_contract_hash, new_db_hash);
+
+	// Bulk query is open and bounded
+	ctx.resolve_routes(&stranger, vec![String::from("bakhrom.cspr")], true);
+	let names = (0..=MAX_RESOLVE_ROUTES_SIZE)
+		.map(|index| format!("name{}.cspr", index))
+		.collect::<Vec<String>>();
+	ctx.resolve_routes(&stranger, names, false);
+}
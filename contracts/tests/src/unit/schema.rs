@@ -0,0 +1,211 @@
+use std::collections::{ BTreeMap, BTreeSet };
+
+use casper_types::{ CLType, CLTyped, U512 };
+use common_lib::{
+	constants::common_keys::{
+		CommonEndpoints,
+		PriceOracleArgs,
+		ENDPOINT_PO_GET_PRICE,
+	},
+	enums::{
+		contracts_enum::ContractKind,
+		controller_roles::ControllerRoles,
+		roles::Role,
+	},
+	schema::{ self, ContractSchema },
+};
+
+/// 1. Test entry point names
+/// 2. Test installed entry points
+/// 3. Test get_price declaration
+/// 4. Test access and errors
+/// 5. Test controller access matches the schema
+
+#[test]
+fn should_test_entry_point_names() {
+	for contract_schema in get_schemas() {
+		let mut names = BTreeSet::new();
+		for entry_point in contract_schema.entry_points.iter() {
+			assert!(
+				names.insert(entry_point.name.clone()),
+				"{} declares {} twice",
+				contract_schema.contract,
+				entry_point.name
+			);
+			let mut args = BTreeSet::new();
+			for arg in entry_point.args.iter() {
+				assert!(args.insert(arg.name.clone()));
+				assert!(!matches!(arg.cl_type, CLType::Any));
+			}
+			assert!(!matches!(entry_point.ret, CLType::Any));
+		}
+		assert!(
+			contract_schema
+				.get_entry_point(&CommonEndpoints::Migrate.to_string())
+				.is_some()
+		);
+	}
+}
+
+#[test]
+fn should_test_installed_entry_points() {
+	for contract_schema in get_schemas() {
+		let entry_points = contract_schema.to_entry_points();
+		assert_eq!(entry_points.len(), contract_schema.entry_points.len());
+		for declared in contract_schema.entry_points.iter() {
+			let installed = entry_points.get(&declared.name).unwrap();
+			assert_eq!(installed.args().len(), declared.args.len());
+			assert_eq!(installed.ret(), &declared.ret);
+		}
+	}
+}
+
+#[test]
+fn should_test_get_price_declaration() {
+	let contract_schema = schema::price_oracle::schema();
+	let get_price = contract_schema
+		.get_entry_point(ENDPOINT_PO_GET_PRICE)
+		.unwrap();
+	let chars_count = get_price.args
+		.iter()
+		.find(|arg| arg.name == PriceOracleArgs::CharsCount.to_string())
+		.unwrap();
+	// main contract passes the chars count as u64
+	assert_eq!(chars_count.cl_type, u64::cl_type());
+	assert!(
+		get_price.args
+			.iter()
+			.any(|arg| arg.name == PriceOracleArgs::Extension.to_string())
+	);
+	assert_eq!(get_price.ret, U512::cl_type());
+}
+
+#[test]
+fn should_test_access_and_errors() {
+	let contract_schema = schema::price_oracle::schema();
+	let migrate = contract_schema
+		.get_entry_point(&CommonEndpoints::Migrate.to_string())
+		.unwrap();
+	assert!(migrate.access == vec![ControllerRoles::OnlyMaintainer]);
+	assert_eq!(
+		ControllerRoles::OnlyRole(Role::PriceSetter).to_string(),
+		"role:PRICE_SETTER"
+	);
+	assert_eq!(
+		ControllerRoles::OnlyContract(ContractKind::Main).to_string(),
+		"contract:Main"
+	);
+
+	for contract_schema in get_schemas() {
+		assert!(!contract_schema.errors.is_empty());
+	}
+	assert!(!schema::administration::schema().events.0.is_empty());
+}
+
+#[test]
+fn should_test_controller_access() {
+	let authorities = include_str!("../../../common/src/controllers/authorities.rs");
+	let sources = vec![
+		include_str!("../../../administration/contract/src/main.rs"),
+		include_str!("../../../database/contract/src/main.rs"),
+		include_str!("../../../main/contract/src/main.rs"),
+		include_str!("../../../nft-contract/contract/src/main.rs"),
+		include_str!("../../../price-oracle/contract/src/main.rs"),
+		include_str!("../../../registry/contract/src/main.rs"),
+		include_str!("../../../transfer-filter/contract/src/main.rs"),
+		include_str!("../../../treasury/contract/src/main.rs")
+	];
+
+	for (contract_schema, source) in get_schemas().into_iter().zip(sources) {
+		let controllers = get_controllers(&format!("{}{}", source, authorities));
+		for entry_point in contract_schema.entry_points.iter() {
+			let declared = entry_point.access
+				.iter()
+				.map(|access| access.to_string())
+				.collect::<Vec<String>>();
+			match controllers.get(&entry_point.name) {
+				Some(checked) =>
+					assert_eq!(
+						checked,
+						&declared,
+						"{} checks other access of {} than declared",
+						contract_schema.contract,
+						entry_point.name
+					),
+				None =>
+					assert!(
+						declared.is_empty(),
+						"{} does not check the access of {}",
+						contract_schema.contract,
+						entry_point.name
+					),
+			}
+		}
+	}
+}
+
+/// Helpers
+/// Access passed to `controller` by every `extern "C"` entry point of the source,
+/// written as `ControllerRoles` display them
+fn get_controllers(source: &str) -> BTreeMap<String, Vec<String>> {
+	let mut controllers = BTreeMap::new();
+	for function in source.split("pub extern \"C\" fn ").skip(1) {
+		let name = function.split('(').next().unwrap().trim().to_string();
+		let body = function.split("#[no_mangle]").next().unwrap();
+		let access = match body.find("controller(") {
+			Some(index) => &body[index..],
+			None => {
+				continue;
+			}
+		};
+		let start = access.find("vec![").unwrap() + "vec![".len();
+		let end = start + access[start..].find(']').unwrap();
+		let roles = access[start..end]
+			.split(',')
+			.map(|role| role.split_whitespace().collect::<String>())
+			.filter(|role| !role.is_empty())
+			.map(|role| get_access_display(&role))
+			.collect::<Vec<String>>();
+		controllers.insert(name, roles);
+	}
+	controllers
+}
+
+fn get_access_display(role: &str) -> String {
+	let variant = |path: &str| path.rsplit("::").next().unwrap().to_string();
+	let argument = || {
+		let start = role.find('(').unwrap() + 1;
+		variant(&role[start..role.len() - 1])
+	};
+	match variant(role.split('(').next().unwrap()).as_str() {
+		"OnlyMaintainer" => "maintainer".to_string(),
+		"OnlyAuthorizedContracts" => "authorized_contracts".to_string(),
+		"OnlyLocalOperators" => "local_operators".to_string(),
+		// `Role` displays the variant in upper snake case
+		"OnlyRole" => {
+			let mut name = String::new();
+			for (index, char) in argument().chars().enumerate() {
+				if char.is_uppercase() && index > 0 {
+					name.push('_');
+				}
+				name.push(char.to_ascii_uppercase());
+			}
+			format!("role:{}", name)
+		}
+		"OnlyContract" => format!("contract:{}", argument()),
+		other => panic!("Unknown access {}", other),
+	}
+}
+
+fn get_schemas() -> Vec<ContractSchema> {
+	vec![
+		schema::administration::schema(),
+		schema::database::schema(),
+		schema::main::schema(),
+		schema::nft::schema(),
+		schema::price_oracle::schema(),
+		schema::registry::schema(),
+		schema::transfer_filter::schema(),
+		schema::treasury::schema()
+	]
+}
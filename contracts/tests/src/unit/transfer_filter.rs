@@ -0,0 +1,184 @@
+use common_lib::{
+	constants::common_keys::{ CommonKeys, DEFAULT_GRACE_PERIOD, YEAR_IN_MILLIS },
+	enums::{
+		transfer_filter::{ TransferDenial, TransferFilterResult },
+		transfer_lock::TransferLock,
+	},
+};
+
+use crate::utils::UnitTestContext;
+
+const CONTRACT_NAME: &str = "administration-contract.wasm";
+
+/// 1. Test transfer of unknown token
+/// 2. Test transfer locks
+/// 3. Test transfers of expired names
+/// 4. Test burnt transfer permission
+
+#[test]
+fn should_test_transfer_of_unknown_token() {
+	let mut ctx = UnitTestContext::instance(
+		0,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.init();
+
+	let system = ctx.install_nft_system("cspr");
+
+	// Tokens the registry doesn't know the name of are never transferred
+	assert_eq!(
+		ctx.get_transfer_denial(&system, "bakhrom.cspr", None),
+		Some(TransferDenial::NameNotFound)
+	);
+	assert_eq!(
+		ctx.can_transfer(&system, "bakhrom.cspr"),
+		TransferFilterResult::DenyTransfer as u8
+	);
+}
+
+#[test]
+fn should_test_transfer_locks() {
+	let mut ctx = UnitTestContext::instance(
+		2,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.fund_account(1);
+	ctx.init();
+
+	let owner = *ctx.accounts.get(0).unwrap();
+	let buyer = *ctx.accounts.get(1).unwrap();
+	let extension = String::from("cspr");
+	let system = ctx.install_nft_system(&extension);
+	let shard = ctx.install_database_shard(&system, &extension);
+	ctx.mint_stored_name(&system, shard, "bakhrom.cspr", owner);
+	ctx.approve_nft_contract(&system, &owner);
+
+	assert_eq!(ctx.get_transfer_denial(&system, "bakhrom.cspr", None), None);
+	assert_eq!(
+		ctx.can_transfer(&system, "bakhrom.cspr"),
+		TransferFilterResult::ProceedTransfer as u8
+	);
+
+	// Locked names stay with the owner until the lock is released
+	ctx.set_transfer_lock(
+		&system,
+		shard,
+		"bakhrom.cspr",
+		Some(TransferLock::Escrow),
+		true
+	);
+	assert_eq!(
+		ctx.get_transfer_denial(&system, "bakhrom.cspr", None),
+		Some(TransferDenial::EscrowLock)
+	);
+	ctx.transfer_name(&system, "bakhrom.cspr", owner, buyer, false);
+
+	ctx.set_transfer_lock(
+		&system,
+		shard,
+		"bakhrom.cspr",
+		Some(TransferLock::Dispute),
+		true
+	);
+	assert_eq!(
+		ctx.get_transfer_denial(&system, "bakhrom.cspr", None),
+		Some(TransferDenial::DisputeLock)
+	);
+	assert_eq!(
+		ctx.can_transfer(&system, "bakhrom.cspr"),
+		TransferFilterResult::DenyTransfer as u8
+	);
+
+	ctx.set_transfer_lock(&system, shard, "bakhrom.cspr", None, true);
+	assert_eq!(ctx.get_transfer_denial(&system, "bakhrom.cspr", None), None);
+	ctx.transfer_name(&system, "bakhrom.cspr", owner, buyer, true);
+
+	// Only stored names can be locked
+	ctx.set_transfer_lock(
+		&system,
+		shard,
+		"alice.cspr",
+		Some(TransferLock::Escrow),
+		false
+	);
+}
+
+#[test]
+fn should_test_transfers_of_expired_names() {
+	let mut ctx = UnitTestContext::instance(
+		1,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.init();
+
+	let owner = *ctx.accounts.get(0).unwrap();
+	let extension = String::from("cspr");
+	let system = ctx.install_nft_system(&extension);
+	let shard = ctx.install_database_shard(&system, &extension);
+	ctx.mint_stored_name(&system, shard, "bakhrom.cspr", owner);
+
+	// Names are stored with the expiration of `YEAR_IN_MILLIS`
+	assert_eq!(
+		ctx.get_transfer_denial(&system, "bakhrom.cspr", Some(YEAR_IN_MILLIS)),
+		None
+	);
+	assert_eq!(
+		ctx.get_transfer_denial(&system, "bakhrom.cspr", Some(YEAR_IN_MILLIS + 1)),
+		Some(TransferDenial::GracePeriod)
+	);
+	assert_eq!(
+		ctx.get_transfer_denial(
+			&system,
+			"bakhrom.cspr",
+			Some(YEAR_IN_MILLIS + DEFAULT_GRACE_PERIOD)
+		),
+		Some(TransferDenial::Expired)
+	);
+}
+
+#[test]
+fn should_test_burnt_transfer_permission() {
+	let mut ctx = UnitTestContext::instance(
+		1,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.init();
+
+	let owner = *ctx.accounts.get(0).unwrap();
+	let extension = String::from("cspr");
+	let system = ctx.install_nft_system(&extension);
+	let shard = ctx.install_database_shard(&system, &extension);
+	ctx.mint_stored_name(&system, shard, "bakhrom.cspr", owner);
+	ctx.save_subdomain_name(
+		&system,
+		shard,
+		"bakhrom.cspr",
+		"sub.bakhrom.cspr",
+		owner
+	);
+	ctx.map_database_route(&system, shard, "sub.bakhrom.cspr");
+
+	assert_eq!(ctx.get_transfer_denial(&system, "sub.bakhrom.cspr", None), None);
+
+	// The main contract burns the permission on behalf of the domain owner,
+	// only subdomains have the permission
+	ctx.burn_transfer_permission(&system, shard, "bakhrom.cspr", false);
+	ctx.burn_transfer_permission(&system, shard, "sub.bakhrom.cspr", true);
+	assert!(
+		ctx.get_transfer_restrictions(shard, "sub.bakhrom.cspr").transfer_permission_burned
+	);
+	assert_eq!(
+		ctx.get_transfer_denial(&system, "sub.bakhrom.cspr", None),
+		Some(TransferDenial::TransferPermissionBurned)
+	);
+
+	// Burnt permission of a subdomain doesn't affect its domain
+	assert_eq!(ctx.get_transfer_denial(&system, "bakhrom.cspr", None), None);
+}
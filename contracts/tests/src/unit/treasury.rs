@@ -0,0 +1,179 @@
+use casper_types::U512;
+use common_lib::{
+	constants::common_keys::{ CommonKeys, ROYALTY_BASIS_POINTS },
+	enums::{ contracts_enum::ContractKind, revenue_stream::RevenueStream },
+	models::treasury::Beneficiary,
+};
+
+use crate::utils::UnitTestContext;
+
+const CONTRACT_NAME: &str = "administration-contract.wasm";
+
+/// 1. Test deposits split among the beneficiaries
+/// 2. Test undistributed deposits
+/// 3. Test withdraw
+
+#[test]
+fn should_test_deposits_split_among_the_beneficiaries() {
+	let mut ctx = UnitTestContext::instance(
+		2,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.init();
+
+	let first = *ctx.accounts.get(0).unwrap();
+	let second = *ctx.accounts.get(1).unwrap();
+	let treasury = ctx.install_treasury();
+	let caller = ctx.install_caller_contract();
+
+	// Shares are positive, listed once and add up to the whole deposit
+	let stream = RevenueStream::Registration;
+	ctx.set_beneficiaries(treasury, stream, vec![], false);
+	ctx.set_beneficiaries(
+		treasury,
+		stream,
+		vec![Beneficiary { account: first, share: 5_000 }],
+		false
+	);
+	ctx.set_beneficiaries(
+		treasury,
+		stream,
+		vec![
+			Beneficiary { account: first, share: 5_000 },
+			Beneficiary { account: first, share: 5_000 }
+		],
+		false
+	);
+	ctx.set_beneficiaries(
+		treasury,
+		stream,
+		vec![
+			Beneficiary { account: first, share: ROYALTY_BASIS_POINTS },
+			Beneficiary { account: second, share: 0 }
+		],
+		false
+	);
+	ctx.set_beneficiaries(
+		treasury,
+		stream,
+		vec![
+			Beneficiary { account: first, share: 3_333 },
+			Beneficiary { account: second, share: ROYALTY_BASIS_POINTS - 3_333 }
+		],
+		true
+	);
+
+	// Only the contracts registered as main or nft deposit
+	let amount = U512::from(10_001);
+	ctx.deposit(treasury, caller, stream, amount, false);
+	ctx.add_contract(ContractKind::Main, caller.into(), None);
+	ctx.deposit(treasury, caller, stream, U512::zero(), false);
+
+	// Purses holding more than the deposit are not handed to the treasury
+	ctx.deposit_with_purse_balance(treasury, caller, stream, amount, amount + 1, false);
+
+	// The rounding remainder goes to the first beneficiary
+	ctx.deposit(treasury, caller, stream, amount, true);
+	assert_eq!(ctx.get_treasury_balance(treasury, stream, first), U512::from(3_334));
+	assert_eq!(ctx.get_treasury_balance(treasury, stream, second), U512::from(6_667));
+	assert_eq!(ctx.get_revenue(treasury, stream), amount);
+	assert_eq!(ctx.get_treasury_purse_balance(treasury), amount);
+
+	// Other streams keep their own beneficiaries
+	ctx.deposit(treasury, caller, RevenueStream::Renewal, amount, true);
+	assert_eq!(ctx.get_revenue(treasury, stream), amount);
+	assert_eq!(ctx.get_revenue(treasury, RevenueStream::Renewal), amount);
+	assert_eq!(ctx.get_treasury_balance(treasury, RevenueStream::Renewal, first), U512::zero());
+}
+
+#[test]
+fn should_test_undistributed_deposits() {
+	let mut ctx = UnitTestContext::instance(
+		2,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.init();
+
+	let first = *ctx.accounts.get(0).unwrap();
+	let second = *ctx.accounts.get(1).unwrap();
+	let treasury = ctx.install_treasury();
+	let caller = ctx.install_caller_contract();
+	ctx.add_contract(ContractKind::Main, caller.into(), None);
+
+	// Fees of a stream without beneficiaries are taken and held
+	let stream = RevenueStream::Renewal;
+	ctx.deposit(treasury, caller, stream, U512::from(600), true);
+	ctx.deposit(treasury, caller, stream, U512::from(401), true);
+	assert_eq!(ctx.get_undistributed(treasury, stream), U512::from(1_001));
+	assert_eq!(ctx.get_revenue(treasury, stream), U512::from(1_001));
+	assert_eq!(ctx.get_treasury_balance(treasury, stream, first), U512::zero());
+
+	// The first beneficiaries are credited with the held amount
+	ctx.set_beneficiaries(
+		treasury,
+		stream,
+		vec![
+			Beneficiary { account: first, share: 5_000 },
+			Beneficiary { account: second, share: 5_000 }
+		],
+		true
+	);
+	assert_eq!(ctx.get_undistributed(treasury, stream), U512::zero());
+	assert_eq!(ctx.get_treasury_balance(treasury, stream, first), U512::from(501));
+	assert_eq!(ctx.get_treasury_balance(treasury, stream, second), U512::from(500));
+
+	// Later beneficiaries only share the later deposits
+	ctx.set_beneficiaries(
+		treasury,
+		stream,
+		vec![Beneficiary { account: second, share: ROYALTY_BASIS_POINTS }],
+		true
+	);
+	ctx.deposit(treasury, caller, stream, U512::from(100), true);
+	assert_eq!(ctx.get_undistributed(treasury, stream), U512::zero());
+	assert_eq!(ctx.get_treasury_balance(treasury, stream, first), U512::from(501));
+	assert_eq!(ctx.get_treasury_balance(treasury, stream, second), U512::from(600));
+}
+
+#[test]
+fn should_test_withdraw() {
+	let mut ctx = UnitTestContext::instance(
+		2,
+		CONTRACT_NAME,
+		&CommonKeys::ContractHash.to_string()
+	);
+	ctx.fund_account(0);
+	ctx.fund_account(1);
+	ctx.init();
+
+	let first = *ctx.accounts.get(0).unwrap();
+	let second = *ctx.accounts.get(1).unwrap();
+	let treasury = ctx.install_treasury();
+	let caller = ctx.install_caller_contract();
+	ctx.add_contract(ContractKind::Main, caller.into(), None);
+
+	let stream = RevenueStream::MarketplaceCommission;
+	let amount = U512::from(1_000_000_000u64);
+	ctx.set_beneficiaries(
+		treasury,
+		stream,
+		vec![Beneficiary { account: first, share: ROYALTY_BASIS_POINTS }],
+		true
+	);
+	ctx.deposit(treasury, caller, stream, amount, true);
+
+	// Accounts withdraw their own balance only
+	ctx.withdraw(treasury, &second, stream, false);
+	ctx.withdraw(treasury, &first, RevenueStream::Registration, false);
+
+	ctx.withdraw(treasury, &first, stream, true);
+	assert_eq!(ctx.get_treasury_balance(treasury, stream, first), U512::zero());
+	assert_eq!(ctx.get_treasury_purse_balance(treasury), U512::zero());
+	// Revenue is the total ever deposited, withdrawals don't change it
+	assert_eq!(ctx.get_revenue(treasury, stream), amount);
+
+	// Nothing is left to withdraw
+	ctx.withdraw(treasury, &first, stream, false);
+}
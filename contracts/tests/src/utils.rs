@@ -1,11 +1,13 @@
-use common_lib::constants::common_keys::KEY_DATABASE_CONTRACT_HASH;
+use common_lib::constants::common_keys::CommonKeys;
 use rand::Rng;
 use std::path::PathBuf;
 use casper_types::{
 	ContractHash,
 	URef,
-	bytesrepr::FromBytes,
+	bytesrepr::{ self, Bytes, FromBytes, ToBytes },
+	CLType,
 	CLTyped,
+	CLValue,
 	runtime_args,
 	system::mint,
 	U512,
@@ -16,6 +18,7 @@ use casper_types::{
 	SecretKey,
 	PublicKey,
 };
+use cspr_names_client::{ CallTarget, ContractCall };
 use casper_engine_test_support::{
 	WasmTestBuilder,
 	InMemoryWasmTestBuilder,
@@ -124,12 +127,18 @@ pub enum DeploySource {
 	},
 	ByPackageHash {
 		package_hash: ContractPackageHash,
+		version: Option<u32>,
 		entry_point: String,
 	},
 	ByContractName {
 		name: String,
 		entry_point: String,
 	},
+	ByPackageName {
+		name: String,
+		version: Option<u32>,
+		entry_point: String,
+	},
 }
 
 pub fn deploy(
@@ -153,16 +162,23 @@ pub fn deploy(
 		DeploySource::ByContractHash { hash, entry_point } => {
 			deploy_builder.with_stored_session_hash(*hash, entry_point, args)
 		}
-		DeploySource::ByPackageHash { package_hash, entry_point } =>
+		DeploySource::ByPackageHash { package_hash, version, entry_point } =>
 			deploy_builder.with_stored_versioned_contract_by_hash(
 				package_hash.value(),
-				None,
+				*version,
 				entry_point,
 				args
 			),
 		DeploySource::ByContractName { name, entry_point } => {
 			deploy_builder.with_stored_session_named_key(name, entry_point, args)
 		}
+		DeploySource::ByPackageName { name, version, entry_point } =>
+			deploy_builder.with_stored_versioned_contract_by_name(
+				name,
+				*version,
+				entry_point,
+				args
+			),
 	};
 
 	let mut execute_request_builder = ExecuteRequestBuilder::from_deploy_item(
@@ -181,6 +197,78 @@ pub fn deploy(
 	).commit();
 }
 
+/// Deploys the call built by `cspr-names-client`
+pub fn deploy_call(
+	builder: &mut InMemoryWasmTestBuilder,
+	deployer: &AccountHash,
+	call: &ContractCall,
+	success: bool,
+	block_time: Option<u64>
+) {
+	let entry_point = call.entry_point.clone();
+	let source = match &call.target {
+		CallTarget::ContractHash(hash) => DeploySource::ByContractHash {
+			hash: *hash,
+			entry_point,
+		},
+		CallTarget::ContractPackageHash { package_hash, version } =>
+			DeploySource::ByPackageHash {
+				package_hash: *package_hash,
+				version: *version,
+				entry_point,
+			},
+		CallTarget::ContractName(name) => DeploySource::ByContractName {
+			name: name.clone(),
+			entry_point,
+		},
+		CallTarget::PackageName { name, version } => DeploySource::ByPackageName {
+			name: name.clone(),
+			version: *version,
+			entry_point,
+		},
+	};
+	deploy(builder, deployer, &source, call.args.clone(), success, block_time)
+}
+
+/// Contracts can't transfer from the main purse of the deployer, the payment session funds a new purse
+/// with the amount and passes it instead of the purse argument of the call
+pub fn deploy_payment(
+	builder: &mut InMemoryWasmTestBuilder,
+	deployer: &AccountHash,
+	call: &ContractCall,
+	amount: U512,
+	success: bool,
+	block_time: Option<u64>
+) {
+	let contract_hash = match call.target {
+		CallTarget::ContractHash(hash) => hash,
+		_ => panic!("payments are made to a contract hash"),
+	};
+	let mut args = RuntimeArgs::new();
+	let mut purse_arg = None;
+	for named_arg in call.args.named_args() {
+		if named_arg.cl_value().cl_type() == &CLType::URef {
+			purse_arg = Some(named_arg.name().to_string());
+		} else {
+			args.insert_cl_value(named_arg.name(), named_arg.cl_value().clone());
+		}
+	}
+	deploy(
+		builder,
+		deployer,
+		&DeploySource::Code(PathBuf::from(PAYMENT_CONTRACT_NAME)),
+		runtime_args! {
+			"contract_hash" => contract_hash,
+			"entry_point" => call.entry_point.clone(),
+			"args" => Bytes::from(args.to_bytes().expect("must serialize args")),
+			"amount" => amount,
+			"purse_arg" => purse_arg.expect("call must have a purse argument"),
+		},
+		success,
+		block_time
+	)
+}
+
 pub fn query<T: FromBytes + CLTyped>(
 	builder: &InMemoryWasmTestBuilder,
 	base: Key,
@@ -231,6 +319,28 @@ pub fn query_dictionary<T>(
 		.expect("Wrong type in query result.")
 }
 
+/// Raw value of the dictionary item, decoded by the caller
+pub fn query_dictionary_cl_value(
+	builder: &InMemoryWasmTestBuilder,
+	base: URef,
+	path: &str
+) -> CLValue {
+	builder
+		.query_dictionary_item(None, base, path)
+		.expect("should be stored dictionary value.")
+		.as_cl_value()
+		.expect("should be cl value.")
+		.to_owned()
+}
+
+const CALLER_CONTRACT_NAME: &str = "caller_test_contract.wasm";
+const PAYMENT_CONTRACT_NAME: &str = "payment_test_contract.wasm";
+const KEY_CALLER_CONTRACT_HASH: &str = "caller_test_contract_hash";
+const KEY_CALLER_RESULT: &str = "result";
+pub const ENDPOINT_CALLER_FORWARD: &str = "forward";
+pub const ENDPOINT_CALLER_FORWARD_PAYMENT: &str = "forward_payment";
+const ENDPOINT_CALLER_FORWARD_AND_STORE: &str = "forward_and_store";
+
 pub struct UnitTestContext {
 	pub builder: InMemoryWasmTestBuilder,
 	pub contract_hash: ContractHash,
@@ -289,6 +399,110 @@ impl UnitTestContext {
 		}
 	}
 
+	/// Installs one more contract by the maintainer, the contracts of the system are wired by their hashes
+	pub fn install_contract(
+		&mut self,
+		contract_path: &str,
+		args: RuntimeArgs
+	) -> ContractHash {
+		let maintainer = self.maintainer;
+		deploy(
+			&mut self.builder,
+			&maintainer,
+			&DeploySource::Code(PathBuf::from(contract_path)),
+			args,
+			true,
+			None
+		);
+		query(
+			&self.builder,
+			Key::Account(maintainer),
+			&[CommonKeys::ContractHash.to_string()]
+		)
+	}
+
+	/// Installs the contract forwarding calls of the maintainer, the tests register it
+	/// as the contract of a kind the called contract only lets through
+	pub fn install_caller_contract(&mut self) -> ContractHash {
+		let maintainer = self.maintainer;
+		deploy(
+			&mut self.builder,
+			&maintainer,
+			&DeploySource::Code(PathBuf::from(CALLER_CONTRACT_NAME)),
+			runtime_args! {},
+			true,
+			None
+		);
+		query(
+			&self.builder,
+			Key::Account(maintainer),
+			&[KEY_CALLER_CONTRACT_HASH.to_string()]
+		)
+	}
+
+	/// Deploys don't keep the value returned by the entry point,
+	/// so the entry point is called through the caller contract which stores it
+	pub fn call_and_read<T: FromBytes>(
+		&mut self,
+		contract_hash: ContractHash,
+		entry_point: &str,
+		args: RuntimeArgs
+	) -> T {
+		self.call_and_read_at(contract_hash, entry_point, args, None)
+	}
+
+	/// Same as `call_and_read`, the entry point sees the given block time
+	pub fn call_and_read_at<T: FromBytes>(
+		&mut self,
+		contract_hash: ContractHash,
+		entry_point: &str,
+		args: RuntimeArgs,
+		block_time: Option<u64>
+	) -> T {
+		let maintainer = self.maintainer;
+		let caller = match
+			self.builder
+				.get_expected_account(maintainer)
+				.named_keys()
+				.get(KEY_CALLER_CONTRACT_HASH)
+		{
+			Some(_) =>
+				query(
+					&self.builder,
+					Key::Account(maintainer),
+					&[KEY_CALLER_CONTRACT_HASH.to_string()]
+				),
+			None => self.install_caller_contract(),
+		};
+		deploy(
+			&mut self.builder,
+			&maintainer,
+			&DeploySource::ByContractHash {
+				hash: caller,
+				entry_point: ENDPOINT_CALLER_FORWARD_AND_STORE.to_string(),
+			},
+			runtime_args! {
+				"contract_hash" => contract_hash,
+				"entry_point" => entry_point.to_string(),
+				"args" => Bytes::from(args.to_bytes().expect("must serialize args")),
+			},
+			true,
+			block_time
+		);
+		let result: Bytes = query(
+			&self.builder,
+			caller.into(),
+			&[KEY_CALLER_RESULT.to_string()]
+		);
+		bytesrepr::deserialize(result.into()).expect("must deserialize returned value")
+	}
+
+	/// Every deploy pays the default payment, systems of many contracts run the maintainer out of funds
+	pub fn fund_maintainer(&mut self) {
+		let fund_acc = fund_account(&self.maintainer);
+		self.builder.exec(fund_acc).expect_success().commit();
+	}
+
 	pub fn fund_account(&mut self, idx: u8) {
 		let acc = self.accounts.get(idx as usize);
 		if let Some(account) = acc {
@@ -0,0 +1,135 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::{string::String, vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, Bytes, FromBytes},
+    CLType, CLTyped, ContractHash, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints,
+    Parameter, RuntimeArgs, URef,
+};
+
+// Stored contract forwarding calls, the tests register it as the contract of a kind
+// the called contract only lets through (e.g. `main` minting names in the nft contract)
+
+const CONTRACT_NAME: &str = "caller_test_contract_hash";
+const HASH_KEY_NAME: &str = "caller_test_contract_package_hash";
+const ACCESS_KEY_NAME: &str = "caller_test_contract_access_uref";
+
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const ARG_ENTRY_POINT: &str = "entry_point";
+const ARG_ARGS: &str = "args";
+const ARG_PURSE_ARG: &str = "purse_arg";
+const ARG_PURSE: &str = "purse";
+
+const KEY_RESULT: &str = "result";
+
+/// Serialized value returned by the called entry point, whatever its type is
+struct ReturnedBytes(Bytes);
+
+impl CLTyped for ReturnedBytes {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for ReturnedBytes {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        Ok((ReturnedBytes(Bytes::from(bytes)), &[]))
+    }
+}
+
+/// Arguments are passed as serialized `RuntimeArgs`, the called entry point has to return unit
+#[no_mangle]
+pub extern "C" fn forward() {
+    let contract_hash: ContractHash = runtime::get_named_arg(ARG_CONTRACT_HASH);
+    let entry_point: String = runtime::get_named_arg(ARG_ENTRY_POINT);
+    let args: Bytes = runtime::get_named_arg(ARG_ARGS);
+    let (args, _) = RuntimeArgs::from_bytes(&args).unwrap_or_revert();
+    runtime::call_contract::<()>(contract_hash, &entry_point, args);
+}
+
+/// Same as `forward`, the serialized returned value is kept under the `result` key
+#[no_mangle]
+pub extern "C" fn forward_and_store() {
+    let contract_hash: ContractHash = runtime::get_named_arg(ARG_CONTRACT_HASH);
+    let entry_point: String = runtime::get_named_arg(ARG_ENTRY_POINT);
+    let args: Bytes = runtime::get_named_arg(ARG_ARGS);
+    let (args, _) = RuntimeArgs::from_bytes(&args).unwrap_or_revert();
+    let ReturnedBytes(result) =
+        runtime::call_contract::<ReturnedBytes>(contract_hash, &entry_point, args);
+    runtime::put_key(KEY_RESULT, storage::new_uref(result).into());
+}
+
+/// Same as `forward`, the purse funded by the payment session is added to the arguments under `purse_arg`,
+/// purses inside the serialized arguments are not granted to the called contract
+#[no_mangle]
+pub extern "C" fn forward_payment() {
+    let contract_hash: ContractHash = runtime::get_named_arg(ARG_CONTRACT_HASH);
+    let entry_point: String = runtime::get_named_arg(ARG_ENTRY_POINT);
+    let args: Bytes = runtime::get_named_arg(ARG_ARGS);
+    let purse_arg: String = runtime::get_named_arg(ARG_PURSE_ARG);
+    let purse: URef = runtime::get_named_arg(ARG_PURSE);
+    let (mut args, _) = RuntimeArgs::from_bytes(&args).unwrap_or_revert();
+    args.insert(purse_arg, purse).unwrap_or_revert();
+    runtime::call_contract::<()>(contract_hash, &entry_point, args);
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "forward",
+        vec![
+            Parameter::new(ARG_CONTRACT_HASH, ContractHash::cl_type()),
+            Parameter::new(ARG_ENTRY_POINT, CLType::String),
+            Parameter::new(ARG_ARGS, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "forward_and_store",
+        vec![
+            Parameter::new(ARG_CONTRACT_HASH, ContractHash::cl_type()),
+            Parameter::new(ARG_ENTRY_POINT, CLType::String),
+            Parameter::new(ARG_ARGS, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "forward_payment",
+        vec![
+            Parameter::new(ARG_CONTRACT_HASH, ContractHash::cl_type()),
+            Parameter::new(ARG_ENTRY_POINT, CLType::String),
+            Parameter::new(ARG_ARGS, Bytes::cl_type()),
+            Parameter::new(ARG_PURSE_ARG, CLType::String),
+            Parameter::new(ARG_PURSE, CLType::URef),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let (contract_hash, _) = storage::new_contract(
+        entry_points,
+        None,
+        Some(HASH_KEY_NAME.into()),
+        Some(ACCESS_KEY_NAME.into()),
+    );
+    runtime::put_key(CONTRACT_NAME, storage::new_uref(contract_hash).into());
+}
@@ -0,0 +1,224 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{
+        runtime::{self, ret},
+        storage,
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::ToBytes, contracts::NamedKeys, system::CallStackElement, ApiError, CLType, CLTyped,
+    CLValue, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter, URef, U256,
+};
+
+// Minimal CEP-18 token, only the entry points the name service calls and a free mint for the tests
+
+const CONTRACT_NAME: &str = "cep18_test_contract_hash";
+const HASH_KEY_NAME: &str = "cep18_test_contract_package_hash";
+const ACCESS_KEY_NAME: &str = "cep18_test_contract_access_uref";
+const BALANCES: &str = "balances";
+const ALLOWANCES: &str = "allowances";
+
+const ARG_OWNER: &str = "owner";
+const ARG_SPENDER: &str = "spender";
+const ARG_RECIPIENT: &str = "recipient";
+const ARG_AMOUNT: &str = "amount";
+const ARG_ADDRESS: &str = "address";
+
+const ERROR_INSUFFICIENT_BALANCE: u16 = 60001;
+const ERROR_INSUFFICIENT_ALLOWANCE: u16 = 60002;
+const ERROR_INVALID_CALLER: u16 = 60003;
+
+/// Dictionary item key of the address, the same hashing the tests use to query balances
+fn address_dictionary_key(address: &[u8]) -> String {
+    runtime::blake2b(address)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn balance_key(owner: Key) -> String {
+    address_dictionary_key(&owner.to_bytes().unwrap_or_revert())
+}
+
+fn allowance_key(owner: Key, spender: Key) -> String {
+    let mut bytes = owner.to_bytes().unwrap_or_revert();
+    bytes.append(&mut spender.to_bytes().unwrap_or_revert());
+    address_dictionary_key(&bytes)
+}
+
+fn dictionary(name: &str) -> URef {
+    runtime::get_key(name)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert()
+}
+
+fn read(name: &str, key: &str) -> U256 {
+    storage::dictionary_get::<U256>(dictionary(name), key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn write(name: &str, key: &str, value: U256) {
+    storage::dictionary_put(dictionary(name), key, value);
+}
+
+/// Spenders are the immediate callers, contracts are addressed by their package hash
+fn get_immediate_caller() -> Key {
+    let call_stack = runtime::get_call_stack();
+    let caller = call_stack
+        .iter()
+        .nth_back(1)
+        .unwrap_or_revert_with(ApiError::User(ERROR_INVALID_CALLER));
+    match caller {
+        CallStackElement::Session { account_hash }
+        | CallStackElement::StoredSession { account_hash, .. } => Key::Account(*account_hash),
+        CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        } => Key::from(*contract_package_hash),
+    }
+}
+
+fn transfer_balance(owner: Key, recipient: Key, amount: U256) {
+    let owner_balance = read(BALANCES, &balance_key(owner));
+    if owner_balance < amount {
+        runtime::revert(ApiError::User(ERROR_INSUFFICIENT_BALANCE));
+    }
+    write(BALANCES, &balance_key(owner), owner_balance - amount);
+    let recipient_balance = read(BALANCES, &balance_key(recipient));
+    write(
+        BALANCES,
+        &balance_key(recipient),
+        recipient_balance + amount,
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn mint() {
+    let owner: Key = runtime::get_named_arg(ARG_OWNER);
+    let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
+    let balance = read(BALANCES, &balance_key(owner));
+    write(BALANCES, &balance_key(owner), balance + amount);
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    let spender: Key = runtime::get_named_arg(ARG_SPENDER);
+    let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
+    let owner = get_immediate_caller();
+    write(ALLOWANCES, &allowance_key(owner, spender), amount);
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let owner: Key = runtime::get_named_arg(ARG_OWNER);
+    let recipient: Key = runtime::get_named_arg(ARG_RECIPIENT);
+    let amount: U256 = runtime::get_named_arg(ARG_AMOUNT);
+    let spender = get_immediate_caller();
+
+    let key = allowance_key(owner, spender);
+    let allowance = read(ALLOWANCES, &key);
+    if allowance < amount {
+        runtime::revert(ApiError::User(ERROR_INSUFFICIENT_ALLOWANCE));
+    }
+    write(ALLOWANCES, &key, allowance - amount);
+    transfer_balance(owner, recipient, amount);
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    let address: Key = runtime::get_named_arg(ARG_ADDRESS);
+    ret(CLValue::from_t(read(BALANCES, &balance_key(address))).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn allowance() {
+    let owner: Key = runtime::get_named_arg(ARG_OWNER);
+    let spender: Key = runtime::get_named_arg(ARG_SPENDER);
+    ret(CLValue::from_t(read(ALLOWANCES, &allowance_key(owner, spender))).unwrap_or_revert());
+}
+
+fn entry_point(name: &str, params: Vec<Parameter>, ret: CLType) -> EntryPoint {
+    EntryPoint::new(
+        name,
+        params,
+        ret,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(entry_point(
+        "mint",
+        vec![
+            Parameter::new(ARG_OWNER, CLType::Key),
+            Parameter::new(ARG_AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(entry_point(
+        "approve",
+        vec![
+            Parameter::new(ARG_SPENDER, CLType::Key),
+            Parameter::new(ARG_AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(entry_point(
+        "transfer_from",
+        vec![
+            Parameter::new(ARG_OWNER, CLType::Key),
+            Parameter::new(ARG_RECIPIENT, CLType::Key),
+            Parameter::new(ARG_AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(entry_point(
+        "balance_of",
+        vec![Parameter::new(ARG_ADDRESS, CLType::Key)],
+        U256::cl_type(),
+    ));
+    entry_points.add_entry_point(entry_point(
+        "allowance",
+        vec![
+            Parameter::new(ARG_OWNER, CLType::Key),
+            Parameter::new(ARG_SPENDER, CLType::Key),
+        ],
+        U256::cl_type(),
+    ));
+
+    // Dictionaries are created by the session and handed over to the contract
+    let mut named_keys = NamedKeys::new();
+    for name in [BALANCES, ALLOWANCES] {
+        let uref = storage::new_dictionary(name).unwrap_or_revert();
+        runtime::remove_key(name);
+        named_keys.insert(name.to_string(), uref.into());
+    }
+
+    let (contract_hash, _) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(HASH_KEY_NAME.to_string()),
+        Some(ACCESS_KEY_NAME.to_string()),
+    );
+    runtime::put_key(CONTRACT_NAME, storage::new_uref(contract_hash).into());
+}
@@ -0,0 +1,66 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use casper_contract::{
+    contract_api::{account, runtime, system},
+    ext_ffi,
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    api_error,
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    ContractHash, RuntimeArgs, U512,
+};
+
+// Session code paying a stored contract, contracts can't transfer from the main purse of an account,
+// the amount is moved to a new purse which is passed to the called entry point
+
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const ARG_ENTRY_POINT: &str = "entry_point";
+const ARG_ARGS: &str = "args";
+const ARG_AMOUNT: &str = "amount";
+const ARG_PURSE_ARG: &str = "purse_arg";
+
+/// Arguments are passed as serialized `RuntimeArgs` without the purse, it is added under `purse_arg`
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_hash: ContractHash = runtime::get_named_arg(ARG_CONTRACT_HASH);
+    let entry_point: String = runtime::get_named_arg(ARG_ENTRY_POINT);
+    let args: Bytes = runtime::get_named_arg(ARG_ARGS);
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+    let purse_arg: String = runtime::get_named_arg(ARG_PURSE_ARG);
+    let (mut args, _) = RuntimeArgs::from_bytes(&args).unwrap_or_revert();
+
+    let purse = system::create_purse();
+    system::transfer_from_purse_to_purse(account::get_main_purse(), purse, amount, None)
+        .unwrap_or_revert();
+    args.insert(purse_arg, purse).unwrap_or_revert();
+    call_contract_ignoring_result(contract_hash, &entry_point, args);
+}
+
+/// Paid entry points return different values, the result is left in the host buffer unread
+fn call_contract_ignoring_result(contract_hash: ContractHash, entry_point: &str, args: RuntimeArgs) {
+    let contract_hash = contract_hash.to_bytes().unwrap_or_revert();
+    let entry_point = entry_point.to_bytes().unwrap_or_revert();
+    let args = args.to_bytes().unwrap_or_revert();
+    let mut bytes_written = 0usize;
+    let ret = unsafe {
+        ext_ffi::casper_call_contract(
+            contract_hash.as_ptr(),
+            contract_hash.len(),
+            entry_point.as_ptr(),
+            entry_point.len(),
+            args.as_ptr(),
+            args.len(),
+            &mut bytes_written as *mut usize,
+        )
+    };
+    api_error::result_from(ret).unwrap_or_revert();
+}
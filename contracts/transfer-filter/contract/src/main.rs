@@ -0,0 +1,67 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!(
+	"target arch should be wasm32: compile with '--target wasm32-unknown-unknown'"
+);
+
+// Transfer filter of nft-core (`transfer_filter_contract` install argument),
+// names are checked against the database shards through the registry
+
+extern crate alloc;
+
+mod service;
+mod types;
+
+use alloc::{ string::ToString, vec };
+
+use casper_contract::contract_api::{ runtime, storage };
+use casper_types::{ contracts::NamedKeys, ContractHash };
+use common_lib::{
+	constants::common_keys::{ CommonArgs, CommonKeys },
+	enums::controller_roles::ControllerRoles,
+	schema,
+	utils::{ contract::setup_contract_info, response::controller },
+};
+
+#[no_mangle]
+pub extern "C" fn can_transfer() {
+	controller(service::can_transfer::can_transfer, vec![]);
+}
+
+#[no_mangle]
+pub extern "C" fn get_transfer_denial() {
+	controller(service::can_transfer::get_transfer_denial, vec![]);
+}
+
+#[no_mangle]
+pub extern "C" fn migrate() {
+	controller(
+		service::migrate::migrate,
+		vec![ControllerRoles::OnlyMaintainer]
+	);
+}
+
+/**
+ * Endpoints:
+ * 1. can_transfer
+ * 2. get_transfer_denial
+ * 3. migrate
+ */
+#[no_mangle]
+pub extern "C" fn call() {
+	let entrypoints = schema::transfer_filter::schema().to_entry_points();
+
+	let mut named_keys = NamedKeys::new();
+
+	let administration_contract_hash: ContractHash = runtime::get_named_arg(
+		&CommonArgs::AdministrationContract.to_string()
+	);
+	named_keys.insert(
+		CommonKeys::AdministrationContract.to_string(),
+		storage::new_uref(administration_contract_hash).into()
+	);
+
+	setup_contract_info(entrypoints, named_keys);
+}
@@ -0,0 +1,33 @@
+use alloc::string::{ String, ToString };
+use casper_contract::contract_api::runtime;
+use common_lib::{
+	constants::common_keys::TransferFilterArgs,
+	enums::transfer_filter::{ TransferDenial, TransferFilterResult },
+};
+
+use crate::{ service::transfer_denial::get_transfer_denial_for_token, types::TResult };
+
+/// Called by nft-core before every transfer of its tokens, any denial reverts the transfer
+/// Parameters:
+/// - source_key, target_key - passed by nft-core, not checked
+/// - token_id - required, token hash
+pub fn can_transfer() -> TResult<u8> {
+	let token_id: String = runtime::get_named_arg(
+		&TransferFilterArgs::TokenId.to_string()
+	);
+	let result = match get_transfer_denial_for_token(&token_id) {
+		Some(_) => TransferFilterResult::DenyTransfer,
+		None => TransferFilterResult::ProceedTransfer,
+	};
+	Ok(result as u8)
+}
+
+/// Reason `can_transfer` denies the transfer, the NFT contract surfaces it as a typed error
+/// Parameters:
+/// - token_id - required, token hash
+pub fn get_transfer_denial() -> TResult<Option<TransferDenial>> {
+	let token_id: String = runtime::get_named_arg(
+		&TransferFilterArgs::TokenId.to_string()
+	);
+	Ok(get_transfer_denial_for_token(&token_id))
+}
@@ -0,0 +1,13 @@
+use common_lib::utils::upgrade::run_migrations;
+
+use crate::types::TResult;
+
+/// The filter keeps no state, the version is stored for the upgrade tooling
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Runs right after a new contract version is added to the package
+/// This service is closed, only maintainer is passed
+/// Returns the schema version of the stored state
+pub fn migrate() -> TResult<u32> {
+	Ok(run_migrations(SCHEMA_VERSION, |_version| {}))
+}
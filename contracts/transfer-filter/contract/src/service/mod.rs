@@ -0,0 +1,3 @@
+pub mod can_transfer;
+pub mod migrate;
+pub mod transfer_denial;
@@ -0,0 +1,126 @@
+use alloc::string::{ String, ToString };
+use casper_contract::contract_api::runtime;
+use casper_types::{ runtime_args, ContractHash, RuntimeArgs };
+use common_lib::{
+	constants::common_keys::{
+		DatabaseArgs,
+		DatabaseEndpoints,
+		RegistryArgs,
+		RegistryEndpoints,
+	},
+	enums::{
+		domain_name_actual_state::DomainNameActualState,
+		transfer_filter::TransferDenial,
+		transfer_lock::TransferLock,
+	},
+	models::{
+		transfer_restrictions::TransferRestrictions,
+		DomainName,
+		SubdomainName,
+	},
+	utils::{
+		domain_name::{ get_domain_name_extension, get_end_time_actual_state },
+		extension_config::get_extension_config,
+		routes::{ get_registry_contract_hash, get_route },
+	},
+};
+
+/// `None` when the token can be transferred.
+/// Subdomains are checked against the expiration of their domain and their own restrictions
+pub(crate) fn get_transfer_denial_for_token(
+	token_id: &str
+) -> Option<TransferDenial> {
+	let name: Option<String> = runtime::call_contract(
+		get_registry_contract_hash(),
+		&RegistryEndpoints::GetNameForToken.to_string(),
+		runtime_args! {
+			RegistryArgs::TokenId.to_string() => token_id.to_string(),
+		}
+	);
+	match name {
+		Some(name) => check_name(&name).err(),
+		None => Some(TransferDenial::NameNotFound),
+	}
+}
+
+fn check_name(name: &str) -> Result<(), TransferDenial> {
+	let parent_domain = get_parent_domain(name);
+	let domain_name = parent_domain.unwrap_or(name);
+
+	// Subdomains are stored in the shard of their domain
+	let database_contract_hash = get_route(domain_name)
+		.map(|route| route.db_contract_hash)
+		.ok_or(TransferDenial::NameNotFound)?;
+
+	let domain: DomainName = runtime
+		::call_contract::<Option<DomainName>>(
+			database_contract_hash,
+			&DatabaseEndpoints::GetDomain.to_string(),
+			runtime_args! {
+				DatabaseArgs::DomainName.to_string() => domain_name.to_string(),
+			}
+		)
+		.ok_or(TransferDenial::NameNotFound)?;
+
+	if parent_domain.is_some() {
+		runtime
+			::call_contract::<Option<SubdomainName>>(
+				database_contract_hash,
+				&DatabaseEndpoints::GetSubdomain.to_string(),
+				runtime_args! {
+					DatabaseArgs::SubdomainName.to_string() => name.to_string(),
+				}
+			)
+			.ok_or(TransferDenial::NameNotFound)?;
+	}
+
+	let grace_period = get_domain_name_extension(domain_name)
+		.map(|extension| get_extension_config(&extension).grace_period)
+		.unwrap_or_default();
+	match get_end_time_actual_state(Some(domain.end_time), grace_period) {
+		DomainNameActualState::GracePeriod => {
+			return Err(TransferDenial::GracePeriod);
+		}
+		DomainNameActualState::Available => {
+			return Err(TransferDenial::Expired);
+		}
+		DomainNameActualState::Busy => {}
+	}
+
+	let restrictions = get_transfer_restrictions(database_contract_hash, name);
+	match restrictions.lock {
+		Some(TransferLock::Escrow) => {
+			return Err(TransferDenial::EscrowLock);
+		}
+		Some(TransferLock::Dispute) => {
+			return Err(TransferDenial::DisputeLock);
+		}
+		None => {}
+	}
+	if parent_domain.is_some() && restrictions.transfer_permission_burned {
+		return Err(TransferDenial::TransferPermissionBurned);
+	}
+	Ok(())
+}
+
+/// Helpers
+/// `test.cspr` of `sub.test.cspr`, domains have no parent
+fn get_parent_domain(name: &str) -> Option<&str> {
+	name
+		.split_once('.')
+		.map(|(_, rest)| rest)
+		.filter(|rest| rest.contains('.'))
+}
+
+fn get_transfer_restrictions(
+	database_contract_hash: ContractHash,
+	name: &str
+) -> TransferRestrictions {
+	runtime::call_contract(
+		database_contract_hash,
+		&DatabaseEndpoints::GetTransferRestrictions.to_string(),
+		runtime_args! {
+			DatabaseArgs::Name.to_string() => name.to_string(),
+		}
+	)
+}
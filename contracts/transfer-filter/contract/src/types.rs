@@ -0,0 +1,3 @@
+use common_lib::errors::CommonError;
+
+pub(crate) type TResult<T> = Result<T, CommonError>;
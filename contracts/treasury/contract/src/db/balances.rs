@@ -0,0 +1,44 @@
+use alloc::{ format, string::{ String, ToString } };
+use casper_types::{ account::AccountHash, U512 };
+use common_lib::{
+	constants::common_keys::TreasuryKeys,
+	db::{ dictionary::Dictionary, traits::Storable },
+	enums::revenue_stream::RevenueStream,
+};
+
+/// Balances of the beneficiaries, every revenue stream has its own dictionary
+/// keyed by the account hash (64 hex chars, the longest dictionary key)
+pub trait Balances {
+	fn balances_initialize(stream: RevenueStream) -> ();
+	fn balances_instance(stream: RevenueStream) -> Self;
+	fn credit(&self, account: AccountHash, amount: U512) -> ();
+	fn clear_balance(&self, account: AccountHash) -> ();
+	fn get_balance(&self, account: AccountHash) -> U512;
+}
+
+fn get_balances_key(stream: RevenueStream) -> String {
+	format!("{}_{}", TreasuryKeys::Balances, stream)
+}
+
+impl Balances for Dictionary {
+	fn balances_initialize(stream: RevenueStream) -> () {
+		Dictionary::init(&get_balances_key(stream))
+	}
+
+	fn balances_instance(stream: RevenueStream) -> Self {
+		Dictionary::instance(&get_balances_key(stream))
+	}
+
+	fn credit(&self, account: AccountHash, amount: U512) -> () {
+		let balance = self.get_balance(account);
+		self.set(&account.to_string(), balance + amount)
+	}
+
+	fn clear_balance(&self, account: AccountHash) -> () {
+		self.remove::<U512>(&account.to_string())
+	}
+
+	fn get_balance(&self, account: AccountHash) -> U512 {
+		self.get(&account.to_string()).unwrap_or_default()
+	}
+}
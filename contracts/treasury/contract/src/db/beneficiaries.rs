@@ -0,0 +1,41 @@
+use alloc::{ string::ToString, vec::Vec };
+use common_lib::{
+	constants::common_keys::TreasuryKeys,
+	db::{ dictionary::Dictionary, traits::Storable },
+	enums::revenue_stream::RevenueStream,
+	models::treasury::Beneficiary,
+};
+
+/// Beneficiaries keyed by the revenue stream
+pub trait Beneficiaries {
+	fn beneficiaries_initialize() -> ();
+	fn beneficiaries_instance() -> Self;
+	fn set_beneficiaries(
+		&self,
+		stream: RevenueStream,
+		beneficiaries: Vec<Beneficiary>
+	) -> ();
+	fn get_beneficiaries(&self, stream: RevenueStream) -> Vec<Beneficiary>;
+}
+
+impl Beneficiaries for Dictionary {
+	fn beneficiaries_initialize() -> () {
+		Dictionary::init(&TreasuryKeys::Beneficiaries.to_string())
+	}
+
+	fn beneficiaries_instance() -> Self {
+		Dictionary::instance(&TreasuryKeys::Beneficiaries.to_string())
+	}
+
+	fn set_beneficiaries(
+		&self,
+		stream: RevenueStream,
+		beneficiaries: Vec<Beneficiary>
+	) -> () {
+		self.set(&stream.to_string(), beneficiaries)
+	}
+
+	fn get_beneficiaries(&self, stream: RevenueStream) -> Vec<Beneficiary> {
+		self.get(&stream.to_string()).unwrap_or_default()
+	}
+}
@@ -0,0 +1,4 @@
+pub mod balances;
+pub mod beneficiaries;
+pub mod revenue;
+pub mod undistributed;
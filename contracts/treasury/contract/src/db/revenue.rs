@@ -0,0 +1,34 @@
+use alloc::string::ToString;
+use casper_types::U512;
+use common_lib::{
+	constants::common_keys::TreasuryKeys,
+	db::{ dictionary::Dictionary, traits::Storable },
+	enums::revenue_stream::RevenueStream,
+};
+
+/// Total deposited into the revenue stream, withdrawals don't lower it
+pub trait Revenue {
+	fn revenue_initialize() -> ();
+	fn revenue_instance() -> Self;
+	fn add_revenue(&self, stream: RevenueStream, amount: U512) -> ();
+	fn get_revenue(&self, stream: RevenueStream) -> U512;
+}
+
+impl Revenue for Dictionary {
+	fn revenue_initialize() -> () {
+		Dictionary::init(&TreasuryKeys::Revenue.to_string())
+	}
+
+	fn revenue_instance() -> Self {
+		Dictionary::instance(&TreasuryKeys::Revenue.to_string())
+	}
+
+	fn add_revenue(&self, stream: RevenueStream, amount: U512) -> () {
+		let revenue = self.get_revenue(stream);
+		self.set(&stream.to_string(), revenue + amount)
+	}
+
+	fn get_revenue(&self, stream: RevenueStream) -> U512 {
+		self.get(&stream.to_string()).unwrap_or_default()
+	}
+}
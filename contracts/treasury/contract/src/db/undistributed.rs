@@ -0,0 +1,44 @@
+use alloc::string::ToString;
+use casper_types::U512;
+use common_lib::{
+	constants::common_keys::TreasuryKeys,
+	db::{ dictionary::Dictionary, traits::Storable },
+	enums::revenue_stream::RevenueStream,
+};
+
+/// Deposits of the revenue stream made while it had no beneficiaries
+pub trait Undistributed {
+	fn undistributed_initialize() -> ();
+	fn undistributed_instance() -> Self;
+	fn add_undistributed(&self, stream: RevenueStream, amount: U512) -> ();
+	/// Returns the held amount and clears it
+	fn take_undistributed(&self, stream: RevenueStream) -> U512;
+	fn get_undistributed(&self, stream: RevenueStream) -> U512;
+}
+
+impl Undistributed for Dictionary {
+	fn undistributed_initialize() -> () {
+		Dictionary::init(&TreasuryKeys::Undistributed.to_string())
+	}
+
+	fn undistributed_instance() -> Self {
+		Dictionary::instance(&TreasuryKeys::Undistributed.to_string())
+	}
+
+	fn add_undistributed(&self, stream: RevenueStream, amount: U512) -> () {
+		let undistributed = self.get_undistributed(stream);
+		self.set(&stream.to_string(), undistributed + amount)
+	}
+
+	fn take_undistributed(&self, stream: RevenueStream) -> U512 {
+		let undistributed = self.get_undistributed(stream);
+		if !undistributed.is_zero() {
+			self.remove::<U512>(&stream.to_string());
+		}
+		undistributed
+	}
+
+	fn get_undistributed(&self, stream: RevenueStream) -> U512 {
+		self.get(&stream.to_string()).unwrap_or_default()
+	}
+}
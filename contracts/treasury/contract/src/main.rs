@@ -0,0 +1,125 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!(
+	"target arch should be wasm32: compile with '--target wasm32-unknown-unknown'"
+);
+
+// Treasury of the name service, the main and nft contracts deposit their CSPR fees
+// split among the beneficiaries of every revenue stream
+
+extern crate alloc;
+
+mod db;
+mod service;
+mod types;
+
+use alloc::{ string::ToString, vec };
+
+use casper_contract::contract_api::{ runtime, storage };
+use casper_types::{ contracts::NamedKeys, ContractHash };
+use common_lib::{
+	constants::common_keys::{ CommonArgs, CommonKeys },
+	enums::{
+		contracts_enum::ContractKind,
+		controller_roles::ControllerRoles,
+		roles::Role,
+	},
+	schema,
+	utils::{
+		contract::setup_contract_info,
+		pause::ensure_not_paused,
+		response::controller,
+	},
+};
+
+#[no_mangle]
+pub extern "C" fn init() {
+	controller(service::init::init, vec![ControllerRoles::OnlyMaintainer])
+}
+
+/// Deposits are taken while the treasury is paused, so fees don't block registrations
+#[no_mangle]
+pub extern "C" fn deposit() {
+	controller(
+		service::treasury::deposit,
+		vec![
+			ControllerRoles::OnlyContract(ContractKind::Main),
+			ControllerRoles::OnlyContract(ContractKind::NFT)
+		]
+	);
+}
+
+#[no_mangle]
+pub extern "C" fn set_beneficiaries() {
+	ensure_not_paused(ContractKind::Treasury, None);
+	controller(
+		service::beneficiaries::set_beneficiaries,
+		vec![ControllerRoles::OnlyRole(Role::TreasuryManager)]
+	);
+}
+
+#[no_mangle]
+pub extern "C" fn get_beneficiaries() {
+	controller(service::beneficiaries::get_beneficiaries, vec![]);
+}
+
+#[no_mangle]
+pub extern "C" fn get_revenue() {
+	controller(service::treasury::get_revenue, vec![]);
+}
+
+#[no_mangle]
+pub extern "C" fn get_balance() {
+	controller(service::treasury::get_balance, vec![]);
+}
+
+#[no_mangle]
+pub extern "C" fn get_undistributed() {
+	controller(service::treasury::get_undistributed, vec![]);
+}
+
+/// The caller withdraws its own balance
+#[no_mangle]
+pub extern "C" fn withdraw() {
+	ensure_not_paused(ContractKind::Treasury, None);
+	controller(service::treasury::withdraw, vec![]);
+}
+
+#[no_mangle]
+pub extern "C" fn migrate() {
+	controller(
+		service::migrate::migrate,
+		vec![ControllerRoles::OnlyMaintainer]
+	);
+}
+
+/**
+ * Endpoints:
+ * 1. init
+ * 2. deposit
+ * 3. set_beneficiaries
+ * 4. get_beneficiaries
+ * 5. get_revenue
+ * 6. get_balance
+ * 7. get_undistributed
+ * 8. withdraw
+ * 9. migrate
+ */
+#[no_mangle]
+pub extern "C" fn call() {
+	let entrypoints = schema::treasury::schema().to_entry_points();
+
+	let mut named_keys = NamedKeys::new();
+
+	let administration_contract_hash: ContractHash = runtime::get_named_arg(
+		&CommonArgs::AdministrationContract.to_string()
+	);
+	named_keys.insert(
+		CommonKeys::AdministrationContract.to_string(),
+		storage::new_uref(administration_contract_hash).into()
+	);
+
+	setup_contract_info(entrypoints, named_keys);
+}
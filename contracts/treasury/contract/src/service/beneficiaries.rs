@@ -0,0 +1,43 @@
+use alloc::{ string::ToString, vec::Vec };
+use casper_contract::contract_api::runtime;
+use common_lib::{
+	constants::common_keys::TreasuryArgs,
+	db::dictionary::Dictionary,
+	enums::revenue_stream::RevenueStream,
+	models::treasury::{ validate_beneficiaries, Beneficiary },
+};
+
+use crate::{
+	db::{ beneficiaries::Beneficiaries, undistributed::Undistributed },
+	service::treasury::credit_beneficiaries,
+	types::TResult,
+};
+
+/// Replaces the beneficiaries of the revenue stream, shares have to add up to 10000 basis points
+/// Balances credited before stay with their beneficiaries,
+/// deposits held while the stream had none are split among the new ones
+/// This service is closed, only maintainer and TREASURY_MANAGER role are passed
+pub fn set_beneficiaries() -> TResult<()> {
+	let stream: RevenueStream = runtime::get_named_arg(
+		&TreasuryArgs::RevenueStream.to_string()
+	);
+	let beneficiaries: Vec<Beneficiary> = runtime::get_named_arg(
+		&TreasuryArgs::Beneficiaries.to_string()
+	);
+	validate_beneficiaries(&beneficiaries)?;
+
+	let undistributed = Dictionary::undistributed_instance().take_undistributed(stream);
+	if !undistributed.is_zero() {
+		credit_beneficiaries(stream, undistributed, &beneficiaries);
+	}
+	Dictionary::beneficiaries_instance().set_beneficiaries(stream, beneficiaries);
+	Ok(())
+}
+
+/// Empty if the beneficiaries of the stream are not set
+pub fn get_beneficiaries() -> TResult<Vec<Beneficiary>> {
+	let stream: RevenueStream = runtime::get_named_arg(
+		&TreasuryArgs::RevenueStream.to_string()
+	);
+	Ok(Dictionary::beneficiaries_instance().get_beneficiaries(stream))
+}
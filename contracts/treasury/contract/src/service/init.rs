@@ -0,0 +1,30 @@
+use alloc::string::ToString;
+use casper_contract::contract_api::{ runtime, system };
+use common_lib::{
+	constants::common_keys::TreasuryKeys,
+	db::dictionary::Dictionary,
+	enums::revenue_stream::RevenueStream,
+};
+
+use crate::db::{
+	balances::Balances,
+	beneficiaries::Beneficiaries,
+	revenue::Revenue,
+	undistributed::Undistributed,
+};
+use crate::types::TResult;
+
+/// Runs in the contract context, creates the treasury purse and dictionaries
+pub fn init() -> TResult<()> {
+	Dictionary::beneficiaries_initialize();
+	Dictionary::revenue_initialize();
+	Dictionary::undistributed_initialize();
+	for stream in RevenueStream::ALL {
+		Dictionary::balances_initialize(stream);
+	}
+	runtime::put_key(
+		&TreasuryKeys::Purse.to_string(),
+		system::create_purse().into()
+	);
+	Ok(())
+}
@@ -0,0 +1,33 @@
+use alloc::string::ToString;
+use casper_contract::contract_api::runtime;
+use common_lib::{
+	constants::common_keys::TreasuryKeys,
+	db::dictionary::Dictionary,
+	utils::upgrade::run_migrations,
+};
+
+use crate::{ db::undistributed::Undistributed, types::TResult };
+
+/// Schema version of the stored beneficiaries and balances
+/// 2 - deposits of streams without beneficiaries are held in the undistributed store
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Runs right after a new contract version is added to the package
+/// This service is closed, only maintainer is passed
+/// Returns the schema version of the stored state
+pub fn migrate() -> TResult<u32> {
+	Ok(
+		run_migrations(SCHEMA_VERSION, |version| {
+			if version == 1 {
+				create_undistributed_store();
+			}
+		})
+	)
+}
+
+/// Helpers
+fn create_undistributed_store() {
+	if runtime::get_key(&TreasuryKeys::Undistributed.to_string()).is_none() {
+		Dictionary::undistributed_initialize();
+	}
+}
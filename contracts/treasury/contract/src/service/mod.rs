@@ -0,0 +1,4 @@
+pub mod beneficiaries;
+pub mod init;
+pub mod migrate;
+pub mod treasury;
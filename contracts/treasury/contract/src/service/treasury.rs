@@ -0,0 +1,129 @@
+use alloc::string::ToString;
+use casper_contract::{
+	contract_api::{ runtime, system },
+	unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{ account::AccountHash, URef, U512 };
+use common_lib::{
+	constants::common_keys::{ TreasuryArgs, TreasuryKeys },
+	db::dictionary::Dictionary,
+	enums::revenue_stream::RevenueStream,
+	errors::TreasuryErrors,
+	models::treasury::{ split_deposit, Beneficiary },
+};
+
+use crate::db::{
+	balances::Balances,
+	beneficiaries::Beneficiaries,
+	revenue::Revenue,
+	undistributed::Undistributed,
+};
+use crate::types::TResult;
+
+/// Moves the amount from the passed purse to the treasury purse and credits
+/// the beneficiaries of the stream by their shares
+/// Streams without beneficiaries hold the amount until they are set, fees never block the payment
+/// This service is closed, only the main and nft contracts are passed
+/// The purse has to be created for the deposit and hold the amount only, a purse holding more
+/// (the purse of a customer or an escrow) is rejected, see `common_lib::utils::treasury::deposit`
+pub fn deposit() -> TResult<()> {
+	let stream: RevenueStream = runtime::get_named_arg(
+		&TreasuryArgs::RevenueStream.to_string()
+	);
+	let amount: U512 = runtime::get_named_arg(&TreasuryArgs::Amount.to_string());
+	let purse: URef = runtime::get_named_arg(&TreasuryArgs::Purse.to_string());
+	if amount.is_zero() {
+		return Err(TreasuryErrors::ZeroDeposit);
+	}
+	if system::get_purse_balance(purse) != Some(amount) {
+		return Err(TreasuryErrors::DepositPurseBalanceMismatch);
+	}
+
+	system::transfer_from_purse_to_purse(
+		purse,
+		get_treasury_purse()?,
+		amount,
+		None
+	).unwrap_or_revert();
+
+	let beneficiaries =
+		Dictionary::beneficiaries_instance().get_beneficiaries(stream);
+	if beneficiaries.is_empty() {
+		Dictionary::undistributed_instance().add_undistributed(stream, amount);
+	} else {
+		credit_beneficiaries(stream, amount, &beneficiaries);
+	}
+	Dictionary::revenue_instance().add_revenue(stream, amount);
+	Ok(())
+}
+
+/// Pays the whole balance of the caller in the stream to the caller
+/// Returns the withdrawn amount
+pub fn withdraw() -> TResult<U512> {
+	let stream: RevenueStream = runtime::get_named_arg(
+		&TreasuryArgs::RevenueStream.to_string()
+	);
+	let account = runtime::get_caller();
+	let balances = Dictionary::balances_instance(stream);
+	let amount = balances.get_balance(account);
+	if amount.is_zero() {
+		return Err(TreasuryErrors::NothingToWithdraw);
+	}
+
+	balances.clear_balance(account);
+	system::transfer_from_purse_to_account(
+		get_treasury_purse()?,
+		account,
+		amount,
+		None
+	).unwrap_or_revert();
+	Ok(amount)
+}
+
+/// Balance the beneficiary can withdraw from the stream
+pub fn get_balance() -> TResult<U512> {
+	let stream: RevenueStream = runtime::get_named_arg(
+		&TreasuryArgs::RevenueStream.to_string()
+	);
+	let beneficiary: AccountHash = runtime::get_named_arg(
+		&TreasuryArgs::Beneficiary.to_string()
+	);
+	Ok(Dictionary::balances_instance(stream).get_balance(beneficiary))
+}
+
+/// Total deposited into the stream
+pub fn get_revenue() -> TResult<U512> {
+	let stream: RevenueStream = runtime::get_named_arg(
+		&TreasuryArgs::RevenueStream.to_string()
+	);
+	Ok(Dictionary::revenue_instance().get_revenue(stream))
+}
+
+/// Deposited into the stream while it had no beneficiaries, not credited to anyone yet
+pub fn get_undistributed() -> TResult<U512> {
+	let stream: RevenueStream = runtime::get_named_arg(
+		&TreasuryArgs::RevenueStream.to_string()
+	);
+	Ok(Dictionary::undistributed_instance().get_undistributed(stream))
+}
+
+pub(crate) fn credit_beneficiaries(
+	stream: RevenueStream,
+	amount: U512,
+	beneficiaries: &[Beneficiary]
+) {
+	let balances = Dictionary::balances_instance(stream);
+	for (account, part) in split_deposit(amount, beneficiaries) {
+		if !part.is_zero() {
+			balances.credit(account, part);
+		}
+	}
+}
+
+/// Helpers
+fn get_treasury_purse() -> TResult<URef> {
+	runtime
+		::get_key(&TreasuryKeys::Purse.to_string())
+		.and_then(|key| key.into_uref())
+		.ok_or(TreasuryErrors::MissingTreasuryPurse)
+}
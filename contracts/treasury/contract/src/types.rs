@@ -0,0 +1,3 @@
+use common_lib::errors::TreasuryErrors;
+
+pub(crate) type TResult<T> = Result<T, TreasuryErrors>;
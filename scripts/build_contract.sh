@@ -1,6 +1,6 @@
 #!/bin/bash
 
-CONTRACT_NAMES=("configuration", "database", "main", "nft-contract", "nft-core", "price-oracle", "registry")
+CONTRACT_NAMES=("configuration", "database", "main", "nft-contract", "nft-core", "price-oracle", "registry", "transfer-filter", "treasury")
 
 echo "${CONTRACT_NAMES[@]}"
 